@@ -0,0 +1,343 @@
+use crate::language::Translator;
+use crate::types::{AlertThresholds, AuthSummary, ContainerOom, DynamicData, PortChange, PortChangeKind};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Alert {
+    HighCpu,
+    CriticalCpu,
+    HighMemory,
+    CriticalMemory,
+    DiskHigh,
+    DiskCritical,
+    DiskFilling { mount: String, seconds: u64 },
+    TcpRetransmits { percent: f64 },
+    ListenDrops { count: u64 },
+    GpuHot { index: usize, temperature: u32 },
+    GpuMemoryFull { index: usize, percent: u32 },
+    GpuMemoryErrors { index: usize, count: u64 },
+    ServiceDown { unit: String },
+    ContainerOom { name: String },
+    AuthFailures { count: usize },
+    PortOpened { port: String },
+    PortClosed { port: String },
+    ClockUnsynced { service: String },
+    ClockDrift { offset_ms: f64 },
+    PluginMetric { metric: String, value: String, critical: bool },
+}
+
+impl Alert {
+    pub fn label(&self, translator: &Translator) -> String {
+        match self {
+            Alert::HighCpu => translator.t("alert.high_cpu"),
+            Alert::CriticalCpu => translator.t("alert.critical_cpu"),
+            Alert::HighMemory => translator.t("alert.high_memory"),
+            Alert::CriticalMemory => translator.t("alert.critical_memory"),
+            Alert::DiskHigh => translator.t("alert.disk_high"),
+            Alert::DiskCritical => translator.t("alert.disk_critical"),
+            Alert::DiskFilling { mount, seconds } => {
+                format!("{} {} ~{}", translator.t("alert.disk_filling"), mount, crate::utils::format_rough_duration(*seconds))
+            }
+            Alert::TcpRetransmits { percent } => format!("{} {:.1}%", translator.t("alert.tcp_retrans"), percent),
+            Alert::ListenDrops { count } => format!("{} {}/min", translator.t("alert.listen_drops"), count),
+            Alert::GpuHot { index, temperature } => {
+                format!("{} GPU {} {}°C", translator.t("alert.gpu_hot"), index, temperature)
+            }
+            Alert::GpuMemoryFull { index, percent } => {
+                format!("{} GPU {} {}%", translator.t("alert.gpu_vram"), index, percent)
+            }
+            Alert::GpuMemoryErrors { index, count } => {
+                format!("{} GPU {} +{}", translator.t("alert.gpu_ecc"), index, count)
+            }
+            Alert::ServiceDown { unit } => format!("{} {}", translator.t("alert.service_down"), unit),
+            Alert::ContainerOom { name } => format!("{} {}", translator.t("alert.container_oom"), name),
+            Alert::AuthFailures { count } => format!("{} {}", translator.t("alert.auth_failures"), count),
+            Alert::PortOpened { port } => format!("{} {}", translator.t("alert.port_opened"), port),
+            Alert::PortClosed { port } => format!("{} {}", translator.t("alert.port_closed"), port),
+            Alert::ClockUnsynced { service } => format!("{} ({})", translator.t("alert.clock_unsynced"), service),
+            Alert::ClockDrift { offset_ms } => format!("{} {:+.0}ms", translator.t("alert.clock_drift"), offset_ms),
+            Alert::PluginMetric { metric, value, critical } => {
+                let level = if *critical { "alert.plugin_critical" } else { "alert.plugin_high" };
+                format!("{} {} {}", translator.t(level), metric, value)
+            }
+        }
+    }
+}
+
+pub fn evaluate(data: &DynamicData, thresholds: &AlertThresholds) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+    let usage = &data.global_usage;
+
+    if usage.cpu > thresholds.cpu.critical {
+        alerts.push(Alert::CriticalCpu);
+    } else if usage.cpu > thresholds.cpu.warn {
+        alerts.push(Alert::HighCpu);
+    }
+
+    let mem_percent = if usage.mem_total > 0 {
+        (usage.mem_used as f64 / usage.mem_total as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    if mem_percent > thresholds.memory.critical as f64 {
+        alerts.push(Alert::CriticalMemory);
+    } else if mem_percent > thresholds.memory.warn as f64 {
+        alerts.push(Alert::HighMemory);
+    }
+
+    let fullest_disk = data.disks.iter()
+        .filter(|d| d.total > 0)
+        .map(|d| (d.used as f64 / d.total as f64 * 100.0) as f32)
+        .fold(0.0, f32::max);
+    if fullest_disk > thresholds.disk.critical {
+        alerts.push(Alert::DiskCritical);
+    } else if fullest_disk > thresholds.disk.warn {
+        alerts.push(Alert::DiskHigh);
+    }
+
+    // Bind mounts of one device fill up together; one alert is enough.
+    let mut devices = std::collections::HashSet::new();
+    for disk in &data.disks {
+        let Some(seconds) = disk.time_to_full.filter(|_| thresholds.disk_full_hours > 0) else { continue };
+        if seconds <= thresholds.disk_full_hours as u64 * 3600 && devices.insert(&disk.device) {
+            alerts.push(Alert::DiskFilling { mount: disk.name.clone(), seconds });
+        }
+    }
+
+    if let Some(tcp) = data.tcp.as_ref().filter(|_| thresholds.tcp_retrans_percent > 0) {
+        if let Some(percent) = tcp.retrans_percent.filter(|&percent| percent >= thresholds.tcp_retrans_percent as f64) {
+            alerts.push(Alert::TcpRetransmits { percent });
+        }
+        if tcp.listen_drops_last_minute > 0 {
+            alerts.push(Alert::ListenDrops { count: tcp.listen_drops_last_minute });
+        }
+    }
+
+    if thresholds.auth_failures > 0 {
+        let count = data.auth.recent(chrono::Local::now(), AuthSummary::ALERT_WINDOW);
+        if count >= thresholds.auth_failures as usize {
+            alerts.push(Alert::AuthFailures { count });
+        }
+    }
+
+    if let Some(clock) = data.clock.as_ref().filter(|_| thresholds.clock_offset_ms > 0) {
+        if let Some(service) = clock.service.as_ref().filter(|_| !clock.synchronized) {
+            alerts.push(Alert::ClockUnsynced { service: service.clone() });
+        }
+        if let Some(offset_ms) = clock.offset_ms.filter(|offset| offset.abs() >= thresholds.clock_offset_ms as f64) {
+            alerts.push(Alert::ClockDrift { offset_ms });
+        }
+    }
+
+    let now = chrono::Local::now();
+    for change in data.port_changes.iter().filter(|change| now - change.at <= PortChange::ALERT_WINDOW) {
+        let port = change.port.to_string();
+        alerts.push(match change.kind {
+            PortChangeKind::Opened => Alert::PortOpened { port },
+            PortChangeKind::Closed => Alert::PortClosed { port },
+        });
+    }
+
+    // One alert per container, however often it was killed.
+    let mut killed = std::collections::HashSet::new();
+    for oom in data.container_ooms.iter().rev().filter(|oom| now - oom.at <= ContainerOom::ALERT_WINDOW) {
+        if killed.insert(&oom.name) {
+            alerts.push(Alert::ContainerOom { name: oom.name.clone() });
+        }
+    }
+
+    if let Ok(gpus) = &data.gpus {
+        for (index, gpu) in gpus.iter().enumerate() {
+            if let Some(temperature) = gpu.temperature {
+                if temperature >= thresholds.gpu_temperature {
+                    alerts.push(Alert::GpuHot { index, temperature });
+                }
+            }
+
+            if gpu.memory_total > 0 {
+                let percent = (gpu.memory_used as f64 / gpu.memory_total as f64 * 100.0) as u32;
+                if percent >= thresholds.gpu_memory_percent {
+                    alerts.push(Alert::GpuMemoryFull { index, percent });
+                }
+            }
+
+            if gpu.ecc_growth > 0 {
+                alerts.push(Alert::GpuMemoryErrors { index, count: gpu.ecc_growth });
+            }
+        }
+    }
+
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GpuInfo;
+
+    #[test]
+    fn test_gpu_alerts() {
+        let data = DynamicData {
+            gpus: Ok(vec![
+                GpuInfo { temperature: Some(91), memory_used: 10, memory_total: 100, ..Default::default() },
+                GpuInfo { temperature: None, memory_used: 97, memory_total: 100, ecc_growth: 3, ..Default::default() },
+            ]),
+            ..Default::default()
+        };
+
+        let thresholds = AlertThresholds::default();
+        let alerts = evaluate(&data, &thresholds);
+        assert_eq!(alerts, vec![
+            Alert::GpuHot { index: 0, temperature: 91 },
+            Alert::GpuMemoryFull { index: 1, percent: 97 },
+            Alert::GpuMemoryErrors { index: 1, count: 3 },
+        ]);
+
+        let relaxed = AlertThresholds { gpu_temperature: 95, gpu_memory_percent: 99, ..thresholds };
+        assert_eq!(evaluate(&data, &relaxed), vec![Alert::GpuMemoryErrors { index: 1, count: 3 }]);
+    }
+
+    #[test]
+    fn test_usage_alert_levels() {
+        let mut data = DynamicData::default();
+        data.global_usage.cpu = 75.0;
+        data.global_usage.mem_used = 95;
+        data.global_usage.mem_total = 100;
+        let thresholds = AlertThresholds::default();
+        assert_eq!(evaluate(&data, &thresholds), vec![Alert::HighCpu, Alert::CriticalMemory]);
+
+        let strict = AlertThresholds { cpu: "50,70".parse().unwrap(), memory: "96,99".parse().unwrap(), ..thresholds };
+        assert_eq!(evaluate(&data, &strict), vec![Alert::CriticalCpu]);
+
+        assert!("90,80".parse::<crate::types::AlertLevels>().is_err());
+        assert!("80".parse::<crate::types::AlertLevels>().is_err());
+        assert!("80,120".parse::<crate::types::AlertLevels>().is_err());
+    }
+
+    #[test]
+    fn test_disk_filling_alert() {
+        use crate::types::DetailedDiskInfo;
+
+        let disk = |name: &str, device: &str, time_to_full| DetailedDiskInfo {
+            name: name.to_string(),
+            device: device.to_string(),
+            time_to_full,
+            ..Default::default()
+        };
+        let data = DynamicData {
+            disks: vec![
+                disk("/var", "/dev/sda2", Some(5 * 3600)),
+                disk("/srv/bind", "/dev/sda2", Some(5 * 3600)),
+                disk("/home", "/dev/sda3", Some(9 * 86400)),
+                disk("/", "/dev/sda1", None),
+            ],
+            ..Default::default()
+        };
+        let thresholds = AlertThresholds::default();
+        assert_eq!(evaluate(&data, &thresholds), vec![Alert::DiskFilling { mount: "/var".to_string(), seconds: 5 * 3600 }]);
+        assert_eq!(evaluate(&data, &AlertThresholds { disk_full_hours: 10 * 24, ..thresholds }).len(), 2);
+        assert!(evaluate(&data, &AlertThresholds { disk_full_hours: 0, ..thresholds }).is_empty());
+    }
+
+    #[test]
+    fn test_tcp_alerts() {
+        let tcp = crate::types::TcpStats { retrans_percent: Some(7.5), listen_drops_last_minute: 12, ..Default::default() };
+        let mut data = DynamicData { tcp: Some(tcp), ..Default::default() };
+        let thresholds = AlertThresholds::default();
+        assert_eq!(evaluate(&data, &thresholds), vec![Alert::TcpRetransmits { percent: 7.5 }, Alert::ListenDrops { count: 12 }]);
+        assert_eq!(evaluate(&data, &AlertThresholds { tcp_retrans_percent: 10, ..thresholds }), vec![Alert::ListenDrops { count: 12 }]);
+        assert!(evaluate(&data, &AlertThresholds { tcp_retrans_percent: 0, ..thresholds }).is_empty());
+
+        // Too little traffic to judge.
+        data.tcp = Some(crate::types::TcpStats::default());
+        assert!(evaluate(&data, &thresholds).is_empty());
+    }
+
+    #[test]
+    fn test_container_oom_alerts() {
+        let oom = |minutes_ago: i64, name: &str| ContainerOom {
+            at: chrono::Local::now() - chrono::Duration::minutes(minutes_ago),
+            name: name.to_string(),
+        };
+        let data = DynamicData {
+            container_ooms: [oom(20, "db"), oom(3, "web"), oom(1, "web"), oom(0, "worker")].into(),
+            ..Default::default()
+        };
+        assert_eq!(evaluate(&data, &AlertThresholds::default()), vec![
+            Alert::ContainerOom { name: "worker".to_string() },
+            Alert::ContainerOom { name: "web".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn test_auth_failure_alert() {
+        let failure = crate::types::FailedLogin {
+            at: chrono::Local::now(),
+            service: crate::types::AuthService::Ssh,
+            user: "root".to_string(),
+            source: "203.0.113.9".to_string(),
+        };
+        let mut data = DynamicData::default();
+        data.auth.failures = std::iter::repeat_n(failure, 5).collect();
+
+        let thresholds = AlertThresholds { auth_failures: 5, ..Default::default() };
+        assert_eq!(evaluate(&data, &thresholds), vec![Alert::AuthFailures { count: 5 }]);
+        assert!(evaluate(&data, &AlertThresholds { auth_failures: 6, ..thresholds }).is_empty());
+        assert!(evaluate(&data, &AlertThresholds { auth_failures: 0, ..thresholds }).is_empty());
+    }
+
+    #[test]
+    fn test_clock_alerts() {
+        use crate::types::ClockStatus;
+
+        let clock = ClockStatus {
+            service: Some("chrony".to_string()),
+            synchronized: true,
+            offset_ms: Some(-250.0),
+            detail: String::new(),
+        };
+        let data = DynamicData { clock: Some(clock.clone()), ..Default::default() };
+        let thresholds = AlertThresholds::default();
+        assert_eq!(evaluate(&data, &thresholds), vec![Alert::ClockDrift { offset_ms: -250.0 }]);
+        assert!(evaluate(&data, &AlertThresholds { clock_offset_ms: 500, ..thresholds }).is_empty());
+
+        let lost = DynamicData { clock: Some(ClockStatus { synchronized: false, offset_ms: None, ..clock.clone() }), ..Default::default() };
+        assert_eq!(evaluate(&lost, &thresholds), vec![Alert::ClockUnsynced { service: "chrony".to_string() }]);
+        assert!(evaluate(&lost, &AlertThresholds { clock_offset_ms: 0, ..thresholds }).is_empty());
+
+        // Nothing is meant to keep the clock in sync.
+        let unmanaged = DynamicData { clock: Some(ClockStatus { service: None, synchronized: false, offset_ms: None, ..clock }), ..Default::default() };
+        assert!(evaluate(&unmanaged, &thresholds).is_empty());
+    }
+
+    #[test]
+    fn test_port_change_alerts() {
+        use crate::types::{ListeningPort, PortChange, PortChangeKind};
+
+        let now = chrono::Local::now();
+        let change = |minutes_ago: i64, kind: PortChangeKind, port: u16| PortChange {
+            at: now - chrono::Duration::minutes(minutes_ago),
+            kind,
+            port: ListeningPort {
+                protocol: "tcp".to_string(),
+                address: std::net::Ipv4Addr::UNSPECIFIED.into(),
+                port,
+                inode: 1,
+                process: Some((42, "nc".to_string())),
+            },
+        };
+        let data = DynamicData {
+            port_changes: [
+                change(30, PortChangeKind::Opened, 22),
+                change(1, PortChangeKind::Opened, 4444),
+                change(0, PortChangeKind::Closed, 80),
+            ].into(),
+            ..Default::default()
+        };
+
+        assert_eq!(evaluate(&data, &AlertThresholds::default()), vec![
+            Alert::PortOpened { port: "tcp 0.0.0.0:4444 (nc, pid 42)".to_string() },
+            Alert::PortClosed { port: "tcp 0.0.0.0:80 (nc, pid 42)".to_string() },
+        ]);
+    }
+}
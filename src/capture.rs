@@ -0,0 +1,283 @@
+// Scheduled capture windows. While the TUI runs, a separate system collector
+// records snapshots at a faster interval during each configured window, so a
+// nightly job can be looked at second by second without raising the UI
+// refresh rate. Captures use the `record` format and play back with `replay`.
+// The retention limits rotate a long capture and delete old ones.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::{Datelike, Local, NaiveDateTime, NaiveTime, Weekday};
+use tokio::time::Instant;
+
+use crate::headless::{headless_control, RecordingWriter};
+use crate::monitors::DataCollector;
+use crate::retention::{self, Retention};
+use crate::types::AppConfig;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+const IDLE_CHECK: Duration = Duration::from_secs(1);
+
+// "[DAYS ]HH:MM-HH:MM[@INTERVAL]", e.g. "02:00-03:00@1s" or
+// "mon-fri 12:00-12:30@500ms". A window ending before it starts runs past
+// midnight; DAYS name the day it starts on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaptureWindow {
+    // Bit n set for n days from Monday; all set when no days were given.
+    days: u8,
+    start: NaiveTime,
+    end: NaiveTime,
+    pub interval: Duration,
+    spec: String,
+}
+
+impl CaptureWindow {
+    pub fn contains(&self, at: NaiveDateTime) -> bool {
+        let time = at.time();
+        let day = at.weekday();
+        if self.start < self.end {
+            self.on(day) && time >= self.start && time < self.end
+        } else if self.start > self.end {
+            (self.on(day) && time >= self.start) || (self.on(day.pred()) && time < self.end)
+        } else {
+            self.on(day)
+        }
+    }
+
+    fn on(&self, day: Weekday) -> bool {
+        self.days & (1 << day.num_days_from_monday()) != 0
+    }
+}
+
+impl fmt::Display for CaptureWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.spec)
+    }
+}
+
+impl FromStr for CaptureWindow {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let spec = s.trim();
+        let (days, rest) = match spec.rsplit_once(char::is_whitespace) {
+            Some((days, rest)) => (parse_days(days.trim())?, rest),
+            None => (0x7f, spec),
+        };
+        let (range, interval) = match rest.split_once('@') {
+            Some((range, interval)) => (range, parse_interval(interval)?),
+            None => (rest, DEFAULT_INTERVAL),
+        };
+        let (start, end) = range.split_once('-')
+            .ok_or_else(|| format!("invalid capture window '{}', expected [DAYS ]HH:MM-HH:MM[@INTERVAL]", spec))?;
+
+        Ok(Self {
+            days,
+            start: parse_time(start)?,
+            end: parse_time(end)?,
+            interval: interval.max(MIN_INTERVAL),
+            spec: spec.to_string(),
+        })
+    }
+}
+
+fn parse_time(s: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(s.trim(), "%H:%M").map_err(|_| format!("invalid time '{}', expected HH:MM", s))
+}
+
+fn parse_day(s: &str) -> Result<Weekday, String> {
+    s.parse::<Weekday>().map_err(|_| format!("invalid day '{}'", s))
+}
+
+// "mon-fri", "sat,sun" or "*".
+fn parse_days(s: &str) -> Result<u8, String> {
+    if s == "*" {
+        return Ok(0x7f);
+    }
+    let mut days = 0u8;
+    for part in s.split(',') {
+        match part.split_once('-') {
+            Some((first, last)) => {
+                let (mut day, last) = (parse_day(first)?, parse_day(last)?);
+                days |= 1 << day.num_days_from_monday();
+                while day != last {
+                    day = day.succ();
+                    days |= 1 << day.num_days_from_monday();
+                }
+            }
+            None => days |= 1 << parse_day(part)?.num_days_from_monday(),
+        }
+    }
+    Ok(days)
+}
+
+// "500ms", "2s", "1m" or plain seconds.
+fn parse_interval(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let invalid = || format!("invalid capture interval '{}'", s);
+    let (number, unit) = s.find(|c: char| !c.is_ascii_digit())
+        .map_or((s, "s"), |split| s.split_at(split));
+    let number: u64 = number.parse().map_err(|_| invalid())?;
+    match unit {
+        "ms" => Ok(Duration::from_millis(number)),
+        "s" => Ok(Duration::from_secs(number)),
+        "m" => Ok(Duration::from_secs(number * 60)),
+        _ => Err(invalid()),
+    }
+}
+
+struct Capture {
+    writer: RecordingWriter,
+    path: PathBuf,
+    started: Instant,
+    count: usize,
+}
+
+enum CaptureState {
+    Idle,
+    Capturing(usize, Capture),
+    // The file couldn't be written; wait for the window to end.
+    Failed(usize),
+}
+
+fn open_capture(dir: &Path, system_info: Vec<(String, String)>, interval: Duration, retention: Retention) -> Result<Capture, Box<dyn std::error::Error>> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("capture-{}.ndjson", Local::now().format("%Y%m%d-%H%M%S")));
+    let writer = RecordingWriter::create(&path, system_info, interval.as_millis() as u64, retention)?;
+    // Earlier captures, and parts of this one once it rotates.
+    retention::prune(dir, |name| name.starts_with("capture-"), &retention, Some(&path));
+    Ok(Capture { writer, path, started: Instant::now(), count: 0 })
+}
+
+fn finish(mut capture: Capture) {
+    match capture.writer.flush() {
+        Ok(()) => log::info!("Capture finished: {} snapshots in {}", capture.count, capture.path.display()),
+        Err(e) => log::error!("Could not finish capture {}: {}", capture.path.display(), e),
+    }
+}
+
+pub fn default_dir() -> Option<PathBuf> {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .map(|dir| dir.join("puls").join("captures"))
+}
+
+pub async fn capture_loop(config: AppConfig, windows: Vec<CaptureWindow>, dir: PathBuf) {
+    let collector = DataCollector::new(config.clone());
+    let system_info = collector.get_system_info();
+    let (mut system, _, _) = collector.into_collectors();
+    let query = headless_control(&config).query;
+    let mut state = CaptureState::Idle;
+
+    loop {
+        let now = Local::now().naive_local();
+        let active = windows.iter().enumerate()
+            .filter(|(_, window)| window.contains(now))
+            .min_by_key(|(_, window)| window.interval)
+            .map(|(index, _)| index);
+
+        state = match (state, active) {
+            (CaptureState::Capturing(_, capture), None) => {
+                finish(capture);
+                CaptureState::Idle
+            }
+            (CaptureState::Capturing(index, capture), Some(active)) if index != active => {
+                finish(capture);
+                CaptureState::Idle
+            }
+            (CaptureState::Failed(_), None) => CaptureState::Idle,
+            (CaptureState::Failed(index), Some(active)) if index != active => CaptureState::Idle,
+            (state, _) => state,
+        };
+
+        let Some(index) = active else {
+            tokio::time::sleep(IDLE_CHECK).await;
+            continue;
+        };
+        let window = &windows[index];
+
+        if matches!(state, CaptureState::Idle) {
+            state = match open_capture(&dir, system_info.clone(), window.interval, config.retention) {
+                Ok(capture) => {
+                    log::info!("Capture window {} started, recording every {:?} to {}", window, window.interval, capture.path.display());
+                    CaptureState::Capturing(index, capture)
+                }
+                Err(e) => {
+                    log::error!("Could not start capture window {}: {}", window, e);
+                    CaptureState::Failed(index)
+                }
+            };
+        }
+
+        let next = Instant::now() + window.interval;
+        if let CaptureState::Capturing(index, mut capture) = state {
+            let query = query.clone();
+            let (returned, update) = match tokio::task::spawn_blocking(move || {
+                let update = system.collect(&query);
+                (system, update)
+            }).await {
+                Ok(result) => result,
+                Err(e) => {
+                    log::error!("Capture collector stopped: {}", e);
+                    finish(capture);
+                    return;
+                }
+            };
+            system = returned;
+
+            let at_ms = capture.started.elapsed().as_millis() as u64;
+            state = match capture.writer.write(at_ms, update) {
+                Ok(()) => {
+                    capture.count += 1;
+                    CaptureState::Capturing(index, capture)
+                }
+                Err(e) => {
+                    log::error!("Capture to {} failed: {}", capture.path.display(), e);
+                    CaptureState::Failed(index)
+                }
+            };
+        }
+        tokio::time::sleep_until(next).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32, time: &str) -> NaiveDateTime {
+        // 2024-01-01 was a Monday.
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap().and_time(NaiveTime::parse_from_str(time, "%H:%M").unwrap())
+    }
+
+    #[test]
+    fn test_capture_window() {
+        let nightly: CaptureWindow = "02:00-03:00@1s".parse().unwrap();
+        assert_eq!(nightly.interval, Duration::from_secs(1));
+        assert!(nightly.contains(at(3, "02:00")));
+        assert!(nightly.contains(at(7, "02:59")));
+        assert!(!nightly.contains(at(3, "03:00")));
+        assert!(!nightly.contains(at(3, "01:59")));
+
+        let weekdays: CaptureWindow = "mon-fri 23:30-00:30@500ms".parse().unwrap();
+        assert_eq!(weekdays.interval, Duration::from_millis(500));
+        assert!(weekdays.contains(at(5, "23:45")));
+        // Friday's window runs into Saturday, but none starts on Saturday.
+        assert!(weekdays.contains(at(6, "00:15")));
+        assert!(!weekdays.contains(at(6, "23:45")));
+        assert!(!weekdays.contains(at(1, "00:15")));
+
+        let weekend: CaptureWindow = "sat,sun 12:00-12:00".parse().unwrap();
+        assert!(weekend.contains(at(7, "08:00")));
+        assert!(!weekend.contains(at(1, "08:00")));
+
+        assert!("02:00".parse::<CaptureWindow>().is_err());
+        assert!("02:00-25:00".parse::<CaptureWindow>().is_err());
+        assert!("funday 02:00-03:00".parse::<CaptureWindow>().is_err());
+        assert!("02:00-03:00@fast".parse::<CaptureWindow>().is_err());
+    }
+}
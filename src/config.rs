@@ -1,66 +1,469 @@
 #![allow(dead_code)]
 
-use clap::Parser;
-use crate::types::AppConfig;
+use clap::builder::BoolishValueParser;
+use clap::{Parser, Subcommand, ValueEnum};
+use crate::types::{AlertLevels, AlertThresholds, AppConfig, ProcessSortBy};
+use crate::capture::CaptureWindow;
+use crate::retention::{FileAge, FileSize, Retention};
+use crate::watchdog::WatchRule;
 use crate::language::Language;
+use crate::ui::colors::ColorDepth;
+use crate::ui::graphics::GraphicsProtocol;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 #[command(name = "puls")]
 #[command(about = "A unified system monitoring and management tool for Linux")]
 pub struct Cli {
-    #[arg(short, long, default_value_t = false)]
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    
+    /// Skip the TUI: collect once, print a summary and exit (the default when stdout is not a terminal)
+    #[arg(long, value_enum, value_name = "FORMAT", num_args = 0..=1, default_missing_value = "text")]
+    pub once: Option<OnceFormat>,
+    
+    /// Start the TUI even when stdout is not a terminal
+    #[arg(long, conflicts_with = "once")]
+    pub tui: bool,
+    
+    #[arg(short, long, global = true, default_value_t = false, env = "PULS_SAFE", value_parser = BoolishValueParser::new())]
     pub safe: bool,
     
-    #[arg(short, long, default_value_t = 1000)]
-    pub refresh: u64,
+    #[arg(short, long, global = true, env = "PULS_REFRESH")]
+    pub refresh: Option<u64>,
+    
+    #[arg(long, global = true, env = "PULS_HISTORY")]
+    pub history: Option<usize>,
+    
+    #[arg(long, global = true, value_enum, default_value_t = ProfileMode::Auto, env = "PULS_PROFILE")]
+    pub profile: ProfileMode,
+    
+    /// View to open on startup (default: the one active when puls last exited)
+    #[arg(long, global = true, value_enum, env = "PULS_TAB")]
+    pub tab: Option<Tab>,
+    
+    /// Start with this process selected and its detail view open
+    #[arg(long, global = true)]
+    pub pid: Option<u32>,
     
-    #[arg(long, default_value_t = 60)]
-    pub history: usize,
+    /// Only show processes whose name or PID contains this text (default: the filter active when puls last exited)
+    #[arg(long, global = true)]
+    pub filter: Option<String>,
     
-    #[arg(long, default_value_t = false)]
+    /// Initial process sort order, highest first (default: the sort active when puls last exited)
+    #[arg(long, global = true, value_enum, env = "PULS_SORT")]
+    pub sort: Option<SortKey>,
+    
+    /// Start with the default tab, sort, filter, theme and columns instead of those saved when puls last exited,
+    /// and leave the saved ones untouched
+    #[arg(long, global = true, default_value_t = false, env = "PULS_NO_RESTORE", value_parser = BoolishValueParser::new())]
+    pub no_restore: bool,
+    
+    /// Disable every action that changes the system (kill, freeze, priorities, CPU limits, service control,
+    /// config edits, container removal, the service watchdog), whatever the privileges
+    #[arg(long, global = true, default_value_t = false, env = "PULS_READ_ONLY", value_parser = BoolishValueParser::new())]
+    pub read_only: bool,
+    
+    #[arg(long, global = true, default_value_t = false, env = "PULS_SHOW_SYSTEM", value_parser = BoolishValueParser::new())]
     pub show_system: bool,
     
-    #[arg(long, default_value_t = false)]
+    #[arg(long, global = true, default_value_t = false, env = "PULS_NO_DOCKER", value_parser = BoolishValueParser::new())]
     pub no_docker: bool,
     
-    #[arg(long, default_value_t = false)]
+    #[arg(long, global = true, default_value_t = false, env = "PULS_NO_GPU", value_parser = BoolishValueParser::new())]
     pub no_gpu: bool,
     
-    #[arg(long, default_value_t = false)]
+    #[arg(long, global = true, default_value_t = false, env = "PULS_NO_NETWORK", value_parser = BoolishValueParser::new())]
     pub no_network: bool,
     
-    #[arg(long, default_value_t = false)]
+    /// host:port the network tab connects to as a reachability check, e.g. 1.1.1.1:443 (off unless given)
+    #[arg(long, global = true, env = "PULS_REACH_TARGET")]
+    pub reach_target: Option<String>,
+    
+    /// Look up the public IP address for the network tab (asks api.ipify.org every 10 minutes)
+    #[arg(long, global = true, default_value_t = false, env = "PULS_PUBLIC_IP", value_parser = BoolishValueParser::new())]
+    pub public_ip: bool,
+    
+    #[arg(long, global = true, default_value_t = false, env = "PULS_AUTO_SCROLL", value_parser = BoolishValueParser::new())]
     pub auto_scroll: bool,
     
-    #[arg(long, default_value = "en")]
-    pub lang: String,
+    /// Interface language: en, tr, de, es, fr, pt, zh, ja, or any code with a file in ~/.config/puls/lang/<code>.toml
+    /// (default: detected from LC_ALL, LC_MESSAGES or LANG)
+    #[arg(long, global = true, env = "PULS_LANG")]
+    pub lang: Option<String>,
+    
+    /// Colour theme (default: nord, or the one active when puls last exited)
+    #[arg(long, global = true, value_enum, env = "PULS_THEME")]
+    pub theme: Option<ThemeName>,
+    
+    /// Colour depth to draw with (default: detected from COLORTERM and terminfo)
+    #[arg(long, global = true, value_enum, default_value_t = ColorMode::Auto, env = "PULS_COLOR")]
+    pub color: ColorMode,
     
-    #[arg(long, default_value_t = false)]
+    /// Draw history charts as images on terminals with kitty graphics or sixel support
+    #[arg(long, global = true, value_enum, default_value_t = GraphicsMode::Off, env = "PULS_GRAPHICS")]
+    pub graphics: GraphicsMode,
+    
+    #[arg(long, global = true, default_value_t = false)]
     pub tr: bool,
     
-    #[arg(short, long, default_value_t = false)]
+    #[arg(short, long, global = true, default_value_t = false, env = "PULS_VERBOSE", value_parser = BoolishValueParser::new())]
     pub verbose: bool,
+    
+    /// CPU usage warning and critical levels, as WARN,CRITICAL percentages
+    #[arg(long, global = true, default_value = "70,85", env = "PULS_CPU_ALERT")]
+    pub cpu_alert: AlertLevels,
+    
+    /// Memory usage warning and critical levels, as WARN,CRITICAL percentages
+    #[arg(long, global = true, default_value = "80,90", env = "PULS_MEM_ALERT")]
+    pub mem_alert: AlertLevels,
+    
+    /// Fullest disk's usage warning and critical levels, as WARN,CRITICAL percentages
+    #[arg(long, global = true, default_value = "85,95", env = "PULS_DISK_ALERT")]
+    pub disk_alert: AlertLevels,
+    
+    #[arg(long, global = true, default_value_t = 85, env = "PULS_GPU_TEMP_ALERT")]
+    pub gpu_temp_alert: u32,
+    
+    #[arg(long, global = true, default_value_t = 95, env = "PULS_GPU_VRAM_ALERT")]
+    pub gpu_vram_alert: u32,
+    
+    /// Failed SSH/sudo authentications within 10 minutes that raise an alert (0 disables)
+    #[arg(long, global = true, default_value_t = 20, env = "PULS_AUTH_ALERT")]
+    pub auth_alert: u32,
+    
+    /// Clock offset from the time source in milliseconds that raises an alert; lost NTP sync alerts too (0 disables)
+    #[arg(long, global = true, default_value_t = 100, env = "PULS_CLOCK_ALERT")]
+    pub clock_alert: u32,
+    
+    /// Hours within which a growing mount is projected to fill up that raise an alert (0 disables)
+    #[arg(long, global = true, default_value_t = 24, env = "PULS_DISK_FULL_ALERT")]
+    pub disk_full_alert: u32,
+    
+    /// TCP retransmission percentage that raises an alert; listen drops alert too unless 0
+    #[arg(long, global = true, default_value_t = 5, env = "PULS_TCP_ALERT")]
+    pub tcp_alert: u32,
+    
+    /// Minutes of steady memory growth before a process is listed as a leak suspect
+    #[arg(long, global = true, default_value_t = 10, env = "PULS_LEAK_WINDOW")]
+    pub leak_window: u64,
+    
+    /// Restart a service whenever it goes inactive, at most N times per hour (default 3); repeatable
+    #[arg(long = "watch-service", global = true, value_name = "UNIT[:N]", env = "PULS_WATCH_SERVICE", value_delimiter = ',')]
+    pub watch_services: Vec<WatchRule>,
+    
+    /// Record snapshots at a faster interval during a daily window, as [DAYS ]HH:MM-HH:MM[@INTERVAL]
+    /// (e.g. "02:00-03:00@1s" or "mon-fri 12:00-12:30@500ms"); repeatable
+    #[arg(long = "capture", global = true, value_name = "WINDOW", env = "PULS_CAPTURE", value_delimiter = ';')]
+    pub capture_windows: Vec<CaptureWindow>,
+    
+    /// Directory for capture window recordings (default: ~/.local/state/puls/captures)
+    #[arg(long, global = true, env = "PULS_CAPTURE_DIR")]
+    pub capture_dir: Option<PathBuf>,
+    
+    /// Rotate `record` output and capture recordings once they reach this size (e.g. 100M, 2G)
+    #[arg(long, global = true, value_name = "SIZE", env = "PULS_MAX_FILE_SIZE")]
+    pub max_file_size: Option<FileSize>,
+    
+    /// Delete rotated parts and capture recordings older than this (e.g. 12h, 7d)
+    #[arg(long, global = true, value_name = "AGE", env = "PULS_MAX_AGE")]
+    pub max_age: Option<FileAge>,
+    
+    /// Rotated parts of a recording, and finished capture recordings, to keep
+    #[arg(long, global = true, value_name = "N", env = "PULS_KEEP_FILES")]
+    pub keep_files: Option<usize>,
+    
+    /// Show create/modify/delete events for a file or the entries of a directory on the Disks tab; repeatable
+    #[arg(long = "watch-path", global = true, value_name = "PATH", env = "PULS_WATCH_PATH", value_delimiter = ',')]
+    pub watch_paths: Vec<PathBuf>,
+    
+    /// File `E` in the TUI writes the live metrics to as JSON, replacing it each time
+    /// (default: a new puls-metrics-<timestamp>.json in the working directory)
+    #[arg(long, global = true, value_name = "PATH", env = "PULS_EXPORT")]
+    pub export: Option<PathBuf>,
+    
+    /// Directory of plugin executables whose JSON output is shown on the System tab (default: ~/.config/puls/plugins)
+    #[arg(long, global = true, env = "PULS_PLUGIN_DIR")]
+    pub plugin_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Interactive terminal UI (the default)
+    Tui,
+    /// Print one JSON snapshot of all metrics and exit
+    Snapshot,
+    /// Compare two `puls snapshot` files: new and gone processes, memory/CPU, disk and service changes
+    Diff {
+        before: PathBuf,
+        after: PathBuf,
+    },
+    /// Record collector updates to a file for later replay
+    Record {
+        output: PathBuf,
+        /// Stop after this many seconds (default: until interrupted)
+        #[arg(long)]
+        duration: Option<u64>,
+    },
+    /// Play a recording back in the terminal UI
+    Replay {
+        input: PathBuf,
+        #[arg(long, default_value_t = 1.0)]
+        speed: f64,
+    },
+    /// Serve the latest metrics as JSON over HTTP
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:9184", env = "PULS_LISTEN")]
+        listen: String,
+    },
+    /// Show which process and container listen on a port, and exit non-zero if none does
+    Port {
+        port: u16,
+    },
+    /// Write a system report (hardware, top processes, disks, network, failed services, recent errors, GPU) for support tickets
+    Report {
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+        /// Write to this file instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Configuration tools
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// Report which monitoring backends are available and exit non-zero if any enabled one is not
+    Check,
+    /// Print a shell completion script to stdout
+    Completions {
+        shell: clap_complete::Shell,
+    },
+    /// Kill a process and its descendants, if PID still belongs to the process started at START_TIME
+    /// (how the TUI kills a tree through sudo or pkexec)
+    #[command(hide = true)]
+    KillTree {
+        pid: u32,
+        /// Seconds since the epoch
+        start_time: u64,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum SortKey {
+    Cpu,
+    #[value(alias = "memory")]
+    Mem,
+    Swap,
+    Name,
+    Pid,
+    General,
+}
+
+impl From<SortKey> for ProcessSortBy {
+    fn from(key: SortKey) -> Self {
+        match key {
+            SortKey::Cpu => ProcessSortBy::Cpu,
+            SortKey::Mem => ProcessSortBy::Memory,
+            SortKey::Swap => ProcessSortBy::Swap,
+            SortKey::Name => ProcessSortBy::Name,
+            SortKey::Pid => ProcessSortBy::Pid,
+            SortKey::General => ProcessSortBy::General,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum ConfigAction {
+    /// Report PULS_* variables, translation files and saved state that puls would ignore, and exit non-zero on errors
+    Check,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum OnceFormat {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum ReportFormat {
+    Text,
+    #[value(alias = "md")]
+    Markdown,
+    Html,
+}
+
+// Same order as ThemeManager::from_index.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    Nord,
+    Dark,
+    Light,
+    Matrix,
+    Solarized,
+    HighContrast,
+}
+
+impl ThemeName {
+    pub const ALL: [ThemeName; 6] = [
+        ThemeName::Nord, ThemeName::Dark, ThemeName::Light,
+        ThemeName::Matrix, ThemeName::Solarized, ThemeName::HighContrast,
+    ];
+    
+    pub fn from_index(index: usize) -> Option<Self> {
+        Self::ALL.get(index).copied()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum ColorMode {
+    Auto,
+    #[value(alias = "24bit")]
+    Truecolor,
+    #[value(name = "256")]
+    Ansi256,
+    #[value(name = "16")]
+    Ansi16,
+}
+
+impl ColorMode {
+    pub fn depth(self) -> ColorDepth {
+        match self {
+            ColorMode::Auto => ColorDepth::detect(),
+            ColorMode::Truecolor => ColorDepth::TrueColor,
+            ColorMode::Ansi256 => ColorDepth::Ansi256,
+            ColorMode::Ansi16 => ColorDepth::Ansi16,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum GraphicsMode {
+    Off,
+    Auto,
+    Kitty,
+    Sixel,
+}
+
+impl GraphicsMode {
+    pub fn protocol(self) -> Option<GraphicsProtocol> {
+        match self {
+            GraphicsMode::Off => None,
+            GraphicsMode::Auto => GraphicsProtocol::detect(),
+            GraphicsMode::Kitty => Some(GraphicsProtocol::Kitty),
+            GraphicsMode::Sixel => Some(GraphicsProtocol::Sixel),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum ProfileMode {
+    Auto,
+    Off,
+}
+
+// Tabs in the order they appear in the tab bar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Tab {
+    Dashboard,
+    Process,
+    Cpu,
+    Memory,
+    Disks,
+    Network,
+    Gpu,
+    System,
+    Services,
+    Logs,
+    Config,
+    Containers,
+    Cgroups,
+    Security,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 14] = [
+        Tab::Dashboard, Tab::Process, Tab::Cpu, Tab::Memory, Tab::Disks, Tab::Network,
+        Tab::Gpu, Tab::System, Tab::Services, Tab::Logs, Tab::Config, Tab::Containers,
+        Tab::Cgroups, Tab::Security,
+    ];
+    
+    pub fn index(self) -> usize {
+        Self::ALL.iter().position(|&t| t == self).unwrap_or(0)
+    }
+    
+    pub fn from_index(index: usize) -> Option<Self> {
+        Self::ALL.get(index).copied()
+    }
 }
 
 impl From<Cli> for AppConfig {
     fn from(cli: Cli) -> Self {
+        let locale = crate::language::detect_locale();
         let language = if cli.tr {
             Language::Turkish
+        } else if let Some(lang) = &cli.lang {
+            Language::from_str(lang)
         } else {
-            Language::from_str(&cli.lang)
+            locale.as_deref().map_or(Language::English, Language::from_locale)
+        };
+        
+        // The profile only fills in what wasn't given on the command line.
+        let profile = match cli.profile {
+            ProfileMode::Off => None,
+            ProfileMode::Auto if cli.safe => Some(PerformanceProfile::safe_mode()),
+            ProfileMode::Auto => Some(PerformanceProfile::detect()),
         };
+        let defaults = profile.clone().unwrap_or_default();
         
         Self {
             safe_mode: cli.safe,
-            refresh_rate_ms: cli.refresh.max(100).min(10000), 
-            history_length: cli.history.max(10).min(300),     
+            refresh_rate_ms: cli.refresh.unwrap_or(defaults.update_interval_ms).max(100).min(10000), 
+            history_length: cli.history.unwrap_or(defaults.history_size).max(10).min(300),     
+            enable_expensive_ops: defaults.enable_expensive_ops,
+            performance_profile: profile.map(|p| p.name),
             enable_docker: !cli.safe && !cli.no_docker,
             enable_gpu_monitoring: !cli.safe && !cli.no_gpu,
             enable_network_monitoring: !cli.safe && !cli.no_network,
             language,
+            locale,
             show_system_processes: cli.show_system,
             auto_scroll: cli.auto_scroll,
+            start_tab: cli.tab,
+            start_pid: cli.pid,
+            theme: cli.theme.map(|theme| theme as usize),
+            color_depth: cli.color.depth(),
+            graphics: cli.graphics.protocol(),
+            process_filter: cli.filter,
+            process_sort: cli.sort.map(ProcessSortBy::from),
+            restore_state: !cli.no_restore,
+            read_only: cli.read_only,
+            alert_thresholds: AlertThresholds {
+                cpu: cli.cpu_alert,
+                memory: cli.mem_alert,
+                disk: cli.disk_alert,
+                gpu_temperature: cli.gpu_temp_alert,
+                gpu_memory_percent: cli.gpu_vram_alert.min(100),
+                auth_failures: cli.auth_alert,
+                clock_offset_ms: cli.clock_alert,
+                disk_full_hours: cli.disk_full_alert,
+                tcp_retrans_percent: cli.tcp_alert,
+            },
+            leak_window: Duration::from_secs(cli.leak_window.max(1) * 60),
+            watch_services: cli.watch_services,
+            capture_windows: cli.capture_windows,
+            capture_dir: cli.capture_dir,
+            retention: Retention { max_size: cli.max_file_size, max_age: cli.max_age, keep: cli.keep_files },
+            watch_paths: cli.watch_paths,
+            plugin_dir: cli.plugin_dir,
+            export_path: cli.export,
+            reach_target: cli.reach_target.filter(|target| target != "off" && !target.is_empty()),
+            public_ip: cli.public_ip,
         }
     }
 }
@@ -87,6 +490,21 @@ impl AppConfig {
         std::time::Duration::from_millis(self.data_refresh_rate_ms())
     }
     
+    // nvidia-smi and the Docker API are the costliest sources; low-end
+    // profiles poll them half as often.
+    fn expensive_ops_factor(&self) -> u64 {
+        if self.enable_expensive_ops { 1 } else { 2 }
+    }
+    
+    pub fn gpu_refresh_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.data_refresh_rate_ms() * self.expensive_ops_factor())
+    }
+    
+    // The Docker API is the slowest source, so poll it half as often.
+    pub fn container_refresh_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.data_refresh_rate_ms() * 2 * self.expensive_ops_factor())
+    }
+    
     pub fn get_operation_timeout(&self) -> std::time::Duration {
         std::time::Duration::from_millis(self.refresh_rate_ms / 2)
     }
@@ -103,7 +521,30 @@ impl Default for AppConfig {
             enable_network_monitoring: true,
             show_system_processes: false,
             auto_scroll: false,
+            start_tab: None,
+            start_pid: None,
+            theme: None,
+            color_depth: ColorDepth::TrueColor,
+            graphics: None,
+            process_filter: None,
+            process_sort: None,
+            restore_state: true,
+            read_only: false,
             language: Language::English,
+            locale: None,
+            alert_thresholds: AlertThresholds::default(),
+            enable_expensive_ops: true,
+            performance_profile: None,
+            leak_window: Duration::from_secs(600),
+            watch_services: Vec::new(),
+            capture_windows: Vec::new(),
+            capture_dir: None,
+            retention: Retention::default(),
+            watch_paths: Vec::new(),
+            plugin_dir: None,
+            export_path: None,
+            reach_target: None,
+            public_ip: false,
         }
     }
 }
@@ -128,7 +569,9 @@ impl Features {
     }
 }
 
+#[derive(Clone, Debug)]
 pub struct PerformanceProfile {
+    pub name: &'static str,
     pub update_interval_ms: u64,
     pub history_size: usize,
     pub enable_expensive_ops: bool,
@@ -136,23 +579,28 @@ pub struct PerformanceProfile {
 
 impl PerformanceProfile {
     pub fn detect() -> Self {
-        let sys = sysinfo::System::new_all();
+        let sys = sysinfo::System::new_with_specifics(
+            sysinfo::RefreshKind::nothing().with_memory(sysinfo::MemoryRefreshKind::nothing().with_ram()),
+        );
         let total_memory_gb = sys.total_memory() / (1024 * 1024 * 1024);
         
         if total_memory_gb >= 16 {
             Self {
+                name: "high",
                 update_interval_ms: 500,
                 history_size: 120,
                 enable_expensive_ops: true,
             }
         } else if total_memory_gb >= 8 {
             Self {
+                name: "balanced",
                 update_interval_ms: 1000,
                 history_size: 60,
                 enable_expensive_ops: true,
             }
         } else {
             Self {
+                name: "low",
                 update_interval_ms: 2000,
                 history_size: 30,
                 enable_expensive_ops: false,
@@ -162,9 +610,73 @@ impl PerformanceProfile {
     
     pub fn safe_mode() -> Self {
         Self {
+            name: "safe",
             update_interval_ms: 2000,
             history_size: 30,
             enable_expensive_ops: false,
         }
     }
-}
\ No newline at end of file
+}
+
+// Used when profiles are switched off: the historical fixed defaults.
+impl Default for PerformanceProfile {
+    fn default() -> Self {
+        Self {
+            name: "default",
+            update_interval_ms: 1000,
+            history_size: 60,
+            enable_expensive_ops: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+    }
+
+    // The only test touching PULS_* variables, so setting them is race free.
+    #[test]
+    fn test_environment_layer() {
+        std::env::set_var("PULS_REFRESH", "2500");
+        std::env::set_var("PULS_NO_DOCKER", "1");
+        std::env::set_var("PULS_THEME", "light");
+
+        let cli = Cli::try_parse_from(["puls"]).unwrap();
+        assert_eq!(cli.refresh, Some(2500));
+        assert!(cli.no_docker);
+        assert_eq!(cli.theme, Some(ThemeName::Light));
+
+        let cli = Cli::try_parse_from(["puls", "--refresh", "500", "--theme", "dark"]).unwrap();
+        assert_eq!(cli.refresh, Some(500));
+        assert_eq!(cli.theme, Some(ThemeName::Dark));
+
+        let cli = Cli::try_parse_from(["puls", "--theme", "high-contrast"]).unwrap();
+        assert_eq!(cli.theme, Some(ThemeName::HighContrast));
+
+        let cli = Cli::try_parse_from(["puls", "--color", "256"]).unwrap();
+        assert_eq!(cli.color.depth(), ColorDepth::Ansi256);
+
+        let config = AppConfig::from(Cli::try_parse_from(["puls", "record", "out.ndjson", "--max-file-size", "1G", "--keep-files", "3"]).unwrap());
+        assert_eq!(config.retention, Retention { max_size: Some(FileSize(1 << 30)), max_age: None, keep: Some(3) });
+
+        std::env::remove_var("PULS_REFRESH");
+        std::env::remove_var("PULS_NO_DOCKER");
+        std::env::remove_var("PULS_THEME");
+    }
+
+    #[test]
+    fn test_tab_indices_match_tab_bar() {
+        for (index, tab) in Tab::ALL.iter().enumerate() {
+            assert_eq!(tab.index(), index);
+            assert_eq!(Tab::from_index(index), Some(*tab));
+        }
+        assert_eq!(Tab::from_index(Tab::ALL.len()), None);
+        assert_eq!(Tab::from_str("network", true), Ok(Tab::Network));
+    }
+}
@@ -0,0 +1,155 @@
+// `puls config check`: looks for settings that puls would otherwise ignore
+// without a word. Flag and PULS_* values are already validated by clap before
+// any subcommand runs, so this covers what clap can't see: misspelled PULS_*
+// variables, translation files that don't parse or use unknown keys, and a
+// corrupt saved state file.
+
+use std::path::Path;
+
+use clap::CommandFactory;
+
+use crate::config::Cli;
+use crate::language::{self, Language, Translator};
+
+#[derive(Debug, PartialEq)]
+enum Finding {
+    Error(String),
+    Warning(String),
+}
+
+// Prints one block per source checked; false when any has an error.
+pub fn run() -> bool {
+    let env: Vec<(String, String)> = std::env::vars().filter(|(name, _)| name.starts_with("PULS_")).collect();
+    let mut sources = vec![(format!("environment ({} PULS_* variables)", env.len()), check_env(&env, &known_env_vars()))];
+
+    if let Some(dir) = language::locale_dir() {
+        let mut files: Vec<_> = std::fs::read_dir(&dir).into_iter().flatten().flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        files.sort();
+        let translator = Translator::new(Language::English);
+        for path in files {
+            sources.push((path.display().to_string(), check_locale(&path, &translator)));
+        }
+    }
+
+    if let Some(path) = crate::state_file::path().filter(|path| path.exists()) {
+        sources.push((path.display().to_string(), check_state(&path)));
+    }
+
+    let mut ok = true;
+    for (source, findings) in sources {
+        if findings.is_empty() {
+            println!("{}: OK", source);
+        } else {
+            println!("{}:", source);
+        }
+        for finding in findings {
+            match finding {
+                Finding::Error(message) => {
+                    ok = false;
+                    println!("  error: {}", message.trim_end().replace('\n', "\n    "));
+                }
+                Finding::Warning(message) => println!("  warning: {}", message),
+            }
+        }
+    }
+    ok
+}
+
+fn known_env_vars() -> Vec<String> {
+    let mut command = Cli::command();
+    command.build();
+    let mut names: Vec<String> = command.get_arguments()
+        .chain(command.get_subcommands().flat_map(|sub| sub.get_arguments()))
+        .filter_map(|arg| arg.get_env())
+        .map(|name| name.to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn check_env(vars: &[(String, String)], known: &[String]) -> Vec<Finding> {
+    vars.iter().filter(|(name, _)| !known.contains(name)).map(|(name, _)| {
+        let closest = known.iter().min_by_key(|candidate| edit_distance(name, candidate));
+        match closest.filter(|candidate| edit_distance(name, candidate) <= 2) {
+            Some(candidate) => Finding::Error(format!("{} is not a puls setting and is ignored; did you mean {}?", name, candidate)),
+            None => Finding::Error(format!("{} is not a puls setting and is ignored", name)),
+        }
+    }).collect()
+}
+
+fn check_locale(path: &Path, translator: &Translator) -> Vec<Finding> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) => return vec![Finding::Error(e.to_string())],
+    };
+    let dict = match language::parse_locale(&contents) {
+        Ok(dict) => dict,
+        // toml's message already quotes the offending line.
+        Err(e) => return vec![Finding::Error(e.to_string())],
+    };
+    let mut unknown: Vec<&String> = dict.keys().filter(|key| !translator.is_known_key(key)).collect();
+    unknown.sort();
+    unknown.into_iter().map(|key| Finding::Warning(format!("unknown key '{}' is never shown", key))).collect()
+}
+
+fn check_state(path: &Path) -> Vec<Finding> {
+    let result = std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| serde_json::from_str::<crate::state_file::SavedState>(&contents)
+            .map_err(|e| format!("{}; puls starts with defaults and overwrites it on exit", e)));
+    result.err().map(Finding::Error).into_iter().collect()
+}
+
+// Levenshtein distance, for suggesting the variable that was meant.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = previous + usize::from(ca != *cb);
+            previous = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_check() {
+        let known = known_env_vars();
+        assert!(known.contains(&"PULS_CPU_ALERT".to_string()));
+        assert!(known.contains(&"PULS_LISTEN".to_string()));
+
+        let vars = [("PULS_CPU_ALERTS".to_string(), "70,85".to_string()), ("PULS_THEME".to_string(), "dark".to_string()),
+            ("PULS_FOO".to_string(), "1".to_string())];
+        assert_eq!(check_env(&vars, &known), vec![
+            Finding::Error("PULS_CPU_ALERTS is not a puls setting and is ignored; did you mean PULS_CPU_ALERT?".to_string()),
+            Finding::Error("PULS_FOO is not a puls setting and is ignored".to_string()),
+        ]);
+
+        let dir = std::env::temp_dir().join(format!("puls-config-check-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let translator = Translator::new(Language::English);
+        std::fs::write(dir.join("xx.toml"), "[title]\ncpu = \"CPU\"\nnot_a_key = \"?\"\n").unwrap();
+        assert_eq!(check_locale(&dir.join("xx.toml"), &translator),
+            vec![Finding::Warning("unknown key 'title.not_a_key' is never shown".to_string())]);
+        std::fs::write(dir.join("yy.toml"), "[title]\ncpu = \"CPU\nmemory = 1\n").unwrap();
+        let broken = check_locale(&dir.join("yy.toml"), &translator);
+        std::fs::write(dir.join("state.json"), "{\"last_tab\": \"nope\"}").unwrap();
+        let state = check_state(&dir.join("state.json"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(&broken[..], [Finding::Error(message)] if message.contains("line 2")));
+        assert!(matches!(&state[..], [Finding::Error(_)]));
+    }
+}
@@ -0,0 +1,281 @@
+// Soft CPU caps for single processes. When the cgroup v2 cpu controller is
+// usable, the process is moved into a cgroup of its own with `cpu.max` set;
+// otherwise a background thread duty-cycles it with SIGSTOP/SIGCONT the way
+// cpulimit does. Either way the limit is lifted when it is dropped, including
+// when PULS exits; `lift_all` covers an exit that skips the drops.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, Signal, System};
+
+use crate::monitors::cgroup_monitor::cgroup_root;
+use crate::monitors::system_monitor::process_start_time;
+
+const PERIOD: Duration = Duration::from_millis(100);
+const CGROUP_PERIOD_USEC: u64 = 100_000;
+
+// Every limit in force, reachable from the signal thread.
+static ACTIVE: Mutex<Vec<Arc<Lift>>> = Mutex::new(Vec::new());
+
+// What it takes to lift one limit, from any thread.
+struct Lift {
+    pid: Pid,
+    stop: AtomicBool,
+    // Set by the duty-cycle thread once it has let the process run for good;
+    // a cgroup limit has no thread to wait for.
+    done: AtomicBool,
+    // The cgroup created for the process and the one it came from.
+    cgroup: Mutex<Option<(PathBuf, PathBuf)>>,
+}
+
+impl Lift {
+    fn new(pid: Pid, cgroup: Option<(PathBuf, PathBuf)>) -> Arc<Self> {
+        let lift = Arc::new(Self {
+            pid,
+            stop: AtomicBool::new(false),
+            done: AtomicBool::new(cgroup.is_some()),
+            cgroup: Mutex::new(cgroup),
+        });
+        ACTIVE.lock().unwrap_or_else(|e| e.into_inner()).push(lift.clone());
+        lift
+    }
+
+    // Safe to call more than once.
+    fn release(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+        let deadline = Instant::now() + PERIOD * 3;
+        while !self.done.load(Ordering::Relaxed) && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        if let Some((dir, original)) = self.cgroup.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            let _ = fs::write(original.join("cgroup.procs"), self.pid.to_string());
+            let _ = fs::remove_dir(dir);
+        }
+    }
+}
+
+// Lifts every limit, for an exit that won't drop them; waits a few duty
+// cycles at most.
+pub fn lift_all() {
+    let active = std::mem::take(&mut *ACTIVE.lock().unwrap_or_else(|e| e.into_inner()));
+    for lift in active {
+        lift.release();
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LimitMethod {
+    Cgroup,
+    DutyCycle,
+}
+
+impl LimitMethod {
+    pub fn label(self) -> &'static str {
+        match self {
+            LimitMethod::Cgroup => "cgroup cpu.max",
+            LimitMethod::DutyCycle => "SIGSTOP/SIGCONT",
+        }
+    }
+}
+
+pub struct CpuLimiter {
+    // Percent of one core.
+    pub percent: u32,
+    pub method: LimitMethod,
+    lift: Arc<Lift>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl CpuLimiter {
+    pub fn start(pid: Pid, percent: u32) -> Result<Self, String> {
+        if !cfg!(unix) {
+            return Err("CPU limiting is not supported on this platform".to_string());
+        }
+        let percent = percent.clamp(1, 100);
+        match move_to_limited_cgroup(pid, percent) {
+            Some(cgroup) => Ok(Self {
+                percent,
+                method: LimitMethod::Cgroup,
+                lift: Lift::new(pid, Some(cgroup)),
+                thread: None,
+            }),
+            None => Self::duty_cycle(pid, percent),
+        }
+    }
+
+    fn duty_cycle(pid: Pid, percent: u32) -> Result<Self, String> {
+        let mut system = System::new();
+        system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, ProcessRefreshKind::nothing());
+        let Some(start_time) = system.process(pid).map(|process| process.start_time()) else {
+            return Err(format!("Process {} not found", pid));
+        };
+
+        let lift = Lift::new(pid, None);
+        let run = PERIOD * percent / 100;
+        let thread = std::thread::spawn({
+            let lift = lift.clone();
+            move || {
+                // Signals only while the PID still names the limited process,
+                // so one that exits and has its PID reused is left alone.
+                let signal = |signal: Signal| {
+                    process_start_time(pid) == Some(start_time)
+                        && system.process(pid).and_then(|process| process.kill_with(signal)) == Some(true)
+                };
+                while !lift.stop.load(Ordering::Relaxed) {
+                    // Stops once the process has exited.
+                    if !signal(Signal::Continue) {
+                        lift.done.store(true, Ordering::Relaxed);
+                        return;
+                    }
+                    std::thread::sleep(run);
+                    if lift.stop.load(Ordering::Relaxed) || !signal(Signal::Stop) {
+                        break;
+                    }
+                    std::thread::sleep(PERIOD - run);
+                }
+                signal(Signal::Continue);
+                lift.done.store(true, Ordering::Relaxed);
+            }
+        });
+
+        Ok(Self {
+            percent,
+            method: LimitMethod::DutyCycle,
+            lift,
+            thread: Some(thread),
+        })
+    }
+
+    // False once the process has exited.
+    pub fn is_active(&self) -> bool {
+        match (&self.thread, &*self.lift.cgroup.lock().unwrap_or_else(|e| e.into_inner())) {
+            (Some(thread), _) => !thread.is_finished(),
+            (None, Some((dir, _))) => fs::read_to_string(dir.join("cgroup.procs")).is_ok_and(|procs| !procs.trim().is_empty()),
+            (None, None) => false,
+        }
+    }
+}
+
+impl Drop for CpuLimiter {
+    fn drop(&mut self) {
+        ACTIVE.lock().unwrap_or_else(|e| e.into_inner()).retain(|lift| !Arc::ptr_eq(lift, &self.lift));
+        self.lift.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        self.lift.release();
+    }
+}
+
+// A leaf cgroup below the root with the cpu controller, holding only this
+// process. Fails without write access to the hierarchy.
+fn move_to_limited_cgroup(pid: Pid, percent: u32) -> Option<(PathBuf, PathBuf)> {
+    let root = cgroup_root()?;
+    let controllers = fs::read_to_string(root.join("cgroup.subtree_control")).ok()?;
+    if !controllers.split_whitespace().any(|c| c == "cpu") {
+        return None;
+    }
+    let membership = fs::read_to_string(format!("/proc/{}/cgroup", pid)).ok()?;
+    let original = root.join(membership.lines().find_map(|line| line.strip_prefix("0::"))?.trim_start_matches('/'));
+
+    let dir = root.join(format!("puls-cpulimit-{}", pid));
+    fs::create_dir(&dir).ok()?;
+    let quota = CGROUP_PERIOD_USEC * percent as u64 / 100;
+    let moved = fs::write(dir.join("cpu.max"), format!("{} {}", quota, CGROUP_PERIOD_USEC))
+        .and_then(|_| fs::write(dir.join("cgroup.procs"), pid.to_string()));
+    if moved.is_err() {
+        let _ = fs::remove_dir(&dir);
+        return None;
+    }
+    Some((dir, original))
+}
+
+// The limits in force, by process. Cloning shares the limiters; the last
+// clone to go lifts them.
+#[derive(Clone, Default)]
+pub struct CpuLimits {
+    limiters: HashMap<Pid, Rc<CpuLimiter>>,
+}
+
+impl CpuLimits {
+    pub fn get(&self, pid: Pid) -> Option<&CpuLimiter> {
+        self.limiters.get(&pid).map(Rc::as_ref)
+    }
+
+    // Replaces any limit already set on the process.
+    pub fn set(&mut self, pid: Pid, percent: u32) -> Result<LimitMethod, String> {
+        self.limiters.remove(&pid);
+        let limiter = CpuLimiter::start(pid, percent)?;
+        let method = limiter.method;
+        self.limiters.insert(pid, Rc::new(limiter));
+        Ok(method)
+    }
+
+    pub fn remove(&mut self, pid: Pid) -> bool {
+        self.limiters.remove(&pid).is_some()
+    }
+
+    // Forgets limiters whose process has exited.
+    pub fn prune(&mut self) {
+        self.limiters.retain(|_, limiter| limiter.is_active());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.limiters.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(pid: u32) -> Option<String> {
+        let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+        Some(stat.rsplit_once(')')?.1.split_whitespace().next()?.to_string())
+    }
+
+    #[test]
+    fn test_duty_cycle_releases_process() {
+        if !cfg!(target_os = "linux") {
+            return;
+        }
+        let mut child = std::process::Command::new("sleep").arg("5").spawn().unwrap();
+        let pid = Pid::from_u32(child.id());
+
+        let limiter = CpuLimiter::duty_cycle(pid, 30).unwrap();
+        std::thread::sleep(Duration::from_millis(250));
+        assert!(limiter.is_active());
+        drop(limiter);
+        // Never left stopped once the limit is lifted.
+        assert_ne!(state(child.id()).as_deref(), Some("T"));
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+        assert!(CpuLimiter::duty_cycle(pid, 30).is_err());
+    }
+
+    #[test]
+    fn test_lift_all_without_drop() {
+        if !cfg!(target_os = "linux") {
+            return;
+        }
+        let mut child = std::process::Command::new("sleep").arg("5").spawn().unwrap();
+        let limiter = CpuLimiter::duty_cycle(Pid::from_u32(child.id()), 10).unwrap();
+        std::thread::sleep(Duration::from_millis(250));
+        lift_all();
+        // Lifted while the limiter is still alive, as on a forced exit.
+        assert!(limiter.lift.done.load(Ordering::Relaxed));
+        assert_ne!(state(child.id()).as_deref(), Some("T"));
+        drop(limiter);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+    }
+}
@@ -0,0 +1,297 @@
+// du-style scans of one mount, run on a thread of their own so the UI keeps
+// drawing. A scan stays on the filesystem it started on, counts allocated
+// blocks the way du does, and keeps only the largest directories.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+pub const TOP_DIRECTORIES: usize = 20;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectoryUsage {
+    pub path: PathBuf,
+    pub bytes: u64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ScanResult {
+    // Largest first; the scanned root is not included.
+    pub largest: Vec<DirectoryUsage>,
+    pub total_bytes: u64,
+    pub files: u64,
+    // Directories that couldn't be read, usually for lack of permission.
+    pub unreadable: u64,
+    pub cancelled: bool,
+}
+
+#[derive(Default)]
+struct Progress {
+    files: AtomicU64,
+    bytes: AtomicU64,
+    current: Mutex<PathBuf>,
+}
+
+pub struct DiskScan {
+    pub root: PathBuf,
+    started: Instant,
+    progress: Arc<Progress>,
+    cancel: Arc<AtomicBool>,
+    thread: Option<JoinHandle<ScanResult>>,
+    result: Option<(ScanResult, Duration)>,
+}
+
+impl DiskScan {
+    pub fn start(root: PathBuf) -> Self {
+        let progress = Arc::new(Progress::default());
+        let cancel = Arc::new(AtomicBool::new(false));
+        let thread = std::thread::spawn({
+            let (root, progress, cancel) = (root.clone(), progress.clone(), cancel.clone());
+            move || scan(&root, TOP_DIRECTORIES, &progress, &cancel)
+        });
+        Self { root, started: Instant::now(), progress, cancel, thread: Some(thread), result: None }
+    }
+
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    // Collects the result once the thread is done; true on the call that does.
+    pub fn poll(&mut self) -> bool {
+        if !self.thread.as_ref().is_some_and(JoinHandle::is_finished) {
+            return false;
+        }
+        let result = self.thread.take().and_then(|thread| thread.join().ok()).unwrap_or_default();
+        self.result = Some((result, self.started.elapsed()));
+        true
+    }
+
+    pub fn result(&self) -> Option<&(ScanResult, Duration)> {
+        self.result.as_ref()
+    }
+
+    // Files and bytes seen so far, the directory being read and the time spent.
+    pub fn progress(&self) -> (u64, u64, PathBuf, Duration) {
+        let current = self.progress.current.lock().map(|path| path.clone()).unwrap_or_default();
+        (
+            self.progress.files.load(Ordering::Relaxed),
+            self.progress.bytes.load(Ordering::Relaxed),
+            current,
+            self.started.elapsed(),
+        )
+    }
+}
+
+impl Drop for DiskScan {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+// The scan on the Disks tab. Cloning shares it, so copies of the app state
+// don't start or stop anything.
+#[derive(Clone, Default)]
+pub struct DiskScanSlot(Option<Rc<std::cell::RefCell<DiskScan>>>);
+
+impl DiskScanSlot {
+    pub fn start(&mut self, root: PathBuf) {
+        self.0 = Some(Rc::new(std::cell::RefCell::new(DiskScan::start(root))));
+    }
+
+    pub fn get(&self) -> Option<std::cell::Ref<'_, DiskScan>> {
+        self.0.as_ref().map(|scan| scan.borrow())
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.get().is_some_and(|scan| scan.result().is_none())
+    }
+
+    // Stops a running scan, or clears the results of a finished one.
+    pub fn cancel_or_close(&mut self) -> bool {
+        match &self.0 {
+            Some(scan) if scan.borrow().result().is_none() => {
+                scan.borrow().cancel();
+                true
+            }
+            Some(_) => {
+                self.0 = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn poll(&self) -> Option<std::cell::Ref<'_, DiskScan>> {
+        let scan = self.0.as_ref()?;
+        if !scan.borrow_mut().poll() {
+            return None;
+        }
+        Some(scan.borrow())
+    }
+}
+
+struct Scanner<'a> {
+    device: u64,
+    progress: &'a Progress,
+    cancel: &'a AtomicBool,
+    seen_links: HashSet<(u64, u64)>,
+    largest: BinaryHeap<Reverse<(u64, PathBuf)>>,
+    limit: usize,
+    unreadable: u64,
+}
+
+fn scan(root: &Path, limit: usize, progress: &Progress, cancel: &AtomicBool) -> ScanResult {
+    let Ok(metadata) = fs::symlink_metadata(root) else {
+        return ScanResult { unreadable: 1, ..Default::default() };
+    };
+    let mut scanner = Scanner {
+        device: device(&metadata),
+        progress,
+        cancel,
+        seen_links: HashSet::new(),
+        largest: BinaryHeap::new(),
+        limit,
+        unreadable: 0,
+    };
+    let total_bytes = scanner.directory(root) + allocated(&metadata);
+
+    let mut largest: Vec<DirectoryUsage> = scanner.largest.into_iter()
+        .map(|Reverse((bytes, path))| DirectoryUsage { path, bytes })
+        .collect();
+    largest.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.path.cmp(&b.path)));
+    ScanResult {
+        largest,
+        total_bytes,
+        files: progress.files.load(Ordering::Relaxed),
+        unreadable: scanner.unreadable,
+        cancelled: cancel.load(Ordering::Relaxed),
+    }
+}
+
+impl Scanner<'_> {
+    // Bytes below `dir`, not counting the directory entry itself.
+    fn directory(&mut self, dir: &Path) -> u64 {
+        if let Ok(mut current) = self.progress.current.lock() {
+            *current = dir.to_path_buf();
+        }
+        let Ok(entries) = fs::read_dir(dir) else {
+            self.unreadable += 1;
+            return 0;
+        };
+
+        let mut total = 0;
+        for entry in entries.flatten() {
+            if self.cancel.load(Ordering::Relaxed) {
+                break;
+            }
+            let Ok(metadata) = entry.metadata() else { continue };
+            // Other filesystems mounted below are scanned on their own.
+            if device(&metadata) != self.device {
+                continue;
+            }
+            let mut bytes = allocated(&metadata);
+            if metadata.is_dir() {
+                let path = entry.path();
+                bytes += self.directory(&path);
+                self.record(path, bytes);
+            } else {
+                if links(&metadata) > 1 && !self.seen_links.insert((device(&metadata), inode(&metadata))) {
+                    continue;
+                }
+                self.progress.files.fetch_add(1, Ordering::Relaxed);
+            }
+            self.progress.bytes.fetch_add(allocated(&metadata), Ordering::Relaxed);
+            total += bytes;
+        }
+        total
+    }
+
+    fn record(&mut self, path: PathBuf, bytes: u64) {
+        if self.largest.len() < self.limit {
+            self.largest.push(Reverse((bytes, path)));
+        } else if self.largest.peek().is_some_and(|Reverse((smallest, _))| bytes > *smallest) {
+            self.largest.pop();
+            self.largest.push(Reverse((bytes, path)));
+        }
+    }
+}
+
+#[cfg(unix)]
+fn allocated(metadata: &fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    metadata.blocks() * 512
+}
+
+#[cfg(not(unix))]
+fn allocated(metadata: &fs::Metadata) -> u64 {
+    metadata.len()
+}
+
+#[cfg(unix)]
+fn device(metadata: &fs::Metadata) -> u64 {
+    std::os::unix::fs::MetadataExt::dev(metadata)
+}
+
+#[cfg(not(unix))]
+fn device(_metadata: &fs::Metadata) -> u64 {
+    0
+}
+
+#[cfg(unix)]
+fn inode(metadata: &fs::Metadata) -> u64 {
+    std::os::unix::fs::MetadataExt::ino(metadata)
+}
+
+#[cfg(not(unix))]
+fn inode(_metadata: &fs::Metadata) -> u64 {
+    0
+}
+
+#[cfg(unix)]
+fn links(metadata: &fs::Metadata) -> u64 {
+    std::os::unix::fs::MetadataExt::nlink(metadata)
+}
+
+#[cfg(not(unix))]
+fn links(_metadata: &fs::Metadata) -> u64 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_finds_largest_directories() {
+        let root = std::env::temp_dir().join(format!("puls-du-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (dir, size) in [("logs/old", 300_000), ("logs", 100_000), ("cache", 50_000), ("empty", 0)] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+            if size > 0 {
+                fs::write(root.join(dir).join("data"), vec![1u8; size]).unwrap();
+            }
+        }
+
+        let progress = Progress::default();
+        let result = scan(&root, 2, &progress, &AtomicBool::new(false));
+        fs::remove_dir_all(&root).unwrap();
+
+        let names: Vec<PathBuf> = result.largest.iter()
+            .map(|usage| usage.path.strip_prefix(&root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(names, vec![PathBuf::from("logs"), PathBuf::from("logs/old")]);
+        assert!(result.largest[0].bytes >= 400_000);
+        assert!(result.total_bytes >= result.largest[0].bytes);
+        assert_eq!(result.files, 3);
+        assert!(!result.cancelled);
+
+        let cancelled = scan(Path::new("/"), 2, &Progress::default(), &AtomicBool::new(true));
+        assert!(cancelled.cancelled);
+    }
+}
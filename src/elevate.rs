@@ -0,0 +1,85 @@
+// Running one action as root from an unprivileged session. Instead of hiding
+// actions that need root, puls offers them as a command for sudo (or pkexec
+// where sudo is missing) and, once confirmed, hands the terminal over so the
+// password prompt can be answered, then returns to the UI with the result.
+
+use std::io::{self, Stdout};
+use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+
+// Set while a command owns the terminal, so the input reader leaves its keys
+// to the password prompt.
+pub static INPUT_PAUSED: AtomicBool = AtomicBool::new(false);
+// How long the input reader waits for a key before checking INPUT_PAUSED.
+pub const INPUT_POLL: Duration = Duration::from_millis(50);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tool {
+    Sudo,
+    Pkexec,
+}
+
+impl Tool {
+    // None on Windows, or when neither is installed.
+    pub fn detect() -> Option<Self> {
+        if !cfg!(unix) {
+            return None;
+        }
+        let path = std::env::var_os("PATH")?;
+        let installed = |name: &str| std::env::split_paths(&path).any(|dir| is_file(&dir.join(name)));
+        if installed("sudo") {
+            Some(Tool::Sudo)
+        } else if installed("pkexec") {
+            Some(Tool::Pkexec)
+        } else {
+            None
+        }
+    }
+
+    pub fn program(self) -> &'static str {
+        match self {
+            Tool::Sudo => "sudo",
+            Tool::Pkexec => "pkexec",
+        }
+    }
+}
+
+fn is_file(path: &Path) -> bool {
+    path.metadata().is_ok_and(|meta| meta.is_file())
+}
+
+pub fn describe(tool: Tool, command: &[String]) -> String {
+    format!("{} {}", tool.program(), command.join(" "))
+}
+
+// Leaves the alternate screen and raw mode, runs `command` through `tool` on
+// the real terminal, and restores the UI. Err carries why the command failed.
+pub fn run(terminal: &mut Terminal<CrosstermBackend<Stdout>>, tool: Tool, command: &[String]) -> io::Result<Result<(), String>> {
+    INPUT_PAUSED.store(true, Ordering::SeqCst);
+    // Let a poll already under way in the input reader finish.
+    std::thread::sleep(INPUT_POLL * 2);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    println!("puls: running {}", describe(tool, command));
+
+    let result = match Command::new(tool.program()).args(command).status() {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => Err(format!("{} exited with {}", tool.program(), status)),
+        Err(e) => Err(format!("Could not run {}: {}", tool.program(), e)),
+    };
+
+    enable_raw_mode()?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+    terminal.clear()?;
+    INPUT_PAUSED.store(false, Ordering::SeqCst);
+    Ok(result)
+}
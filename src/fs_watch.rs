@@ -0,0 +1,205 @@
+// Create, modify and delete events under user-chosen paths, from inotify. A
+// watched directory reports on the entries directly inside it. Events are
+// read on a thread of their own and handed to the UI in batches, with
+// repeated events on the same path folded into one, so a runaway log writer
+// shows up as a climbing count instead of a flood of rows.
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use tokio::sync::mpsc;
+
+use crate::types::AppMessage;
+
+const BATCH_INTERVAL: Duration = Duration::from_millis(500);
+const RETAINED: usize = 200;
+// Events on a path this close together extend the same row.
+const MERGE_WINDOW: chrono::Duration = chrono::Duration::seconds(5);
+pub const BUSIEST_WINDOW: chrono::Duration = chrono::Duration::minutes(1);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FsEventKind {
+    Created,
+    Modified,
+    Deleted,
+    Moved,
+}
+
+impl FsEventKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            FsEventKind::Created => "create",
+            FsEventKind::Modified => "modify",
+            FsEventKind::Deleted => "delete",
+            FsEventKind::Moved => "move",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FsEvent {
+    // When the event was last seen.
+    pub at: DateTime<Local>,
+    pub kind: FsEventKind,
+    pub path: PathBuf,
+    pub count: u32,
+}
+
+// Recent events, newest last, for the panel on the Disks tab.
+#[derive(Clone, Debug, Default)]
+pub struct FsEventLog {
+    pub watched: Vec<PathBuf>,
+    pub events: VecDeque<FsEvent>,
+}
+
+impl FsEventLog {
+    pub fn new(watched: Vec<PathBuf>) -> Self {
+        Self { watched, events: VecDeque::new() }
+    }
+
+    pub fn extend(&mut self, batch: Vec<FsEvent>) {
+        for event in batch {
+            let recent = self.events.iter_mut().rev()
+                .take_while(|seen| event.at - seen.at <= MERGE_WINDOW)
+                .find(|seen| seen.kind == event.kind && seen.path == event.path);
+            match recent {
+                Some(seen) => {
+                    seen.count += event.count;
+                    seen.at = event.at;
+                }
+                None => self.events.push_back(event),
+            }
+        }
+        // Rows updated in place move to the end, so the log stays in order.
+        self.events.make_contiguous().sort_by_key(|event| event.at);
+        while self.events.len() > RETAINED {
+            self.events.pop_front();
+        }
+    }
+
+    // Paths by event count within the window, busiest first.
+    pub fn busiest(&self, now: DateTime<Local>, window: chrono::Duration) -> Vec<(PathBuf, u32)> {
+        let mut paths: Vec<(PathBuf, u32)> = Vec::new();
+        for event in self.events.iter().filter(|event| now - event.at <= window) {
+            match paths.iter_mut().find(|(path, _)| *path == event.path) {
+                Some((_, count)) => *count += event.count,
+                None => paths.push((event.path.clone(), event.count)),
+            }
+        }
+        paths.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        paths
+    }
+}
+
+// Adds one read of raw events to a batch, folding repeats together.
+fn push_event(batch: &mut Vec<FsEvent>, kind: FsEventKind, path: PathBuf, at: DateTime<Local>) {
+    match batch.iter_mut().find(|event| event.kind == kind && event.path == path) {
+        Some(event) => {
+            event.count += 1;
+            event.at = at;
+        }
+        None => batch.push(FsEvent { at, kind, path, count: 1 }),
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn spawn_watcher(tx: mpsc::UnboundedSender<AppMessage>, paths: Vec<PathBuf>) {
+    use std::collections::HashMap;
+    use inotify::{EventMask, Inotify, WatchMask};
+
+    let mut inotify = match Inotify::init() {
+        Ok(inotify) => inotify,
+        Err(e) => {
+            log::error!("Filesystem watch unavailable: {}", e);
+            return;
+        }
+    };
+    let mask = WatchMask::CREATE | WatchMask::MODIFY | WatchMask::DELETE | WatchMask::MOVED_FROM
+        | WatchMask::MOVED_TO | WatchMask::DELETE_SELF | WatchMask::MOVE_SELF;
+    let mut watches = HashMap::new();
+    for path in paths {
+        match inotify.watches().add(&path, mask) {
+            Ok(wd) => {
+                watches.insert(wd, path);
+            }
+            Err(e) => log::warn!("Cannot watch {}: {}", path.display(), e),
+        }
+    }
+    if watches.is_empty() {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        let mut buffer = [0u8; 16 * 1024];
+        loop {
+            std::thread::sleep(BATCH_INTERVAL);
+            let mut batch = Vec::new();
+            loop {
+                let events = match inotify.read_events(&mut buffer) {
+                    Ok(events) => events,
+                    Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => break,
+                    Err(e) => {
+                        log::error!("Filesystem watch stopped: {}", e);
+                        return;
+                    }
+                };
+                let now = Local::now();
+                for event in events {
+                    let Some(dir) = watches.get(&event.wd) else { continue };
+                    let path = event.name.map_or_else(|| dir.clone(), |name| dir.join(name));
+                    let kind = if event.mask.contains(EventMask::CREATE) {
+                        FsEventKind::Created
+                    } else if event.mask.contains(EventMask::MODIFY) {
+                        FsEventKind::Modified
+                    } else if event.mask.intersects(EventMask::DELETE | EventMask::DELETE_SELF) {
+                        FsEventKind::Deleted
+                    } else if event.mask.intersects(EventMask::MOVED_FROM | EventMask::MOVED_TO | EventMask::MOVE_SELF) {
+                        FsEventKind::Moved
+                    } else {
+                        continue;
+                    };
+                    push_event(&mut batch, kind, path, now);
+                }
+            }
+            if !batch.is_empty() && tx.send(AppMessage::FsEvents(batch)).is_err() {
+                return;
+            }
+        }
+    });
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn spawn_watcher(_tx: mpsc::UnboundedSender<AppMessage>, _paths: Vec<PathBuf>) {
+    log::warn!("Filesystem watch requires inotify (Linux)");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_log_merges_repeats() {
+        let start = Local::now();
+        let at = |seconds: i64| start + chrono::Duration::seconds(seconds);
+        let log_path = PathBuf::from("/var/log/app.log");
+
+        let mut batch = Vec::new();
+        for _ in 0..3 {
+            push_event(&mut batch, FsEventKind::Modified, log_path.clone(), at(0));
+        }
+        push_event(&mut batch, FsEventKind::Created, PathBuf::from("/tmp/x"), at(0));
+        assert_eq!(batch.len(), 2);
+
+        let mut log = FsEventLog::new(vec![PathBuf::from("/var/log")]);
+        log.extend(batch);
+        log.extend(vec![FsEvent { at: at(2), kind: FsEventKind::Modified, path: log_path.clone(), count: 4 }]);
+        // Too long after the last write to extend that row.
+        log.extend(vec![FsEvent { at: at(10), kind: FsEventKind::Modified, path: log_path.clone(), count: 1 }]);
+
+        let rows: Vec<(FsEventKind, u32)> = log.events.iter().map(|event| (event.kind, event.count)).collect();
+        assert_eq!(rows, vec![(FsEventKind::Created, 1), (FsEventKind::Modified, 7), (FsEventKind::Modified, 1)]);
+        assert_eq!(log.busiest(at(10), BUSIEST_WINDOW), vec![(log_path, 8), (PathBuf::from("/tmp/x"), 1)]);
+    }
+}
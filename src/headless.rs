@@ -0,0 +1,365 @@
+// Non-interactive subcommands. They share the collectors with the TUI and
+// consume the same `MonitorUpdate` stream, so a recording replays exactly
+// what the live UI would have shown.
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, watch};
+use tokio::time::Instant;
+
+use crate::monitors::DataCollector;
+use crate::retention::{self, Retention};
+use crate::system_service::SystemManager;
+use crate::types::{AppConfig, AppMessage, CollectorControl, DynamicData, MonitorUpdate, ProcessQuery, ServiceInfo};
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RecordLine {
+    Header { system_info: Vec<(String, String)>, refresh_rate_ms: u64 },
+    Update { at_ms: u64, update: MonitorUpdate },
+}
+
+pub struct Recording {
+    pub system_info: Vec<(String, String)>,
+    updates: Vec<(u64, MonitorUpdate)>,
+}
+
+impl Recording {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut recording = Recording { system_info: Vec::new(), updates: Vec::new() };
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match serde_json::from_str(&line).map_err(|e| format!("{}:{}: {}", path.display(), index + 1, e))? {
+                RecordLine::Header { system_info, .. } => recording.system_info = system_info,
+                RecordLine::Update { at_ms, update } => recording.updates.push((at_ms, update)),
+            }
+        }
+
+        Ok(recording)
+    }
+}
+
+// Appends updates to a recording file in the format `Recording::load` reads.
+// Past the retention size limit the file is rotated and a new one started
+// with the same header, so every part replays on its own.
+pub struct RecordingWriter {
+    file: BufWriter<File>,
+    path: PathBuf,
+    system_info: Vec<(String, String)>,
+    refresh_rate_ms: u64,
+    retention: Retention,
+    // Bytes in the current part, past its header.
+    written: u64,
+    // When the current part started; its updates are timed from there.
+    part_start_ms: u64,
+}
+
+impl RecordingWriter {
+    pub fn create(path: &Path, system_info: Vec<(String, String)>, refresh_rate_ms: u64, retention: Retention) -> Result<Self, Box<dyn Error>> {
+        let mut writer = Self {
+            file: BufWriter::new(File::create(path)?),
+            path: path.to_path_buf(),
+            system_info,
+            refresh_rate_ms,
+            retention,
+            written: 0,
+            part_start_ms: 0,
+        };
+        writer.write_header()?;
+        writer.written = 0;
+        Ok(writer)
+    }
+
+    pub fn write(&mut self, at_ms: u64, update: MonitorUpdate) -> Result<(), Box<dyn Error>> {
+        if self.retention.needs_rotation(self.written) {
+            self.rotate(at_ms)?;
+        }
+        self.write_line(&RecordLine::Update { at_ms: at_ms.saturating_sub(self.part_start_ms), update })
+    }
+
+    pub fn flush(&mut self) -> Result<(), Box<dyn Error>> {
+        self.file.flush()?;
+        Ok(())
+    }
+
+    fn rotate(&mut self, at_ms: u64) -> Result<(), Box<dyn Error>> {
+        self.flush()?;
+        retention::rotate(&self.path, &self.retention)?;
+        self.file = BufWriter::new(File::create(&self.path)?);
+        self.part_start_ms = at_ms;
+        log::info!("Rotated {} at {}", self.path.display(), self.retention.max_size.map(|size| size.to_string()).unwrap_or_default());
+        self.write_header()?;
+        self.written = 0;
+        Ok(())
+    }
+
+    fn write_header(&mut self) -> Result<(), Box<dyn Error>> {
+        let header = RecordLine::Header { system_info: self.system_info.clone(), refresh_rate_ms: self.refresh_rate_ms };
+        self.write_line(&header)
+    }
+
+    fn write_line(&mut self, line: &RecordLine) -> Result<(), Box<dyn Error>> {
+        let mut bytes = serde_json::to_vec(line)?;
+        bytes.push(b'\n');
+        self.file.write_all(&bytes)?;
+        self.written += bytes.len() as u64;
+        Ok(())
+    }
+}
+
+// Headless runs always want the process list, whatever tab the TUI last had.
+pub fn headless_control(config: &AppConfig) -> CollectorControl {
+    CollectorControl {
+        query: ProcessQuery {
+            show_system_processes: config.show_system_processes,
+            filter_text: config.process_filter.clone().unwrap_or_default(),
+            sort_by: config.process_sort.clone().unwrap_or_default(),
+            selected_pid: config.start_pid.map(sysinfo::Pid::from_u32),
+            show_state_columns: true,
+            ..Default::default()
+        },
+        paused: false,
+    }
+}
+
+// `puls snapshot` output: the collected data plus services, which only the
+// TUI's services tab otherwise loads.
+#[derive(Serialize)]
+struct Snapshot<'a> {
+    #[serde(flatten)]
+    data: &'a DynamicData,
+    services: &'a [ServiceInfo],
+}
+
+pub async fn snapshot(config: &AppConfig) -> Result<(), Box<dyn Error>> {
+    let (_, data) = crate::report::collect_once(config).await;
+    let services = tokio::task::spawn_blocking(|| SystemManager::new().get_services()).await?;
+
+    let mut stdout = std::io::stdout().lock();
+    serde_json::to_writer_pretty(&mut stdout, &Snapshot { data: &data, services: &services })?;
+    writeln!(stdout)?;
+    Ok(())
+}
+
+// `E` in the TUI: what is on screen now, in the `puls snapshot` format, so
+// `puls diff` compares exports too. Returns the file written.
+pub fn export(path: Option<&Path>, data: &DynamicData, services: &[ServiceInfo]) -> Result<PathBuf, Box<dyn Error>> {
+    let path = path.map_or_else(
+        || PathBuf::from(format!("puls-metrics-{}.json", chrono::Local::now().format("%Y%m%d-%H%M%S"))),
+        Path::to_path_buf,
+    );
+    let mut file = BufWriter::new(File::create(&path)?);
+    serde_json::to_writer_pretty(&mut file, &Snapshot { data, services })?;
+    writeln!(file)?;
+    file.flush()?;
+    Ok(path)
+}
+
+pub async fn record(config: &AppConfig, output: &Path, duration: Option<u64>) -> Result<(), Box<dyn Error>> {
+    let collector = DataCollector::new(config.clone());
+    let mut file = RecordingWriter::create(output, collector.get_system_info(), config.refresh_rate_ms, config.retention)?;
+
+    let local = tokio::task::LocalSet::new();
+    local.run_until(async {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let (_control_tx, control_rx) = watch::channel(headless_control(config));
+        crate::spawn_collectors(tx, control_rx, collector, config);
+
+        let stop = async {
+            match duration {
+                Some(secs) => tokio::time::sleep(Duration::from_secs(secs)).await,
+                None => std::future::pending().await,
+            }
+        };
+        tokio::pin!(stop);
+
+        let start = Instant::now();
+        let mut count = 0usize;
+        loop {
+            tokio::select! {
+                message = rx.recv() => match message {
+                    Some(AppMessage::Update(update)) => {
+                        let at_ms = start.elapsed().as_millis() as u64;
+                        file.write(at_ms, update)?;
+                        count += 1;
+                    }
+                    Some(_) => {}
+                    None => break,
+                },
+                _ = &mut stop => break,
+                _ = tokio::signal::ctrl_c() => break,
+            }
+        }
+
+        file.flush()?;
+        eprintln!("Recorded {} updates to {}", count, output.display());
+        Ok(())
+    }).await
+}
+
+// Feeds a recording into the UI on its original timeline, scaled by `speed`.
+// Time spent paused doesn't count, so pausing holds the replay in place.
+pub async fn replay_loop(
+    tx: mpsc::UnboundedSender<AppMessage>,
+    control: watch::Receiver<CollectorControl>,
+    recording: Recording,
+    speed: f64,
+) {
+    let speed = if speed > 0.0 { speed } else { 1.0 };
+    let mut clock = Duration::ZERO;
+    let mut last = Instant::now();
+
+    for (at_ms, update) in recording.updates {
+        let due = Duration::from_millis(at_ms).div_f64(speed);
+        while clock < due {
+            tokio::time::sleep((due - clock).min(Duration::from_millis(100))).await;
+            let now = Instant::now();
+            if !control.borrow().paused {
+                clock += now - last;
+            }
+            last = now;
+        }
+
+        if tx.send(AppMessage::Update(update)).is_err() {
+            return;
+        }
+    }
+
+    log::info!("Replay finished");
+}
+
+pub async fn serve(config: &AppConfig, listen: &str) -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind(listen).await?;
+    eprintln!("Serving metrics on http://{}/", listener.local_addr()?);
+
+    let collector = DataCollector::new(config.clone());
+    let local = tokio::task::LocalSet::new();
+    local.run_until(async {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let (_control_tx, control_rx) = watch::channel(headless_control(config));
+        crate::spawn_collectors(tx, control_rx, collector, config);
+
+        let mut data = DynamicData::default();
+        loop {
+            tokio::select! {
+                message = rx.recv() => match message {
+                    Some(AppMessage::Update(update)) => data.apply(update),
+                    Some(_) => {}
+                    None => break,
+                },
+                accepted = listener.accept() => {
+                    if let Ok((stream, _)) = accepted {
+                        if let Err(e) = respond(stream, &data).await {
+                            log::debug!("HTTP client error: {}", e);
+                        }
+                    }
+                }
+                _ = tokio::signal::ctrl_c() => break,
+            }
+        }
+        Ok(())
+    }).await
+}
+
+async fn respond(mut stream: TcpStream, data: &DynamicData) -> Result<(), Box<dyn Error>> {
+    let mut buffer = [0u8; 4096];
+    let read = tokio::time::timeout(Duration::from_secs(2), stream.read(&mut buffer)).await??;
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+    let (status, body) = match path {
+        "/" | "/metrics" => ("200 OK", serde_json::to_string(data)?),
+        _ => ("404 Not Found", "{\"error\":\"not found\"}".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ContainerInfo;
+
+    #[test]
+    fn test_recording_round_trip() {
+        let path = std::env::temp_dir().join(format!("puls-recording-{}.ndjson", std::process::id()));
+        let mut file = RecordingWriter::create(&path, vec![("Hostname".to_string(), "box".to_string())], 1000, Retention::default()).unwrap();
+        file.write(1500, MonitorUpdate::Containers { containers: Vec::<ContainerInfo>::new(), error: Some("down".to_string()), ooms: Vec::new() }).unwrap();
+        file.flush().unwrap();
+        drop(file);
+
+        let recording = Recording::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(recording.system_info[0].1, "box");
+        assert_eq!(recording.updates.len(), 1);
+        assert_eq!(recording.updates[0].0, 1500);
+        assert!(matches!(&recording.updates[0].1, MonitorUpdate::Containers { error: Some(e), .. } if e == "down"));
+    }
+
+    #[test]
+    fn test_export() {
+        let path = std::env::temp_dir().join(format!("puls-export-{}.json", std::process::id()));
+        let data = DynamicData {
+            containers: vec![ContainerInfo { name: "web".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+        let services = vec![ServiceInfo { name: "sshd.service".to_string(), ..Default::default() }];
+        assert_eq!(export(Some(&path), &data, &services).unwrap(), path);
+
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(json["containers"][0]["name"], "web");
+        assert_eq!(json["services"][0]["name"], "sshd.service");
+        assert!(json["processes"].is_array() && json["disks"].is_array() && json["networks"].is_array());
+    }
+
+    #[test]
+    fn test_recording_rotation() {
+        let path = std::env::temp_dir().join(format!("puls-rotation-{}.ndjson", std::process::id()));
+        let retention = Retention { max_size: Some(retention::FileSize(10)), keep: Some(1), ..Default::default() };
+        let mut file = RecordingWriter::create(&path, Vec::new(), 1000, retention).unwrap();
+        for at_ms in [1000, 2000, 3000] {
+            file.write(at_ms, MonitorUpdate::Containers { containers: Vec::new(), error: None, ooms: Vec::new() }).unwrap();
+        }
+        file.flush().unwrap();
+        drop(file);
+
+        // Each part holds one update, timed from the part's start.
+        let current = Recording::load(&path).unwrap();
+        let mut older = path.clone().into_os_string();
+        older.push(".1");
+        let older = PathBuf::from(older);
+        let previous = Recording::load(&older).unwrap();
+        let mut dropped = older.clone().into_os_string();
+        dropped.push(".2");
+        assert!(!PathBuf::from(dropped).exists());
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&older).unwrap();
+
+        assert_eq!(current.updates.iter().map(|(at, _)| *at).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(previous.updates.iter().map(|(at, _)| *at).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(previous.system_info, current.system_info);
+    }
+}
@@ -1,26 +1,83 @@
+use std::cell::RefCell;
 use std::fmt;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
-#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Language {
     English,
     Turkish,
+    German,
+    Spanish,
+    French,
+    Portuguese,
+    Chinese,
+    Japanese,
+    // Loaded at startup from `<config dir>/puls/lang/<code>.toml`.
+    External(String),
 }
 
 impl Language {
     pub fn from_str(s: &str) -> Self {
-        match s.to_lowercase().as_str() {
-            "tr" | "turkish" => Language::Turkish,
-            _ => Language::English,
+        let code = s.to_lowercase();
+        Self::builtin(&code).unwrap_or(Language::External(code))
+    }
+
+    fn builtin(code: &str) -> Option<Self> {
+        match code {
+            "en" | "english" => Some(Language::English),
+            "tr" | "turkish" => Some(Language::Turkish),
+            "de" | "german" => Some(Language::German),
+            "es" | "spanish" => Some(Language::Spanish),
+            "fr" | "french" => Some(Language::French),
+            "pt" | "portuguese" => Some(Language::Portuguese),
+            "zh" | "chinese" => Some(Language::Chinese),
+            "ja" | "japanese" => Some(Language::Japanese),
+            _ => None,
+        }
+    }
+}
+
+impl Language {
+    // Maps a POSIX locale such as `tr_TR.UTF-8` or `pt_BR@euro` to a
+    // translation: built-in languages by their language part, otherwise a
+    // locale file for the full code (`pt_br`) or the language part (`pt`).
+    pub fn from_locale(locale: &str) -> Self {
+        let name = locale.split(['.', '@']).next().unwrap_or("").to_lowercase();
+        let language = name.split(['_', '-']).next().unwrap_or("");
+
+        if let Some(builtin) = Self::builtin(language) {
+            return builtin;
         }
+
+        let has_file = |code: &str| locale_dir().is_some_and(|dir| dir.join(format!("{}.toml", code)).is_file());
+        let code = [name.as_str(), language]
+            .into_iter()
+            .find(|code| !code.is_empty() && has_file(code));
+        code.map_or(Language::English, |code| Language::External(code.to_string()))
     }
 }
 
+// The locale that governs messages, in POSIX precedence order.
+pub fn detect_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+}
+
 impl fmt::Display for Language {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Language::English => write!(f, "English"),
             Language::Turkish => write!(f, "Türkçe"),
+            Language::German => write!(f, "Deutsch"),
+            Language::Spanish => write!(f, "Español"),
+            Language::French => write!(f, "Français"),
+            Language::Portuguese => write!(f, "Português"),
+            Language::Chinese => write!(f, "中文"),
+            Language::Japanese => write!(f, "日本語"),
+            Language::External(code) => write!(f, "{}", code),
         }
     }
 }
@@ -28,22 +85,74 @@ impl fmt::Display for Language {
 pub struct Translator {
     lang: Language,
     en_dict: HashMap<&'static str, &'static str>,
-    tr_dict: HashMap<&'static str, &'static str>,
+    // The active built-in language; empty for English and external files.
+    dict: HashMap<&'static str, &'static str>,
+    external_dict: HashMap<String, String>,
+    // Keys already reported, so each gap is logged once rather than per frame.
+    missing: RefCell<HashSet<String>>,
 }
 
 impl Translator {
     pub fn new(lang: Language) -> Self {
+        let external_dict = match &lang {
+            Language::External(code) => load_locale_file(code).unwrap_or_else(|e| {
+                log::warn!("Language '{}' unavailable, using English: {}", code, e);
+                HashMap::new()
+            }),
+            _ => HashMap::new(),
+        };
+
+        let dict = match &lang {
+            Language::Turkish => Self::create_tr_dict(),
+            Language::German => Self::create_de_dict(),
+            Language::Spanish => Self::create_es_dict(),
+            Language::French => Self::create_fr_dict(),
+            Language::Portuguese => Self::create_pt_dict(),
+            Language::Chinese => Self::create_zh_dict(),
+            Language::Japanese => Self::create_ja_dict(),
+            Language::English | Language::External(_) => HashMap::new(),
+        };
+
         Self {
             lang,
             en_dict: Self::create_en_dict(),
-            tr_dict: Self::create_tr_dict(),
+            dict,
+            external_dict,
+            missing: RefCell::new(HashSet::new()),
         }
     }
 
+    // Falls back per key: active language, then English, then the key itself.
     pub fn t(&self, key: &str) -> String {
-        match self.lang {
-            Language::English => self.en_dict.get(key).unwrap_or(&key).to_string(),
-            Language::Turkish => self.tr_dict.get(key).unwrap_or(&key).to_string(),
+        let translated = match &self.lang {
+            Language::English => None,
+            Language::External(_) => self.external_dict.get(key).map(String::as_str),
+            _ => self.dict.get(key).copied(),
+        };
+        if let Some(text) = translated {
+            return text.to_string();
+        }
+
+        let english = self.en_dict.get(key).copied();
+        if english.is_none() || self.lang != Language::English {
+            self.report_missing(key, english.is_some());
+        }
+        english.unwrap_or(key).to_string()
+    }
+
+    // Whether `key` is one puls looks up, i.e. has an English text.
+    pub fn is_known_key(&self, key: &str) -> bool {
+        self.en_dict.contains_key(key)
+    }
+
+    fn report_missing(&self, key: &str, has_english: bool) {
+        if !self.missing.borrow_mut().insert(key.to_string()) {
+            return;
+        }
+        if has_english {
+            log::info!("No {} translation for '{}', using English", self.lang, key);
+        } else {
+            log::warn!("Unknown translation key '{}'", key);
         }
     }
 
@@ -62,6 +171,10 @@ impl Translator {
         map.insert("tab.logs", "0:Logs");
         map.insert("tab.config", "-:Config");
         map.insert("tab.containers", "=:Docker");
+        map.insert("tab.cgroups", "]:cgroups");
+        map.insert("title.cgroups", "Control Groups (cgroup v2)");
+        map.insert("tab.security", "[:Security");
+        map.insert("title.security", "Security Overview");
         map.insert("title.config", "Configuration");
         map.insert("title.puls", "PULS - System Monitor & Admin Tool");
         map.insert("title.cpu", "CPU");
@@ -88,11 +201,21 @@ impl Translator {
         map.insert("header.user", "User");
         map.insert("header.cpu", "CPU %");
         map.insert("header.memory", "Memory");
+        map.insert("header.swap", "Swap");
         map.insert("header.disk_read", "Disk Read");
         map.insert("header.disk_write", "Disk Write");
+        map.insert("header.mem_growth", "Growth/min");
+        map.insert("title.leak_suspects", "Leak Suspects");
+        map.insert("header.container", "Container");
+        map.insert("header.threads", "Threads");
+        map.insert("header.state", "State");
+        map.insert("header.elapsed", "Elapsed");
+        map.insert("header.cpu_time", "CPU Time");
+        map.insert("label.filter", "filter");
         map.insert("header.service", "Service");
         map.insert("header.status", "Status");
         map.insert("header.enabled", "Enabled");
+        map.insert("header.tasks", "Tasks");
         map.insert("header.uptime", "Uptime");
         map.insert("header.timestamp", "Timestamp");
         map.insert("header.level", "Level");
@@ -116,12 +239,91 @@ impl Translator {
         map.insert("health.moderate", "MODERATE");
         map.insert("alert.title", "ALERTS");
         map.insert("alert.high_cpu", "HIGH CPU!");
+        map.insert("alert.critical_cpu", "CRITICAL CPU!");
         map.insert("alert.high_memory", "HIGH MEMORY!");
         map.insert("alert.critical_memory", "CRITICAL MEMORY!");
         map.insert("alert.disk_critical", "DISK CRITICAL!");
+        map.insert("alert.disk_filling", "DISK FULL IN");
+        map.insert("alert.tcp_retrans", "TCP RETRANSMITS");
+        map.insert("alert.listen_drops", "LISTEN DROPS");
+        map.insert("alert.container_oom", "CONTAINER OOM KILL");
+        map.insert("alert.disk_high", "DISK HIGH!");
+        map.insert("alert.auth_failures", "FAILED LOGINS!");
+        map.insert("alert.port_opened", "NEW PORT!");
+        map.insert("alert.port_closed", "PORT CLOSED!");
+        map.insert("alert.clock_unsynced", "CLOCK NOT SYNCED!");
+        map.insert("alert.clock_drift", "CLOCK DRIFT!");
         map.insert("alert.service_down", "SERVICE DOWN!");
-        map.insert("help.main", "q:Quit | Tab/1-9:Navigate | ↑↓:Select | p:Pause | t:Theme | k:Kill | /:Search");
+        map.insert("alert.gpu_hot", "HOT");
+        map.insert("alert.gpu_vram", "VRAM FULL");
+        map.insert("alert.gpu_ecc", "ECC ERRORS");
+        map.insert("alert.plugin_high", "HIGH");
+        map.insert("alert.plugin_critical", "CRITICAL");
         map.insert("help.paused", "[PAUSED] Resume: p | Quit: q | Tabs: 1-9,0 | Navigate: ↑↓ | Details: Enter");
+        map.insert("hint.apply", "Apply");
+        map.insert("hint.cpu_limit", "CPU limit");
+        map.insert("hint.cancel_scan", "Cancel scan");
+        map.insert("hint.cancel", "Cancel");
+        map.insert("hint.clear_container_filter", "Clear container filter");
+        map.insert("hint.close_scan", "Close scan");
+        map.insert("hint.collapse_expand", "Collapse/Expand");
+        map.insert("hint.container_column", "Container column");
+        map.insert("hint.copy_command", "Copy command");
+        map.insert("hint.details", "Details");
+        map.insert("hint.edit", "Edit");
+        map.insert("hint.enable_disable", "Enable/Disable");
+        map.insert("hint.export_metrics", "Export metrics");
+        map.insert("hint.filter_logs", "Filter logs");
+        map.insert("hint.filter", "Filter");
+        map.insert("hint.force_kill", "Force kill");
+        map.insert("hint.freeze_resume", "Freeze/Resume");
+        map.insert("hint.io_priority", "I/O priority");
+        map.insert("hint.jump_to_name", "Jump to name");
+        map.insert("hint.kill_parent", "Kill parent");
+        map.insert("hint.kill_tree", "Kill tree");
+        map.insert("hint.navigate", "Navigate");
+        map.insert("hint.new_value", "New value");
+        map.insert("hint.older_newer_boot", "Older/newer boot");
+        map.insert("hint.open_files", "Open files");
+        map.insert("hint.owning_process_details", "Owning process details");
+        map.insert("hint.parent_details", "Parent details");
+        map.insert("hint.pause", "Pause");
+        map.insert("hint.port_owner", "Port owner");
+        map.insert("hint.process_details", "Process details");
+        map.insert("hint.quit", "Quit");
+        map.insert("hint.remove", "Remove");
+        map.insert("hint.reset_session_totals", "Reset session totals");
+        map.insert("hint.restart", "Restart");
+        map.insert("hint.root_actions_password", "Root actions ask for a password");
+        map.insert("hint.save", "Save");
+        map.insert("hint.scan_largest_dirs", "Scan for largest directories");
+        map.insert("hint.scroll_command", "Scroll command");
+        map.insert("hint.scroll", "Scroll");
+        map.insert("hint.select_gpu_process", "Select GPU process");
+        map.insert("hint.select_gpu", "Select GPU");
+        map.insert("hint.select_mount", "Select mount");
+        map.insert("hint.select_port", "Select port");
+        map.insert("hint.select_thread", "Select thread");
+        map.insert("hint.select_zombie", "Select zombie");
+        map.insert("hint.select", "Select");
+        map.insert("hint.send_sigchld", "Send SIGCHLD to parent");
+        map.insert("hint.show_container_processes", "Show container processes");
+        map.insert("hint.container_columns", "Size/Uptime/Restarts columns");
+        map.insert("hint.sort_keys", "Sort CPU/Mem/Name/General");
+        map.insert("hint.sort_reverse_threads", "Sort/Reverse threads");
+        map.insert("hint.sort_reverse", "Sort/Reverse");
+        map.insert("hint.start", "Start");
+        map.insert("hint.status_log", "Status log");
+        map.insert("hint.status", "Status");
+        map.insert("hint.stop", "Stop");
+        map.insert("hint.terminate", "Terminate");
+        map.insert("hint.theme", "Theme");
+        map.insert("hint.process_columns", "Threads/State/Time columns");
+        map.insert("hint.tree_view", "Tree view");
+        map.insert("hint.zoom", "Zoom");
+        map.insert("hint.read_only", "Read-only mode");
+        map.insert("hint.service_actions_need_root", "Service actions need root");
+        map.insert("hint.editing_needs_root", "Editing needs root");
         map.insert("help.services", "↑↓: Navigate | Start: s | Stop: x | Restart: r | Enable: e | Disable: d | Edit: v | Quit: q");
         map.insert("help.logs", "↑↓: Navigate | Filter: f | Clear: c | Export: e | Search: / | Quit: q");
         map.insert("help.config", "↑↓: Navigate | Edit: e | Save: Ctrl+S | Revert: R | Quit: q");
@@ -159,6 +361,11 @@ impl Translator {
         map.insert("config.timezone", "Timezone");
         map.insert("config.dns", "DNS Servers");
         map.insert("config.ntp", "NTP Service");
+        map.insert("config.column.setting", "Setting");
+        map.insert("config.column.value", "Value");
+        map.insert("config.column.description", "Description");
+        map.insert("config.empty", "No configuration items available");
+        map.insert("label.read_only", "Read-Only");
         map.insert("msg.success", "Success");
         map.insert("msg.error", "Error");
         map.insert("msg.warning", "Warning");
@@ -204,6 +411,10 @@ impl Translator {
         map.insert("tab.logs", "0:Günlükler");
         map.insert("tab.config", "-:Ayarlar");
         map.insert("tab.containers", "=:Konteynerler");
+        map.insert("tab.cgroups", "]:Cgroup'lar");
+        map.insert("title.cgroups", "Kontrol Grupları (cgroup v2)");
+        map.insert("tab.security", "[:Güvenlik");
+        map.insert("title.security", "Güvenlik Özeti");
         map.insert("title.config", "Ayarlar");
         map.insert("title.puls", "PULS - Sistem İzleyici & Yönetim Aracı");
         map.insert("title.cpu", "CPU");
@@ -230,11 +441,21 @@ impl Translator {
         map.insert("header.user", "Kullanıcı");
         map.insert("header.cpu", "CPU %");
         map.insert("header.memory", "Bellek");
+        map.insert("header.swap", "Takas");
         map.insert("header.disk_read", "Disk Okuma");
         map.insert("header.disk_write", "Disk Yazma");
+        map.insert("header.mem_growth", "Artış/dk");
+        map.insert("title.leak_suspects", "Sızıntı Şüphelileri");
+        map.insert("header.container", "Konteyner");
+        map.insert("header.threads", "İş Parçacığı");
+        map.insert("header.state", "Durum");
+        map.insert("header.elapsed", "Geçen Süre");
+        map.insert("header.cpu_time", "CPU Süresi");
+        map.insert("label.filter", "filtre");
         map.insert("header.service", "Hizmet");
         map.insert("header.status", "Durum");
         map.insert("header.enabled", "Etkin");
+        map.insert("header.tasks", "Görevler");
         map.insert("header.uptime", "Çalışma Süresi");
         map.insert("header.timestamp", "Zaman Damgası");
         map.insert("header.level", "Seviye");
@@ -258,12 +479,91 @@ impl Translator {
         map.insert("health.moderate", "UYGUN");
         map.insert("alert.title", "UYARILAR");
         map.insert("alert.high_cpu", "YÜKSEK CPU!");
+        map.insert("alert.critical_cpu", "KRİTİK CPU!");
         map.insert("alert.high_memory", "YÜKSEK BELLEK!");
         map.insert("alert.critical_memory", "KRİTİK BELLEK!");
         map.insert("alert.disk_critical", "DISK KRİTİK!");
+        map.insert("alert.disk_filling", "DİSK DOLUYOR");
+        map.insert("alert.tcp_retrans", "TCP YENİDEN GÖNDERİM");
+        map.insert("alert.listen_drops", "DİNLEME KAYIPLARI");
+        map.insert("alert.container_oom", "KONTEYNER OOM");
+        map.insert("alert.disk_high", "DISK YÜKSEK!");
+        map.insert("alert.auth_failures", "BAŞARISIZ GİRİŞLER!");
+        map.insert("alert.port_opened", "YENİ PORT!");
+        map.insert("alert.port_closed", "PORT KAPANDI!");
+        map.insert("alert.clock_unsynced", "SAAT SENKRON DEĞİL!");
+        map.insert("alert.clock_drift", "SAAT KAYMASI!");
         map.insert("alert.service_down", "HİZMET KAPALI!");
-        map.insert("help.main", "q:Çık | Tab/1-9:Gezin | ↑↓:Seç | p:Duraklat | t:Tema | k:Sonlandır | /:Ara");
+        map.insert("alert.gpu_hot", "SICAK");
+        map.insert("alert.gpu_vram", "VRAM DOLU");
+        map.insert("alert.gpu_ecc", "ECC HATALARI");
+        map.insert("alert.plugin_high", "YÜKSEK");
+        map.insert("alert.plugin_critical", "KRİTİK");
         map.insert("help.paused", "[DURAKLATILDI] Devam: p | Çık: q | Sekmeler: 1-9,0 | Gezin: ↑↓ | Detaylar: Enter");
+        map.insert("hint.apply", "Uygula");
+        map.insert("hint.cpu_limit", "CPU sınırı");
+        map.insert("hint.cancel_scan", "Taramayı iptal et");
+        map.insert("hint.cancel", "İptal");
+        map.insert("hint.clear_container_filter", "Konteyner filtresini temizle");
+        map.insert("hint.close_scan", "Taramayı kapat");
+        map.insert("hint.collapse_expand", "Daralt/Genişlet");
+        map.insert("hint.container_column", "Konteyner sütunu");
+        map.insert("hint.copy_command", "Komutu kopyala");
+        map.insert("hint.details", "Detaylar");
+        map.insert("hint.edit", "Düzenle");
+        map.insert("hint.enable_disable", "Etkinleştir/Devre dışı bırak");
+        map.insert("hint.export_metrics", "Metrikleri dışa aktar");
+        map.insert("hint.filter_logs", "Günlükleri filtrele");
+        map.insert("hint.filter", "Filtrele");
+        map.insert("hint.force_kill", "Zorla sonlandır");
+        map.insert("hint.freeze_resume", "Dondur/Sürdür");
+        map.insert("hint.io_priority", "G/Ç önceliği");
+        map.insert("hint.jump_to_name", "Ada atla");
+        map.insert("hint.kill_parent", "Üst süreci sonlandır");
+        map.insert("hint.kill_tree", "Ağacı sonlandır");
+        map.insert("hint.navigate", "Gezin");
+        map.insert("hint.new_value", "Yeni değer");
+        map.insert("hint.older_newer_boot", "Eski/yeni açılış");
+        map.insert("hint.open_files", "Açık dosyalar");
+        map.insert("hint.owning_process_details", "Sahip süreç detayları");
+        map.insert("hint.parent_details", "Üst süreç detayları");
+        map.insert("hint.pause", "Duraklat");
+        map.insert("hint.port_owner", "Port sahibi");
+        map.insert("hint.process_details", "Süreç detayları");
+        map.insert("hint.quit", "Çık");
+        map.insert("hint.remove", "Kaldır");
+        map.insert("hint.reset_session_totals", "Oturum toplamlarını sıfırla");
+        map.insert("hint.restart", "Yeniden başlat");
+        map.insert("hint.root_actions_password", "Root işlemleri parola ister");
+        map.insert("hint.save", "Kaydet");
+        map.insert("hint.scan_largest_dirs", "En büyük dizinleri tara");
+        map.insert("hint.scroll_command", "Komutu kaydır");
+        map.insert("hint.scroll", "Kaydır");
+        map.insert("hint.select_gpu_process", "GPU sürecini seç");
+        map.insert("hint.select_gpu", "GPU seç");
+        map.insert("hint.select_mount", "Bağlama noktası seç");
+        map.insert("hint.select_port", "Port seç");
+        map.insert("hint.select_thread", "İş parçacığı seç");
+        map.insert("hint.select_zombie", "Zombi seç");
+        map.insert("hint.select", "Seç");
+        map.insert("hint.send_sigchld", "Üst sürece SIGCHLD gönder");
+        map.insert("hint.show_container_processes", "Konteyner süreçlerini göster");
+        map.insert("hint.container_columns", "Boyut/Çalışma süresi/Yeniden başlatma sütunları");
+        map.insert("hint.sort_keys", "CPU/Bellek/Ad/Genel sırala");
+        map.insert("hint.sort_reverse_threads", "İş parçacıklarını sırala/ters çevir");
+        map.insert("hint.sort_reverse", "Sırala/Ters çevir");
+        map.insert("hint.start", "Başlat");
+        map.insert("hint.status_log", "Durum günlüğü");
+        map.insert("hint.status", "Durum");
+        map.insert("hint.stop", "Durdur");
+        map.insert("hint.terminate", "Sonlandır");
+        map.insert("hint.theme", "Tema");
+        map.insert("hint.process_columns", "İş parçacığı/Durum/Süre sütunları");
+        map.insert("hint.tree_view", "Ağaç görünümü");
+        map.insert("hint.zoom", "Yakınlaştır");
+        map.insert("hint.read_only", "Salt okunur mod");
+        map.insert("hint.service_actions_need_root", "Servis işlemleri root gerektirir");
+        map.insert("hint.editing_needs_root", "Düzenleme root gerektirir");
         map.insert("help.services", "↑↓: Gezin | Başlat: s | Durdur: x | Yeniden Başlat: r | Etkinleştir: e | Devre Dışı: d | Düzenle: v | Çık: q");
         map.insert("help.logs", "↑↓: Gezin | Filtre: f | Temizle: c | Dışa Aktar: e | Ara: / | Çık: q");
         map.insert("help.config", "↑↓: Gezin | Düzenle: e | Kaydet: Ctrl+S | Geri Al: R | Çık: q");
@@ -301,6 +601,11 @@ impl Translator {
         map.insert("config.timezone", "Saat Dilimi");
         map.insert("config.dns", "DNS Sunucuları");
         map.insert("config.ntp", "NTP Hizmeti");
+        map.insert("config.column.setting", "Ayar");
+        map.insert("config.column.value", "Değer");
+        map.insert("config.column.description", "Açıklama");
+        map.insert("config.empty", "Kullanılabilir yapılandırma öğesi yok");
+        map.insert("label.read_only", "Salt Okunur");
         map.insert("msg.success", "Başarılı");
         map.insert("msg.error", "Hata");
         map.insert("msg.warning", "Uyarı");
@@ -330,4 +635,1536 @@ impl Translator {
         map.insert("label.na", "Yok");
         map
     }
+
+    fn create_de_dict() -> HashMap<&'static str, &'static str> {
+        let mut map = HashMap::new();
+        
+        map.insert("tab.dashboard", "1:Übersicht");
+        map.insert("tab.process", "2:Prozesse");
+        map.insert("tab.cpu", "3:CPU");
+        map.insert("tab.memory", "4:Speicher");
+        map.insert("tab.disks", "5:Laufwerke");
+        map.insert("tab.network", "6:Netzwerk");
+        map.insert("tab.gpu", "7:GPU");
+        map.insert("tab.system", "8:System");
+        map.insert("tab.services", "9:Dienste");
+        map.insert("tab.logs", "0:Protokolle");
+        map.insert("tab.config", "-:Konfig");
+        map.insert("tab.containers", "=:Docker");
+        map.insert("tab.cgroups", "]:Cgroups");
+        map.insert("title.cgroups", "Kontrollgruppen (cgroup v2)");
+        map.insert("tab.security", "[:Sicherheit");
+        map.insert("title.security", "Sicherheitsübersicht");
+        map.insert("title.config", "Konfiguration");
+        map.insert("title.puls", "PULS - Systemmonitor & Verwaltungswerkzeug");
+        map.insert("title.cpu", "CPU");
+        map.insert("title.memory", "Speicher");
+        map.insert("title.gpu", "GPU");
+        map.insert("title.network", "Netzwerk-E/A");
+        map.insert("title.disk", "Datenträger-E/A");
+        map.insert("title.processes", "Prozesse");
+        map.insert("title.system_overview", "Systemübersicht");
+        map.insert("title.system_info", "Systeminformationen");
+        map.insert("title.process_stats", "Prozessstatistik");
+        map.insert("title.containers", "Container");
+        map.insert("title.cpu_cores", "CPU-Kerne");
+        map.insert("title.disks", "Laufwerke");
+        map.insert("title.networks", "Netzwerke");
+        map.insert("title.gpus", "GPUs");
+        map.insert("title.services", "Systemdienste");
+        map.insert("title.logs", "Systemprotokolle");
+        map.insert("title.grub", "GRUB-Einstellungen");
+        map.insert("title.network_config", "Netzwerkkonfiguration");
+        map.insert("title.kernel", "Kernelparameter");
+        map.insert("header.pid", "PID");
+        map.insert("header.name", "Name");
+        map.insert("header.user", "Benutzer");
+        map.insert("header.cpu", "CPU %");
+        map.insert("header.memory", "Speicher");
+        map.insert("header.swap", "Swap");
+        map.insert("header.disk_read", "Lesen");
+        map.insert("header.disk_write", "Schreiben");
+        map.insert("header.mem_growth", "Zuwachs/min");
+        map.insert("title.leak_suspects", "Leck-Verdacht");
+        map.insert("header.container", "Container");
+        map.insert("header.threads", "Threads");
+        map.insert("header.state", "Zustand");
+        map.insert("header.elapsed", "Laufzeit");
+        map.insert("header.cpu_time", "CPU-Zeit");
+        map.insert("label.filter", "Filter");
+        map.insert("header.service", "Dienst");
+        map.insert("header.status", "Status");
+        map.insert("header.enabled", "Aktiviert");
+        map.insert("header.tasks", "Tasks");
+        map.insert("header.uptime", "Laufzeit");
+        map.insert("header.timestamp", "Zeitstempel");
+        map.insert("header.level", "Stufe");
+        map.insert("header.message", "Meldung");
+        map.insert("status.running", "Läuft");
+        map.insert("status.stopped", "Gestoppt");
+        map.insert("status.failed", "Fehlgeschlagen");
+        map.insert("status.inactive", "Inaktiv");
+        map.insert("status.activating", "Wird aktiviert");
+        map.insert("status.deactivating", "Wird deaktiviert");
+        map.insert("status.enabled", "Aktiviert");
+        map.insert("status.disabled", "Deaktiviert");
+        map.insert("status.active", "Aktiv");
+        map.insert("status.paused", "[PAUSIERT]");
+        map.insert("health.idle", "LEERLAUF");
+        map.insert("health.normal", "NORMAL");
+        map.insert("health.high", "HOCH");
+        map.insert("health.overload", "ÜBERLAST");
+        map.insert("health.critical", "KRITISCH");
+        map.insert("health.healthy", "GESUND");
+        map.insert("health.moderate", "MÄSSIG");
+        map.insert("alert.title", "WARNUNGEN");
+        map.insert("alert.high_cpu", "HOHE CPU-LAST!");
+        map.insert("alert.critical_cpu", "CPU KRITISCH!");
+        map.insert("alert.high_memory", "HOHER SPEICHER!");
+        map.insert("alert.critical_memory", "SPEICHER KRITISCH!");
+        map.insert("alert.disk_critical", "LAUFWERK KRITISCH!");
+        map.insert("alert.disk_filling", "FESTPLATTE VOLL IN");
+        map.insert("alert.tcp_retrans", "TCP-WIEDERHOLUNGEN");
+        map.insert("alert.listen_drops", "LISTEN-VERWERFUNGEN");
+        map.insert("alert.container_oom", "CONTAINER-OOM");
+        map.insert("alert.disk_high", "LAUFWERK FAST VOLL!");
+        map.insert("alert.auth_failures", "FEHLGESCHLAGENE ANMELDUNGEN!");
+        map.insert("alert.port_opened", "NEUER PORT!");
+        map.insert("alert.port_closed", "PORT GESCHLOSSEN!");
+        map.insert("alert.clock_unsynced", "UHR NICHT SYNCHRON!");
+        map.insert("alert.clock_drift", "UHRABWEICHUNG!");
+        map.insert("alert.service_down", "DIENST AUSGEFALLEN!");
+        map.insert("alert.gpu_hot", "HEISS");
+        map.insert("alert.gpu_vram", "VRAM VOLL");
+        map.insert("alert.gpu_ecc", "ECC-FEHLER");
+        map.insert("alert.plugin_high", "HOCH");
+        map.insert("alert.plugin_critical", "KRITISCH");
+        map.insert("help.paused", "[PAUSIERT] Fortsetzen: p | Beenden: q | Tabs: 1-9,0 | Navigieren: ↑↓ | Details: Enter");
+        map.insert("hint.apply", "Anwenden");
+        map.insert("hint.cpu_limit", "CPU-Limit");
+        map.insert("hint.cancel_scan", "Scan abbrechen");
+        map.insert("hint.cancel", "Abbrechen");
+        map.insert("hint.clear_container_filter", "Containerfilter löschen");
+        map.insert("hint.close_scan", "Scan schließen");
+        map.insert("hint.collapse_expand", "Einklappen/Ausklappen");
+        map.insert("hint.container_column", "Container-Spalte");
+        map.insert("hint.copy_command", "Befehl kopieren");
+        map.insert("hint.details", "Details");
+        map.insert("hint.edit", "Bearbeiten");
+        map.insert("hint.enable_disable", "Aktivieren/Deaktivieren");
+        map.insert("hint.export_metrics", "Metriken exportieren");
+        map.insert("hint.filter_logs", "Logs filtern");
+        map.insert("hint.filter", "Filtern");
+        map.insert("hint.force_kill", "Erzwungen beenden");
+        map.insert("hint.freeze_resume", "Einfrieren/Fortsetzen");
+        map.insert("hint.io_priority", "I/O-Priorität");
+        map.insert("hint.jump_to_name", "Zu Name springen");
+        map.insert("hint.kill_parent", "Elternprozess beenden");
+        map.insert("hint.kill_tree", "Baum beenden");
+        map.insert("hint.navigate", "Navigieren");
+        map.insert("hint.new_value", "Neuer Wert");
+        map.insert("hint.older_newer_boot", "Älterer/neuerer Boot");
+        map.insert("hint.open_files", "Offene Dateien");
+        map.insert("hint.owning_process_details", "Details zum Besitzerprozess");
+        map.insert("hint.parent_details", "Elternprozess-Details");
+        map.insert("hint.pause", "Pause");
+        map.insert("hint.port_owner", "Port-Besitzer");
+        map.insert("hint.process_details", "Prozessdetails");
+        map.insert("hint.quit", "Beenden");
+        map.insert("hint.remove", "Entfernen");
+        map.insert("hint.reset_session_totals", "Sitzungssummen zurücksetzen");
+        map.insert("hint.restart", "Neu starten");
+        map.insert("hint.root_actions_password", "Root-Aktionen fragen nach einem Passwort");
+        map.insert("hint.save", "Speichern");
+        map.insert("hint.scan_largest_dirs", "Nach größten Verzeichnissen suchen");
+        map.insert("hint.scroll_command", "Befehl scrollen");
+        map.insert("hint.scroll", "Scrollen");
+        map.insert("hint.select_gpu_process", "GPU-Prozess auswählen");
+        map.insert("hint.select_gpu", "GPU auswählen");
+        map.insert("hint.select_mount", "Einhängepunkt auswählen");
+        map.insert("hint.select_port", "Port auswählen");
+        map.insert("hint.select_thread", "Thread auswählen");
+        map.insert("hint.select_zombie", "Zombie auswählen");
+        map.insert("hint.select", "Auswählen");
+        map.insert("hint.send_sigchld", "SIGCHLD an Elternprozess senden");
+        map.insert("hint.show_container_processes", "Container-Prozesse anzeigen");
+        map.insert("hint.container_columns", "Spalten Größe/Laufzeit/Neustarts");
+        map.insert("hint.sort_keys", "Nach CPU/Speicher/Name/Allgemein sortieren");
+        map.insert("hint.sort_reverse_threads", "Threads sortieren/umkehren");
+        map.insert("hint.sort_reverse", "Sortieren/Umkehren");
+        map.insert("hint.start", "Starten");
+        map.insert("hint.status_log", "Statusprotokoll");
+        map.insert("hint.status", "Status");
+        map.insert("hint.stop", "Stoppen");
+        map.insert("hint.terminate", "Beenden");
+        map.insert("hint.theme", "Design");
+        map.insert("hint.process_columns", "Spalten Threads/Zustand/Zeit");
+        map.insert("hint.tree_view", "Baumansicht");
+        map.insert("hint.zoom", "Zoom");
+        map.insert("hint.read_only", "Schreibgeschützter Modus");
+        map.insert("hint.service_actions_need_root", "Dienstaktionen brauchen root");
+        map.insert("hint.editing_needs_root", "Bearbeiten braucht root");
+        map.insert("help.services", "↑↓: Navigieren | Starten: s | Stoppen: x | Neustart: r | Aktivieren: e | Deaktivieren: d | Bearbeiten: v | Beenden: q");
+        map.insert("help.logs", "↑↓: Navigieren | Filter: f | Leeren: c | Export: e | Suche: / | Beenden: q");
+        map.insert("help.config", "↑↓: Navigieren | Bearbeiten: e | Speichern: Strg+S | Zurücksetzen: R | Beenden: q");
+        map.insert("log.debug", "DEBUG");
+        map.insert("log.info", "INFO");
+        map.insert("log.warning", "WARNUNG");
+        map.insert("log.error", "FEHLER");
+        map.insert("log.critical", "KRITISCH");
+        map.insert("action.start", "Starten");
+        map.insert("action.stop", "Stoppen");
+        map.insert("action.restart", "Neu starten");
+        map.insert("action.reload", "Neu laden");
+        map.insert("action.enable", "Aktivieren");
+        map.insert("action.disable", "Deaktivieren");
+        map.insert("action.status", "Status");
+        map.insert("action.logs", "Protokolle anzeigen");
+        map.insert("action.edit", "Bearbeiten");
+        map.insert("action.save", "Speichern");
+        map.insert("action.cancel", "Abbrechen");
+        map.insert("info.hostname", "Hostname");
+        map.insert("info.kernel", "Kernel");
+        map.insert("info.uptime", "Laufzeit");
+        map.insert("info.load", "Systemlast");
+        map.insert("info.cores", "CPU-Kerne");
+        map.insert("info.threads", "Threads");
+        map.insert("info.memory_total", "Gesamtspeicher");
+        map.insert("info.memory_used", "Belegter Speicher");
+        map.insert("info.memory_available", "Verfügbarer Speicher");
+        map.insert("info.swap_total", "Swap gesamt");
+        map.insert("info.swap_used", "Swap belegt");
+        map.insert("config.grub_timeout", "GRUB-Zeitlimit");
+        map.insert("config.grub_default", "GRUB-Standardeintrag");
+        map.insert("config.grub_cmd", "Kernel-Befehlszeile");
+        map.insert("config.hostname", "Hostname");
+        map.insert("config.timezone", "Zeitzone");
+        map.insert("config.dns", "DNS-Server");
+        map.insert("config.ntp", "NTP-Dienst");
+        map.insert("config.column.setting", "Einstellung");
+        map.insert("config.column.value", "Wert");
+        map.insert("config.column.description", "Beschreibung");
+        map.insert("config.empty", "Keine Konfigurationseinträge verfügbar");
+        map.insert("label.read_only", "Schreibgeschützt");
+        map.insert("msg.success", "Erfolgreich");
+        map.insert("msg.error", "Fehler");
+        map.insert("msg.warning", "Warnung");
+        map.insert("msg.confirm", "Bestätigen");
+        map.insert("msg.loading", "Wird geladen...");
+        map.insert("msg.saved", "Konfiguration gespeichert");
+        map.insert("msg.failed", "Vorgang fehlgeschlagen");
+        map.insert("msg.unsaved", "Ungespeicherte Änderungen");
+        map.insert("msg.container_disabled", "Container-Überwachung ist deaktiviert");
+        map.insert("msg.no_containers", "Keine laufenden Container");
+        map.insert("status.sleeping", "Schlafend");
+        map.insert("status.zombie", "Zombie");
+        map.insert("status.other", "Sonstige");
+        map.insert("memory.healthy", "GESUND");
+        map.insert("memory.moderate", "MÄSSIG");
+        map.insert("memory.high", "HOCH");
+        map.insert("memory.critical", "KRITISCH");
+        map.insert("memory.comfortable", "ENTSPANNT");
+        map.insert("memory.tight", "KNAPP");
+        map.insert("efficiency.optimal", "OPTIMAL");
+        map.insert("efficiency.good", "GUT");
+        map.insert("efficiency.fair", "MITTEL");
+        map.insert("efficiency.poor", "SCHLECHT");
+        map.insert("label.load", "Last");
+        map.insert("label.efficiency", "Eff");
+        map.insert("label.available", "Verfügbar");
+        map.insert("label.na", "k. A.");
+        map
+    }
+
+    fn create_es_dict() -> HashMap<&'static str, &'static str> {
+        let mut map = HashMap::new();
+        
+        map.insert("tab.dashboard", "1:Panel");
+        map.insert("tab.process", "2:Procesos");
+        map.insert("tab.cpu", "3:CPU");
+        map.insert("tab.memory", "4:Memoria");
+        map.insert("tab.disks", "5:Discos");
+        map.insert("tab.network", "6:Red");
+        map.insert("tab.gpu", "7:GPU");
+        map.insert("tab.system", "8:Sistema");
+        map.insert("tab.services", "9:Servicios");
+        map.insert("tab.logs", "0:Registros");
+        map.insert("tab.config", "-:Config");
+        map.insert("tab.containers", "=:Docker");
+        map.insert("tab.cgroups", "]:Cgroups");
+        map.insert("title.cgroups", "Grupos de control (cgroup v2)");
+        map.insert("tab.security", "[:Seguridad");
+        map.insert("title.security", "Resumen de seguridad");
+        map.insert("title.config", "Configuración");
+        map.insert("title.puls", "PULS - Monitor y herramienta de administración del sistema");
+        map.insert("title.cpu", "CPU");
+        map.insert("title.memory", "Memoria");
+        map.insert("title.gpu", "GPU");
+        map.insert("title.network", "E/S de red");
+        map.insert("title.disk", "E/S de disco");
+        map.insert("title.processes", "Procesos");
+        map.insert("title.system_overview", "Resumen del sistema");
+        map.insert("title.system_info", "Información del sistema");
+        map.insert("title.process_stats", "Estadísticas de procesos");
+        map.insert("title.containers", "Contenedores");
+        map.insert("title.cpu_cores", "Núcleos de CPU");
+        map.insert("title.disks", "Discos");
+        map.insert("title.networks", "Redes");
+        map.insert("title.gpus", "GPUs");
+        map.insert("title.services", "Servicios del sistema");
+        map.insert("title.logs", "Registros del sistema");
+        map.insert("title.grub", "Ajustes de GRUB");
+        map.insert("title.network_config", "Configuración de red");
+        map.insert("title.kernel", "Parámetros del kernel");
+        map.insert("header.pid", "PID");
+        map.insert("header.name", "Nombre");
+        map.insert("header.user", "Usuario");
+        map.insert("header.cpu", "CPU %");
+        map.insert("header.memory", "Memoria");
+        map.insert("header.swap", "Swap");
+        map.insert("header.disk_read", "Lectura");
+        map.insert("header.disk_write", "Escritura");
+        map.insert("header.mem_growth", "Crec./min");
+        map.insert("title.leak_suspects", "Posibles fugas");
+        map.insert("header.container", "Contenedor");
+        map.insert("header.threads", "Hilos");
+        map.insert("header.state", "Estado");
+        map.insert("header.elapsed", "Transcurrido");
+        map.insert("header.cpu_time", "Tiempo CPU");
+        map.insert("label.filter", "filtro");
+        map.insert("header.service", "Servicio");
+        map.insert("header.status", "Estado");
+        map.insert("header.enabled", "Habilitado");
+        map.insert("header.tasks", "Tareas");
+        map.insert("header.uptime", "Tiempo activo");
+        map.insert("header.timestamp", "Fecha y hora");
+        map.insert("header.level", "Nivel");
+        map.insert("header.message", "Mensaje");
+        map.insert("status.running", "En ejecución");
+        map.insert("status.stopped", "Detenido");
+        map.insert("status.failed", "Fallido");
+        map.insert("status.inactive", "Inactivo");
+        map.insert("status.activating", "Activando");
+        map.insert("status.deactivating", "Desactivando");
+        map.insert("status.enabled", "Habilitado");
+        map.insert("status.disabled", "Deshabilitado");
+        map.insert("status.active", "Activo");
+        map.insert("status.paused", "[EN PAUSA]");
+        map.insert("health.idle", "INACTIVO");
+        map.insert("health.normal", "NORMAL");
+        map.insert("health.high", "ALTO");
+        map.insert("health.overload", "SOBRECARGA");
+        map.insert("health.critical", "CRÍTICO");
+        map.insert("health.healthy", "SANO");
+        map.insert("health.moderate", "MODERADO");
+        map.insert("alert.title", "ALERTAS");
+        map.insert("alert.high_cpu", "¡CPU ALTA!");
+        map.insert("alert.critical_cpu", "¡CPU CRÍTICA!");
+        map.insert("alert.high_memory", "¡MEMORIA ALTA!");
+        map.insert("alert.critical_memory", "¡MEMORIA CRÍTICA!");
+        map.insert("alert.disk_critical", "¡DISCO CRÍTICO!");
+        map.insert("alert.disk_filling", "DISCO LLENO EN");
+        map.insert("alert.tcp_retrans", "RETRANSMISIONES TCP");
+        map.insert("alert.listen_drops", "DESCARTES EN ESCUCHA");
+        map.insert("alert.container_oom", "OOM EN CONTENEDOR");
+        map.insert("alert.disk_high", "¡DISCO ALTO!");
+        map.insert("alert.auth_failures", "¡INICIOS DE SESIÓN FALLIDOS!");
+        map.insert("alert.port_opened", "¡PUERTO NUEVO!");
+        map.insert("alert.port_closed", "¡PUERTO CERRADO!");
+        map.insert("alert.clock_unsynced", "¡RELOJ SIN SINCRONIZAR!");
+        map.insert("alert.clock_drift", "¡DESFASE DEL RELOJ!");
+        map.insert("alert.service_down", "¡SERVICIO CAÍDO!");
+        map.insert("alert.gpu_hot", "CALIENTE");
+        map.insert("alert.gpu_vram", "VRAM LLENA");
+        map.insert("alert.gpu_ecc", "ERRORES ECC");
+        map.insert("alert.plugin_high", "ALTO");
+        map.insert("alert.plugin_critical", "CRÍTICO");
+        map.insert("help.paused", "[EN PAUSA] Reanudar: p | Salir: q | Pestañas: 1-9,0 | Navegar: ↑↓ | Detalles: Enter");
+        map.insert("hint.apply", "Aplicar");
+        map.insert("hint.cpu_limit", "Límite de CPU");
+        map.insert("hint.cancel_scan", "Cancelar análisis");
+        map.insert("hint.cancel", "Cancelar");
+        map.insert("hint.clear_container_filter", "Quitar filtro de contenedor");
+        map.insert("hint.close_scan", "Cerrar análisis");
+        map.insert("hint.collapse_expand", "Contraer/Expandir");
+        map.insert("hint.container_column", "Columna de contenedor");
+        map.insert("hint.copy_command", "Copiar comando");
+        map.insert("hint.details", "Detalles");
+        map.insert("hint.edit", "Editar");
+        map.insert("hint.enable_disable", "Habilitar/Deshabilitar");
+        map.insert("hint.export_metrics", "Exportar métricas");
+        map.insert("hint.filter_logs", "Filtrar registros");
+        map.insert("hint.filter", "Filtrar");
+        map.insert("hint.force_kill", "Forzar cierre");
+        map.insert("hint.freeze_resume", "Congelar/Reanudar");
+        map.insert("hint.io_priority", "Prioridad de E/S");
+        map.insert("hint.jump_to_name", "Saltar a nombre");
+        map.insert("hint.kill_parent", "Terminar padre");
+        map.insert("hint.kill_tree", "Terminar árbol");
+        map.insert("hint.navigate", "Navegar");
+        map.insert("hint.new_value", "Nuevo valor");
+        map.insert("hint.older_newer_boot", "Arranque anterior/siguiente");
+        map.insert("hint.open_files", "Archivos abiertos");
+        map.insert("hint.owning_process_details", "Detalles del proceso dueño");
+        map.insert("hint.parent_details", "Detalles del padre");
+        map.insert("hint.pause", "Pausa");
+        map.insert("hint.port_owner", "Dueño del puerto");
+        map.insert("hint.process_details", "Detalles del proceso");
+        map.insert("hint.quit", "Salir");
+        map.insert("hint.remove", "Eliminar");
+        map.insert("hint.reset_session_totals", "Reiniciar totales de sesión");
+        map.insert("hint.restart", "Reiniciar");
+        map.insert("hint.root_actions_password", "Las acciones de root piden contraseña");
+        map.insert("hint.save", "Guardar");
+        map.insert("hint.scan_largest_dirs", "Buscar los directorios más grandes");
+        map.insert("hint.scroll_command", "Desplazar comando");
+        map.insert("hint.scroll", "Desplazar");
+        map.insert("hint.select_gpu_process", "Seleccionar proceso de GPU");
+        map.insert("hint.select_gpu", "Seleccionar GPU");
+        map.insert("hint.select_mount", "Seleccionar montaje");
+        map.insert("hint.select_port", "Seleccionar puerto");
+        map.insert("hint.select_thread", "Seleccionar hilo");
+        map.insert("hint.select_zombie", "Seleccionar zombi");
+        map.insert("hint.select", "Seleccionar");
+        map.insert("hint.send_sigchld", "Enviar SIGCHLD al padre");
+        map.insert("hint.show_container_processes", "Mostrar procesos del contenedor");
+        map.insert("hint.container_columns", "Columnas tamaño/tiempo activo/reinicios");
+        map.insert("hint.sort_keys", "Ordenar CPU/Mem/Nombre/General");
+        map.insert("hint.sort_reverse_threads", "Ordenar/invertir hilos");
+        map.insert("hint.sort_reverse", "Ordenar/Invertir");
+        map.insert("hint.start", "Iniciar");
+        map.insert("hint.status_log", "Registro de estado");
+        map.insert("hint.status", "Estado");
+        map.insert("hint.stop", "Detener");
+        map.insert("hint.terminate", "Terminar");
+        map.insert("hint.theme", "Tema");
+        map.insert("hint.process_columns", "Columnas hilos/estado/tiempo");
+        map.insert("hint.tree_view", "Vista de árbol");
+        map.insert("hint.zoom", "Ampliar");
+        map.insert("hint.read_only", "Modo de solo lectura");
+        map.insert("hint.service_actions_need_root", "Las acciones de servicio requieren root");
+        map.insert("hint.editing_needs_root", "Editar requiere root");
+        map.insert("help.services", "↑↓: Navegar | Iniciar: s | Detener: x | Reiniciar: r | Habilitar: e | Deshabilitar: d | Editar: v | Salir: q");
+        map.insert("help.logs", "↑↓: Navegar | Filtrar: f | Limpiar: c | Exportar: e | Buscar: / | Salir: q");
+        map.insert("help.config", "↑↓: Navegar | Editar: e | Guardar: Ctrl+S | Revertir: R | Salir: q");
+        map.insert("log.debug", "DEPURACIÓN");
+        map.insert("log.info", "INFO");
+        map.insert("log.warning", "AVISO");
+        map.insert("log.error", "ERROR");
+        map.insert("log.critical", "CRÍTICO");
+        map.insert("action.start", "Iniciar");
+        map.insert("action.stop", "Detener");
+        map.insert("action.restart", "Reiniciar");
+        map.insert("action.reload", "Recargar");
+        map.insert("action.enable", "Habilitar");
+        map.insert("action.disable", "Deshabilitar");
+        map.insert("action.status", "Estado");
+        map.insert("action.logs", "Ver registros");
+        map.insert("action.edit", "Editar");
+        map.insert("action.save", "Guardar");
+        map.insert("action.cancel", "Cancelar");
+        map.insert("info.hostname", "Nombre de host");
+        map.insert("info.kernel", "Kernel");
+        map.insert("info.uptime", "Tiempo activo");
+        map.insert("info.load", "Carga media");
+        map.insert("info.cores", "Núcleos de CPU");
+        map.insert("info.threads", "Hilos");
+        map.insert("info.memory_total", "Memoria total");
+        map.insert("info.memory_used", "Memoria usada");
+        map.insert("info.memory_available", "Memoria disponible");
+        map.insert("info.swap_total", "Swap total");
+        map.insert("info.swap_used", "Swap usada");
+        map.insert("config.grub_timeout", "Tiempo de espera de GRUB");
+        map.insert("config.grub_default", "Entrada predeterminada de GRUB");
+        map.insert("config.grub_cmd", "Línea de comandos del kernel");
+        map.insert("config.hostname", "Nombre de host");
+        map.insert("config.timezone", "Zona horaria");
+        map.insert("config.dns", "Servidores DNS");
+        map.insert("config.ntp", "Servicio NTP");
+        map.insert("config.column.setting", "Ajuste");
+        map.insert("config.column.value", "Valor");
+        map.insert("config.column.description", "Descripción");
+        map.insert("config.empty", "No hay elementos de configuración disponibles");
+        map.insert("label.read_only", "Solo lectura");
+        map.insert("msg.success", "Correcto");
+        map.insert("msg.error", "Error");
+        map.insert("msg.warning", "Advertencia");
+        map.insert("msg.confirm", "Confirmar");
+        map.insert("msg.loading", "Cargando...");
+        map.insert("msg.saved", "Configuración guardada");
+        map.insert("msg.failed", "La operación falló");
+        map.insert("msg.unsaved", "Cambios sin guardar");
+        map.insert("msg.container_disabled", "La supervisión de contenedores está desactivada");
+        map.insert("msg.no_containers", "No hay contenedores en ejecución");
+        map.insert("status.sleeping", "Durmiendo");
+        map.insert("status.zombie", "Zombi");
+        map.insert("status.other", "Otro");
+        map.insert("memory.healthy", "SANA");
+        map.insert("memory.moderate", "MODERADA");
+        map.insert("memory.high", "ALTA");
+        map.insert("memory.critical", "CRÍTICA");
+        map.insert("memory.comfortable", "HOLGADA");
+        map.insert("memory.tight", "JUSTA");
+        map.insert("efficiency.optimal", "ÓPTIMA");
+        map.insert("efficiency.good", "BUENA");
+        map.insert("efficiency.fair", "REGULAR");
+        map.insert("efficiency.poor", "MALA");
+        map.insert("label.load", "Carga");
+        map.insert("label.efficiency", "Efic");
+        map.insert("label.available", "Disponible");
+        map.insert("label.na", "N/D");
+        map
+    }
+
+    fn create_fr_dict() -> HashMap<&'static str, &'static str> {
+        let mut map = HashMap::new();
+        
+        map.insert("tab.dashboard", "1:Tableau de bord");
+        map.insert("tab.process", "2:Processus");
+        map.insert("tab.cpu", "3:CPU");
+        map.insert("tab.memory", "4:Mémoire");
+        map.insert("tab.disks", "5:Disques");
+        map.insert("tab.network", "6:Réseau");
+        map.insert("tab.gpu", "7:GPU");
+        map.insert("tab.system", "8:Système");
+        map.insert("tab.services", "9:Services");
+        map.insert("tab.logs", "0:Journaux");
+        map.insert("tab.config", "-:Config");
+        map.insert("tab.containers", "=:Docker");
+        map.insert("tab.cgroups", "]:Cgroups");
+        map.insert("title.cgroups", "Groupes de contrôle (cgroup v2)");
+        map.insert("tab.security", "[:Sécurité");
+        map.insert("title.security", "Aperçu de la sécurité");
+        map.insert("title.config", "Configuration");
+        map.insert("title.puls", "PULS - Moniteur système et outil d'administration");
+        map.insert("title.cpu", "CPU");
+        map.insert("title.memory", "Mémoire");
+        map.insert("title.gpu", "GPU");
+        map.insert("title.network", "E/S réseau");
+        map.insert("title.disk", "E/S disque");
+        map.insert("title.processes", "Processus");
+        map.insert("title.system_overview", "Vue d'ensemble du système");
+        map.insert("title.system_info", "Informations système");
+        map.insert("title.process_stats", "Statistiques des processus");
+        map.insert("title.containers", "Conteneurs");
+        map.insert("title.cpu_cores", "Cœurs CPU");
+        map.insert("title.disks", "Disques");
+        map.insert("title.networks", "Réseaux");
+        map.insert("title.gpus", "GPU");
+        map.insert("title.services", "Services système");
+        map.insert("title.logs", "Journaux système");
+        map.insert("title.grub", "Paramètres GRUB");
+        map.insert("title.network_config", "Configuration réseau");
+        map.insert("title.kernel", "Paramètres du noyau");
+        map.insert("header.pid", "PID");
+        map.insert("header.name", "Nom");
+        map.insert("header.user", "Utilisateur");
+        map.insert("header.cpu", "CPU %");
+        map.insert("header.memory", "Mémoire");
+        map.insert("header.swap", "Swap");
+        map.insert("header.disk_read", "Lecture");
+        map.insert("header.disk_write", "Écriture");
+        map.insert("header.mem_growth", "Hausse/min");
+        map.insert("title.leak_suspects", "Fuites suspectes");
+        map.insert("header.container", "Conteneur");
+        map.insert("header.threads", "Threads");
+        map.insert("header.state", "État");
+        map.insert("header.elapsed", "Écoulé");
+        map.insert("header.cpu_time", "Temps CPU");
+        map.insert("label.filter", "filtre");
+        map.insert("header.service", "Service");
+        map.insert("header.status", "État");
+        map.insert("header.enabled", "Activé");
+        map.insert("header.tasks", "Tâches");
+        map.insert("header.uptime", "Durée d'activité");
+        map.insert("header.timestamp", "Horodatage");
+        map.insert("header.level", "Niveau");
+        map.insert("header.message", "Message");
+        map.insert("status.running", "En cours");
+        map.insert("status.stopped", "Arrêté");
+        map.insert("status.failed", "En échec");
+        map.insert("status.inactive", "Inactif");
+        map.insert("status.activating", "Activation");
+        map.insert("status.deactivating", "Désactivation");
+        map.insert("status.enabled", "Activé");
+        map.insert("status.disabled", "Désactivé");
+        map.insert("status.active", "Actif");
+        map.insert("status.paused", "[EN PAUSE]");
+        map.insert("health.idle", "REPOS");
+        map.insert("health.normal", "NORMAL");
+        map.insert("health.high", "ÉLEVÉ");
+        map.insert("health.overload", "SURCHARGE");
+        map.insert("health.critical", "CRITIQUE");
+        map.insert("health.healthy", "SAIN");
+        map.insert("health.moderate", "MODÉRÉ");
+        map.insert("alert.title", "ALERTES");
+        map.insert("alert.high_cpu", "CPU ÉLEVÉ !");
+        map.insert("alert.critical_cpu", "CPU CRITIQUE !");
+        map.insert("alert.high_memory", "MÉMOIRE ÉLEVÉE !");
+        map.insert("alert.critical_memory", "MÉMOIRE CRITIQUE !");
+        map.insert("alert.disk_critical", "DISQUE CRITIQUE !");
+        map.insert("alert.disk_filling", "DISQUE PLEIN DANS");
+        map.insert("alert.tcp_retrans", "RETRANSMISSIONS TCP");
+        map.insert("alert.listen_drops", "REJETS EN ÉCOUTE");
+        map.insert("alert.container_oom", "OOM CONTENEUR");
+        map.insert("alert.disk_high", "DISQUE ÉLEVÉ !");
+        map.insert("alert.auth_failures", "ÉCHECS DE CONNEXION !");
+        map.insert("alert.port_opened", "NOUVEAU PORT !");
+        map.insert("alert.port_closed", "PORT FERMÉ !");
+        map.insert("alert.clock_unsynced", "HORLOGE NON SYNCHRONISÉE !");
+        map.insert("alert.clock_drift", "DÉRIVE D'HORLOGE !");
+        map.insert("alert.service_down", "SERVICE ARRÊTÉ !");
+        map.insert("alert.gpu_hot", "CHAUD");
+        map.insert("alert.gpu_vram", "VRAM PLEINE");
+        map.insert("alert.gpu_ecc", "ERREURS ECC");
+        map.insert("alert.plugin_high", "ÉLEVÉ");
+        map.insert("alert.plugin_critical", "CRITIQUE");
+        map.insert("help.paused", "[EN PAUSE] Reprendre : p | Quitter : q | Onglets : 1-9,0 | Naviguer : ↑↓ | Détails : Entrée");
+        map.insert("hint.apply", "Appliquer");
+        map.insert("hint.cpu_limit", "Limite CPU");
+        map.insert("hint.cancel_scan", "Annuler l'analyse");
+        map.insert("hint.cancel", "Annuler");
+        map.insert("hint.clear_container_filter", "Effacer le filtre de conteneur");
+        map.insert("hint.close_scan", "Fermer l'analyse");
+        map.insert("hint.collapse_expand", "Replier/Déplier");
+        map.insert("hint.container_column", "Colonne conteneur");
+        map.insert("hint.copy_command", "Copier la commande");
+        map.insert("hint.details", "Détails");
+        map.insert("hint.edit", "Modifier");
+        map.insert("hint.enable_disable", "Activer/Désactiver");
+        map.insert("hint.export_metrics", "Exporter les métriques");
+        map.insert("hint.filter_logs", "Filtrer les journaux");
+        map.insert("hint.filter", "Filtrer");
+        map.insert("hint.force_kill", "Tuer de force");
+        map.insert("hint.freeze_resume", "Geler/Reprendre");
+        map.insert("hint.io_priority", "Priorité d'E/S");
+        map.insert("hint.jump_to_name", "Aller au nom");
+        map.insert("hint.kill_parent", "Tuer le parent");
+        map.insert("hint.kill_tree", "Tuer l'arbre");
+        map.insert("hint.navigate", "Naviguer");
+        map.insert("hint.new_value", "Nouvelle valeur");
+        map.insert("hint.older_newer_boot", "Démarrage précédent/suivant");
+        map.insert("hint.open_files", "Fichiers ouverts");
+        map.insert("hint.owning_process_details", "Détails du processus propriétaire");
+        map.insert("hint.parent_details", "Détails du parent");
+        map.insert("hint.pause", "Pause");
+        map.insert("hint.port_owner", "Propriétaire du port");
+        map.insert("hint.process_details", "Détails du processus");
+        map.insert("hint.quit", "Quitter");
+        map.insert("hint.remove", "Supprimer");
+        map.insert("hint.reset_session_totals", "Réinitialiser les totaux de session");
+        map.insert("hint.restart", "Redémarrer");
+        map.insert("hint.root_actions_password", "Les actions root demandent un mot de passe");
+        map.insert("hint.save", "Enregistrer");
+        map.insert("hint.scan_largest_dirs", "Rechercher les plus gros répertoires");
+        map.insert("hint.scroll_command", "Faire défiler la commande");
+        map.insert("hint.scroll", "Défiler");
+        map.insert("hint.select_gpu_process", "Sélectionner un processus GPU");
+        map.insert("hint.select_gpu", "Sélectionner le GPU");
+        map.insert("hint.select_mount", "Sélectionner le montage");
+        map.insert("hint.select_port", "Sélectionner le port");
+        map.insert("hint.select_thread", "Sélectionner le thread");
+        map.insert("hint.select_zombie", "Sélectionner le zombie");
+        map.insert("hint.select", "Sélectionner");
+        map.insert("hint.send_sigchld", "Envoyer SIGCHLD au parent");
+        map.insert("hint.show_container_processes", "Afficher les processus du conteneur");
+        map.insert("hint.container_columns", "Colonnes taille/durée/redémarrages");
+        map.insert("hint.sort_keys", "Trier CPU/Mém/Nom/Général");
+        map.insert("hint.sort_reverse_threads", "Trier/inverser les threads");
+        map.insert("hint.sort_reverse", "Trier/Inverser");
+        map.insert("hint.start", "Démarrer");
+        map.insert("hint.status_log", "Journal d'état");
+        map.insert("hint.status", "État");
+        map.insert("hint.stop", "Arrêter");
+        map.insert("hint.terminate", "Tuer");
+        map.insert("hint.theme", "Thème");
+        map.insert("hint.process_columns", "Colonnes threads/état/temps");
+        map.insert("hint.tree_view", "Vue arborescente");
+        map.insert("hint.zoom", "Zoom");
+        map.insert("hint.read_only", "Mode lecture seule");
+        map.insert("hint.service_actions_need_root", "Les actions de service nécessitent root");
+        map.insert("hint.editing_needs_root", "La modification nécessite root");
+        map.insert("help.services", "↑↓ : Naviguer | Démarrer : s | Arrêter : x | Redémarrer : r | Activer : e | Désactiver : d | Modifier : v | Quitter : q");
+        map.insert("help.logs", "↑↓ : Naviguer | Filtrer : f | Effacer : c | Exporter : e | Rechercher : / | Quitter : q");
+        map.insert("help.config", "↑↓ : Naviguer | Modifier : e | Enregistrer : Ctrl+S | Annuler : R | Quitter : q");
+        map.insert("log.debug", "DÉBOGAGE");
+        map.insert("log.info", "INFO");
+        map.insert("log.warning", "AVERTISSEMENT");
+        map.insert("log.error", "ERREUR");
+        map.insert("log.critical", "CRITIQUE");
+        map.insert("action.start", "Démarrer");
+        map.insert("action.stop", "Arrêter");
+        map.insert("action.restart", "Redémarrer");
+        map.insert("action.reload", "Recharger");
+        map.insert("action.enable", "Activer");
+        map.insert("action.disable", "Désactiver");
+        map.insert("action.status", "État");
+        map.insert("action.logs", "Voir les journaux");
+        map.insert("action.edit", "Modifier");
+        map.insert("action.save", "Enregistrer");
+        map.insert("action.cancel", "Annuler");
+        map.insert("info.hostname", "Nom d'hôte");
+        map.insert("info.kernel", "Noyau");
+        map.insert("info.uptime", "Durée d'activité");
+        map.insert("info.load", "Charge moyenne");
+        map.insert("info.cores", "Cœurs CPU");
+        map.insert("info.threads", "Threads");
+        map.insert("info.memory_total", "Mémoire totale");
+        map.insert("info.memory_used", "Mémoire utilisée");
+        map.insert("info.memory_available", "Mémoire disponible");
+        map.insert("info.swap_total", "Swap totale");
+        map.insert("info.swap_used", "Swap utilisée");
+        map.insert("config.grub_timeout", "Délai GRUB");
+        map.insert("config.grub_default", "Entrée GRUB par défaut");
+        map.insert("config.grub_cmd", "Ligne de commande du noyau");
+        map.insert("config.hostname", "Nom d'hôte");
+        map.insert("config.timezone", "Fuseau horaire");
+        map.insert("config.dns", "Serveurs DNS");
+        map.insert("config.ntp", "Service NTP");
+        map.insert("config.column.setting", "Paramètre");
+        map.insert("config.column.value", "Valeur");
+        map.insert("config.column.description", "Description");
+        map.insert("config.empty", "Aucun élément de configuration disponible");
+        map.insert("label.read_only", "Lecture seule");
+        map.insert("msg.success", "Succès");
+        map.insert("msg.error", "Erreur");
+        map.insert("msg.warning", "Avertissement");
+        map.insert("msg.confirm", "Confirmer");
+        map.insert("msg.loading", "Chargement...");
+        map.insert("msg.saved", "Configuration enregistrée");
+        map.insert("msg.failed", "Échec de l'opération");
+        map.insert("msg.unsaved", "Modifications non enregistrées");
+        map.insert("msg.container_disabled", "La surveillance des conteneurs est désactivée");
+        map.insert("msg.no_containers", "Aucun conteneur en cours d'exécution");
+        map.insert("status.sleeping", "En sommeil");
+        map.insert("status.zombie", "Zombie");
+        map.insert("status.other", "Autre");
+        map.insert("memory.healthy", "SAINE");
+        map.insert("memory.moderate", "MODÉRÉE");
+        map.insert("memory.high", "ÉLEVÉE");
+        map.insert("memory.critical", "CRITIQUE");
+        map.insert("memory.comfortable", "CONFORTABLE");
+        map.insert("memory.tight", "SERRÉE");
+        map.insert("efficiency.optimal", "OPTIMALE");
+        map.insert("efficiency.good", "BONNE");
+        map.insert("efficiency.fair", "CORRECTE");
+        map.insert("efficiency.poor", "FAIBLE");
+        map.insert("label.load", "Charge");
+        map.insert("label.efficiency", "Eff");
+        map.insert("label.available", "Disponible");
+        map.insert("label.na", "N/D");
+        map
+    }
+
+    fn create_pt_dict() -> HashMap<&'static str, &'static str> {
+        let mut map = HashMap::new();
+        
+        map.insert("tab.dashboard", "1:Painel");
+        map.insert("tab.process", "2:Processos");
+        map.insert("tab.cpu", "3:CPU");
+        map.insert("tab.memory", "4:Memória");
+        map.insert("tab.disks", "5:Discos");
+        map.insert("tab.network", "6:Rede");
+        map.insert("tab.gpu", "7:GPU");
+        map.insert("tab.system", "8:Sistema");
+        map.insert("tab.services", "9:Serviços");
+        map.insert("tab.logs", "0:Logs");
+        map.insert("tab.config", "-:Config");
+        map.insert("tab.containers", "=:Docker");
+        map.insert("tab.cgroups", "]:Cgroups");
+        map.insert("title.cgroups", "Grupos de controle (cgroup v2)");
+        map.insert("tab.security", "[:Segurança");
+        map.insert("title.security", "Visão geral de segurança");
+        map.insert("title.config", "Configuração");
+        map.insert("title.puls", "PULS - Monitor e ferramenta de administração do sistema");
+        map.insert("title.cpu", "CPU");
+        map.insert("title.memory", "Memória");
+        map.insert("title.gpu", "GPU");
+        map.insert("title.network", "E/S de rede");
+        map.insert("title.disk", "E/S de disco");
+        map.insert("title.processes", "Processos");
+        map.insert("title.system_overview", "Visão geral do sistema");
+        map.insert("title.system_info", "Informações do sistema");
+        map.insert("title.process_stats", "Estatísticas de processos");
+        map.insert("title.containers", "Contêineres");
+        map.insert("title.cpu_cores", "Núcleos da CPU");
+        map.insert("title.disks", "Discos");
+        map.insert("title.networks", "Redes");
+        map.insert("title.gpus", "GPUs");
+        map.insert("title.services", "Serviços do sistema");
+        map.insert("title.logs", "Logs do sistema");
+        map.insert("title.grub", "Configurações do GRUB");
+        map.insert("title.network_config", "Configuração de rede");
+        map.insert("title.kernel", "Parâmetros do kernel");
+        map.insert("header.pid", "PID");
+        map.insert("header.name", "Nome");
+        map.insert("header.user", "Usuário");
+        map.insert("header.cpu", "CPU %");
+        map.insert("header.memory", "Memória");
+        map.insert("header.swap", "Swap");
+        map.insert("header.disk_read", "Leitura");
+        map.insert("header.disk_write", "Escrita");
+        map.insert("header.mem_growth", "Cresc./min");
+        map.insert("title.leak_suspects", "Possíveis vazamentos");
+        map.insert("header.container", "Contêiner");
+        map.insert("header.threads", "Threads");
+        map.insert("header.state", "Estado");
+        map.insert("header.elapsed", "Decorrido");
+        map.insert("header.cpu_time", "Tempo CPU");
+        map.insert("label.filter", "filtro");
+        map.insert("header.service", "Serviço");
+        map.insert("header.status", "Estado");
+        map.insert("header.enabled", "Habilitado");
+        map.insert("header.tasks", "Tarefas");
+        map.insert("header.uptime", "Tempo ativo");
+        map.insert("header.timestamp", "Data e hora");
+        map.insert("header.level", "Nível");
+        map.insert("header.message", "Mensagem");
+        map.insert("status.running", "Em execução");
+        map.insert("status.stopped", "Parado");
+        map.insert("status.failed", "Falhou");
+        map.insert("status.inactive", "Inativo");
+        map.insert("status.activating", "Ativando");
+        map.insert("status.deactivating", "Desativando");
+        map.insert("status.enabled", "Habilitado");
+        map.insert("status.disabled", "Desabilitado");
+        map.insert("status.active", "Ativo");
+        map.insert("status.paused", "[PAUSADO]");
+        map.insert("health.idle", "OCIOSO");
+        map.insert("health.normal", "NORMAL");
+        map.insert("health.high", "ALTO");
+        map.insert("health.overload", "SOBRECARGA");
+        map.insert("health.critical", "CRÍTICO");
+        map.insert("health.healthy", "SAUDÁVEL");
+        map.insert("health.moderate", "MODERADO");
+        map.insert("alert.title", "ALERTAS");
+        map.insert("alert.high_cpu", "CPU ALTA!");
+        map.insert("alert.critical_cpu", "CPU CRÍTICA!");
+        map.insert("alert.high_memory", "MEMÓRIA ALTA!");
+        map.insert("alert.critical_memory", "MEMÓRIA CRÍTICA!");
+        map.insert("alert.disk_critical", "DISCO CRÍTICO!");
+        map.insert("alert.disk_filling", "DISCO CHEIO EM");
+        map.insert("alert.tcp_retrans", "RETRANSMISSÕES TCP");
+        map.insert("alert.listen_drops", "DESCARTES EM ESCUTA");
+        map.insert("alert.container_oom", "OOM NO CONTÊINER");
+        map.insert("alert.disk_high", "DISCO ALTO!");
+        map.insert("alert.auth_failures", "LOGINS COM FALHA!");
+        map.insert("alert.port_opened", "PORTA NOVA!");
+        map.insert("alert.port_closed", "PORTA FECHADA!");
+        map.insert("alert.clock_unsynced", "RELÓGIO NÃO SINCRONIZADO!");
+        map.insert("alert.clock_drift", "DESVIO DO RELÓGIO!");
+        map.insert("alert.service_down", "SERVIÇO PARADO!");
+        map.insert("alert.gpu_hot", "QUENTE");
+        map.insert("alert.gpu_vram", "VRAM CHEIA");
+        map.insert("alert.gpu_ecc", "ERROS ECC");
+        map.insert("alert.plugin_high", "ALTO");
+        map.insert("alert.plugin_critical", "CRÍTICO");
+        map.insert("help.paused", "[PAUSADO] Retomar: p | Sair: q | Abas: 1-9,0 | Navegar: ↑↓ | Detalhes: Enter");
+        map.insert("hint.apply", "Aplicar");
+        map.insert("hint.cpu_limit", "Limite de CPU");
+        map.insert("hint.cancel_scan", "Cancelar análise");
+        map.insert("hint.cancel", "Cancelar");
+        map.insert("hint.clear_container_filter", "Limpar filtro de contêiner");
+        map.insert("hint.close_scan", "Fechar análise");
+        map.insert("hint.collapse_expand", "Recolher/Expandir");
+        map.insert("hint.container_column", "Coluna de contêiner");
+        map.insert("hint.copy_command", "Copiar comando");
+        map.insert("hint.details", "Detalhes");
+        map.insert("hint.edit", "Editar");
+        map.insert("hint.enable_disable", "Habilitar/Desabilitar");
+        map.insert("hint.export_metrics", "Exportar métricas");
+        map.insert("hint.filter_logs", "Filtrar logs");
+        map.insert("hint.filter", "Filtrar");
+        map.insert("hint.force_kill", "Forçar encerramento");
+        map.insert("hint.freeze_resume", "Congelar/Retomar");
+        map.insert("hint.io_priority", "Prioridade de E/S");
+        map.insert("hint.jump_to_name", "Ir para nome");
+        map.insert("hint.kill_parent", "Encerrar pai");
+        map.insert("hint.kill_tree", "Encerrar árvore");
+        map.insert("hint.navigate", "Navegar");
+        map.insert("hint.new_value", "Novo valor");
+        map.insert("hint.older_newer_boot", "Boot anterior/seguinte");
+        map.insert("hint.open_files", "Arquivos abertos");
+        map.insert("hint.owning_process_details", "Detalhes do processo dono");
+        map.insert("hint.parent_details", "Detalhes do pai");
+        map.insert("hint.pause", "Pausar");
+        map.insert("hint.port_owner", "Dono da porta");
+        map.insert("hint.process_details", "Detalhes do processo");
+        map.insert("hint.quit", "Sair");
+        map.insert("hint.remove", "Remover");
+        map.insert("hint.reset_session_totals", "Zerar totais da sessão");
+        map.insert("hint.restart", "Reiniciar");
+        map.insert("hint.root_actions_password", "Ações de root pedem senha");
+        map.insert("hint.save", "Salvar");
+        map.insert("hint.scan_largest_dirs", "Procurar os maiores diretórios");
+        map.insert("hint.scroll_command", "Rolar comando");
+        map.insert("hint.scroll", "Rolar");
+        map.insert("hint.select_gpu_process", "Selecionar processo da GPU");
+        map.insert("hint.select_gpu", "Selecionar GPU");
+        map.insert("hint.select_mount", "Selecionar montagem");
+        map.insert("hint.select_port", "Selecionar porta");
+        map.insert("hint.select_thread", "Selecionar thread");
+        map.insert("hint.select_zombie", "Selecionar zumbi");
+        map.insert("hint.select", "Selecionar");
+        map.insert("hint.send_sigchld", "Enviar SIGCHLD ao pai");
+        map.insert("hint.show_container_processes", "Mostrar processos do contêiner");
+        map.insert("hint.container_columns", "Colunas tamanho/tempo ativo/reinícios");
+        map.insert("hint.sort_keys", "Ordenar CPU/Mem/Nome/Geral");
+        map.insert("hint.sort_reverse_threads", "Ordenar/inverter threads");
+        map.insert("hint.sort_reverse", "Ordenar/Inverter");
+        map.insert("hint.start", "Iniciar");
+        map.insert("hint.status_log", "Log de status");
+        map.insert("hint.status", "Status");
+        map.insert("hint.stop", "Parar");
+        map.insert("hint.terminate", "Encerrar");
+        map.insert("hint.theme", "Tema");
+        map.insert("hint.process_columns", "Colunas threads/estado/tempo");
+        map.insert("hint.tree_view", "Visão em árvore");
+        map.insert("hint.zoom", "Ampliar");
+        map.insert("hint.read_only", "Modo somente leitura");
+        map.insert("hint.service_actions_need_root", "Ações de serviço exigem root");
+        map.insert("hint.editing_needs_root", "Editar exige root");
+        map.insert("help.services", "↑↓: Navegar | Iniciar: s | Parar: x | Reiniciar: r | Habilitar: e | Desabilitar: d | Editar: v | Sair: q");
+        map.insert("help.logs", "↑↓: Navegar | Filtrar: f | Limpar: c | Exportar: e | Buscar: / | Sair: q");
+        map.insert("help.config", "↑↓: Navegar | Editar: e | Salvar: Ctrl+S | Reverter: R | Sair: q");
+        map.insert("log.debug", "DEPURAÇÃO");
+        map.insert("log.info", "INFO");
+        map.insert("log.warning", "AVISO");
+        map.insert("log.error", "ERRO");
+        map.insert("log.critical", "CRÍTICO");
+        map.insert("action.start", "Iniciar");
+        map.insert("action.stop", "Parar");
+        map.insert("action.restart", "Reiniciar");
+        map.insert("action.reload", "Recarregar");
+        map.insert("action.enable", "Habilitar");
+        map.insert("action.disable", "Desabilitar");
+        map.insert("action.status", "Estado");
+        map.insert("action.logs", "Ver logs");
+        map.insert("action.edit", "Editar");
+        map.insert("action.save", "Salvar");
+        map.insert("action.cancel", "Cancelar");
+        map.insert("info.hostname", "Nome do host");
+        map.insert("info.kernel", "Kernel");
+        map.insert("info.uptime", "Tempo ativo");
+        map.insert("info.load", "Carga média");
+        map.insert("info.cores", "Núcleos da CPU");
+        map.insert("info.threads", "Threads");
+        map.insert("info.memory_total", "Memória total");
+        map.insert("info.memory_used", "Memória usada");
+        map.insert("info.memory_available", "Memória disponível");
+        map.insert("info.swap_total", "Swap total");
+        map.insert("info.swap_used", "Swap usada");
+        map.insert("config.grub_timeout", "Tempo limite do GRUB");
+        map.insert("config.grub_default", "Entrada padrão do GRUB");
+        map.insert("config.grub_cmd", "Linha de comando do kernel");
+        map.insert("config.hostname", "Nome do host");
+        map.insert("config.timezone", "Fuso horário");
+        map.insert("config.dns", "Servidores DNS");
+        map.insert("config.ntp", "Serviço NTP");
+        map.insert("config.column.setting", "Configuração");
+        map.insert("config.column.value", "Valor");
+        map.insert("config.column.description", "Descrição");
+        map.insert("config.empty", "Nenhum item de configuração disponível");
+        map.insert("label.read_only", "Somente leitura");
+        map.insert("msg.success", "Sucesso");
+        map.insert("msg.error", "Erro");
+        map.insert("msg.warning", "Aviso");
+        map.insert("msg.confirm", "Confirmar");
+        map.insert("msg.loading", "Carregando...");
+        map.insert("msg.saved", "Configuração salva");
+        map.insert("msg.failed", "A operação falhou");
+        map.insert("msg.unsaved", "Alterações não salvas");
+        map.insert("msg.container_disabled", "O monitoramento de contêineres está desativado");
+        map.insert("msg.no_containers", "Nenhum contêiner em execução");
+        map.insert("status.sleeping", "Dormindo");
+        map.insert("status.zombie", "Zumbi");
+        map.insert("status.other", "Outro");
+        map.insert("memory.healthy", "SAUDÁVEL");
+        map.insert("memory.moderate", "MODERADA");
+        map.insert("memory.high", "ALTA");
+        map.insert("memory.critical", "CRÍTICA");
+        map.insert("memory.comfortable", "CONFORTÁVEL");
+        map.insert("memory.tight", "APERTADA");
+        map.insert("efficiency.optimal", "ÓTIMA");
+        map.insert("efficiency.good", "BOA");
+        map.insert("efficiency.fair", "RAZOÁVEL");
+        map.insert("efficiency.poor", "RUIM");
+        map.insert("label.load", "Carga");
+        map.insert("label.efficiency", "Efic");
+        map.insert("label.available", "Disponível");
+        map.insert("label.na", "N/D");
+        map
+    }
+
+    fn create_zh_dict() -> HashMap<&'static str, &'static str> {
+        let mut map = HashMap::new();
+        
+        map.insert("tab.dashboard", "1:仪表盘");
+        map.insert("tab.process", "2:进程");
+        map.insert("tab.cpu", "3:CPU");
+        map.insert("tab.memory", "4:内存");
+        map.insert("tab.disks", "5:磁盘");
+        map.insert("tab.network", "6:网络");
+        map.insert("tab.gpu", "7:GPU");
+        map.insert("tab.system", "8:系统");
+        map.insert("tab.services", "9:服务");
+        map.insert("tab.logs", "0:日志");
+        map.insert("tab.config", "-:配置");
+        map.insert("tab.containers", "=:Docker");
+        map.insert("tab.cgroups", "]:控制组");
+        map.insert("title.cgroups", "控制组 (cgroup v2)");
+        map.insert("tab.security", "[:安全");
+        map.insert("title.security", "安全概览");
+        map.insert("title.config", "配置");
+        map.insert("title.puls", "PULS - 系统监控与管理工具");
+        map.insert("title.cpu", "CPU");
+        map.insert("title.memory", "内存");
+        map.insert("title.gpu", "GPU");
+        map.insert("title.network", "网络 I/O");
+        map.insert("title.disk", "磁盘 I/O");
+        map.insert("title.processes", "进程");
+        map.insert("title.system_overview", "系统概览");
+        map.insert("title.system_info", "系统信息");
+        map.insert("title.process_stats", "进程统计");
+        map.insert("title.containers", "容器");
+        map.insert("title.cpu_cores", "CPU 核心");
+        map.insert("title.disks", "磁盘");
+        map.insert("title.networks", "网络");
+        map.insert("title.gpus", "GPU");
+        map.insert("title.services", "系统服务");
+        map.insert("title.logs", "系统日志");
+        map.insert("title.grub", "GRUB 设置");
+        map.insert("title.network_config", "网络配置");
+        map.insert("title.kernel", "内核参数");
+        map.insert("header.pid", "PID");
+        map.insert("header.name", "名称");
+        map.insert("header.user", "用户");
+        map.insert("header.cpu", "CPU %");
+        map.insert("header.memory", "内存");
+        map.insert("header.swap", "交换");
+        map.insert("header.disk_read", "磁盘读取");
+        map.insert("header.disk_write", "磁盘写入");
+        map.insert("header.mem_growth", "增长/分");
+        map.insert("title.leak_suspects", "疑似泄漏");
+        map.insert("header.container", "容器");
+        map.insert("header.threads", "线程");
+        map.insert("header.state", "状态");
+        map.insert("header.elapsed", "运行时长");
+        map.insert("header.cpu_time", "CPU 时间");
+        map.insert("label.filter", "筛选");
+        map.insert("header.service", "服务");
+        map.insert("header.status", "状态");
+        map.insert("header.enabled", "已启用");
+        map.insert("header.tasks", "任务");
+        map.insert("header.uptime", "运行时间");
+        map.insert("header.timestamp", "时间");
+        map.insert("header.level", "级别");
+        map.insert("header.message", "消息");
+        map.insert("status.running", "运行中");
+        map.insert("status.stopped", "已停止");
+        map.insert("status.failed", "失败");
+        map.insert("status.inactive", "未激活");
+        map.insert("status.activating", "正在激活");
+        map.insert("status.deactivating", "正在停用");
+        map.insert("status.enabled", "已启用");
+        map.insert("status.disabled", "已禁用");
+        map.insert("status.active", "活动");
+        map.insert("status.paused", "[已暂停]");
+        map.insert("health.idle", "空闲");
+        map.insert("health.normal", "正常");
+        map.insert("health.high", "偏高");
+        map.insert("health.overload", "过载");
+        map.insert("health.critical", "危急");
+        map.insert("health.healthy", "健康");
+        map.insert("health.moderate", "中等");
+        map.insert("alert.title", "警报");
+        map.insert("alert.high_cpu", "CPU 过高！");
+        map.insert("alert.critical_cpu", "CPU 危急！");
+        map.insert("alert.high_memory", "内存过高！");
+        map.insert("alert.critical_memory", "内存危急！");
+        map.insert("alert.disk_critical", "磁盘危急！");
+        map.insert("alert.disk_filling", "磁盘将满");
+        map.insert("alert.tcp_retrans", "TCP 重传");
+        map.insert("alert.listen_drops", "监听丢弃");
+        map.insert("alert.container_oom", "容器内存溢出");
+        map.insert("alert.disk_high", "磁盘过高！");
+        map.insert("alert.auth_failures", "登录失败！");
+        map.insert("alert.port_opened", "新端口！");
+        map.insert("alert.port_closed", "端口关闭！");
+        map.insert("alert.clock_unsynced", "时钟未同步！");
+        map.insert("alert.clock_drift", "时钟偏移！");
+        map.insert("alert.service_down", "服务已停止！");
+        map.insert("alert.gpu_hot", "过热");
+        map.insert("alert.gpu_vram", "显存已满");
+        map.insert("alert.gpu_ecc", "ECC 错误");
+        map.insert("alert.plugin_high", "偏高");
+        map.insert("alert.plugin_critical", "严重");
+        map.insert("help.paused", "[已暂停] 继续: p | 退出: q | 标签: 1-9,0 | 导航: ↑↓ | 详情: Enter");
+        map.insert("hint.apply", "应用");
+        map.insert("hint.cpu_limit", "CPU 限制");
+        map.insert("hint.cancel_scan", "取消扫描");
+        map.insert("hint.cancel", "取消");
+        map.insert("hint.clear_container_filter", "清除容器筛选");
+        map.insert("hint.close_scan", "关闭扫描");
+        map.insert("hint.collapse_expand", "折叠/展开");
+        map.insert("hint.container_column", "容器列");
+        map.insert("hint.copy_command", "复制命令");
+        map.insert("hint.details", "详情");
+        map.insert("hint.edit", "编辑");
+        map.insert("hint.enable_disable", "启用/禁用");
+        map.insert("hint.export_metrics", "导出指标");
+        map.insert("hint.filter_logs", "筛选日志");
+        map.insert("hint.filter", "筛选");
+        map.insert("hint.force_kill", "强制结束");
+        map.insert("hint.freeze_resume", "冻结/恢复");
+        map.insert("hint.io_priority", "I/O 优先级");
+        map.insert("hint.jump_to_name", "按名称跳转");
+        map.insert("hint.kill_parent", "结束父进程");
+        map.insert("hint.kill_tree", "结束进程树");
+        map.insert("hint.navigate", "切换");
+        map.insert("hint.new_value", "新值");
+        map.insert("hint.older_newer_boot", "较早/较新启动");
+        map.insert("hint.open_files", "打开的文件");
+        map.insert("hint.owning_process_details", "所属进程详情");
+        map.insert("hint.parent_details", "父进程详情");
+        map.insert("hint.pause", "暂停");
+        map.insert("hint.port_owner", "端口所属");
+        map.insert("hint.process_details", "进程详情");
+        map.insert("hint.quit", "退出");
+        map.insert("hint.remove", "删除");
+        map.insert("hint.reset_session_totals", "重置会话总计");
+        map.insert("hint.restart", "重启");
+        map.insert("hint.root_actions_password", "root 操作需要输入密码");
+        map.insert("hint.save", "保存");
+        map.insert("hint.scan_largest_dirs", "扫描最大的目录");
+        map.insert("hint.scroll_command", "滚动命令");
+        map.insert("hint.scroll", "滚动");
+        map.insert("hint.select_gpu_process", "选择 GPU 进程");
+        map.insert("hint.select_gpu", "选择 GPU");
+        map.insert("hint.select_mount", "选择挂载点");
+        map.insert("hint.select_port", "选择端口");
+        map.insert("hint.select_thread", "选择线程");
+        map.insert("hint.select_zombie", "选择僵尸进程");
+        map.insert("hint.select", "选择");
+        map.insert("hint.send_sigchld", "向父进程发送 SIGCHLD");
+        map.insert("hint.show_container_processes", "显示容器进程");
+        map.insert("hint.container_columns", "大小/运行时间/重启列");
+        map.insert("hint.sort_keys", "按 CPU/内存/名称/综合排序");
+        map.insert("hint.sort_reverse_threads", "线程排序/反转");
+        map.insert("hint.sort_reverse", "排序/反转");
+        map.insert("hint.start", "启动");
+        map.insert("hint.status_log", "状态日志");
+        map.insert("hint.status", "状态");
+        map.insert("hint.stop", "停止");
+        map.insert("hint.terminate", "结束");
+        map.insert("hint.theme", "主题");
+        map.insert("hint.process_columns", "线程/状态/时间列");
+        map.insert("hint.tree_view", "树状视图");
+        map.insert("hint.zoom", "放大");
+        map.insert("hint.read_only", "只读模式");
+        map.insert("hint.service_actions_need_root", "服务操作需要 root");
+        map.insert("hint.editing_needs_root", "编辑需要 root");
+        map.insert("help.services", "↑↓: 导航 | 启动: s | 停止: x | 重启: r | 启用: e | 禁用: d | 编辑: v | 退出: q");
+        map.insert("help.logs", "↑↓: 导航 | 筛选: f | 清除: c | 导出: e | 搜索: / | 退出: q");
+        map.insert("help.config", "↑↓: 导航 | 编辑: e | 保存: Ctrl+S | 还原: R | 退出: q");
+        map.insert("log.debug", "调试");
+        map.insert("log.info", "信息");
+        map.insert("log.warning", "警告");
+        map.insert("log.error", "错误");
+        map.insert("log.critical", "严重");
+        map.insert("action.start", "启动");
+        map.insert("action.stop", "停止");
+        map.insert("action.restart", "重启");
+        map.insert("action.reload", "重新加载");
+        map.insert("action.enable", "启用");
+        map.insert("action.disable", "禁用");
+        map.insert("action.status", "状态");
+        map.insert("action.logs", "查看日志");
+        map.insert("action.edit", "编辑");
+        map.insert("action.save", "保存");
+        map.insert("action.cancel", "取消");
+        map.insert("info.hostname", "主机名");
+        map.insert("info.kernel", "内核");
+        map.insert("info.uptime", "运行时间");
+        map.insert("info.load", "平均负载");
+        map.insert("info.cores", "CPU 核心");
+        map.insert("info.threads", "线程");
+        map.insert("info.memory_total", "总内存");
+        map.insert("info.memory_used", "已用内存");
+        map.insert("info.memory_available", "可用内存");
+        map.insert("info.swap_total", "交换空间总量");
+        map.insert("info.swap_used", "已用交换空间");
+        map.insert("config.grub_timeout", "GRUB 超时");
+        map.insert("config.grub_default", "GRUB 默认项");
+        map.insert("config.grub_cmd", "内核命令行");
+        map.insert("config.hostname", "主机名");
+        map.insert("config.timezone", "时区");
+        map.insert("config.dns", "DNS 服务器");
+        map.insert("config.ntp", "NTP 服务");
+        map.insert("config.column.setting", "设置项");
+        map.insert("config.column.value", "值");
+        map.insert("config.column.description", "说明");
+        map.insert("config.empty", "没有可用的配置项");
+        map.insert("label.read_only", "只读");
+        map.insert("msg.success", "成功");
+        map.insert("msg.error", "错误");
+        map.insert("msg.warning", "警告");
+        map.insert("msg.confirm", "确认");
+        map.insert("msg.loading", "加载中...");
+        map.insert("msg.saved", "配置已保存");
+        map.insert("msg.failed", "操作失败");
+        map.insert("msg.unsaved", "有未保存的更改");
+        map.insert("msg.container_disabled", "容器监控已禁用");
+        map.insert("msg.no_containers", "没有正在运行的容器");
+        map.insert("status.sleeping", "休眠");
+        map.insert("status.zombie", "僵尸");
+        map.insert("status.other", "其他");
+        map.insert("memory.healthy", "健康");
+        map.insert("memory.moderate", "中等");
+        map.insert("memory.high", "偏高");
+        map.insert("memory.critical", "危急");
+        map.insert("memory.comfortable", "充裕");
+        map.insert("memory.tight", "紧张");
+        map.insert("efficiency.optimal", "最佳");
+        map.insert("efficiency.good", "良好");
+        map.insert("efficiency.fair", "一般");
+        map.insert("efficiency.poor", "较差");
+        map.insert("label.load", "负载");
+        map.insert("label.efficiency", "效率");
+        map.insert("label.available", "可用");
+        map.insert("label.na", "无");
+        map
+    }
+
+    fn create_ja_dict() -> HashMap<&'static str, &'static str> {
+        let mut map = HashMap::new();
+        
+        map.insert("tab.dashboard", "1:ダッシュボード");
+        map.insert("tab.process", "2:プロセス");
+        map.insert("tab.cpu", "3:CPU");
+        map.insert("tab.memory", "4:メモリ");
+        map.insert("tab.disks", "5:ディスク");
+        map.insert("tab.network", "6:ネットワーク");
+        map.insert("tab.gpu", "7:GPU");
+        map.insert("tab.system", "8:システム");
+        map.insert("tab.services", "9:サービス");
+        map.insert("tab.logs", "0:ログ");
+        map.insert("tab.config", "-:設定");
+        map.insert("tab.containers", "=:Docker");
+        map.insert("tab.cgroups", "]:cgroup");
+        map.insert("title.cgroups", "コントロールグループ (cgroup v2)");
+        map.insert("tab.security", "[:セキュリティ");
+        map.insert("title.security", "セキュリティ概要");
+        map.insert("title.config", "設定");
+        map.insert("title.puls", "PULS - システムモニター＆管理ツール");
+        map.insert("title.cpu", "CPU");
+        map.insert("title.memory", "メモリ");
+        map.insert("title.gpu", "GPU");
+        map.insert("title.network", "ネットワーク I/O");
+        map.insert("title.disk", "ディスク I/O");
+        map.insert("title.processes", "プロセス");
+        map.insert("title.system_overview", "システム概要");
+        map.insert("title.system_info", "システム情報");
+        map.insert("title.process_stats", "プロセス統計");
+        map.insert("title.containers", "コンテナ");
+        map.insert("title.cpu_cores", "CPU コア");
+        map.insert("title.disks", "ディスク");
+        map.insert("title.networks", "ネットワーク");
+        map.insert("title.gpus", "GPU");
+        map.insert("title.services", "システムサービス");
+        map.insert("title.logs", "システムログ");
+        map.insert("title.grub", "GRUB 設定");
+        map.insert("title.network_config", "ネットワーク設定");
+        map.insert("title.kernel", "カーネルパラメータ");
+        map.insert("header.pid", "PID");
+        map.insert("header.name", "名前");
+        map.insert("header.user", "ユーザー");
+        map.insert("header.cpu", "CPU %");
+        map.insert("header.memory", "メモリ");
+        map.insert("header.swap", "スワップ");
+        map.insert("header.disk_read", "読み込み");
+        map.insert("header.disk_write", "書き込み");
+        map.insert("header.mem_growth", "増加/分");
+        map.insert("title.leak_suspects", "リーク疑い");
+        map.insert("header.container", "コンテナ");
+        map.insert("header.threads", "スレッド");
+        map.insert("header.state", "状態");
+        map.insert("header.elapsed", "経過時間");
+        map.insert("header.cpu_time", "CPU時間");
+        map.insert("label.filter", "フィルター");
+        map.insert("header.service", "サービス");
+        map.insert("header.status", "状態");
+        map.insert("header.enabled", "有効");
+        map.insert("header.tasks", "タスク");
+        map.insert("header.uptime", "稼働時間");
+        map.insert("header.timestamp", "日時");
+        map.insert("header.level", "レベル");
+        map.insert("header.message", "メッセージ");
+        map.insert("status.running", "実行中");
+        map.insert("status.stopped", "停止");
+        map.insert("status.failed", "失敗");
+        map.insert("status.inactive", "非アクティブ");
+        map.insert("status.activating", "起動中");
+        map.insert("status.deactivating", "停止中");
+        map.insert("status.enabled", "有効");
+        map.insert("status.disabled", "無効");
+        map.insert("status.active", "アクティブ");
+        map.insert("status.paused", "[一時停止]");
+        map.insert("health.idle", "アイドル");
+        map.insert("health.normal", "正常");
+        map.insert("health.high", "高負荷");
+        map.insert("health.overload", "過負荷");
+        map.insert("health.critical", "危険");
+        map.insert("health.healthy", "良好");
+        map.insert("health.moderate", "中程度");
+        map.insert("alert.title", "アラート");
+        map.insert("alert.high_cpu", "CPU 高負荷！");
+        map.insert("alert.critical_cpu", "CPU 危険！");
+        map.insert("alert.high_memory", "メモリ高使用！");
+        map.insert("alert.critical_memory", "メモリ危険！");
+        map.insert("alert.disk_critical", "ディスク危険！");
+        map.insert("alert.disk_filling", "ディスク満杯まで");
+        map.insert("alert.tcp_retrans", "TCP 再送");
+        map.insert("alert.listen_drops", "リッスン破棄");
+        map.insert("alert.container_oom", "コンテナOOM");
+        map.insert("alert.disk_high", "ディスク高使用！");
+        map.insert("alert.auth_failures", "ログイン失敗！");
+        map.insert("alert.port_opened", "新規ポート！");
+        map.insert("alert.port_closed", "ポート停止！");
+        map.insert("alert.clock_unsynced", "時刻未同期！");
+        map.insert("alert.clock_drift", "時刻ずれ！");
+        map.insert("alert.service_down", "サービス停止！");
+        map.insert("alert.gpu_hot", "高温");
+        map.insert("alert.gpu_vram", "VRAM 不足");
+        map.insert("alert.gpu_ecc", "ECCエラー");
+        map.insert("alert.plugin_high", "高");
+        map.insert("alert.plugin_critical", "危険");
+        map.insert("help.paused", "[一時停止] 再開: p | 終了: q | タブ: 1-9,0 | 移動: ↑↓ | 詳細: Enter");
+        map.insert("hint.apply", "適用");
+        map.insert("hint.cpu_limit", "CPU 制限");
+        map.insert("hint.cancel_scan", "スキャン中止");
+        map.insert("hint.cancel", "キャンセル");
+        map.insert("hint.clear_container_filter", "コンテナフィルタ解除");
+        map.insert("hint.close_scan", "スキャンを閉じる");
+        map.insert("hint.collapse_expand", "折りたたみ/展開");
+        map.insert("hint.container_column", "コンテナ列");
+        map.insert("hint.copy_command", "コマンドをコピー");
+        map.insert("hint.details", "詳細");
+        map.insert("hint.edit", "編集");
+        map.insert("hint.enable_disable", "有効化/無効化");
+        map.insert("hint.export_metrics", "メトリクスを書き出し");
+        map.insert("hint.filter_logs", "ログを絞り込み");
+        map.insert("hint.filter", "絞り込み");
+        map.insert("hint.force_kill", "強制終了");
+        map.insert("hint.freeze_resume", "凍結/再開");
+        map.insert("hint.io_priority", "I/O 優先度");
+        map.insert("hint.jump_to_name", "名前へジャンプ");
+        map.insert("hint.kill_parent", "親を終了");
+        map.insert("hint.kill_tree", "ツリーを終了");
+        map.insert("hint.navigate", "移動");
+        map.insert("hint.new_value", "新しい値");
+        map.insert("hint.older_newer_boot", "前/次のブート");
+        map.insert("hint.open_files", "開いているファイル");
+        map.insert("hint.owning_process_details", "所有プロセスの詳細");
+        map.insert("hint.parent_details", "親の詳細");
+        map.insert("hint.pause", "一時停止");
+        map.insert("hint.port_owner", "ポート所有者");
+        map.insert("hint.process_details", "プロセスの詳細");
+        map.insert("hint.quit", "終了");
+        map.insert("hint.remove", "削除");
+        map.insert("hint.reset_session_totals", "セッション合計をリセット");
+        map.insert("hint.restart", "再起動");
+        map.insert("hint.root_actions_password", "root 操作はパスワードを求めます");
+        map.insert("hint.save", "保存");
+        map.insert("hint.scan_largest_dirs", "最大のディレクトリを探す");
+        map.insert("hint.scroll_command", "コマンドをスクロール");
+        map.insert("hint.scroll", "スクロール");
+        map.insert("hint.select_gpu_process", "GPU プロセスを選択");
+        map.insert("hint.select_gpu", "GPU を選択");
+        map.insert("hint.select_mount", "マウントを選択");
+        map.insert("hint.select_port", "ポートを選択");
+        map.insert("hint.select_thread", "スレッドを選択");
+        map.insert("hint.select_zombie", "ゾンビを選択");
+        map.insert("hint.select", "選択");
+        map.insert("hint.send_sigchld", "親に SIGCHLD を送信");
+        map.insert("hint.show_container_processes", "コンテナのプロセスを表示");
+        map.insert("hint.container_columns", "サイズ/稼働時間/再起動列");
+        map.insert("hint.sort_keys", "CPU/メモリ/名前/総合で並べ替え");
+        map.insert("hint.sort_reverse_threads", "スレッドの並べ替え/逆順");
+        map.insert("hint.sort_reverse", "並べ替え/逆順");
+        map.insert("hint.start", "開始");
+        map.insert("hint.status_log", "ステータスログ");
+        map.insert("hint.status", "状態");
+        map.insert("hint.stop", "停止");
+        map.insert("hint.terminate", "終了");
+        map.insert("hint.theme", "テーマ");
+        map.insert("hint.process_columns", "スレッド/状態/時間列");
+        map.insert("hint.tree_view", "ツリー表示");
+        map.insert("hint.zoom", "拡大");
+        map.insert("hint.read_only", "読み取り専用モード");
+        map.insert("hint.service_actions_need_root", "サービス操作には root が必要です");
+        map.insert("hint.editing_needs_root", "編集には root が必要です");
+        map.insert("help.services", "↑↓: 移動 | 開始: s | 停止: x | 再起動: r | 有効化: e | 無効化: d | 編集: v | 終了: q");
+        map.insert("help.logs", "↑↓: 移動 | フィルター: f | クリア: c | エクスポート: e | 検索: / | 終了: q");
+        map.insert("help.config", "↑↓: 移動 | 編集: e | 保存: Ctrl+S | 元に戻す: R | 終了: q");
+        map.insert("log.debug", "デバッグ");
+        map.insert("log.info", "情報");
+        map.insert("log.warning", "警告");
+        map.insert("log.error", "エラー");
+        map.insert("log.critical", "重大");
+        map.insert("action.start", "開始");
+        map.insert("action.stop", "停止");
+        map.insert("action.restart", "再起動");
+        map.insert("action.reload", "再読み込み");
+        map.insert("action.enable", "有効化");
+        map.insert("action.disable", "無効化");
+        map.insert("action.status", "状態");
+        map.insert("action.logs", "ログを表示");
+        map.insert("action.edit", "編集");
+        map.insert("action.save", "保存");
+        map.insert("action.cancel", "キャンセル");
+        map.insert("info.hostname", "ホスト名");
+        map.insert("info.kernel", "カーネル");
+        map.insert("info.uptime", "稼働時間");
+        map.insert("info.load", "ロードアベレージ");
+        map.insert("info.cores", "CPU コア");
+        map.insert("info.threads", "スレッド");
+        map.insert("info.memory_total", "総メモリ");
+        map.insert("info.memory_used", "使用メモリ");
+        map.insert("info.memory_available", "利用可能メモリ");
+        map.insert("info.swap_total", "スワップ合計");
+        map.insert("info.swap_used", "スワップ使用量");
+        map.insert("config.grub_timeout", "GRUB タイムアウト");
+        map.insert("config.grub_default", "GRUB デフォルトエントリ");
+        map.insert("config.grub_cmd", "カーネルコマンドライン");
+        map.insert("config.hostname", "ホスト名");
+        map.insert("config.timezone", "タイムゾーン");
+        map.insert("config.dns", "DNS サーバー");
+        map.insert("config.ntp", "NTP サービス");
+        map.insert("config.column.setting", "設定項目");
+        map.insert("config.column.value", "値");
+        map.insert("config.column.description", "説明");
+        map.insert("config.empty", "利用可能な設定項目がありません");
+        map.insert("label.read_only", "読み取り専用");
+        map.insert("msg.success", "成功");
+        map.insert("msg.error", "エラー");
+        map.insert("msg.warning", "警告");
+        map.insert("msg.confirm", "確認");
+        map.insert("msg.loading", "読み込み中...");
+        map.insert("msg.saved", "設定を保存しました");
+        map.insert("msg.failed", "操作に失敗しました");
+        map.insert("msg.unsaved", "未保存の変更があります");
+        map.insert("msg.container_disabled", "コンテナ監視は無効です");
+        map.insert("msg.no_containers", "実行中のコンテナはありません");
+        map.insert("status.sleeping", "スリープ");
+        map.insert("status.zombie", "ゾンビ");
+        map.insert("status.other", "その他");
+        map.insert("memory.healthy", "良好");
+        map.insert("memory.moderate", "中程度");
+        map.insert("memory.high", "高い");
+        map.insert("memory.critical", "危険");
+        map.insert("memory.comfortable", "余裕あり");
+        map.insert("memory.tight", "逼迫");
+        map.insert("efficiency.optimal", "最適");
+        map.insert("efficiency.good", "良好");
+        map.insert("efficiency.fair", "普通");
+        map.insert("efficiency.poor", "低い");
+        map.insert("label.load", "負荷");
+        map.insert("label.efficiency", "効率");
+        map.insert("label.available", "利用可能");
+        map.insert("label.na", "なし");
+        map
+    }
+}
+
+pub fn locale_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("puls").join("lang"))
+}
+
+fn load_locale_file(code: &str) -> Result<HashMap<String, String>, String> {
+    // The code becomes a file name, so keep it to something like `pt_br` or `de-at`.
+    if code.is_empty() || !code.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(format!("invalid language code '{}'", code));
+    }
+
+    let path = locale_dir().ok_or("no config directory")?.join(format!("{}.toml", code));
+    let contents = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse_locale(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+// Keys are the same dotted names used in code. Both `tab.cpu = "..."` and a
+// `[tab]` table with `cpu = "..."` produce the key `tab.cpu`.
+pub fn parse_locale(contents: &str) -> Result<HashMap<String, String>, toml::de::Error> {
+    fn flatten(prefix: &str, table: toml::Table, out: &mut HashMap<String, String>) {
+        for (name, value) in table {
+            let key = if prefix.is_empty() { name } else { format!("{}.{}", prefix, name) };
+            match value {
+                toml::Value::String(text) => {
+                    out.insert(key, text);
+                }
+                toml::Value::Table(table) => flatten(&key, table, out),
+                _ => {}
+            }
+        }
+    }
+
+    let mut dict = HashMap::new();
+    flatten("", contents.parse::<toml::Table>()?, &mut dict);
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_locale() {
+        let dict = parse_locale("title.cpu = \"Prozessor\"\n[tab]\ncpu = \"3:CPU\"\nmemory = \"4:Speicher\"\n").unwrap();
+        assert_eq!(dict.get("title.cpu").map(String::as_str), Some("Prozessor"));
+        assert_eq!(dict.get("tab.memory").map(String::as_str), Some("4:Speicher"));
+        assert!(parse_locale("tab = [").is_err());
+    }
+
+    #[test]
+    fn test_external_language_falls_back_to_english() {
+        let mut translator = Translator::new(Language::English);
+        translator.lang = Language::External("de".to_string());
+        translator.external_dict.insert("title.cpu".to_string(), "Prozessor".to_string());
+
+        assert_eq!(translator.t("title.cpu"), "Prozessor");
+        assert_eq!(translator.t("title.memory"), "Memory");
+        assert!(load_locale_file("../secrets").is_err());
+    }
+
+    #[test]
+    fn test_missing_keys_fall_back_to_english() {
+        let mut translator = Translator::new(Language::German);
+        translator.dict.remove("title.memory");
+
+        assert_eq!(translator.t("title.cpu"), "CPU");
+        assert_eq!(translator.t("title.memory"), "Memory");
+        assert_eq!(translator.t("title.memory"), "Memory");
+        assert_eq!(translator.t("no.such.key"), "no.such.key");
+        assert_eq!(translator.missing.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_builtin_languages_are_complete() {
+        let english = Translator::create_en_dict();
+        for lang in ["tr", "de", "es", "fr", "pt", "zh", "ja"] {
+            let translator = Translator::new(Language::from_str(lang));
+            let missing: Vec<_> = english.keys().filter(|key| !translator.dict.contains_key(*key)).collect();
+            assert!(missing.is_empty(), "{} is missing {:?}", lang, missing);
+        }
+    }
+
+    #[test]
+    fn test_language_from_locale() {
+        assert_eq!(Language::from_locale("tr_TR.UTF-8"), Language::Turkish);
+        assert_eq!(Language::from_locale("pt_BR.UTF-8"), Language::Portuguese);
+        assert_eq!(Language::from_locale("ja_JP.eucJP"), Language::Japanese);
+        assert_eq!(Language::from_locale("en_GB@euro"), Language::English);
+        assert_eq!(Language::from_locale("C"), Language::English);
+        assert_eq!(Language::from_locale(""), Language::English);
+    }
 }
@@ -0,0 +1,190 @@
+#![cfg_attr(not(target_os = "macos"), allow(dead_code))]
+
+// launchd and unified log counterparts of the systemctl/journalctl calls in
+// system_service. Parsing is kept platform independent so it can be tested
+// on any host.
+
+use std::collections::HashSet;
+use std::process::Command;
+use crate::types::{LogEntry, ServiceInfo};
+
+pub fn get_services(has_root: bool) -> Vec<ServiceInfo> {
+    let output = match Command::new("launchctl").arg("list").output() {
+        Ok(output) => output,
+        Err(_) => return Vec::new(),
+    };
+
+    let disabled = Command::new("launchctl")
+        .args(["print-disabled", "system"])
+        .output()
+        .map(|out| parse_disabled_labels(&String::from_utf8_lossy(&out.stdout)))
+        .unwrap_or_default();
+
+    let mut services = parse_launchctl_list(&String::from_utf8_lossy(&output.stdout), &disabled, has_root);
+    services.sort_by(|a, b| a.name.cmp(&b.name));
+    services
+}
+
+pub fn service_action(action: &str, label: &str) -> Result<(), String> {
+    let target = format!("system/{}", label);
+    let args: Vec<&str> = match action {
+        "start" => vec!["kickstart", &target],
+        "stop" => vec!["kill", "SIGTERM", &target],
+        "restart" => vec!["kickstart", "-k", &target],
+        "enable" => vec!["enable", &target],
+        "disable" => vec!["disable", &target],
+        _ => return Err(format!("Unsupported service action: {}", action)),
+    };
+
+    let output = Command::new("launchctl")
+        .args(&args)
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).to_string())
+    }
+}
+
+pub fn get_service_status(label: &str) -> String {
+    match Command::new("launchctl").args(["print", &format!("system/{}", label)]).output() {
+        Ok(out) => {
+            let stdout = String::from_utf8_lossy(&out.stdout);
+            if stdout.trim().is_empty() {
+                String::from_utf8_lossy(&out.stderr).to_string()
+            } else {
+                stdout.to_string()
+            }
+        }
+        Err(e) => format!("Error getting status: {}", e),
+    }
+}
+
+pub fn get_logs(limit: usize, filter: Option<&str>) -> Vec<LogEntry> {
+    let mut args = vec![
+        "show".to_string(),
+        "--style".to_string(),
+        "json".to_string(),
+        "--last".to_string(),
+        "5m".to_string(),
+    ];
+
+    if let Some(f) = filter {
+        if !f.is_empty() {
+            args.push("--predicate".to_string());
+            args.push(format!("eventMessage CONTAINS[c] \"{}\"", f.replace('"', "\\\"")));
+        }
+    }
+
+    let output = match Command::new("log").args(&args).output() {
+        Ok(output) => output,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut logs = parse_log_json(&String::from_utf8_lossy(&output.stdout));
+    let skip = logs.len().saturating_sub(limit);
+    logs.drain(..skip);
+    logs
+}
+
+pub fn parse_launchctl_list(output: &str, disabled: &HashSet<String>, has_root: bool) -> Vec<ServiceInfo> {
+    output.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 3 {
+                return None;
+            }
+
+            let running = parts[0].parse::<u32>().is_ok();
+            let failed = !running && parts[1] != "0";
+            let label = parts[2].to_string();
+            let status = if running { "Running" } else if failed { "Failed" } else { "Stopped" };
+
+            Some(ServiceInfo {
+                description: format!("launchd job (last exit {})", parts[1]),
+                status: status.to_string(),
+                enabled: !disabled.contains(&label),
+                can_start: !running && has_root,
+                can_stop: running && has_root,
+                name: label,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+// `launchctl print-disabled` prints `"label" => disabled` on recent releases and
+// `"label" => true` on older ones.
+pub fn parse_disabled_labels(output: &str) -> HashSet<String> {
+    output.lines()
+        .filter_map(|line| {
+            let (label, state) = line.split_once("=>")?;
+            let state = state.trim();
+            if state == "disabled" || state == "true" {
+                Some(label.trim().trim_matches('"').to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+pub fn parse_log_json(json: &str) -> Vec<LogEntry> {
+    let entries: Vec<serde_json::Value> = match serde_json::from_str(json) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    entries.iter()
+        .map(|entry| {
+            let field = |key: &str| entry.get(key).and_then(|v| v.as_str()).unwrap_or("");
+            let service = field("processImagePath").rsplit('/').next().unwrap_or("").to_string();
+            let level = match field("messageType") {
+                "Error" | "Fault" => "ERROR",
+                "Debug" => "DEBUG",
+                _ => "INFO",
+            };
+
+            LogEntry {
+                timestamp: field("timestamp").chars().take(19).collect(),
+                level: level.to_string(),
+                service,
+                message: field("eventMessage").to_string(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_launchctl_list() {
+        let output = "PID\tStatus\tLabel\n312\t0\tcom.apple.Finder\n-\t0\tcom.apple.idle\n-\t78\tcom.example.broken\n";
+        let disabled = parse_disabled_labels("disabled services = {\n\t\"com.apple.idle\" => disabled\n\t\"com.apple.ftpd\" => true\n\t\"com.apple.sshd\" => enabled\n}");
+        assert_eq!(disabled.len(), 2);
+
+        let services = parse_launchctl_list(output, &disabled, true);
+        assert_eq!(services.len(), 3);
+        assert_eq!(services[0].status, "Running");
+        assert!(services[0].can_stop && services[0].enabled);
+        assert_eq!(services[1].status, "Stopped");
+        assert!(!services[1].enabled);
+        assert_eq!(services[2].status, "Failed");
+    }
+
+    #[test]
+    fn test_parse_log_json() {
+        let json = r#"[{"timestamp":"2024-05-01 10:20:30.123456-0700","messageType":"Error","processImagePath":"/usr/libexec/sshd","eventMessage":"auth failed"}]"#;
+        let logs = parse_log_json(json);
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].timestamp, "2024-05-01 10:20:30");
+        assert_eq!(logs[0].level, "ERROR");
+        assert_eq!(logs[0].service, "sshd");
+        assert!(parse_log_json("not json").is_empty());
+    }
+}
@@ -1,64 +1,186 @@
 mod types;
+mod alerts;
 mod utils;
 mod config;
 mod monitors;
 mod ui;
 mod language;
 mod system_service;
+mod windows_service;
+mod macos_service;
 mod error_logger;
+mod status;
+mod headless;
+mod state_file;
+mod cpu_limit;
+mod disk_usage;
+mod fs_watch;
+mod plugins;
+mod open_files;
+mod port_lookup;
+mod report;
+mod snapshot_diff;
+mod config_check;
+mod sample_history;
+mod net_session;
+mod watchdog;
+mod capture;
+mod retention;
+mod elevate;
 
-use crate::types::{AppState, ProcessSortBy};
-use std::io;
-use std::sync::Arc;
+use crate::types::{AppMessage, AppState, CollectorControl, CollectorKind, KillSignal, PendingAction, ProcessSortBy, TypeAhead};
+use crate::ui::widgets::{ConfirmDialog, DialogResponse, LookupAction};
+use std::io::{self, IsTerminal};
 use std::time::{Duration, Instant};
 
-
-use parking_lot::Mutex;
+use tokio::sync::{mpsc, watch};
 use crossterm::{
     event::{self, Event, KeyCode, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{prelude::*, Terminal};
-use tokio::time::sleep;
 
-use clap::Parser;
-use crate::config::{Cli};
-use crate::monitors::DataCollector;
+use clap::{CommandFactory, Parser};
+use crate::config::{Cli, Command, ConfigAction, OnceFormat};
+use crate::monitors::{ContainerCollector, DataCollector, GpuCollector, SystemCollector};
+use crate::monitors::scheduler::Scheduler;
+use crate::monitors::cgroup_monitor::visible_cgroups;
 use crate::types::AppConfig;
 use crate::ui::render_ui;
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
+    let command = cli.command.clone().unwrap_or(Command::Tui);
+    // Scripts and pipes get a summary instead of a TUI they can't show,
+    // unless --tui insists (e.g. to capture the screen with script or tee).
+    let once = cli.once.or_else(|| (!cli.tui && !io::stdout().is_terminal()).then_some(OnceFormat::Text));
+    init_logging(cli.verbose)?;
     let config = AppConfig::from(cli);
     
+    if let Some(pid) = config.start_pid {
+        if !monitors::system_monitor::process_exists(pid) {
+            eprintln!("puls: no process with PID {}", pid);
+            std::process::exit(1);
+        }
+    }
+    
+    match command {
+        Command::Tui => match once {
+            Some(format) => report::once(&config, format).await,
+            None => run_tui(config, None).await,
+        },
+        Command::Replay { input, speed } => {
+            let recording = headless::Recording::load(&input)?;
+            run_tui(config, Some((recording, speed))).await
+        }
+        Command::Snapshot => headless::snapshot(&config).await,
+        Command::Diff { before, after } => snapshot_diff::run(&before, &after),
+        Command::Record { output, duration } => headless::record(&config, &output, duration).await,
+        Command::Serve { listen } => headless::serve(&config, &listen).await,
+        Command::Report { format, output } => report::run(&config, format, output.as_deref()).await,
+        Command::Port { port } => {
+            let found = port_lookup::run(&config, port).await;
+            std::process::exit(if found { 0 } else { 1 });
+        }
+        Command::Config { action: ConfigAction::Check } => {
+            std::process::exit(if config_check::run() { 0 } else { 1 });
+        }
+        Command::Check => {
+            let healthy = run_check(&config).await;
+            std::process::exit(if healthy { 0 } else { 1 });
+        }
+        Command::KillTree { pid, start_time } => {
+            match kill_tree(sysinfo::Pid::from_u32(pid), start_time) {
+                Ok(count) => println!("Killed {} processes", count),
+                Err(e) => {
+                    eprintln!("puls: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            Ok(())
+        }
+        Command::Completions { shell } => {
+            // clap_complete panics on write errors, so render first and let
+            // a closed pipe surface as an ordinary error.
+            let mut script = Vec::new();
+            clap_complete::generate(shell, &mut Cli::command(), "puls", &mut script);
+            io::Write::write_all(&mut io::stdout().lock(), &script)?;
+            Ok(())
+        }
+    }
+}
+
+async fn run_tui(
+    config: AppConfig,
+    replay: Option<(headless::Recording, f64)>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    status::init(tx.clone());
+    
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
     
-    let app_state = Arc::new(Mutex::new(AppState::default()));
-    let data_collector = Arc::new(Mutex::new(DataCollector::new(config.clone())));
+    let mut state = AppState::default();
+    let saved = if config.restore_state { state_file::load() } else { state_file::SavedState::default() };
+    state.selected_pid = config.start_pid.map(sysinfo::Pid::from_u32);
+    // An explicit --tab still wins over the detail view --pid implies.
+    state.active_tab = config.start_tab
+        .or(config.start_pid.map(|_| config::Tab::Process))
+        .or(saved.last_tab)
+        .map(|tab| tab.index())
+        .unwrap_or(0);
     
-    let system_info = {
-        let collector = data_collector.lock();
-        collector.get_system_info()
+    let (data_collector, system_info) = match &replay {
+        Some((recording, _)) => {
+            let mut info = recording.system_info.clone();
+            info.push(("Mode".to_string(), "Replay".to_string()));
+            (None, info)
+        }
+        None => {
+            let collector = DataCollector::new(config.clone());
+            let info = collector.get_system_info();
+            (Some(collector), info)
+        }
     };
     
     {
-        let mut state = app_state.lock();
         state.system_info = system_info;
+        state.alert_thresholds = config.alert_thresholds;
+        state.fs_events = fs_watch::FsEventLog::new(config.watch_paths.clone());
+        if replay.is_none() {
+            let dir = config.plugin_dir.clone().or_else(plugins::default_dir);
+            state.plugins = plugins::PluginReports::new(&dir.map(|dir| plugins::discover(&dir)).unwrap_or_default());
+        }
+        // Flags win over what was saved at the last exit.
+        state.current_theme = config.theme.or(saved.theme.map(|theme| theme as usize)).unwrap_or(0);
+        state.filter_text = config.process_filter.clone().unwrap_or(saved.filter);
+        state.sort_by = config.process_sort.clone().or(saved.sort).unwrap_or_default();
+        state.sort_ascending = config.process_sort.is_none() && saved.sort_ascending;
+        state.show_container_column = saved.show_container_column;
+        state.show_state_columns = saved.show_state_columns;
+        state.show_container_details = saved.show_container_details;
+        state.hide_summary = saved.hide_summary;
         
         if config.safe_mode {
             state.system_info.push(("Mode".to_string(), "Safe Mode".to_string()));
+        } else if config.read_only {
+            state.system_info.push(("Mode".to_string(), "Read-Only".to_string()));
         }
         
         let sys_mgr = system_service::SystemManager::new();
         state.has_sudo = sys_mgr.has_sudo_privileges();
+        state.read_only = config.read_only;
+        state.export_path = config.export_path.clone();
+        if !state.has_sudo {
+            state.elevate_tool = elevate::Tool::detect();
+        }
         
-        state.services = sys_mgr.get_services();
+        reload_services(&mut state, &sys_mgr);
         if !state.services.is_empty() {
             state.services_table_state.select(Some(0));
         }
@@ -82,19 +204,51 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let local = tokio::task::LocalSet::new();
 
     let result = local.run_until(async {
-        let app_state_clone = app_state.clone();
-        let data_collector_clone = data_collector.clone();
-        let config_clone = config.clone();
-        tokio::task::spawn_local(async move {
-            data_collection_loop(app_state_clone, data_collector_clone, config_clone).await;
-        });
-
-        ui_loop(&mut terminal, app_state, &config).await
+        if let Err(e) = setup_signal_handlers(tx.clone()) {
+            log::warn!("Signal handling unavailable: {}", e);
+        }
+        let (control_tx, control_rx) = watch::channel(CollectorControl::from_state(&state));
+        
+        // A replay only shows recorded data; nothing live is restarted.
+        let watch_services = !config.watch_services.is_empty() && replay.is_none();
+        let capture_windows = !config.capture_windows.is_empty() && replay.is_none();
+        let watch_paths = !config.watch_paths.is_empty() && replay.is_none();
+        match (data_collector, replay) {
+            (Some(collector), _) => spawn_collectors(tx.clone(), control_rx, collector, &config),
+            (None, Some((recording, speed))) => {
+                tokio::task::spawn_local(headless::replay_loop(tx.clone(), control_rx, recording, speed));
+            }
+            (None, None) => {}
+        }
+        if watch_services {
+            spawn_watchdog(tx.clone(), &config, state.has_sudo);
+        }
+        if capture_windows {
+            spawn_capture(&config);
+        }
+        if watch_paths {
+            fs_watch::spawn_watcher(tx.clone(), config.watch_paths.clone());
+        }
+        plugins::spawn_plugins(tx.clone(), state.plugins.plugins.iter().map(|plugin| plugin.path.clone()).collect());
+        spawn_input_reader(tx.clone());
+
+        ui_loop(&mut terminal, &mut state, (tx, rx), control_tx, &config).await
     }).await;
+    
+    // Dropping the set cancels the collector loops before the terminal is handed back.
+    drop(local);
+    plugins::stop_all();
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
+    log::logger().flush();
+    
+    if config.restore_state {
+        if let Err(e) = state_file::save(&state_file::SavedState::from_state(&state)) {
+            log::debug!("Could not save UI state: {}", e);
+        }
+    }
 
     if let Err(ref e) = result {
         eprintln!("Application error: {}", e);
@@ -104,51 +258,357 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     result.map_err(|e| e.into())
 }
 
+async fn run_check(config: &AppConfig) -> bool {
+    let collector = DataCollector::new(config.clone());
+    let health = collector.health_check().await;
+    
+    for (name, available) in &health {
+        println!("{:<10} {}", name, if *available { "OK" } else { "UNAVAILABLE" });
+    }
+    
+    for (name, enabled) in [
+        ("Docker", config.enable_docker),
+        ("GPU", config.enable_gpu_monitoring),
+        ("Network", config.enable_network_monitoring),
+    ] {
+        if !enabled {
+            println!("{:<10} disabled", name);
+        }
+    }
+    
+    health.iter().all(|(_, available)| *available)
+}
+
+const RENDER_KEEPALIVE: Duration = Duration::from_secs(1);
+
 async fn ui_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
-    app_state: Arc<Mutex<AppState>>,
+    state: &mut AppState,
+    (tx, mut rx): (mpsc::UnboundedSender<AppMessage>, mpsc::UnboundedReceiver<AppMessage>),
+    control_tx: watch::Sender<CollectorControl>,
     config: &AppConfig,
 ) -> io::Result<()> {
-    let ui_refresh_interval = Duration::from_millis(config.ui_refresh_rate_ms());
-    let mut last_render = Instant::now();
+    let translator = crate::language::Translator::new(config.language.clone());
+    let mut render_interval = tokio::time::interval(Duration::from_millis(config.ui_refresh_rate_ms()));
+    render_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut plotter = crate::ui::graphics::init(config.graphics);
+    let mut frames = 0u32;
+    let mut fps_window = Instant::now();
+    
+    // Frames are only drawn when something changed, plus a slow keepalive so
+    // clocks and uptime keep moving while paused.
+    let mut dirty = true;
+    let mut last_draw = Instant::now();
     
     loop {
-        let now = Instant::now();
-        
-        while event::poll(Duration::from_millis(0))? {
-            if let Event::Key(key) = event::read()? {
-                let should_quit = handle_key_event(key, &app_state)?;
-                if should_quit {
-                    return Ok(());
+        tokio::select! {
+            message = rx.recv() => {
+                match message {
+                    Some(AppMessage::Update(update)) => {
+                        apply_update(state, update);
+                        dirty = true;
+                    }
+                    Some(AppMessage::Timing(kind, timing)) => {
+                        match kind {
+                            CollectorKind::System => state.performance.system = timing,
+                            CollectorKind::Gpu => state.performance.gpu = timing,
+                            CollectorKind::Containers => state.performance.containers = timing,
+                        }
+                        dirty |= state.show_perf_overlay;
+                    }
+                    Some(AppMessage::Resize) => dirty = true,
+                    Some(AppMessage::Shutdown(signal)) => {
+                        log::info!("Received {}, shutting down", signal);
+                        return Ok(());
+                    }
+                    Some(AppMessage::Input(key)) => {
+                        dirty = true;
+                        if handle_key_event(key, state)? {
+                            return Ok(());
+                        }
+                        run_elevation(terminal, state)?;
+                        if let Some(path) = state.open_files_lookup.take() {
+                            let tx = tx.clone();
+                            tokio::task::spawn_blocking(move || {
+                                let holders = open_files::find_holders(&path);
+                                let _ = tx.send(AppMessage::OpenFiles(path, holders));
+                            });
+                        }
+                        control_tx.send_if_modified(|control| {
+                            let next = CollectorControl::from_state(state);
+                            let changed = *control != next;
+                            *control = next;
+                            changed
+                        });
+                    }
+                    Some(AppMessage::Status(message)) => {
+                        state.status_log.push(message);
+                        dirty = true;
+                    }
+                    Some(AppMessage::Watchdog(down)) => {
+                        dirty |= state.watchdog_down != down;
+                        state.watchdog_down = down;
+                    }
+                    Some(AppMessage::FsEvents(events)) => {
+                        state.fs_events.extend(events);
+                        dirty |= state.active_tab == 4;
+                    }
+                    Some(AppMessage::Plugin(update)) => {
+                        state.plugins.apply(update);
+                        dirty = true;
+                    }
+                    Some(AppMessage::OpenFiles(path, holders)) => {
+                        if let Some(popup) = state.open_files_popup.as_mut() {
+                            popup.set_results(path, holders);
+                            dirty = true;
+                        }
+                    }
+                    None => return Ok(()),
                 }
             }
-        }
-        
-        if now.duration_since(last_render) >= ui_refresh_interval {
-            {
-                let mut state = app_state.lock();
-                let translator = crate::language::Translator::new(config.language);
-                terminal.draw(|f| render_ui(f, &mut state, config.safe_mode, &translator))?;
+            _ = render_interval.tick() => {
+                dirty |= state.toasts.expire(Instant::now());
+                if !dirty && last_draw.elapsed() < RENDER_KEEPALIVE {
+                    continue;
+                }
+                dirty = false;
+                last_draw = Instant::now();
+                
+                let render_start = Instant::now();
+                let frame = terminal.draw(|f| {
+                    render_ui(f, state, config.safe_mode, &translator);
+                    config.color_depth.adapt_buffer(f.buffer_mut());
+                })?;
+                if std::mem::take(&mut state.export_screen) {
+                    match crate::ui::export::save(frame.buffer) {
+                        Ok((ansi, html)) => state.toasts.success(format!("Exported to {} and {}", ansi.display(), html.display())),
+                        Err(e) => state.toasts.error(format!("Could not save screen: {}", e)),
+                    }
+                    dirty = true;
+                }
+                if let Some(plotter) = plotter.as_mut() {
+                    let buffer = frame.buffer.clone();
+                    plotter.draw(terminal.backend_mut(), &buffer)?;
+                }
+                state.performance.render_duration = render_start.elapsed();
+                
+                frames += 1;
+                let elapsed = fps_window.elapsed();
+                if elapsed >= Duration::from_secs(1) {
+                    state.performance.fps = frames as f32 / elapsed.as_secs_f32();
+                    frames = 0;
+                    fps_window = Instant::now();
+                }
             }
-            last_render = now;
         }
-        
-        sleep(Duration::from_millis(1)).await;
     }
 }
 
+// Runs the elevated command confirmed by the last key, if any, on the real
+// terminal, then reports how it went.
+fn run_elevation(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, state: &mut AppState) -> io::Result<()> {
+    let (Some(command), Some(tool)) = (state.elevation.take(), state.elevate_tool) else { return Ok(()) };
+    match elevate::run(terminal, tool, &command)? {
+        Ok(()) => state.toasts.success(format!("Ran {}", elevate::describe(tool, &command))),
+        Err(e) => state.toasts.error(e),
+    }
+    match (command[0].as_str(), command.get(1).map(String::as_str)) {
+        ("kill", _) | (_, Some("kill-tree")) => state.selected_pid = None,
+        ("systemctl", _) => reload_services(state, &system_service::SystemManager::new()),
+        _ => {}
+    }
+    Ok(())
+}
+
+// crossterm's blocking reader lives on its own thread and forwards key
+// presses to the UI loop as commands.
+fn spawn_input_reader(tx: mpsc::UnboundedSender<AppMessage>) {
+    use std::sync::atomic::Ordering;
+    std::thread::spawn(move || {
+        loop {
+            // Polled rather than blocking in read, so the reader can step
+            // aside while an elevated command prompts for a password.
+            if elevate::INPUT_PAUSED.load(Ordering::SeqCst) {
+                std::thread::sleep(elevate::INPUT_POLL);
+                continue;
+            }
+            match event::poll(elevate::INPUT_POLL) {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(_) => break,
+            }
+            let Ok(event) = event::read() else { break };
+            let message = match event {
+                Event::Key(key) => AppMessage::Input(key),
+                Event::Resize(..) => AppMessage::Resize,
+                _ => continue,
+            };
+            if tx.send(message).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+fn arrows_select(state: &AppState) -> bool {
+    matches!(state.active_tab, 6 | 9 | 12) || (state.active_tab == 0 && state.process_tree)
+}
+
 fn handle_key_event(
     key: crossterm::event::KeyEvent,
-    app_state: &Arc<Mutex<AppState>>,
+    state: &mut AppState,
 ) -> io::Result<bool> {
-    let mut state = app_state.lock();
+    if state.show_log_viewer {
+        // The log popup is modal: it only scrolls and closes.
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => state.show_log_viewer = false,
+            KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => state.show_log_viewer = false,
+            KeyCode::Up => state.log_viewer_scroll = state.log_viewer_scroll.saturating_add(1),
+            KeyCode::Down => state.log_viewer_scroll = state.log_viewer_scroll.saturating_sub(1),
+            KeyCode::PageUp => state.log_viewer_scroll = state.log_viewer_scroll.saturating_add(10),
+            KeyCode::PageDown => state.log_viewer_scroll = state.log_viewer_scroll.saturating_sub(10),
+            _ => {}
+        }
+        return Ok(false);
+    }
     
-    match key.code {
-        KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
-            if state.pending_kill_pid.is_some() {
-                state.pending_kill_pid = None;
+    if let Some(popup) = state.cpu_limit_popup.as_mut() {
+        match popup.handle_key(key) {
+            DialogResponse::Pending => {}
+            DialogResponse::Cancel => state.cpu_limit_popup = None,
+            DialogResponse::Confirm => {
+                if let Some(popup) = state.cpu_limit_popup.take() {
+                    match state.cpu_limits.set(popup.pid, popup.percent) {
+                        Ok(method) => state.toasts.success(format!("Process {} limited to {}% CPU ({})", popup.pid, popup.percent, method.label())),
+                        Err(e) => state.toasts.error(format!("CPU limit failed: {}", e)),
+                    }
+                }
+            }
+        }
+        return Ok(false);
+    }
+    
+    if let Some(popup) = state.open_files_popup.as_mut() {
+        match popup.handle_key(key) {
+            LookupAction::Pending => {}
+            LookupAction::Cancel => state.open_files_popup = None,
+            LookupAction::Search => {
+                popup.searching = true;
+                state.open_files_lookup = Some(popup.input.clone());
+            }
+            LookupAction::Jump(pid) => {
+                state.open_files_popup = None;
+                let row = state.dynamic_data.processes.iter().position(|process| process.pid == pid.to_string());
+                match row {
+                    Some(row) => {
+                        state.process_table_state.select(Some(row));
+                        state.active_tab = 0;
+                    }
+                    // Hidden by the filter, or outside the listed processes.
+                    None => {
+                        state.selected_pid = Some(sysinfo::Pid::from_u32(pid));
+                        state.active_tab = 1;
+                    }
+                }
+            }
+        }
+        return Ok(false);
+    }
+    
+    if let Some(popup) = state.port_lookup_popup.as_mut() {
+        match popup.handle_key(key) {
+            LookupAction::Pending => {}
+            LookupAction::Cancel => state.port_lookup_popup = None,
+            LookupAction::Search => {
+                if let Some(port) = popup.port() {
+                    popup.set_results(port_lookup::lookup(port, &state.dynamic_data.containers));
+                }
+            }
+            LookupAction::Jump(pid) => {
+                state.port_lookup_popup = None;
+                state.selected_pid = Some(sysinfo::Pid::from_u32(pid));
+                state.active_tab = 1;
+            }
+        }
+        return Ok(false);
+    }
+    
+    if let Some(popup) = state.io_priority_popup.as_mut() {
+        match popup.handle_key(key) {
+            DialogResponse::Pending => {}
+            DialogResponse::Cancel => state.io_priority_popup = None,
+            DialogResponse::Confirm => {
+                if let Some(popup) = state.io_priority_popup.take() {
+                    match monitors::system_monitor::set_io_priority(popup.pid, popup.priority) {
+                        Ok(()) => {
+                            state.toasts.success(format!("I/O priority of {} set to {}", popup.pid, popup.priority));
+                            if let Some(process) = state.dynamic_data.detailed_process.as_mut() {
+                                process.io_priority = Some(popup.priority);
+                            }
+                        }
+                        Err(e) => state.toasts.error(format!("ionice failed: {}", e)),
+                    }
+                }
+            }
+        }
+        return Ok(false);
+    }
+    
+    if let Some(dialog) = state.confirm.as_mut() {
+        match dialog.handle_key(key) {
+            DialogResponse::Pending => {}
+            DialogResponse::Cancel => state.confirm = None,
+            DialogResponse::Confirm => {
+                if let Some(dialog) = state.confirm.take() {
+                    run_confirmed_action(state, dialog.action);
+                }
+            }
+        }
+        return Ok(false);
+    }
+
+    if state.active_tab != 0 || state.type_ahead.as_ref().is_some_and(TypeAhead::expired) {
+        state.type_ahead = None;
+    }
+    if let Some(jump) = state.type_ahead.as_mut() {
+        match key.code {
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => jump.text.push(c),
+            KeyCode::Backspace => {
+                jump.text.pop();
+            }
+            KeyCode::Esc => {
+                state.type_ahead = None;
                 return Ok(false);
             }
+            // Anything else ends the jump and acts as usual, so Enter opens
+            // the process jumped to.
+            _ => state.type_ahead = None,
+        }
+        if let Some(jump) = state.type_ahead.as_mut() {
+            jump.last_key = Instant::now();
+            if let Some(row) = utils::type_ahead_match(&state.dynamic_data.processes, &jump.text) {
+                state.process_table_state.select(Some(row));
+            }
+            return Ok(false);
+        }
+    }
+
+    // A past sample shows processes as they were; their PIDs may belong to
+    // something else by now.
+    if state.samples.viewing().is_some() && is_process_action(state.active_tab, key) {
+        state.toasts.error("Showing a past sample: press p to resume live before acting on processes");
+        return Ok(false);
+    }
+
+    match key.code {
+        KeyCode::Char('\'') if state.active_tab == 0 => state.type_ahead = Some(TypeAhead::new()),
+
+        KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.show_log_viewer = true;
+            state.log_viewer_scroll = 0;
+        }
+        
+        KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
             if state.service_status_modal.is_some() {
                  state.service_status_modal = None;
                  return Ok(false);
@@ -164,6 +624,18 @@ fn handle_key_event(
                 state.edit_buffer.clear();
                 return Ok(false);
             }
+            if state.zoomed {
+                state.zoomed = false;
+                return Ok(false);
+            }
+            if state.active_tab == 4 && state.disk_scan.cancel_or_close() {
+                return Ok(false);
+            }
+            if state.active_tab == 0 && state.container_filter.is_some() {
+                state.container_filter = None;
+                state.process_table_state.select(Some(0));
+                return Ok(false);
+            }
             return Ok(true);
         }
         
@@ -227,13 +699,24 @@ fn handle_key_event(
 
         KeyCode::Char('p') | KeyCode::Char('P') => {
             state.paused = !state.paused;
+            if !state.paused {
+                state.samples.resume(&mut state.dynamic_data);
+            }
+        }
+        
+        // Those tabs, and the process tree, use the arrows to pick things.
+        KeyCode::Left if state.paused && !arrows_select(state) => {
+            state.samples.step_back(&mut state.dynamic_data);
+        }
+        KeyCode::Right if state.paused && !arrows_select(state) => {
+            state.samples.step_forward(&mut state.dynamic_data);
         }
         
         KeyCode::Tab => {
-            state.active_tab = (state.active_tab + 1) % 12;
+            state.active_tab = (state.active_tab + 1) % 14;
         }
         KeyCode::BackTab => {
-            state.active_tab = (state.active_tab + 11) % 12;
+            state.active_tab = (state.active_tab + 13) % 14;
         }
         
         KeyCode::Char('1') => state.active_tab = 0,
@@ -248,86 +731,355 @@ fn handle_key_event(
         KeyCode::Char('0') => state.active_tab = 9,
         KeyCode::Char('-') => state.active_tab = 10,
         KeyCode::Char('=') => state.active_tab = 11,
+        KeyCode::Char(']') => state.active_tab = 12,
+        KeyCode::Char('[') => state.active_tab = 13,
         
         KeyCode::Char('t') | KeyCode::Char('T') => {
-            state.current_theme = (state.current_theme + 1) % 3;
+            state.current_theme = (state.current_theme + 1) % ui::colors::ThemeManager::COUNT;
+            state.toasts.success(format!("Theme: {}", ui::colors::ThemeManager::theme_name(state.current_theme)));
         }
         
         KeyCode::Down if state.active_tab == 0 => {
-            handle_process_navigation(&mut state, true);
+            handle_process_navigation(state, true);
         }
         KeyCode::Up if state.active_tab == 0 => {
-            handle_process_navigation(&mut state, false);
+            handle_process_navigation(state, false);
         }
         
-        KeyCode::Char('k') | KeyCode::Char('K') if state.active_tab == 0 && state.pending_kill_pid.is_none() => {
+        // k and F9 ask the process to exit, K kills it outright.
+        KeyCode::Char('k') | KeyCode::Char('K') | KeyCode::F(9) if state.active_tab == 0 => {
+            let signal = if key.code == KeyCode::Char('K') { KillSignal::Kill } else { KillSignal::Term };
+            let (title, question) = match signal {
+                KillSignal::Term => ("Terminate Process", "Send SIGTERM to"),
+                KillSignal::Kill => ("Kill Process", "Send SIGKILL to"),
+            };
             if let Some(idx) = state.process_table_state.selected() {
-                if idx < state.dynamic_data.processes.len() {
-                    let pid_str = &state.dynamic_data.processes[idx].pid;
-                    if let Ok(pid_num) = pid_str.parse::<usize>() {
+                if let Some(process) = state.dynamic_data.processes.get(idx) {
+                    if let Ok(pid_num) = process.pid.parse::<usize>() {
                          let pid = sysinfo::Pid::from(pid_num);
-                         if state.has_sudo {
-                             state.pending_kill_pid = Some(pid);
+                         if state.can_modify() {
+                             state.confirm = Some(ConfirmDialog::new(
+                                 title,
+                                 format!("{} process {} ({})?", question, pid, process.name),
+                                 PendingAction::KillProcess(pid, signal),
+                             ));
+                         } else {
+                             state.confirm = elevation_dialog(state, title, &["kill", signal.kill_arg(), &pid.to_string()]);
                          }
                     }
                 }
             }
         }
         
-        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter if state.pending_kill_pid.is_some() => {
-            if let Some(pid) = state.pending_kill_pid.take() {
-                use std::process::Command;
-                let output = Command::new("kill")
-                    .args(["-9", &pid.to_string()])
-                    .output();
-                
-                match output {
-                    Ok(out) if !out.status.success() => {
-                        let err = String::from_utf8_lossy(&out.stderr).to_string();
-                        state.service_status_modal = Some(("Kill Failed".to_string(), err));
+        KeyCode::Char('f') if (state.active_tab == 0 || state.active_tab == 1) && state.can_modify() => {
+            let selected = if state.active_tab == 0 {
+                state.process_table_state.selected()
+                    .and_then(|idx| state.dynamic_data.processes.get(idx))
+                    .and_then(|process| process.pid.parse::<usize>().ok())
+                    .map(sysinfo::Pid::from)
+            } else {
+                state.selected_pid
+            };
+            if let Some(pid) = selected {
+                toggle_process_stopped(state, pid);
+            }
+        }
+        
+        KeyCode::Char('i') if state.active_tab == 1 && state.can_modify() && cfg!(target_os = "linux") => {
+            if let Some(process) = &state.dynamic_data.detailed_process {
+                if let Ok(pid) = process.pid.parse::<usize>() {
+                    state.io_priority_popup = Some(ui::widgets::IoPriorityPopup::new(
+                        sysinfo::Pid::from(pid),
+                        process.name.clone(),
+                        process.io_priority,
+                    ));
+                }
+            }
+        }
+        
+        // Opens the limit picker, or lifts the limit already in force.
+        KeyCode::Char('l') if state.active_tab == 1 && state.can_modify() => {
+            if let Some(process) = &state.dynamic_data.detailed_process {
+                if let Ok(pid) = process.pid.parse::<usize>() {
+                    let pid = sysinfo::Pid::from(pid);
+                    if state.cpu_limits.remove(pid) {
+                        state.toasts.success(format!("CPU limit on {} lifted", pid));
+                    } else {
+                        state.cpu_limit_popup = Some(ui::widgets::CpuLimitPopup::new(pid, process.name.clone(), None));
                     }
-                    Err(e) => {
-                        state.service_status_modal = Some(("Kill Failed".to_string(), e.to_string()));
+                }
+            }
+        }
+        
+        KeyCode::Char('k') | KeyCode::Char('K') if state.active_tab == 1 && (state.can_modify() || state.can_elevate()) => {
+            if let Some(pid) = state.selected_pid {
+                let subtree = monitors::system_monitor::process_subtree(pid);
+                let start_time = monitors::system_monitor::process_start_time(pid);
+                if let (Some((_, name)), Some(start_time)) = (subtree.first(), start_time) {
+                    let message = kill_tree_message(&subtree, name);
+                    if state.can_modify() {
+                        state.confirm = Some(ConfirmDialog::new(
+                            "Kill Process Tree",
+                            message,
+                            PendingAction::KillTree { root: pid, start_time, elevated: false },
+                        ));
+                    } else if let Some(tool) = state.elevate_tool.filter(|_| state.can_elevate()) {
+                        state.confirm = Some(ConfirmDialog::new(
+                            "Kill Process Tree as Root",
+                            format!("{}\n\nRuns through {}; you may be asked for your password.", message, tool.program()),
+                            PendingAction::KillTree { root: pid, start_time, elevated: true },
+                        ));
                     }
-                    _ => {}
                 }
-                
-                state.selected_pid = None;
             }
         }
         
-        KeyCode::Char('n') | KeyCode::Char('N') if state.pending_kill_pid.is_some() => {
-            state.pending_kill_pid = None;
+        KeyCode::Char('c') if state.active_tab == 0 && !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.show_container_column = !state.show_container_column;
         }
-
-        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter if state.pending_service_action.is_some() => {
-             if let Some((action, service_name)) = state.pending_service_action.take() {
-                let sys_mgr = system_service::SystemManager::new();
-                let result = match action.as_str() {
-                    "stop" => sys_mgr.stop_service(&service_name),
-                    _ => Ok(()),
+        
+        KeyCode::Down if state.active_tab == 11 => {
+            let len = state.dynamic_data.containers.len();
+            if len > 0 {
+                let current = state.container_table_state.selected().unwrap_or(0);
+                state.container_table_state.select(Some((current + 1) % len));
+            }
+        }
+        KeyCode::Up if state.active_tab == 11 => {
+            let len = state.dynamic_data.containers.len();
+            if len > 0 {
+                let current = state.container_table_state.selected().unwrap_or(0);
+                state.container_table_state.select(Some(if current == 0 { len - 1 } else { current - 1 }));
+            }
+        }
+        KeyCode::Enter if state.active_tab == 11 => {
+            if let Some(idx) = state.container_table_state.selected() {
+                if let Some(container) = state.dynamic_data.containers.get(idx) {
+                    state.container_filter = Some(container.id.clone());
+                    state.show_container_column = true;
+                    state.process_table_state.select(Some(0));
+                    state.active_tab = 0;
+                }
+            }
+        }
+        KeyCode::Char('x') if state.active_tab == 0 => {
+            state.show_state_columns = !state.show_state_columns;
+        }
+        KeyCode::Char('r') if state.active_tab == 5 => {
+            state.net_session.reset(&state.dynamic_data.networks);
+        }
+        KeyCode::Char('v') | KeyCode::F(5) if state.active_tab == 0 => {
+            state.process_tree = !state.process_tree;
+        }
+        // Collapses or expands the selected process in the tree.
+        KeyCode::Left | KeyCode::Right if state.active_tab == 0 && state.process_tree => {
+            let processes = &state.dynamic_data.processes;
+            let selected = state.process_table_state.selected().and_then(|idx| processes.get(idx));
+            if let Some(process) = selected {
+                let pid = process.pid.clone();
+                if key.code == KeyCode::Right {
+                    state.collapsed_processes.remove(&pid);
+                } else if processes.iter().any(|p| p.parent.as_ref() == Some(&pid)) {
+                    state.collapsed_processes.insert(pid);
+                }
+            }
+        }
+        
+        KeyCode::Char('x') if state.active_tab == 11 => {
+            state.show_container_details = !state.show_container_details;
+        }
+        KeyCode::Char('d') | KeyCode::Delete if state.active_tab == 11 && (state.can_modify() || state.can_elevate()) => {
+            if let Some(idx) = state.container_table_state.selected() {
+                if let Some(container) = state.dynamic_data.containers.get(idx) {
+                    let dialog = if state.can_modify() {
+                        Some(ConfirmDialog::new(
+                            "Remove Container",
+                            format!("Force-remove container '{}' ({})? Its data is lost.", container.name, container.image),
+                            PendingAction::RemoveContainer(container.id.clone()),
+                        ))
+                    } else {
+                        elevation_dialog(state, "Remove Container", &["docker", "rm", "-f", &container.id])
+                    };
+                    state.confirm = dialog.map(|dialog| dialog.require_typing(container.name.clone()));
+                }
+            }
+        }
+        
+        KeyCode::Down if state.active_tab == 12 => {
+            let len = visible_cgroups(state.dynamic_data.cgroups.as_deref().unwrap_or_default(), &state.cgroup_collapsed).len();
+            if len > 0 {
+                let current = state.cgroup_table_state.selected().unwrap_or(0);
+                state.cgroup_table_state.select(Some((current + 1) % len));
+            }
+        }
+        KeyCode::Up if state.active_tab == 12 => {
+            let len = visible_cgroups(state.dynamic_data.cgroups.as_deref().unwrap_or_default(), &state.cgroup_collapsed).len();
+            if len > 0 {
+                let current = state.cgroup_table_state.selected().unwrap_or(0);
+                state.cgroup_table_state.select(Some(if current == 0 { len - 1 } else { current - 1 }));
+            }
+        }
+        KeyCode::Enter | KeyCode::Left | KeyCode::Right if state.active_tab == 12 => {
+            let visible = visible_cgroups(state.dynamic_data.cgroups.as_deref().unwrap_or_default(), &state.cgroup_collapsed);
+            let selected = state.cgroup_table_state.selected().and_then(|idx| visible.get(idx));
+            if let Some(node) = selected.filter(|node| node.has_children) {
+                let path = node.path.clone();
+                let collapse = match key.code {
+                    KeyCode::Left => true,
+                    KeyCode::Right => false,
+                    _ => !state.cgroup_collapsed.contains(&path),
                 };
-
-                match result {
-                    Ok(_) => state.service_status_modal = Some(("Success".to_string(), format!("Stopped {}", service_name))),
-                    Err(e) => state.service_status_modal = Some(("Error".to_string(), e)),
+                if collapse {
+                    state.cgroup_collapsed.insert(path);
+                } else {
+                    state.cgroup_collapsed.remove(&path);
                 }
-                state.services = sys_mgr.get_services();
-             }
+            }
         }
-
-        KeyCode::Char('n') | KeyCode::Char('N') if state.pending_service_action.is_some() => {
-             state.pending_service_action = None;
+        
+        KeyCode::Down if state.active_tab == 7 => {
+            let len = utils::zombies_with_parents(&state.dynamic_data.processes).len();
+            if len > 0 {
+                let current = state.zombie_table_state.selected().unwrap_or(0);
+                state.zombie_table_state.select(Some((current + 1) % len));
+            }
+        }
+        KeyCode::Up if state.active_tab == 7 => {
+            let len = utils::zombies_with_parents(&state.dynamic_data.processes).len();
+            if len > 0 {
+                let current = state.zombie_table_state.selected().unwrap_or(0);
+                state.zombie_table_state.select(Some(if current == 0 { len - 1 } else { current - 1 }));
+            }
+        }
+        KeyCode::Enter | KeyCode::Char('r') | KeyCode::Char('k') if state.active_tab == 7 => {
+            let zombies = utils::zombies_with_parents(&state.dynamic_data.processes);
+            let selected = state.zombie_table_state.selected().and_then(|idx| zombies.get(idx));
+            let parent = selected.and_then(|(zombie, parent)| {
+                let ppid = zombie.parent.as_deref()?.parse::<usize>().ok()?;
+                Some((sysinfo::Pid::from(ppid), parent.map_or("?".to_string(), |p| p.name.clone())))
+            });
+            if let Some((ppid, name)) = parent {
+                match key.code {
+                    KeyCode::Enter => {
+                        state.selected_pid = Some(ppid);
+                        state.active_tab = 1;
+                    }
+                    // A parent that handles SIGCHLD reaps when nudged.
+                    KeyCode::Char('r') if state.can_modify() => {
+                        match std::process::Command::new("kill").args(["-CHLD", &ppid.to_string()]).output() {
+                            Ok(out) if out.status.success() => state.toasts.success(format!("Sent SIGCHLD to {} ({})", ppid, name)),
+                            Ok(out) => state.toasts.error(format!("Signal failed: {}", String::from_utf8_lossy(&out.stderr))),
+                            Err(e) => state.toasts.error(format!("Signal failed: {}", e)),
+                        }
+                    }
+                    KeyCode::Char('k') if state.can_modify() && ppid.as_u32() > 1 => {
+                        state.confirm = Some(ConfirmDialog::new(
+                            "Kill Zombie Parent",
+                            format!("Kill process {} ({})? Its zombie children are then adopted and reaped by init.", ppid, name),
+                            PendingAction::KillProcess(ppid, KillSignal::Kill),
+                        ));
+                    }
+                    KeyCode::Char('k') if state.can_elevate() && ppid.as_u32() > 1 => {
+                        state.confirm = elevation_dialog(state, "Kill Zombie Parent", &["kill", "-9", &ppid.to_string()]);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        
+        KeyCode::Down if state.active_tab == 4 => {
+            let len = state.dynamic_data.disks.len();
+            if len > 0 {
+                let current = state.disk_table_state.selected().unwrap_or(0);
+                state.disk_table_state.select(Some((current + 1) % len));
+            }
+        }
+        KeyCode::Up if state.active_tab == 4 => {
+            let len = state.dynamic_data.disks.len();
+            if len > 0 {
+                let current = state.disk_table_state.selected().unwrap_or(0);
+                state.disk_table_state.select(Some(if current == 0 { len - 1 } else { current - 1 }));
+            }
+        }
+        
+        KeyCode::Enter if state.active_tab == 4 && !state.disk_scan.is_running() => {
+            let mount = state.disk_table_state.selected().and_then(|idx| state.dynamic_data.disks.get(idx));
+            if let Some(disk) = mount {
+                log::info!("Scanning {} for its largest directories", disk.name);
+                state.disk_scan.start(disk.name.clone().into());
+            }
+        }
+        
+        KeyCode::Down if state.active_tab == 13 => {
+            let len = state.dynamic_data.security.as_ref().map_or(0, |security| security.ports.len());
+            if len > 0 {
+                let current = state.port_table_state.selected().unwrap_or(0);
+                state.port_table_state.select(Some((current + 1) % len));
+            }
+        }
+        KeyCode::Up if state.active_tab == 13 => {
+            let len = state.dynamic_data.security.as_ref().map_or(0, |security| security.ports.len());
+            if len > 0 {
+                let current = state.port_table_state.selected().unwrap_or(0);
+                state.port_table_state.select(Some(if current == 0 { len - 1 } else { current - 1 }));
+            }
         }
         
-        KeyCode::Down if state.active_tab == 8 && state.pending_service_action.is_none() => {
+        KeyCode::Enter if state.active_tab == 13 => {
+            let ports = state.dynamic_data.security.as_ref().map(|security| &security.ports);
+            let owner = state.port_table_state.selected().and_then(|idx| ports?.get(idx)?.process.as_ref());
+            if let Some((pid, _)) = owner {
+                state.selected_pid = Some(sysinfo::Pid::from_u32(*pid));
+                state.active_tab = 1;
+            }
+        }
+        
+        KeyCode::Down if state.active_tab == 6 => {
+            let len = state.dynamic_data.gpu_processes.len();
+            if len > 0 {
+                let current = state.gpu_process_table_state.selected().unwrap_or(0);
+                state.gpu_process_table_state.select(Some((current + 1) % len));
+            }
+        }
+        KeyCode::Up if state.active_tab == 6 => {
+            let len = state.dynamic_data.gpu_processes.len();
+            if len > 0 {
+                let current = state.gpu_process_table_state.selected().unwrap_or(0);
+                state.gpu_process_table_state.select(Some(if current == 0 { len - 1 } else { current - 1 }));
+            }
+        }
+        KeyCode::Right if state.active_tab == 6 => {
+            if let Ok(gpus) = &state.dynamic_data.gpus {
+                if !gpus.is_empty() {
+                    state.selected_gpu = (state.selected_gpu + 1) % gpus.len();
+                }
+            }
+        }
+        KeyCode::Left if state.active_tab == 6 => {
+            if let Ok(gpus) = &state.dynamic_data.gpus {
+                if !gpus.is_empty() {
+                    let current = state.selected_gpu.min(gpus.len() - 1);
+                    state.selected_gpu = if current == 0 { gpus.len() - 1 } else { current - 1 };
+                }
+            }
+        }
+        KeyCode::Enter if state.active_tab == 6 => {
+            if let Some(idx) = state.gpu_process_table_state.selected() {
+                if let Some(process) = state.dynamic_data.gpu_processes.get(idx) {
+                    state.selected_pid = Some(sysinfo::Pid::from_u32(process.pid));
+                    state.active_tab = 1;
+                }
+            }
+        }
+        
+        KeyCode::Down if state.active_tab == 8 => {
             let len = state.services.len();
             if len > 0 {
                 let current = state.services_table_state.selected().unwrap_or(0);
                 state.services_table_state.select(Some((current + 1) % len));
             }
         }
-        KeyCode::Up if state.active_tab == 8 && state.pending_service_action.is_none() => {
+        KeyCode::Up if state.active_tab == 8 => {
             let len = state.services.len();
             if len > 0 {
                 let current = state.services_table_state.selected().unwrap_or(0);
@@ -367,34 +1119,52 @@ fn handle_key_event(
         
         KeyCode::Char('e') if state.active_tab == 8 => {
             if let Some(idx) = state.services_table_state.selected() {
-                if state.has_sudo {
+                if state.can_modify() {
                     state.editing_service = Some(idx);
                     state.edit_buffer.clear();
                 }
             }
         }
         
-        KeyCode::Char('s') if state.active_tab == 8 && state.editing_service.is_none() && state.pending_service_action.is_none() => {
+        KeyCode::Char('s') if state.active_tab == 8 && state.editing_service.is_none() => {
             if let Some(idx) = state.services_table_state.selected() {
                 if let Some(service) = state.services.get(idx) {
-                    if service.can_start && state.has_sudo {
+                    if service.can_start && state.can_modify() {
                         let sys_mgr = system_service::SystemManager::new();
                         let service_name = service.name.clone();
                         match sys_mgr.start_service(&service_name) {
-                            Ok(_) => state.service_status_modal = Some(("Success".to_string(), format!("Started {}", service_name))),
-                            Err(e) => state.service_status_modal = Some(("Error".to_string(), e)),
+                            Ok(_) => state.toasts.success(format!("Service {} started", service_name)),
+                            Err(e) => state.toasts.error(e),
                         }
-                        state.services = sys_mgr.get_services();
+                        reload_services(state, &sys_mgr);
+                    } else if let Some(command) = elevated_service_command("start", service) {
+                        state.confirm = elevation_dialog(state, "Start Service", &command);
                     }
                 }
             }
         }
         
-        KeyCode::Char('x') if state.active_tab == 8 && state.editing_service.is_none() && state.pending_service_action.is_none() => {
+        KeyCode::Char('o') if state.active_tab == 8 && state.editing_service.is_none() => {
+            state.service_sort = state.service_sort.next();
+            state.service_sort_reversed = false;
+            sort_services(state);
+        }
+        KeyCode::Char('O') if state.active_tab == 8 && state.editing_service.is_none() => {
+            state.service_sort_reversed = !state.service_sort_reversed;
+            sort_services(state);
+        }
+        
+        KeyCode::Char('x') if state.active_tab == 8 && state.editing_service.is_none() => {
             if let Some(idx) = state.services_table_state.selected() {
                 if let Some(service) = state.services.get(idx) {
-                    if service.can_stop && state.has_sudo {
-                        state.pending_service_action = Some(("stop".to_string(), service.name.clone()));
+                    if service.can_stop && state.can_modify() {
+                        state.confirm = Some(ConfirmDialog::new(
+                            "Stop Service",
+                            format!("Stop service '{}'?", service.name),
+                            PendingAction::StopService(service.name.clone()),
+                        ));
+                    } else if let Some(command) = elevated_service_command("stop", service) {
+                        state.confirm = elevation_dialog(state, "Stop Service", &command);
                     }
                 }
             }
@@ -403,14 +1173,16 @@ fn handle_key_event(
         KeyCode::Char('r') if state.active_tab == 8 && state.editing_service.is_none() => {
             if let Some(idx) = state.services_table_state.selected() {
                 if let Some(service) = state.services.get(idx) {
-                    if state.has_sudo {
+                    if state.can_modify() {
                         let sys_mgr = system_service::SystemManager::new();
                         let service_name = service.name.clone();
                         match sys_mgr.restart_service(&service_name) {
-                            Ok(_) => state.service_status_modal = Some(("Success".to_string(), format!("Restarted {}", service_name))),
-                            Err(e) => state.service_status_modal = Some(("Error".to_string(), e)),
+                            Ok(_) => state.toasts.success(format!("Service {} restarted", service_name)),
+                            Err(e) => state.toasts.error(e),
                         }
-                        state.services = sys_mgr.get_services();
+                        reload_services(state, &sys_mgr);
+                    } else if let Some(command) = elevated_service_command("restart", service) {
+                        state.confirm = elevation_dialog(state, "Restart Service", &command);
                     }
                 }
             }
@@ -419,14 +1191,16 @@ fn handle_key_event(
         KeyCode::Char('+') if state.active_tab == 8 && state.editing_service.is_none() => {
             if let Some(idx) = state.services_table_state.selected() {
                 if let Some(service) = state.services.get(idx) {
-                    if state.has_sudo {
+                    if state.can_modify() {
                          let sys_mgr = system_service::SystemManager::new();
                          let service_name = service.name.clone();
                          match sys_mgr.enable_service(&service_name) {
-                             Ok(_) => state.service_status_modal = Some(("Success".to_string(), format!("Enabled {}", service_name))),
-                             Err(e) => state.service_status_modal = Some(("Error".to_string(), e)),
+                             Ok(_) => state.toasts.success(format!("Service {} enabled", service_name)),
+                             Err(e) => state.toasts.error(e),
                          }
-                         state.services = sys_mgr.get_services();
+                         reload_services(state, &sys_mgr);
+                    } else if let Some(command) = elevated_service_command("enable", service) {
+                        state.confirm = elevation_dialog(state, "Enable Service", &command);
                     }
                 }
             }
@@ -435,14 +1209,16 @@ fn handle_key_event(
         KeyCode::Char('_') if state.active_tab == 8 && state.editing_service.is_none() => {
             if let Some(idx) = state.services_table_state.selected() {
                 if let Some(service) = state.services.get(idx) {
-                    if state.has_sudo {
+                    if state.can_modify() {
                          let sys_mgr = system_service::SystemManager::new();
                          let service_name = service.name.clone();
                          match sys_mgr.disable_service(&service_name) {
-                             Ok(_) => state.service_status_modal = Some(("Success".to_string(), format!("Disabled {}", service_name))),
-                             Err(e) => state.service_status_modal = Some(("Error".to_string(), e)),
+                             Ok(_) => state.toasts.success(format!("Service {} disabled", service_name)),
+                             Err(e) => state.toasts.error(e),
                          }
-                         state.services = sys_mgr.get_services();
+                         reload_services(state, &sys_mgr);
+                    } else if let Some(command) = elevated_service_command("disable", service) {
+                        state.confirm = elevation_dialog(state, "Disable Service", &command);
                     }
                 }
             }
@@ -450,7 +1226,7 @@ fn handle_key_event(
         
         KeyCode::Char('e') if state.active_tab == 10 => {
             if let Some(idx) = state.config_table_state.selected() {
-                if state.has_sudo {
+                if state.can_modify() {
                     state.editing_config = Some(idx);
                     if let Some(item) = state.config_items.get(idx) {
                         state.edit_buffer = item.value.clone();
@@ -469,25 +1245,17 @@ fn handle_key_event(
         
         KeyCode::Enter if state.editing_config.is_some() => {
             if let Some(idx) = state.editing_config {
-                let buffer = state.edit_buffer.clone();
-                let has_sudo = state.has_sudo;
-                if let Some(item) = state.config_items.get_mut(idx) {
-                    let key = item.key.clone();
-                    item.value = buffer.clone();
-                    if has_sudo {
-                        let sys_mgr = system_service::SystemManager::new();
-                        match key.as_str() {
-                            "hostname" => {
-                                let _ = sys_mgr.set_hostname(&buffer);
-                            }
-                            "timezone" => {
-                                let _ = sys_mgr.set_timezone(&buffer);
-                            }
-                            _ if key.starts_with("GRUB_") => {
-                                let _ = sys_mgr.set_grub_config(&key, &buffer);
-                            }
-                            _ => {}
-                        }
+                let value = state.edit_buffer.clone();
+                if let Some(item) = state.config_items.get(idx) {
+                    let action = PendingAction::WriteConfig { index: idx, key: item.key.clone(), value };
+                    if item.key.starts_with("GRUB_") && state.can_modify() {
+                        state.confirm = Some(ConfirmDialog::new(
+                            "Write GRUB Config",
+                            format!("Set {} in /etc/default/grub? A backup is kept.", item.key),
+                            action,
+                        ));
+                    } else {
+                        run_confirmed_action(state, action);
                     }
                 }
             }
@@ -508,6 +1276,53 @@ fn handle_key_event(
             }
         }
         
+        KeyCode::Down if state.active_tab == 1 => {
+            let len = state.dynamic_data.detailed_process.as_ref().map_or(0, |process| process.thread_list.len());
+            if len > 0 {
+                let current = state.thread_table_state.selected().unwrap_or(0);
+                state.thread_table_state.select(Some((current + 1) % len));
+            }
+        }
+        KeyCode::Up if state.active_tab == 1 => {
+            let len = state.dynamic_data.detailed_process.as_ref().map_or(0, |process| process.thread_list.len());
+            if len > 0 {
+                let current = state.thread_table_state.selected().unwrap_or(0);
+                state.thread_table_state.select(Some(if current == 0 { len - 1 } else { current - 1 }));
+            }
+        }
+        KeyCode::PageDown if state.active_tab == 1 => {
+            state.command_scroll = state.command_scroll.saturating_add(10);
+        }
+        KeyCode::PageUp if state.active_tab == 1 => {
+            state.command_scroll = state.command_scroll.saturating_sub(10);
+        }
+        KeyCode::Char('c') if state.active_tab == 1 && !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Some(process) = &state.dynamic_data.detailed_process {
+                match utils::copy_to_clipboard(&process.command_line()) {
+                    Ok(()) => state.toasts.success(format!("Copied the command line of PID {}", process.pid)),
+                    Err(e) => state.toasts.error(format!("Could not copy: {}", e)),
+                }
+            }
+        }
+        KeyCode::Char('s') if state.active_tab == 1 && !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            let selected = selected_thread(state);
+            state.thread_sort = state.thread_sort.next();
+            state.thread_sort_reversed = false;
+            sort_threads(state, selected);
+        }
+        KeyCode::Char('S') if state.active_tab == 1 => {
+            let selected = selected_thread(state);
+            state.thread_sort_reversed = !state.thread_sort_reversed;
+            sort_threads(state, selected);
+        }
+        
+        KeyCode::Char('s') if state.active_tab == 0 && !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.sort_by = state.sort_by.next();
+            state.sort_ascending = state.sort_by.default_ascending();
+        }
+        KeyCode::Char('S') if state.active_tab == 0 => {
+            state.sort_ascending = !state.sort_ascending;
+        }
         KeyCode::Char('c') if state.active_tab == 0 && key.modifiers.contains(KeyModifiers::CONTROL) => {
             state.sort_by = ProcessSortBy::Cpu;
             state.sort_ascending = !state.sort_ascending;
@@ -531,12 +1346,142 @@ fn handle_key_event(
         KeyCode::Char('h') | KeyCode::F(1) => {
         }
         
+        KeyCode::F(12) => {
+            state.show_perf_overlay = !state.show_perf_overlay;
+        }
+        
+        KeyCode::F(10) => {
+            state.export_screen = true;
+        }
+        
+        KeyCode::Char('E') => {
+            match headless::export(state.export_path.as_deref(), &state.dynamic_data, &state.services) {
+                Ok(path) => state.toasts.success(format!("Metrics exported to {}", path.display())),
+                Err(e) => state.toasts.error(format!("Export failed: {}", e)),
+            }
+        }
+        
+        KeyCode::Char('z') => {
+            state.zoomed = !state.zoomed;
+        }
+        
+        KeyCode::Char('b') => {
+            state.hide_summary = !state.hide_summary;
+        }
+        
+        KeyCode::Char('F') => {
+            state.open_files_popup = Some(ui::widgets::OpenFilesPopup::default());
+        }
+        
+        KeyCode::Char('W') => {
+            state.port_lookup_popup = Some(ui::widgets::PortLookupPopup::default());
+        }
+        
         _ => {}
     }
     
     Ok(false)
 }
 
+// Asks before running `command` as root through sudo or pkexec; None when
+// that isn't on offer.
+fn elevation_dialog(state: &AppState, title: &str, command: &[impl AsRef<str>]) -> Option<ConfirmDialog<PendingAction>> {
+    let tool = state.elevate_tool.filter(|_| state.can_elevate())?;
+    let command: Vec<String> = command.iter().map(|arg| arg.as_ref().to_string()).collect();
+    Some(ConfirmDialog::new(
+        format!("{} as Root", title),
+        format!("Run `{}`? You may be asked for your password.", elevate::describe(tool, &command)),
+        PendingAction::Elevated(command),
+    ))
+}
+
+// systemctl only; launchd and the Windows service manager are left alone.
+fn elevated_service_command(verb: &str, service: &crate::types::ServiceInfo) -> Option<Vec<String>> {
+    cfg!(target_os = "linux").then(|| vec!["systemctl".to_string(), verb.to_string(), format!("{}.service", service.name)])
+}
+
+fn run_confirmed_action(state: &mut AppState, action: PendingAction) {
+    use std::process::Command;
+    // The keys that lead here are already off in read-only mode; this catches
+    // any path that slips past them.
+    if state.read_only {
+        state.toasts.error("Read-only mode: nothing was changed");
+        return;
+    }
+    match action {
+        PendingAction::Elevated(command) => state.elevation = Some(command),
+        PendingAction::KillProcess(pid, signal) => {
+            match system_service::SystemManager::new().signal_process(pid.as_u32(), signal) {
+                Ok(()) => state.toasts.success(format!("Sent {} to {}", signal.name(), pid)),
+                Err(e) => state.toasts.error(format!("Kill failed: {}", e)),
+            }
+            
+            state.selected_pid = None;
+        }
+        PendingAction::KillTree { root, start_time, elevated: true } => {
+            match std::env::current_exe() {
+                Ok(exe) => state.elevation = Some(vec![
+                    exe.to_string_lossy().into_owned(),
+                    "kill-tree".to_string(),
+                    root.to_string(),
+                    start_time.to_string(),
+                ]),
+                Err(e) => state.toasts.error(format!("Kill failed: {}", e)),
+            }
+        }
+        PendingAction::KillTree { root, start_time, elevated: false } => {
+            match kill_tree(root, start_time) {
+                Ok(count) => state.toasts.success(format!("Killed {} processes", count)),
+                Err(e) => state.toasts.error(e),
+            }
+            state.selected_pid = None;
+        }
+        PendingAction::StopService(service_name) => {
+            let sys_mgr = system_service::SystemManager::new();
+            match sys_mgr.stop_service(&service_name) {
+                Ok(_) => state.toasts.success(format!("Service {} stopped", service_name)),
+                Err(e) => state.toasts.error(e),
+            }
+            reload_services(state, &sys_mgr);
+        }
+        PendingAction::RemoveContainer(id) => {
+            match Command::new("docker").args(["rm", "-f", &id]).output() {
+                Ok(out) if !out.status.success() => {
+                    let err = String::from_utf8_lossy(&out.stderr).to_string();
+                    state.toasts.error(format!("Remove failed: {}", err));
+                }
+                Err(e) => {
+                    state.toasts.error(format!("Remove failed: {}", e));
+                }
+                _ => {}
+            }
+        }
+        PendingAction::WriteConfig { index, key, value } => {
+            let has_sudo = state.can_modify();
+            if let Some(item) = state.config_items.get_mut(index) {
+                item.value = value.clone();
+                if has_sudo {
+                    let sys_mgr = system_service::SystemManager::new();
+                    match key.as_str() {
+                        "hostname" => {
+                            let _ = sys_mgr.set_hostname(&value);
+                        }
+                        "timezone" => {
+                            let _ = sys_mgr.set_timezone(&value);
+                        }
+                        _ if key.starts_with("GRUB_") => {
+                            if let Err(e) = sys_mgr.set_grub_config(&key, &value) {
+                                state.toasts.error(e);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn handle_process_navigation(state: &mut AppState, down: bool) {
     let processes = &state.dynamic_data.processes;
     if processes.is_empty() {
@@ -553,71 +1498,330 @@ fn handle_process_navigation(state: &mut AppState, down: bool) {
     state.process_table_state.select(Some(new_index));
 }
 
-async fn data_collection_loop(
-    app_state: Arc<Mutex<AppState>>,
-    data_collector: Arc<Mutex<DataCollector>>,
+fn apply_update(state: &mut AppState, update: types::MonitorUpdate) {
+    // Service figures follow the system collector while the tab is open.
+    if state.active_tab == 8 && matches!(update, types::MonitorUpdate::System(_)) {
+        system_service::update_service_usage(&mut state.services);
+        sort_services(state);
+    }
+    if !state.cpu_limits.is_empty() && matches!(update, types::MonitorUpdate::System(_)) {
+        state.cpu_limits.prune();
+    }
+    if let Some(scan) = state.disk_scan.poll() {
+        if let Some((result, elapsed)) = scan.result() {
+            let verb = if result.cancelled { "Cancelled scan of" } else { "Scanned" };
+            log::info!("{} {}: {} files, {} in {:.1}s", verb, scan.root.display(), result.files,
+                utils::format_size(result.total_bytes), elapsed.as_secs_f32());
+        }
+    }
+    // An update still in flight when paused lands on the live data.
+    state.samples.resume(&mut state.dynamic_data);
+    let is_system = matches!(update, types::MonitorUpdate::System(_));
+    let selected_thread = selected_thread(state);
+    let detailed_pid = state.dynamic_data.detailed_process.as_ref().map(|process| process.pid.clone());
+    state.dynamic_data.apply(update);
+    if state.dynamic_data.detailed_process.as_ref().map(|process| &process.pid) != detailed_pid.as_ref() {
+        state.command_scroll = 0;
+    }
+    sort_threads(state, selected_thread);
+    if is_system {
+        state.samples.record(&state.dynamic_data);
+        state.net_session.observe(&state.dynamic_data.networks);
+    }
+    
+    if state.process_table_state.selected().is_none() && !state.dynamic_data.processes.is_empty() {
+        state.process_table_state.select(Some(0));
+    }
+    
+    if state.container_table_state.selected().is_none() && !state.dynamic_data.containers.is_empty() {
+        state.container_table_state.select(Some(0));
+    }
+    
+    if state.gpu_process_table_state.selected().is_none() && !state.dynamic_data.gpu_processes.is_empty() {
+        state.gpu_process_table_state.select(Some(0));
+    }
+    
+    if state.disk_table_state.selected().is_none() && !state.dynamic_data.disks.is_empty() {
+        state.disk_table_state.select(Some(0));
+    }
+}
+
+fn reload_services(state: &mut AppState, sys_mgr: &system_service::SystemManager) {
+    state.services = sys_mgr.get_services();
+    sort_services(state);
+}
+
+// Re-sorts the services, keeping the same service selected.
+fn sort_services(state: &mut AppState) {
+    let selected = state.services_table_state.selected()
+        .and_then(|idx| state.services.get(idx))
+        .map(|service| service.name.clone());
+    system_service::sort_services(&mut state.services, state.service_sort, state.service_sort_reversed);
+    if let Some(name) = selected {
+        if let Some(idx) = state.services.iter().position(|service| service.name == name) {
+            state.services_table_state.select(Some(idx));
+        }
+    }
+}
+
+// SIGSTOP for a running process, SIGCONT for a stopped one. The table shows
+// the new state straight away instead of on the next refresh.
+fn toggle_process_stopped(state: &mut AppState, pid: sysinfo::Pid) {
+    if cfg!(windows) {
+        state.toasts.error("Suspending processes is not supported on Windows");
+        return;
+    }
+    
+    let pid_text = pid.to_string();
+    let stopped = match &state.dynamic_data.detailed_process {
+        Some(process) if state.active_tab == 1 && process.pid == pid_text => process.status == types::STOPPED_STATUS,
+        _ => state.dynamic_data.processes.iter().any(|process| process.pid == pid_text && process.is_stopped()),
+    };
+    let (signal, new_status, verb) = if stopped {
+        ("-CONT", "Sleeping", "resumed")
+    } else {
+        ("-STOP", types::STOPPED_STATUS, "stopped")
+    };
+    
+    match std::process::Command::new("kill").args([signal, &pid_text]).output() {
+        Ok(out) if out.status.success() => {
+            state.toasts.success(format!("Process {} {}", pid, verb));
+            for process in state.dynamic_data.processes.iter_mut().filter(|process| process.pid == pid_text) {
+                process.status = new_status.to_string();
+            }
+            if let Some(process) = state.dynamic_data.detailed_process.as_mut().filter(|process| process.pid == pid_text) {
+                process.status = new_status.to_string();
+            }
+        }
+        Ok(out) => state.toasts.error(format!("Signal failed: {}", String::from_utf8_lossy(&out.stderr))),
+        Err(e) => state.toasts.error(format!("Signal failed: {}", e)),
+    }
+}
+
+// The PIDs of `root` and its descendants as of now, or None when `root` has
+// exited or its PID belongs to a newer process.
+fn current_subtree(root: sysinfo::Pid, start_time: u64) -> Option<Vec<String>> {
+    if monitors::system_monitor::process_start_time(root) != Some(start_time) {
+        return None;
+    }
+    let subtree = monitors::system_monitor::process_subtree(root);
+    (!subtree.is_empty()).then(|| subtree.iter().map(|(pid, _)| pid.to_string()).collect())
+}
+
+// Keys that signal or change the process on the selected row.
+fn is_process_action(active_tab: usize, key: crossterm::event::KeyEvent) -> bool {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return false;
+    }
+    match active_tab {
+        0 => matches!(key.code, KeyCode::Char('k' | 'K' | 'f') | KeyCode::F(9)),
+        1 => matches!(key.code, KeyCode::Char('k' | 'K' | 'f' | 'i' | 'l')),
+        7 => matches!(key.code, KeyCode::Char('k' | 'r')),
+        _ => false,
+    }
+}
+
+// Kills `root` and its descendants, stopping them all first; the number
+// killed, or why nothing was.
+fn kill_tree(root: sysinfo::Pid, start_time: u64) -> Result<usize, String> {
+    use std::process::Command;
+    // The tree shown in the dialog may be gone by now and its PIDs reused, so
+    // it is walked again from the root, if that is still the same process.
+    let pids = current_subtree(root, start_time).ok_or_else(|| format!("Process {} has exited; nothing was killed", root))?;
+    let output = if cfg!(windows) {
+        Command::new("taskkill")
+            .args(["/F", "/T", "/PID", &root.to_string()])
+            .output()
+    } else {
+        // Stopped parents can neither fork replacements nor reap their
+        // children while the list is killed.
+        let _ = Command::new("kill").arg("-STOP").args(&pids).output();
+        Command::new("kill").arg("-9").args(&pids).output()
+    };
+    match output {
+        Ok(out) if !out.status.success() => Err(format!("Kill failed: {}", String::from_utf8_lossy(&out.stderr).trim())),
+        Err(e) => Err(format!("Kill failed: {}", e)),
+        Ok(_) => Ok(pids.len()),
+    }
+}
+
+// Lists the processes about to be killed, up to a screenful.
+fn kill_tree_message(subtree: &[(sysinfo::Pid, String)], name: &str) -> String {
+    const LISTED: usize = 10;
+    let mut message = match subtree.len() {
+        1 => format!("Kill process {} ({})? It has no child processes.", subtree[0].0, name),
+        n => format!("Kill process {} ({}) and its {} descendants?\n", subtree[0].0, name, n - 1),
+    };
+    if subtree.len() > 1 {
+        for (pid, name) in subtree.iter().take(LISTED) {
+            message.push_str(&format!("\n{} {}", pid, name));
+        }
+        if subtree.len() > LISTED {
+            message.push_str(&format!("\n... and {} more", subtree.len() - LISTED));
+        }
+    }
+    message
+}
+
+fn selected_thread(state: &AppState) -> Option<u32> {
+    let process = state.dynamic_data.detailed_process.as_ref()?;
+    state.thread_table_state.selected()
+        .and_then(|idx| process.thread_list.get(idx))
+        .map(|thread| thread.tid)
+}
+
+// Sorts the inspected process's threads, keeping the given thread selected.
+fn sort_threads(state: &mut AppState, selected: Option<u32>) {
+    let Some(process) = state.dynamic_data.detailed_process.as_mut() else {
+        return;
+    };
+    monitors::system_monitor::sort_threads(&mut process.thread_list, state.thread_sort, state.thread_sort_reversed);
+    if let Some(tid) = selected {
+        let idx = process.thread_list.iter().position(|thread| thread.tid == tid);
+        state.thread_table_state.select(idx.or(Some(0)).filter(|_| !process.thread_list.is_empty()));
+    }
+}
+
+// Must be called from within a LocalSet.
+fn spawn_collectors(
+    tx: mpsc::UnboundedSender<AppMessage>,
+    control: watch::Receiver<CollectorControl>,
+    collector: DataCollector,
+    config: &AppConfig,
+) {
+    let (system_collector, gpu_collector, container_collector) = collector.into_collectors();
+    tokio::task::spawn_local(system_collection_loop(tx.clone(), control.clone(), system_collector, config.clone()));
+    tokio::task::spawn_local(gpu_collection_loop(tx.clone(), control.clone(), gpu_collector, config.clone()));
+    tokio::task::spawn_local(container_collection_loop(tx, control, container_collector, config.clone()));
+}
+
+fn spawn_watchdog(tx: mpsc::UnboundedSender<AppMessage>, config: &AppConfig, has_sudo: bool) {
+    if config.safe_mode {
+        log::warn!("Service watchdog is disabled in safe mode");
+    } else if config.read_only {
+        log::warn!("Service watchdog is disabled in read-only mode");
+    } else if !has_sudo {
+        log::warn!("Service watchdog is disabled: restarting services requires root");
+    } else if !cfg!(target_os = "linux") {
+        log::warn!("Service watchdog requires systemd");
+    } else {
+        let units: Vec<&str> = config.watch_services.iter().map(|rule| rule.unit.as_str()).collect();
+        log::info!("Service watchdog watching {}", units.join(", "));
+        tokio::task::spawn_local(watchdog::watchdog_loop(tx, config.watch_services.clone()));
+    }
+}
+
+fn spawn_capture(config: &AppConfig) {
+    let Some(dir) = config.capture_dir.clone().or_else(capture::default_dir) else {
+        log::warn!("Capture windows are disabled: no state directory, use --capture-dir");
+        return;
+    };
+    tokio::task::spawn_local(capture::capture_loop(config.clone(), config.capture_windows.clone(), dir));
+}
+
+// Returns false once the UI has gone away and the collector should stop.
+fn publish(
+    tx: &mpsc::UnboundedSender<AppMessage>,
+    kind: CollectorKind,
+    update: types::MonitorUpdate,
+    timing: types::CollectorTiming,
+) -> bool {
+    tx.send(AppMessage::Update(update)).is_ok() && tx.send(AppMessage::Timing(kind, timing)).is_ok()
+}
+
+// Process, disk and network collection is synchronous, so it runs on the
+// blocking pool and hands the collector back once the snapshot is taken.
+async fn system_collection_loop(
+    tx: mpsc::UnboundedSender<AppMessage>,
+    control: watch::Receiver<CollectorControl>,
+    mut collector: SystemCollector,
     config: AppConfig,
 ) {
-    let mut interval = tokio::time::interval(config.get_collection_sleep_duration());
-    let mut prev_global_usage = types::GlobalUsage::default();
+    let mut scheduler = Scheduler::new(config.get_collection_sleep_duration());
     
     loop {
-        interval.tick().await;
+        let started = scheduler.tick().await;
         
-        let is_paused = {
-            let state = app_state.lock();
-            state.paused
+        let query = {
+            let control = control.borrow();
+            if control.paused {
+                scheduler.pause();
+                continue;
+            }
+            control.query.clone()
         };
         
-        if is_paused {
-            continue;
-        }
-        
-        let collection_start = Instant::now();
-        
-        let (selected_pid, show_system_processes, filter_text, sort_by, sort_ascending) = {
-            let state = app_state.lock();
-            (
-                state.selected_pid,
-                state.show_system_processes,
-                state.filter_text.clone(),
-                state.sort_by.clone(),
-                state.sort_ascending,
-            )
+        let (returned, update) = match tokio::task::spawn_blocking(move || {
+            let update = collector.collect(&query);
+            (collector, update)
+        }).await {
+            Ok(result) => result,
+            Err(e) => {
+                log::error!("System collector stopped: {}", e);
+                return;
+            }
         };
+        collector = returned;
         
-        let new_data = {
-            let mut collector = data_collector.lock();
-            collector.collect_data(
-                selected_pid,
-                show_system_processes,
-                &filter_text,
-                &sort_by,
-                sort_ascending,
-                prev_global_usage.clone(),
-            ).await
-        };
+        if !publish(&tx, CollectorKind::System, update, scheduler.finish(started)) {
+            return;
+        }
+    }
+}
+
+async fn gpu_collection_loop(
+    tx: mpsc::UnboundedSender<AppMessage>,
+    control: watch::Receiver<CollectorControl>,
+    mut collector: GpuCollector,
+    config: AppConfig,
+) {
+    let mut scheduler = Scheduler::new(config.gpu_refresh_interval());
+    
+    loop {
+        let started = scheduler.tick().await;
         
-        prev_global_usage = new_data.global_usage.clone();
+        if control.borrow().paused {
+            scheduler.pause();
+            continue;
+        }
         
-        {
-            let mut state = app_state.lock();
-            state.dynamic_data = new_data;
-            
-            if state.process_table_state.selected().is_none() && !state.dynamic_data.processes.is_empty() {
-                state.process_table_state.select(Some(0));
+        let (returned, update) = match tokio::task::spawn_blocking(move || {
+            let update = collector.collect();
+            (collector, update)
+        }).await {
+            Ok(result) => result,
+            Err(e) => {
+                log::error!("GPU collector stopped: {}", e);
+                return;
             }
-        }
+        };
+        collector = returned;
         
-        let collection_duration = collection_start.elapsed();
+        if !publish(&tx, CollectorKind::Gpu, update, scheduler.finish(started)) {
+            return;
+        }
+    }
+}
+
+async fn container_collection_loop(
+    tx: mpsc::UnboundedSender<AppMessage>,
+    control: watch::Receiver<CollectorControl>,
+    mut collector: ContainerCollector,
+    config: AppConfig,
+) {
+    let mut scheduler = Scheduler::new(config.container_refresh_interval());
+    
+    loop {
+        let started = scheduler.tick().await;
         
-        if collection_duration > Duration::from_millis(config.refresh_rate_ms / 2) {
-            eprintln!("Slow data collection: {:?}", collection_duration);
+        if control.borrow().paused {
+            scheduler.pause();
+            continue;
         }
         
-        let remaining_time = config.get_collection_sleep_duration().saturating_sub(collection_duration);
-        if remaining_time > Duration::from_millis(10) {
-            sleep(remaining_time).await;
+        let update = collector.collect().await;
+        if !publish(&tx, CollectorKind::Containers, update, scheduler.finish(started)) {
+            return;
         }
     }
 }
@@ -647,30 +1851,68 @@ impl From<io::Error> for AppError {
     }
 }
 
+// Termination signals are turned into a shutdown message so the UI loop
+// returns normally and the terminal gets restored on the way out.
 #[cfg(unix)]
-fn setup_signal_handlers() -> Result<(), Box<dyn std::error::Error>> {
-    use signal_hook::{consts::SIGTERM, iterator::Signals};
-    use std::sync::atomic::{AtomicBool, Ordering};
-    use std::sync::Arc;
+fn setup_signal_handlers(tx: mpsc::UnboundedSender<AppMessage>) -> Result<(), Box<dyn std::error::Error>> {
+    use signal_hook::{consts::{SIGHUP, SIGINT, SIGTERM}, iterator::Signals};
     
-    let term = Arc::new(AtomicBool::new(false));
-    let mut signals = Signals::new(&[SIGTERM])?;
+    let mut signals = Signals::new([SIGTERM, SIGHUP, SIGINT])?;
     
-    let term_clone = Arc::clone(&term);
     std::thread::spawn(move || {
-        for sig in signals.forever() {
-            match sig {
-                SIGTERM => {
-                    term_clone.store(true, Ordering::Relaxed);
-                }
+        // Ctrl+C while sudo or pkexec asks for a password reaches puls too,
+        // and is only meant to abort the prompt.
+        let mut signals = signals.forever().filter(|&sig| sig != SIGINT || !elevate::INPUT_PAUSED.load(std::sync::atomic::Ordering::SeqCst));
+        if let Some(sig) = signals.next() {
+            let name = match sig {
+                SIGTERM => "SIGTERM",
+                SIGHUP => "SIGHUP",
+                SIGINT => "SIGINT",
                 _ => unreachable!(),
-            }
+            };
+            let _ = tx.send(AppMessage::Shutdown(name));
+        }
+        // A second SIGINT or SIGTERM means the orderly shutdown is stuck
+        // (a hung collector or plugin), so give the terminal back and leave.
+        if let Some(sig) = signals.find(|&sig| sig == SIGINT || sig == SIGTERM) {
+            force_exit(128 + sig);
         }
     });
     
     Ok(())
 }
 
+#[cfg(not(unix))]
+fn setup_signal_handlers(tx: mpsc::UnboundedSender<AppMessage>) -> Result<(), Box<dyn std::error::Error>> {
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            let _ = tx.send(AppMessage::Shutdown("Ctrl+C"));
+        }
+        if tokio::signal::ctrl_c().await.is_ok() {
+            force_exit(130);
+        }
+    });
+    
+    Ok(())
+}
+
+// Best-effort cleanup followed by an immediate exit. CPU limits are lifted so
+// no process is left stopped or capped, and plugins are killed; either gets a
+// second at most, since whatever held up the shutdown may be holding them.
+fn force_exit(code: i32) -> ! {
+    let (done_tx, done_rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        cpu_limit::lift_all();
+        plugins::stop_all();
+        let _ = done_tx.send(());
+    });
+    let _ = done_rx.recv_timeout(Duration::from_secs(1));
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, crossterm::cursor::Show);
+    log::logger().flush();
+    std::process::exit(code);
+}
+
 fn check_system_requirements() -> Result<(), AppError> {
     if !atty::is(atty::Stream::Stdout) {
         return Err(AppError::Config(
@@ -688,13 +1930,7 @@ fn check_system_requirements() -> Result<(), AppError> {
 }
 
 fn init_logging(verbose: bool) -> Result<(), AppError> {
-    if verbose {
-        env_logger::Builder::from_default_env()
-            .filter_level(log::LevelFilter::Debug)
-            .init();
-    }
-    
-    Ok(())
+    status::init_logger(verbose).map_err(|e| AppError::Config(e.to_string()))
 }
 
 #[cfg(test)]
@@ -712,4 +1948,56 @@ mod tests {
         let monitor_error = AppError::Monitor("test monitor error".to_string());
         assert!(format!("{}", monitor_error).contains("Monitoring Error"));
     }
+
+    #[test]
+    fn test_current_subtree() {
+        let pid = sysinfo::Pid::from_u32(std::process::id());
+        let start_time = monitors::system_monitor::process_start_time(pid).unwrap();
+        assert_eq!(current_subtree(pid, start_time).unwrap()[0], pid.to_string());
+        // A different start time means the PID now names another process.
+        assert_eq!(current_subtree(pid, start_time + 1), None);
+    }
+
+    #[test]
+    fn test_no_process_actions_on_past_samples() {
+        let mut state = AppState { has_sudo: true, ..Default::default() };
+        state.dynamic_data.processes = vec![crate::types::ProcessInfo { pid: "4242".to_string(), ..Default::default() }];
+        state.process_table_state.select(Some(0));
+        let kill = crossterm::event::KeyEvent::from(KeyCode::Char('K'));
+
+        state.samples.record(&state.dynamic_data);
+        state.samples.record(&state.dynamic_data);
+        assert!(state.samples.step_back(&mut state.dynamic_data));
+        handle_key_event(kill, &mut state).unwrap();
+        assert!(state.confirm.is_none());
+
+        state.samples.resume(&mut state.dynamic_data);
+        handle_key_event(kill, &mut state).unwrap();
+        assert!(state.confirm.is_some());
+    }
+
+    #[test]
+    fn test_tree_arrows_while_paused() {
+        let process = |pid: &str, parent: Option<&str>| crate::types::ProcessInfo {
+            pid: pid.to_string(),
+            parent: parent.map(str::to_string),
+            ..Default::default()
+        };
+        let mut state = AppState { paused: true, process_tree: true, ..Default::default() };
+        state.dynamic_data.processes = vec![process("1", None), process("2", Some("1"))];
+        state.process_table_state.select(Some(0));
+        state.samples.record(&state.dynamic_data);
+        state.samples.record(&state.dynamic_data);
+
+        handle_key_event(crossterm::event::KeyEvent::from(KeyCode::Left), &mut state).unwrap();
+        assert!(state.collapsed_processes.contains("1"));
+        assert!(state.samples.viewing().is_none());
+        handle_key_event(crossterm::event::KeyEvent::from(KeyCode::Right), &mut state).unwrap();
+        assert!(state.collapsed_processes.is_empty());
+
+        // Without the tree the arrows step through the samples again.
+        state.process_tree = false;
+        handle_key_event(crossterm::event::KeyEvent::from(KeyCode::Left), &mut state).unwrap();
+        assert!(state.samples.viewing().is_some());
+    }
 }
\ No newline at end of file
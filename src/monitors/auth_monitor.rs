@@ -0,0 +1,174 @@
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+
+use crate::types::{AuthService, AuthSummary, FailedLogin};
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+const RETENTION: chrono::Duration = chrono::Duration::hours(24);
+const MAX_FAILURES: usize = 5000;
+
+// Follows sshd and sudo entries in the journal. Each poll asks only for what
+// was logged after the cursor of the previous one.
+pub struct AuthMonitor {
+    summary: AuthSummary,
+    cursor: Option<String>,
+    last_refresh: Option<Instant>,
+}
+
+impl AuthMonitor {
+    pub fn new() -> Self {
+        Self { summary: AuthSummary::default(), cursor: None, last_refresh: None }
+    }
+
+    // None until the journal is due to be read again.
+    pub fn collect(&mut self) -> Option<AuthSummary> {
+        if !cfg!(target_os = "linux") || self.last_refresh.is_some_and(|at| at.elapsed() < REFRESH_INTERVAL) {
+            return None;
+        }
+        self.last_refresh = Some(Instant::now());
+
+        match self.read_journal() {
+            Ok(failures) => {
+                self.summary.error = None;
+                self.summary.failures.extend(failures);
+            }
+            Err(e) => self.summary.error = Some(e),
+        }
+        let cutoff = Local::now() - RETENTION;
+        while self.summary.failures.front().is_some_and(|f| f.at < cutoff) || self.summary.failures.len() > MAX_FAILURES {
+            self.summary.failures.pop_front();
+        }
+        Some(self.summary.clone())
+    }
+
+    fn read_journal(&mut self) -> Result<Vec<FailedLogin>, String> {
+        let mut command = Command::new("journalctl");
+        command.args(["-q", "--no-pager", "-o", "short-iso", "--show-cursor", "-t", "sshd", "-t", "sshd-session", "-t", "sudo"]);
+        match &self.cursor {
+            Some(cursor) => command.arg(format!("--after-cursor={}", cursor)),
+            None => command.args(["--since", "-24h"]),
+        };
+        let output = command.output().map_err(|_| "journalctl not available".to_string())?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stdout.trim().is_empty() && !stderr.trim().is_empty() {
+            return Err(stderr.lines().next().unwrap_or_default().trim().to_string());
+        }
+        if let Some(cursor) = stdout.lines().rev().find_map(|line| line.strip_prefix("-- cursor: ")) {
+            self.cursor = Some(cursor.trim().to_string());
+        }
+        Ok(parse_auth_failures(&stdout))
+    }
+}
+
+// Journal lines in `journalctl -o short-iso` form, oldest first.
+pub fn parse_auth_failures(journal: &str) -> Vec<FailedLogin> {
+    journal.lines().filter_map(|line| {
+        let (time, rest) = line.split_once(' ')?;
+        let at = DateTime::parse_from_str(time, "%Y-%m-%dT%H:%M:%S%z").ok()?.with_timezone(&Local);
+        let (_host, rest) = rest.split_once(' ')?;
+        let (ident, message) = rest.split_once(": ")?;
+        let ident = ident.split('[').next().unwrap_or(ident);
+
+        let (service, user, source) = match ident {
+            "sshd" | "sshd-session" => {
+                let (user, source) = parse_ssh_failure(message)?;
+                (AuthService::Ssh, user, source)
+            }
+            "sudo" => (AuthService::Sudo, parse_sudo_failure(message)?, "local".to_string()),
+            _ => return None,
+        };
+        Some(FailedLogin { at, service, user, source })
+    }).collect()
+}
+
+// A bad password for an unknown user also logs "Invalid user", so only the
+// latter counts.
+fn parse_ssh_failure(message: &str) -> Option<(String, String)> {
+    let (user, after) = if let Some(rest) = message.strip_prefix("Invalid user ") {
+        rest.split_once("from ")?
+    } else if let Some(rest) = message.strip_prefix("Failed ") {
+        let (_, rest) = rest.split_once(" for ")?;
+        if rest.starts_with("invalid user ") {
+            return None;
+        }
+        rest.split_once(" from ")?
+    } else {
+        return None;
+    };
+    Some((user.trim().to_string(), after.split_whitespace().next()?.to_string()))
+}
+
+// One PAM failure is logged per wrong password; the "incorrect password
+// attempts" summary that follows would count them twice.
+fn parse_sudo_failure(message: &str) -> Option<String> {
+    if message.starts_with("pam_unix(sudo:auth): authentication failure;") {
+        let field = |name: &str| message.split_whitespace()
+            .find_map(|part| part.strip_prefix(name))
+            .filter(|value| !value.is_empty());
+        return field("ruser=").or_else(|| field("logname=")).map(str::to_string);
+    }
+    let (user, rest) = message.split_once(" : ")?;
+    rest.starts_with("user NOT in sudoers").then(|| user.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_auth_failures() {
+        let journal = "\
+2024-05-01T10:00:00+0000 host sshd[100]: Invalid user admin from 203.0.113.9 port 40022
+2024-05-01T10:00:02+0000 host sshd[100]: Failed password for invalid user admin from 203.0.113.9 port 40022 ssh2
+2024-05-01T10:05:00+0000 host sshd-session[120]: Failed password for root from 198.51.100.4 port 51000 ssh2
+2024-05-01T10:06:00+0000 host sshd[130]: Accepted publickey for alice from 192.0.2.10 port 52000 ssh2
+2024-05-01T10:07:00+0000 host sshd[140]: Invalid user  from 203.0.113.9 port 40100
+2024-05-01T10:08:00+0000 host sudo[200]: pam_unix(sudo:auth): authentication failure; logname=bob uid=1000 euid=0 tty=/dev/pts/1 ruser=bob rhost=  user=bob
+2024-05-01T10:08:09+0000 host sudo[200]:      bob : 1 incorrect password attempt ; TTY=pts/1 ; PWD=/home/bob ; USER=root ; COMMAND=/usr/bin/id
+2024-05-01T10:09:00+0000 host sudo[210]:    carol : user NOT in sudoers ; TTY=pts/2 ; PWD=/home/carol ; USER=root ; COMMAND=/usr/bin/id
+-- cursor: s=abc;i=1
+";
+        let failures = parse_auth_failures(journal);
+        let summary: Vec<(AuthService, &str, &str)> = failures.iter()
+            .map(|f| (f.service, f.user.as_str(), f.source.as_str()))
+            .collect();
+        assert_eq!(summary, vec![
+            (AuthService::Ssh, "admin", "203.0.113.9"),
+            (AuthService::Ssh, "root", "198.51.100.4"),
+            (AuthService::Ssh, "", "203.0.113.9"),
+            (AuthService::Sudo, "bob", "local"),
+            (AuthService::Sudo, "carol", "local"),
+        ]);
+        assert_eq!(failures[1].at, DateTime::parse_from_rfc3339("2024-05-01T10:05:00Z").unwrap());
+    }
+
+    #[test]
+    fn test_auth_summary() {
+        let now = Local::now();
+        let failure = |minutes_ago: i64, source: &str, user: &str| FailedLogin {
+            at: now - chrono::Duration::minutes(minutes_ago),
+            service: AuthService::Ssh,
+            user: user.to_string(),
+            source: source.to_string(),
+        };
+        let summary = AuthSummary {
+            failures: [
+                failure(90, "203.0.113.9", "admin"),
+                failure(30, "198.51.100.4", "root"),
+                failure(5, "203.0.113.9", "test"),
+                failure(1, "203.0.113.9", "oracle"),
+            ].into(),
+            error: None,
+        };
+
+        assert_eq!(summary.recent(now, AuthSummary::ALERT_WINDOW), 2);
+        let offenders = summary.offenders();
+        assert_eq!(offenders.len(), 2);
+        assert_eq!((offenders[0].source.as_str(), offenders[0].count, offenders[0].last_user.as_str()), ("203.0.113.9", 3, "oracle"));
+        assert_eq!((offenders[1].source.as_str(), offenders[1].count), ("198.51.100.4", 1));
+    }
+}
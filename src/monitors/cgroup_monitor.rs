@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::types::CgroupNode;
+use crate::utils::calculate_rate;
+
+// Cumulative counters from the previous walk, keyed by cgroup path.
+#[derive(Clone, Copy, Default)]
+struct CgroupCounters {
+    cpu_usec: u64,
+    io_read: u64,
+    io_write: u64,
+}
+
+// Walks the cgroup v2 hierarchy. On hybrid systems the unified hierarchy is
+// mounted below the v1 controllers at /sys/fs/cgroup/unified.
+pub struct CgroupMonitor {
+    root: Option<PathBuf>,
+    prev: HashMap<String, CgroupCounters>,
+    last_update: Instant,
+    cpu_count: f32,
+}
+
+impl CgroupMonitor {
+    pub fn new() -> Self {
+        Self {
+            root: cgroup_root(),
+            prev: HashMap::new(),
+            last_update: Instant::now(),
+            cpu_count: std::thread::available_parallelism().map_or(1, |n| n.get()) as f32,
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.root.is_some()
+    }
+
+    // Every cgroup in pre-order, children sorted by name.
+    pub fn collect(&mut self) -> Vec<CgroupNode> {
+        let Some(root) = self.root.clone() else {
+            return Vec::new();
+        };
+
+        let now = Instant::now();
+        let elapsed_secs = now.duration_since(self.last_update).as_secs_f64().max(0.1);
+        self.last_update = now;
+
+        let mut nodes = Vec::new();
+        let mut counters = HashMap::new();
+        self.walk(&root, "/".to_string(), 0, elapsed_secs, &mut nodes, &mut counters);
+        self.prev = counters;
+        nodes
+    }
+
+    fn walk(&self, dir: &Path, path: String, depth: usize, elapsed_secs: f64,
+            nodes: &mut Vec<CgroupNode>, counters: &mut HashMap<String, CgroupCounters>) {
+        let mut children: Vec<(String, PathBuf)> = fs::read_dir(dir)
+            .map(|entries| entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+                .map(|entry| (entry.file_name().to_string_lossy().into_owned(), entry.path()))
+                .collect())
+            .unwrap_or_default();
+        children.sort();
+
+        let read = |file: &str| fs::read_to_string(dir.join(file)).ok();
+        let (io_read, io_write) = read("io.stat").map_or((0, 0), |c| parse_io_stat(&c));
+        let current = CgroupCounters {
+            cpu_usec: read("cpu.stat").and_then(|c| parse_cpu_stat(&c)).unwrap_or(0),
+            io_read,
+            io_write,
+        };
+
+        // New cgroups show no rate until their second sample.
+        let prev = self.prev.get(&path).copied().unwrap_or(current);
+        let cpu_percent = (current.cpu_usec.saturating_sub(prev.cpu_usec) as f64
+            / (elapsed_secs * 1_000_000.0) * 100.0) as f32 / self.cpu_count;
+
+        nodes.push(CgroupNode {
+            name: if depth == 0 { "/".to_string() } else { path.rsplit('/').next().unwrap_or_default().to_string() },
+            path: path.clone(),
+            depth,
+            has_children: !children.is_empty(),
+            processes: read("cgroup.procs").map_or(0, |c| c.lines().count()),
+            cpu_percent: cpu_percent.clamp(0.0, 100.0),
+            memory: read("memory.current").and_then(|c| c.trim().parse().ok()),
+            io_read_rate: calculate_rate(current.io_read, prev.io_read, elapsed_secs),
+            io_write_rate: calculate_rate(current.io_write, prev.io_write, elapsed_secs),
+        });
+        counters.insert(path.clone(), current);
+
+        for (name, child) in children {
+            let child_path = if depth == 0 { format!("/{}", name) } else { format!("{}/{}", path, name) };
+            self.walk(&child, child_path, depth + 1, elapsed_secs, nodes, counters);
+        }
+    }
+}
+
+impl Default for CgroupMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn cgroup_root() -> Option<PathBuf> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+
+    ["/sys/fs/cgroup", "/sys/fs/cgroup/unified"]
+        .iter()
+        .map(PathBuf::from)
+        .find(|dir| dir.join("cgroup.controllers").exists())
+}
+
+// `usage_usec` from cpu.stat: CPU time used by the cgroup and its descendants.
+pub fn parse_cpu_stat(content: &str) -> Option<u64> {
+    content.lines()
+        .find_map(|line| line.strip_prefix("usage_usec "))
+        .and_then(|value| value.trim().parse().ok())
+}
+
+// Bytes read and written, summed over the devices in io.stat.
+pub fn parse_io_stat(content: &str) -> (u64, u64) {
+    let mut read = 0;
+    let mut write = 0;
+    for field in content.split_whitespace() {
+        if let Some(value) = field.strip_prefix("rbytes=") {
+            read += value.parse::<u64>().unwrap_or(0);
+        } else if let Some(value) = field.strip_prefix("wbytes=") {
+            write += value.parse::<u64>().unwrap_or(0);
+        }
+    }
+    (read, write)
+}
+
+// The rows of the tree that aren't inside a collapsed cgroup.
+pub fn visible_cgroups<'a>(nodes: &'a [CgroupNode], collapsed: &HashSet<String>) -> Vec<&'a CgroupNode> {
+    let mut visible = Vec::new();
+    let mut hidden_below: Option<usize> = None;
+    for node in nodes {
+        match hidden_below {
+            Some(depth) if node.depth > depth => continue,
+            _ => hidden_below = None,
+        }
+        if collapsed.contains(&node.path) {
+            hidden_below = Some(node.depth);
+        }
+        visible.push(node);
+    }
+    visible
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(path: &str, depth: usize) -> CgroupNode {
+        CgroupNode { path: path.to_string(), depth, ..Default::default() }
+    }
+
+    #[test]
+    fn test_parse_cgroup_stats() {
+        let cpu = "usage_usec 1500000\nuser_usec 1000000\nsystem_usec 500000\nnr_periods 0\n";
+        assert_eq!(parse_cpu_stat(cpu), Some(1_500_000));
+        assert_eq!(parse_cpu_stat(""), None);
+
+        let io = "8:0 rbytes=4096 wbytes=8192 rios=1 wios=2 dbytes=0 dios=0\n\
+                  259:0 rbytes=1024 wbytes=0 rios=1 wios=0 dbytes=0 dios=0\n";
+        assert_eq!(parse_io_stat(io), (5120, 8192));
+    }
+
+    #[test]
+    fn test_visible_cgroups() {
+        let nodes = vec![
+            node("/", 0),
+            node("/system.slice", 1),
+            node("/system.slice/ssh.service", 2),
+            node("/system.slice/cron.service", 2),
+            node("/user.slice", 1),
+            node("/user.slice/user-1000.slice", 2),
+        ];
+        let paths = |collapsed: &HashSet<String>| -> Vec<String> {
+            visible_cgroups(&nodes, collapsed).iter().map(|n| n.path.clone()).collect()
+        };
+
+        assert_eq!(paths(&HashSet::new()).len(), 6);
+
+        let collapsed = HashSet::from(["/system.slice".to_string()]);
+        assert_eq!(paths(&collapsed), vec!["/", "/system.slice", "/user.slice", "/user.slice/user-1000.slice"]);
+
+        let collapsed = HashSet::from(["/".to_string()]);
+        assert_eq!(paths(&collapsed), vec!["/"]);
+    }
+}
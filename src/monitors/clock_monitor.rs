@@ -0,0 +1,237 @@
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::types::ClockStatus;
+
+// Sync state moves slowly, and chronyc and ntpq are cheap but not free.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+// Asks whichever time sync daemon is running: chrony, then ntpd, then
+// systemd-timesyncd through timedated.
+pub struct ClockMonitor {
+    last_refresh: Option<Instant>,
+}
+
+impl ClockMonitor {
+    pub fn new() -> Self {
+        Self { last_refresh: None }
+    }
+
+    // None until the clock is due to be checked again.
+    pub fn collect(&mut self) -> Option<ClockStatus> {
+        if !cfg!(target_os = "linux") || self.last_refresh.is_some_and(|at| at.elapsed() < REFRESH_INTERVAL) {
+            return None;
+        }
+        self.last_refresh = Some(Instant::now());
+
+        Some(run("chronyc", &["-n", "tracking"]).and_then(|out| parse_chrony_tracking(&out))
+            .or_else(|| run("ntpq", &["-n", "-c", "rv"]).and_then(|out| parse_ntpq_rv(&out)))
+            .or_else(timedated_status)
+            .unwrap_or_default())
+    }
+}
+
+// Stdout of a command that ran and exited cleanly.
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).env("LC_ALL", "C").output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn timedated_status() -> Option<ClockStatus> {
+    let mut status = parse_timedatectl_show(&run("timedatectl", &["show"])?);
+    if status.service.is_some() {
+        if let Some(out) = run("timedatectl", &["timesync-status"]) {
+            let (offset_ms, server) = parse_timesync_status(&out);
+            status.offset_ms = offset_ms;
+            status.detail = server.unwrap_or_default();
+        }
+    }
+    Some(status)
+}
+
+// "System time : 0.000012345 seconds fast of NTP time" is how far the local
+// clock is off; "Leap status : Not synchronised" means no usable source.
+pub fn parse_chrony_tracking(output: &str) -> Option<ClockStatus> {
+    let field = |name: &str| output.lines()
+        .find_map(|line| line.split_once(':').filter(|(key, _)| key.trim() == name))
+        .map(|(_, value)| value.trim());
+
+    let leap = field("Leap status")?;
+    let offset_ms = field("System time").and_then(|value| {
+        let mut parts = value.split_whitespace();
+        let seconds: f64 = parts.next()?.parse().ok()?;
+        let sign = if parts.nth(1)? == "slow" { -1.0 } else { 1.0 };
+        Some(sign * seconds * 1000.0)
+    });
+    let reference = field("Reference ID").map(|value| match value.split_once('(') {
+        Some((_, name)) => name.trim_end_matches(')').to_string(),
+        None => value.to_string(),
+    });
+    let stratum = field("Stratum").unwrap_or("?");
+
+    Some(ClockStatus {
+        service: Some("chrony".to_string()),
+        synchronized: leap != "Not synchronised",
+        offset_ms,
+        detail: reference.map(|r| format!("{} (stratum {})", r, stratum)).unwrap_or_default(),
+    })
+}
+
+// The system variables of `ntpq -c rv`, comma separated key=value pairs.
+// The offset is already in milliseconds, but NTP's: the reference's time
+// minus the local clock, so the sign is turned around to match chrony's
+// "fast". leap_alarm means unsynchronized.
+pub fn parse_ntpq_rv(output: &str) -> Option<ClockStatus> {
+    let flat = output.replace('\n', " ");
+    let field = |name: &str| flat.split(',')
+        .filter_map(|part| part.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.trim().trim_matches('"'));
+
+    let stratum = field("stratum")?;
+    Some(ClockStatus {
+        service: Some("ntpd".to_string()),
+        synchronized: !flat.contains("leap_alarm") && stratum != "16",
+        offset_ms: field("offset").and_then(|value| value.parse::<f64>().ok()).map(|offset| -offset),
+        detail: format!("{} (stratum {})", field("refid").unwrap_or("?"), stratum),
+    })
+}
+
+// `timedatectl show`: NTP=yes when timesyncd is enabled, NTPSynchronized
+// from the kernel's view of the clock either way.
+pub fn parse_timedatectl_show(output: &str) -> ClockStatus {
+    let field = |name: &str| output.lines().find_map(|line| line.strip_prefix(name)?.strip_prefix('='));
+    ClockStatus {
+        service: (field("NTP") == Some("yes")).then(|| "timesyncd".to_string()),
+        synchronized: field("NTPSynchronized") == Some("yes"),
+        offset_ms: None,
+        detail: String::new(),
+    }
+}
+
+// "Offset: -1.234ms" and "Server: 192.0.2.1 (ntp.example.org)". The offset
+// is NTP's like ntpq's, and turned around the same way.
+pub fn parse_timesync_status(output: &str) -> (Option<f64>, Option<String>) {
+    let field = |name: &str| output.lines()
+        .find_map(|line| line.trim().strip_prefix(name)?.strip_prefix(':'))
+        .map(str::trim);
+
+    let offset_ms = field("Offset").and_then(|value| {
+        let split = value.find(|c: char| c.is_ascii_alphabetic() || c == 'µ')?;
+        let (number, unit) = value.split_at(split);
+        let number: f64 = number.trim_start_matches('+').parse().ok()?;
+        match unit {
+            "s" => Some(-number * 1000.0),
+            "ms" => Some(-number),
+            "us" | "µs" => Some(-number / 1000.0),
+            "ns" => Some(-number / 1_000_000.0),
+            _ => None,
+        }
+    });
+    (offset_ms, field("Server").map(str::to_string))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chrony_tracking() {
+        let tracking = "\
+Reference ID    : C0000201 (192.0.2.1)
+Stratum         : 3
+Ref time (UTC)  : Wed May 01 10:00:00 2024
+System time     : 0.002500000 seconds slow of NTP time
+Last offset     : -0.000012345 seconds
+Leap status     : Normal
+";
+        let status = parse_chrony_tracking(tracking).unwrap();
+        assert!(status.synchronized);
+        assert_eq!(status.offset_ms, Some(-2.5));
+        assert_eq!(status.detail, "192.0.2.1 (stratum 3)");
+
+        let lost = "Reference ID    : 00000000 ()\nStratum         : 0\nSystem time     : 0.000000000 seconds fast of NTP time\nLeap status     : Not synchronised\n";
+        assert!(!parse_chrony_tracking(lost).unwrap().synchronized);
+        assert_eq!(parse_chrony_tracking("506 Cannot talk to daemon\n"), None);
+    }
+
+    #[test]
+    fn test_parse_ntpq_rv() {
+        let rv = "associd=0 status=0615 leap_none, sync_ntp, 1 event, clock_sync,
+version=\"ntpd 4.2.8p15\", processor=\"x86_64\", stratum=2, precision=-24,
+rootdelay=1.234, refid=192.0.2.1, offset=12.345, frequency=-3.2, sys_jitter=0.1\n";
+        let status = parse_ntpq_rv(rv).unwrap();
+        assert!(status.synchronized);
+        assert_eq!(status.offset_ms, Some(-12.345));
+        assert_eq!(status.detail, "192.0.2.1 (stratum 2)");
+
+        let unsynced = "associd=0 status=c016 leap_alarm, sync_unspec, 1 event, restart,\nstratum=16, refid=INIT, offset=0.000\n";
+        assert!(!parse_ntpq_rv(unsynced).unwrap().synchronized);
+    }
+
+    #[test]
+    fn test_parse_timedated() {
+        let status = parse_timedatectl_show("Timezone=UTC\nNTP=yes\nNTPSynchronized=no\n");
+        assert_eq!(status.service.as_deref(), Some("timesyncd"));
+        assert!(!status.synchronized);
+        assert_eq!(parse_timedatectl_show("NTP=no\nNTPSynchronized=yes\n").service, None);
+
+        let (offset, server) = parse_timesync_status("       Server: 192.0.2.1 (ntp.example.org)\n       Offset: +850us\n");
+        assert_eq!(offset, Some(-0.85));
+        assert_eq!(server.as_deref(), Some("192.0.2.1 (ntp.example.org)"));
+        assert_eq!(parse_timesync_status("Offset: -1.5s").0, Some(1500.0));
+    }
+
+    // The same clock, 0.41 ms ahead of its server, as each daemon puts it.
+    #[test]
+    fn test_offset_sign_agrees() {
+        let chrony = "\
+Reference ID    : A29FC87B (162.159.200.123)
+Stratum         : 4
+Ref time (UTC)  : Thu Oct 15 09:12:44 2026
+System time     : 0.000412873 seconds fast of NTP time
+Last offset     : +0.000218559 seconds
+RMS offset      : 0.000390142 seconds
+Frequency       : 11.524 ppm slow
+Residual freq   : +0.012 ppm
+Skew            : 0.154 ppm
+Root delay      : 0.022713771 seconds
+Root dispersion : 0.001032946 seconds
+Update interval : 1031.9 seconds
+Leap status     : Normal
+";
+        let ntpq = "\
+associd=0 status=0615 leap_none, sync_ntp, 1 event, clock_sync,
+version=\"ntpd 4.2.8p15@1.3728-o Wed Feb 16 17:13:02 UTC 2022 (1)\",
+processor=\"x86_64\", system=\"Linux/5.15.0-91-generic\", leap=00,
+stratum=3, precision=-24, rootdelay=25.307, rootdisp=31.476,
+refid=162.159.200.123,
+reftime=e8d4f2a1.1b2c3d4e  Thu, Oct 15 2026  9:12:44.106,
+clock=e8d4f3b2.9a8b7c6d  Thu, Oct 15 2026  9:17:06.603, peer=54211, tc=10,
+mintc=3, offset=-0.412873, frequency=-11.524, sys_jitter=0.390142,
+clk_jitter=0.201, clk_wander=0.012, tai=37, leapsec=201701010000,
+expire=202712280000
+";
+        let timesyncd = "\
+       Server: 162.159.200.123 (time.cloudflare.com)
+Poll interval: 34min 8s (min: 32s; max 34min 8s)
+         Leap: normal
+      Version: 4
+      Stratum: 3
+    Reference: 8EC9E2D
+    Precision: 1us (-24)
+Root distance: 12.812ms (max: 5s)
+       Offset: -412us
+        Delay: 25.307ms
+       Jitter: 390us
+ Packet count: 42
+    Frequency: -11.524ppm
+";
+        let chrony = parse_chrony_tracking(chrony).unwrap().offset_ms.unwrap();
+        let ntpq = parse_ntpq_rv(ntpq).unwrap().offset_ms.unwrap();
+        let timesyncd = parse_timesync_status(timesyncd).0.unwrap();
+        assert!((chrony - 0.412873).abs() < 1e-9);
+        assert!((ntpq - 0.412873).abs() < 1e-9);
+        assert!((timesyncd - 0.412).abs() < 1e-9);
+    }
+}
@@ -4,9 +4,9 @@ use futures_util::{future, stream::StreamExt};
 use tokio::time::timeout;
 
 #[cfg(feature = "docker")]
-use bollard::{container::StatsOptions, Docker};
+use bollard::{container::StatsOptions, image::ListImagesOptions, system::EventsOptions, Docker};
 
-use crate::types::{ContainerInfo, ContainerIoStats};
+use crate::types::{ContainerInfo, ContainerIoStats, ContainerOom};
 use crate::utils::{format_size, format_rate, calculate_rate};
 
 pub struct ContainerMonitor {
@@ -14,9 +14,22 @@ pub struct ContainerMonitor {
     docker: Option<Docker>,
     
     prev_container_stats: HashMap<String, ContainerIoStats>,
+    // Image sizes by image ID, re-read when a container uses an image not in it.
+    image_sizes: HashMap<String, u64>,
     last_update: Instant,
+    // Seconds since the epoch up to which Docker events have been read; None
+    // before the first check, which only sets the starting point.
+    events_until: Option<i64>,
+    ooms: Vec<ContainerOom>,
+    // Inspect results by full container ID and when they were read. Dropped
+    // when Docker reports the container started or was updated.
+    lifecycles: HashMap<String, (Instant, Lifecycle)>,
 }
 
+// Inspect results go stale without an event only in ways that don't matter
+// much (a missed event), so they are re-read rarely.
+const INSPECT_INTERVAL: Duration = Duration::from_secs(300);
+
 impl ContainerMonitor {
     pub fn new() -> Self {
         Self {
@@ -24,7 +37,11 @@ impl ContainerMonitor {
             docker: Self::init_docker(),
             
             prev_container_stats: HashMap::new(),
+            image_sizes: HashMap::new(),
             last_update: Instant::now(),
+            events_until: None,
+            ooms: Vec::new(),
+            lifecycles: HashMap::new(),
         }
     }
     
@@ -57,6 +74,11 @@ impl ContainerMonitor {
         Err("Docker support not compiled".to_string())
     }
     
+    // OOM kills seen since the last call.
+    pub fn take_ooms(&mut self) -> Vec<ContainerOom> {
+        std::mem::take(&mut self.ooms)
+    }
+    
     #[cfg(feature = "docker")]
     async fn get_docker_containers(&mut self, docker: &Docker, timeout_ms: u64) -> Result<Vec<ContainerInfo>, Box<dyn std::error::Error + Send + Sync>> {
         let now = Instant::now();
@@ -72,6 +94,13 @@ impl ContainerMonitor {
             docker.list_containers::<String>(None)
         ).await??;
         
+        // Before the early return, as a container killed for memory may have
+        // been the last one running.
+        self.read_events(docker, timeout_ms).await;
+        self.lifecycles.retain(|id, (at, _)| {
+            at.elapsed() < INSPECT_INTERVAL && containers_list.iter().any(|container| container.id.as_ref() == Some(id))
+        });
+        
         if containers_list.is_empty() {
             return Ok(Vec::new());
         }
@@ -96,7 +125,58 @@ impl ContainerMonitor {
                 }
             });
         
-        let stats_results = future::join_all(stats_futures).await;
+        // Start time and restart count are only in the full inspect output.
+        let inspect_futures = containers_list.iter()
+            .filter_map(|container| container.id.as_ref())
+            .filter(|id| !self.lifecycles.contains_key(*id))
+            .map(|id| {
+                let docker_clone = docker.clone();
+                let id_clone = id.clone();
+                let timeout_duration = Duration::from_millis(timeout_ms / 4);
+                
+                async move {
+                    let result = timeout(timeout_duration, docker_clone.inspect_container(&id_clone, None)).await;
+                    (id_clone, result)
+                }
+            });
+        
+        let (stats_results, inspect_results) = future::join(
+            future::join_all(stats_futures),
+            future::join_all(inspect_futures),
+        ).await;
+        
+        let inspected = inspect_results.into_iter()
+            .filter_map(|(id, result)| {
+                let inspect = result.ok()?.ok()?;
+                let started = inspect.state
+                    .and_then(|state| state.started_at)
+                    .and_then(|at| parse_docker_time(&at));
+                let host = inspect.host_config.unwrap_or_default();
+                Some((id, (now, Lifecycle {
+                    started,
+                    restart_count: inspect.restart_count.unwrap_or(0).max(0) as u32,
+                    cpu_limit: cpu_limit(host.nano_cpus, host.cpu_quota, host.cpu_period),
+                    mem_limit: host.memory.filter(|&bytes| bytes > 0).map(|bytes| bytes as u64),
+                })))
+            });
+        self.lifecycles.extend(inspected);
+        
+        let unknown_image = containers_list.iter()
+            .filter_map(|container| container.image_id.as_ref())
+            .any(|id| !self.image_sizes.contains_key(id));
+        if unknown_image {
+            let images = timeout(
+                Duration::from_millis(timeout_ms / 4),
+                docker.list_images(None::<ListImagesOptions<String>>),
+            ).await;
+            match images {
+                Ok(Ok(images)) => {
+                    self.image_sizes = images.into_iter().map(|image| (image.id, image.size.max(0) as u64)).collect();
+                }
+                Ok(Err(e)) => log::debug!("Failed to list images: {}", e),
+                Err(_) => log::debug!("Timeout listing images"),
+            }
+        }
         
         let mut stats_map = HashMap::new();
         for (id, stats_result) in stats_results {
@@ -105,13 +185,13 @@ impl ContainerMonitor {
                     stats_map.insert(id, stats);
                 }
                 Ok(Some(Err(e))) => {
-                    eprintln!("Failed to get stats for container {}: {}", id, e);
+                    log::warn!("Failed to get stats for container {}: {}", id, e);
                 }
                 Ok(None) => {
-                    eprintln!("No stats available for container {}", id);
+                    log::debug!("No stats available for container {}", id);
                 }
                 Err(_) => {
-                    eprintln!("Timeout getting stats for container {}", id);
+                    log::warn!("Timeout getting stats for container {}", id);
                 }
             }
         }
@@ -140,8 +220,10 @@ impl ContainerMonitor {
                 .to_string();
             
             let ports = self.format_ports(&container.ports);
+            let image_size = container.image_id.as_ref().and_then(|id| self.image_sizes.get(id)).copied();
+            let lifecycle = self.lifecycles.get(&id_full).map(|(_, lifecycle)| *lifecycle).unwrap_or_default();
             
-            let (cpu, mem, net_down, net_up, disk_r, disk_w) = 
+            let (cpu_percent, mem_bytes, net_down, net_up, disk_r, disk_w) = 
                 if let Some(stats) = stats_map.get(&id_full) {
                     self.calculate_container_metrics(
                         &id_full, 
@@ -151,8 +233,8 @@ impl ContainerMonitor {
                     )
                 } else {
                     (
-                        "0.00%".to_string(),
-                        "0 B".to_string(),
+                        0.0,
+                        0,
                         "0 B/s".to_string(),
                         "0 B/s".to_string(),
                         "0 B/s".to_string(),
@@ -160,19 +242,29 @@ impl ContainerMonitor {
                     )
                 };
             
-            container_infos.push(ContainerInfo {
+            let mut info = ContainerInfo {
                 id: id_short,
                 name,
                 status,
-                cpu,
-                mem,
+                cpu: String::new(),
+                mem: String::new(),
                 net_down,
                 net_up,
                 disk_r,
                 disk_w,
                 image,
                 ports,
-            });
+                image_size,
+                created: container.created,
+                started: lifecycle.started,
+                restart_count: lifecycle.restart_count,
+                cpu_percent,
+                mem_bytes,
+                cpu_limit: lifecycle.cpu_limit,
+                mem_limit: lifecycle.mem_limit,
+            };
+            (info.cpu, info.mem) = format_usage(&info);
+            container_infos.push(info);
         }
         
         self.prev_container_stats = current_container_stats;
@@ -186,7 +278,7 @@ impl ContainerMonitor {
         stats: &bollard::container::Stats,
         elapsed_secs: f64,
         current_stats: &mut HashMap<String, ContainerIoStats>
-    ) -> (String, String, String, String, String, String) {
+    ) -> (f64, u64, String, String, String, String) {
         let prev_stats = self.prev_container_stats
             .get(container_id)
             .cloned()
@@ -195,10 +287,7 @@ impl ContainerMonitor {
         let mut container_io_stats = ContainerIoStats::default();
         
         let cpu_usage = self.calculate_cpu_usage(stats);
-        let cpu_display = format!("{:.2}%", cpu_usage);
-        
         let memory_usage = stats.memory_stats.usage.unwrap_or(0);
-        let memory_display = format_size(memory_usage);
         
         if let Some(ref networks) = stats.networks {
             for (_, net_data) in networks {
@@ -248,8 +337,8 @@ impl ContainerMonitor {
         current_stats.insert(container_id.to_string(), container_io_stats);
         
         (
-            cpu_display,
-            memory_display,
+            cpu_usage,
+            memory_usage,
             net_down_display,
             net_up_display,
             disk_read_display,
@@ -257,6 +346,49 @@ impl ContainerMonitor {
         )
     }
     
+    // Reads the events Docker logged since the last check: OOM kills into
+    // `ooms`, and starts and updates, which make a container's inspect
+    // results stale.
+    #[cfg(feature = "docker")]
+    async fn read_events(&mut self, docker: &Docker, timeout_ms: u64) {
+        let now = chrono::Local::now().timestamp();
+        let Some(since) = self.events_until.replace(now) else { return };
+        let options = EventsOptions::<String> {
+            since: Some(since.to_string()),
+            until: Some(now.to_string()),
+            filters: HashMap::from([
+                ("type".to_string(), vec!["container".to_string()]),
+                ("event".to_string(), vec!["oom".to_string(), "start".to_string(), "update".to_string()]),
+            ]),
+        };
+        // With `until` set the stream ends once the past events are sent.
+        let events = docker.events(Some(options)).collect::<Vec<_>>();
+        let Ok(events) = timeout(Duration::from_millis(timeout_ms / 4), events).await else {
+            log::debug!("Timeout reading Docker events");
+            self.events_until = Some(since);
+            return;
+        };
+        for event in events {
+            match event {
+                Ok(event) if event.action.as_deref() != Some("oom") => {
+                    if let Some(id) = event.actor.and_then(|actor| actor.id) {
+                        self.lifecycles.remove(&id);
+                    }
+                }
+                Ok(event) => {
+                    let actor = event.actor.unwrap_or_default();
+                    let name = actor.attributes.and_then(|mut attributes| attributes.remove("name"))
+                        .or(actor.id.map(|id| id.chars().take(12).collect()))
+                        .unwrap_or_else(|| "unnamed".to_string());
+                    let at = event.time.and_then(|seconds| chrono::DateTime::from_timestamp(seconds, 0))
+                        .map_or_else(chrono::Local::now, |at| at.with_timezone(&chrono::Local));
+                    self.ooms.push(ContainerOom { at, name });
+                }
+                Err(e) => log::debug!("Failed to read Docker events: {}", e),
+            }
+        }
+    }
+    
     #[cfg(feature = "docker")]
     fn calculate_cpu_usage(&self, stats: &bollard::container::Stats) -> f64 {
         let cpu_delta = stats.cpu_stats.cpu_usage.total_usage
@@ -340,6 +472,55 @@ impl ContainerMonitor {
     }
 }
 
+// What only the full inspect output has.
+#[derive(Clone, Copy, Default)]
+struct Lifecycle {
+    started: Option<i64>,
+    restart_count: u32,
+    cpu_limit: Option<f64>,
+    mem_limit: Option<u64>,
+}
+
+// CPU limit in cores: `--cpus` sets NanoCpus, `--cpu-quota` with
+// `--cpu-period` the CFS quota, with 100ms the kernel's default period.
+fn cpu_limit(nano_cpus: Option<i64>, quota: Option<i64>, period: Option<i64>) -> Option<f64> {
+    if let Some(nano_cpus) = nano_cpus.filter(|&nano_cpus| nano_cpus > 0) {
+        return Some(nano_cpus as f64 / 1e9);
+    }
+    let quota = quota.filter(|&quota| quota > 0)?;
+    let period = period.filter(|&period| period > 0).unwrap_or(100_000);
+    Some(quota as f64 / period as f64)
+}
+
+// "85% of 2 CPU" and "412.0 MiB (81% of 512.0 MiB)" for limited containers,
+// plain host figures otherwise.
+fn format_usage(info: &ContainerInfo) -> (String, String) {
+    let cpu = match (info.cpu_limit, info.cpu_of_limit()) {
+        (Some(cores), Some(percent)) => format!("{:.0}% of {} CPU", percent, format_cores(cores)),
+        _ => format!("{:.2}%", info.cpu_percent),
+    };
+    let mem = match (info.mem_limit, info.mem_of_limit()) {
+        (Some(limit), Some(percent)) => format!("{} ({:.0}% of {})", format_size(info.mem_bytes), percent, format_size(limit)),
+        _ => format_size(info.mem_bytes),
+    };
+    (cpu, mem)
+}
+
+fn format_cores(cores: f64) -> String {
+    if cores.fract() == 0.0 {
+        format!("{:.0}", cores)
+    } else {
+        format!("{:.2}", cores).trim_end_matches('0').to_string()
+    }
+}
+
+// Docker reports times as RFC 3339, with the year 1 for "never".
+fn parse_docker_time(value: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(value).ok()
+        .map(|time| time.timestamp())
+        .filter(|&seconds| seconds > 0)
+}
+
 impl Default for ContainerMonitor {
     fn default() -> Self {
         Self::new()
@@ -356,6 +537,41 @@ mod tests {
         assert!(true);
     }
     
+    #[test]
+    fn test_parse_docker_time() {
+        assert_eq!(parse_docker_time("2024-05-01T12:00:00.123456789Z"), Some(1714564800));
+        assert_eq!(parse_docker_time("0001-01-01T00:00:00Z"), None);
+        assert_eq!(parse_docker_time(""), None);
+    }
+    
+    #[test]
+    fn test_container_limits() {
+        assert_eq!(cpu_limit(Some(1_500_000_000), None, None), Some(1.5));
+        assert_eq!(cpu_limit(Some(0), Some(50_000), Some(100_000)), Some(0.5));
+        assert_eq!(cpu_limit(None, Some(200_000), None), Some(2.0));
+        assert_eq!(cpu_limit(None, Some(-1), Some(100_000)), None);
+        
+        let mut info = ContainerInfo {
+            cpu_percent: 170.0,
+            mem_bytes: 412 * 1024 * 1024,
+            cpu_limit: Some(2.0),
+            mem_limit: Some(512 * 1024 * 1024),
+            ..Default::default()
+        };
+        assert_eq!(info.cpu_of_limit(), Some(85.0));
+        let (cpu, mem) = format_usage(&info);
+        assert_eq!(cpu, "85% of 2 CPU");
+        assert!(mem.ends_with("(80% of 512.0 MiB)"), "{}", mem);
+        
+        info.cpu_percent = 20.0;
+        info.cpu_limit = Some(0.25);
+        assert_eq!(format_usage(&info).0, "80% of 0.25 CPU");
+        info.cpu_limit = None;
+        info.mem_limit = None;
+        assert_eq!(info.mem_of_limit(), None);
+        assert_eq!(format_usage(&info).0, "20.00%");
+    }
+    
     #[tokio::test]
     async fn test_container_health_check() {
         let monitor = ContainerMonitor::new();
@@ -1,4 +1,5 @@
-use crate::types::GpuInfo;
+use crate::types::{GpuInfo, GpuMemoryRepair, GpuProcessInfo};
+use std::collections::{HashMap, HashSet};
 use std::collections::VecDeque;
 use std::process::Command;
 use std::path::Path;
@@ -7,6 +8,20 @@ use std::fs;
 pub struct GpuMonitor {
     gpu_history: VecDeque<Vec<u32>>,
     gpu_memory_history: VecDeque<Vec<u32>>,
+    gpu_temperature_history: VecDeque<Vec<u32>>,
+    // Memory error totals per GPU when first seen, for ecc_growth.
+    ecc_baseline: HashMap<String, u64>,
+    // Cleared the first time nvidia-smi can't be spawned.
+    nvidia_smi: bool,
+    // Fields this driver's nvidia-smi rejects, left out of later queries.
+    unsupported_fields: HashSet<&'static str>,
+    // PCIe throughput by bus id, from the last full report.
+    pcie_throughput: Option<(std::time::Instant, HashMap<String, PcieThroughput>)>,
+    // DRM clients from the last walk over every process's descriptors.
+    drm_clients: Option<(std::time::Instant, Vec<DrmClientUsage>)>,
+    // NVIDIA clients from the last `nvidia-smi pmon` sample, which takes a
+    // second to produce.
+    nvidia_clients: Option<(std::time::Instant, Vec<NvidiaClient>)>,
     last_update: std::time::Instant,
 }
 
@@ -15,6 +30,13 @@ impl GpuMonitor {
         Self {
             gpu_history: VecDeque::new(),
             gpu_memory_history: VecDeque::new(),
+            gpu_temperature_history: VecDeque::new(),
+            ecc_baseline: HashMap::new(),
+            nvidia_smi: true,
+            unsupported_fields: HashSet::new(),
+            pcie_throughput: None,
+            drm_clients: None,
+            nvidia_clients: None,
             last_update: std::time::Instant::now(),
         }
     }
@@ -24,13 +46,18 @@ impl GpuMonitor {
         let mut errors = Vec::new();
         
         match self.get_nvidia_gpus() {
-            Ok(mut nvidia_gpus) => gpus.append(&mut nvidia_gpus),
+            Ok(mut nvidia_gpus) => {
+                track_ecc_growth(&mut self.ecc_baseline, &mut nvidia_gpus);
+                gpus.append(&mut nvidia_gpus);
+            }
             Err(e) => errors.push(format!("NVIDIA: {}", e)),
         }
         
-        match self.get_drm_gpus() {
-            Ok(mut drm_gpus) => gpus.append(&mut drm_gpus),
-            Err(e) => errors.push(format!("DRM: {}", e)),
+        if cfg!(target_os = "linux") {
+            match self.get_drm_gpus() {
+                Ok(mut drm_gpus) => gpus.append(&mut drm_gpus),
+                Err(e) => errors.push(format!("DRM: {}", e)),
+            }
         }
         
         if gpus.is_empty() {
@@ -40,75 +67,87 @@ impl GpuMonitor {
                 Err(format!("No GPUs found. Errors: {}", errors.join(", ")))
             }
         } else {
-            for (i, gpu) in gpus.iter_mut().enumerate() {
-                gpu.utilization_history = self.gpu_history
-                    .iter()
-                    .filter_map(|frame| frame.get(i).cloned())
-                    .collect();
-                    
-                gpu.memory_history = self.gpu_memory_history
-                    .iter()
-                    .filter_map(|frame| frame.get(i).cloned())
-                    .collect();
-            }
+            self.attach_history(&mut gpus);
             Ok(gpus)
         }
     }
     
-    fn get_nvidia_gpus(&self) -> Result<Vec<GpuInfo>, String> {
-        let output = Command::new("nvidia-smi")
-            .arg("--query-gpu=name,utilization.gpu,memory.used,memory.total,temperature.gpu,power.draw,clocks.gr,clocks.mem,fan.speed,driver_version")
-            .arg("--format=csv,noheader,nounits")
-            .output()
-            .map_err(|e| e.to_string())?;
-            
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("nvidia-smi failed: {}", stderr.trim()));
+    fn attach_history(&self, gpus: &mut [GpuInfo]) {
+        for (i, gpu) in gpus.iter_mut().enumerate() {
+            gpu.utilization_history = self.gpu_history
+                .iter()
+                .filter_map(|frame| frame.get(i).cloned())
+                .collect();
+                
+            gpu.memory_history = self.gpu_memory_history
+                .iter()
+                .filter_map(|frame| frame.get(i).cloned())
+                .collect();
+                
+            gpu.temperature_history = self.gpu_temperature_history
+                .iter()
+                .filter_map(|frame| frame.get(i).cloned())
+                .collect();
+        }
+    }
+    
+    fn get_nvidia_gpus(&mut self) -> Result<Vec<GpuInfo>, String> {
+        if !self.nvidia_smi {
+            return Err("nvidia-smi not found".to_string());
         }
         
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut gpus = Vec::new();
+        let mut fields = self.supported_fields();
+        let rows = match query_nvidia_smi(&fields) {
+            Err(NvidiaSmiError::InvalidField(_)) => {
+                // Older drivers reject the whole query over a single unknown
+                // field. Find out once which ones, then leave them out.
+                for field in &fields {
+                    if let Err(NvidiaSmiError::InvalidField(_)) = query_nvidia_smi(&[field]) {
+                        self.unsupported_fields.insert(field);
+                    }
+                }
+                fields = self.supported_fields();
+                query_nvidia_smi(&fields)
+            }
+            result => result,
+        };
+        let rows = match rows {
+            Ok(rows) => rows,
+            Err(NvidiaSmiError::Missing) => {
+                self.nvidia_smi = false;
+                return Err("nvidia-smi not found".to_string());
+            }
+            Err(NvidiaSmiError::InvalidField(e) | NvidiaSmiError::Failed(e)) => return Err(e),
+        };
         
-        for line in stdout.lines() {
-            let parts: Vec<&str> = line.split(", ").collect();
-            if parts.len() < 9 { 
-                continue;
+        let mut gpus: Vec<GpuInfo> = rows.iter().map(|row| parse_nvidia_row(&spread_nvidia_row(row, &fields))).collect();
+        
+        // PCIe throughput is not exposed through --query-gpu, only in the full
+        // report, which is slow enough to produce that it is only asked for
+        // now and then.
+        if self.pcie_throughput.as_ref().is_none_or(|(at, _)| at.elapsed() >= NVIDIA_REPORT_INTERVAL) {
+            let throughput = Command::new("nvidia-smi").arg("-q").output().ok()
+                .filter(|output| output.status.success())
+                .map(|output| parse_nvidia_pcie_throughput(&String::from_utf8_lossy(&output.stdout)))
+                .unwrap_or_default();
+            self.pcie_throughput = Some((std::time::Instant::now(), throughput));
+        }
+        if let Some((at, throughput)) = &self.pcie_throughput {
+            for gpu in gpus.iter_mut() {
+                if let Some((rx, tx)) = gpu.pci_bus_id.as_ref().and_then(|id| throughput.get(id)) {
+                    gpu.pcie_rx_bytes = *rx;
+                    gpu.pcie_tx_bytes = *tx;
+                    gpu.pcie_sample_age = Some(at.elapsed().as_secs());
+                }
             }
-            
-            let name = parts[0].to_string();
-            let utilization = parts[1].parse::<u32>().unwrap_or(0);
-            let memory_used = parts[2].parse::<u64>().unwrap_or(0) * 1024 * 1024;
-            let memory_total = parts[3].parse::<u64>().unwrap_or(0) * 1024 * 1024;
-            let temperature = parts[4].parse::<u32>().unwrap_or(0);
-            let power_usage = (parts[5].parse::<f32>().unwrap_or(0.0) * 1000.0) as u32;
-            let graphics_clock = parts[6].parse::<u32>().unwrap_or(0);
-            let memory_clock = parts[7].parse::<u32>().unwrap_or(0);
-            let fan_speed = parts[8].parse::<u32>().ok();
-            let driver_version = parts.get(9).unwrap_or(&"Unknown").to_string();
-            
-            gpus.push(GpuInfo {
-                name,
-                brand: "NVIDIA".to_string(),
-                utilization,
-                memory_used,
-                memory_total,
-                temperature,
-                memory_temperature: None,
-                power_usage,
-                graphics_clock,
-                memory_clock,
-                fan_speed,
-                pci_link_gen: None,
-                pci_link_width: None,
-                driver_version,
-                utilization_history: Vec::new(),
-                memory_history: Vec::new(),
-            });
         }
         
         Ok(gpus)
     }
+    
+    fn supported_fields(&self) -> Vec<&'static str> {
+        NVIDIA_FIELDS.iter().copied().filter(|field| !self.unsupported_fields.contains(field)).collect()
+    }
 
     fn get_drm_gpus(&self) -> Result<Vec<GpuInfo>, String> {
         let mut gpus = Vec::new();
@@ -126,16 +165,35 @@ impl GpuMonitor {
             if name.starts_with("card") && !name.contains("-") && name.chars().skip(4).all(|c| c.is_numeric()) {
                 let device_path = path.join("device");
                 
+                let pci_bus_id = fs::canonicalize(&device_path)
+                    .ok()
+                    .and_then(|p| p.file_name().map(|n| normalize_bus_id(&n.to_string_lossy())));
+                
                 if let Ok(vendor_str) = fs::read_to_string(device_path.join("vendor")) {
                     let vendor_id = vendor_str.trim();
-                    if vendor_id == "0x1002" {
-                        if let Ok(gpu) = self.parse_amd_gpu(&device_path, &name) {
-                            gpus.push(gpu);
-                        }
+                    let parsed = if vendor_id == "0x1002" {
+                        self.parse_amd_gpu(&device_path, &name)
                     } else if vendor_id == "0x8086" {
-                        if let Ok(gpu) = self.parse_intel_gpu(&path, &device_path, &name) {
-                            gpus.push(gpu);
-                        }
+                        self.parse_intel_gpu(&path, &device_path, &name)
+                    } else {
+                        continue;
+                    };
+                    
+                    if let Ok(mut gpu) = parsed {
+                        gpu.pci_bus_id = pci_bus_id;
+                        gpu.pci_link_gen = fs::read_to_string(device_path.join("current_link_speed"))
+                            .ok()
+                            .and_then(|s| pcie_gen_from_speed(&s));
+                        gpu.pci_link_width = fs::read_to_string(device_path.join("current_link_width"))
+                            .ok()
+                            .and_then(|s| s.trim().parse::<u32>().ok());
+                        gpu.pci_link_gen_max = fs::read_to_string(device_path.join("max_link_speed"))
+                            .ok()
+                            .and_then(|s| pcie_gen_from_speed(&s));
+                        gpu.pci_link_width_max = fs::read_to_string(device_path.join("max_link_width"))
+                            .ok()
+                            .and_then(|s| s.trim().parse::<u32>().ok());
+                        gpus.push(gpu);
                     }
                 }
             }
@@ -161,15 +219,17 @@ impl GpuMonitor {
                 fs::read_to_string(device_path.join("busy_percent"))
                     .ok()
                     .and_then(|s| s.trim().parse::<u32>().ok())
-            })
-            .unwrap_or(0);
+            });
 
         let (memory_used, memory_total) = self.read_amd_memory(device_path);
-        let temperature = self.find_hwmon_temp(device_path).unwrap_or(0);
-        let power_usage = self.find_hwmon_power(device_path).unwrap_or(0);
+        let temperature = self.find_hwmon_temp(device_path);
+        let power_usage = self.find_hwmon_power(device_path);
 
-        let graphics_clock = self.read_amd_clock(device_path, "pp_dpm_sclk").unwrap_or(0);
-        let memory_clock = self.read_amd_clock(device_path, "pp_dpm_mclk").unwrap_or(0);
+        let graphics_clock = self.read_amd_clock(device_path, "pp_dpm_sclk");
+        let memory_clock = self.read_amd_clock(device_path, "pp_dpm_mclk");
+        let max_graphics_clock = fs::read_to_string(device_path.join("pp_dpm_sclk"))
+            .ok()
+            .and_then(|content| content.lines().filter_map(parse_dpm_clock).max());
 
         Ok(GpuInfo {
             name,
@@ -183,11 +243,26 @@ impl GpuMonitor {
             graphics_clock,
             memory_clock,
             fan_speed: None, 
+            sm_clock: None,
+            video_clock: None,
+            max_graphics_clock,
             pci_link_gen: None,
             pci_link_width: None,
+            pci_link_gen_max: None,
+            pci_link_width_max: None,
+            pcie_rx_bytes: None,
+            pcie_tx_bytes: None,
+            pcie_sample_age: None,
+            pci_bus_id: None,
+            throttle_reasons: Vec::new(),
+            ecc_errors_corrected: None,
+            ecc_errors_uncorrected: None,
+            memory_repair: None,
+            ecc_growth: 0,
             driver_version: "amdgpu".to_string(),
             utilization_history: Vec::new(),
             memory_history: Vec::new(),
+            temperature_history: Vec::new(),
         })
     }
     
@@ -237,41 +312,60 @@ impl GpuMonitor {
             device_path.join("gt_cur_freq_mhz"),
         ];
 
-        let mut graphics_clock = 0;
+        let mut graphics_clock = None;
         for path in &freq_paths {
             if let Ok(s) = fs::read_to_string(path) {
                 if let Ok(val) = s.trim().parse::<u32>() {
-                     if graphics_clock == 0 {
-                         graphics_clock = val;
+                     if graphics_clock.is_none() {
+                         graphics_clock = Some(val);
                      }
                     if val > 0 {
-                        graphics_clock = val;
+                        graphics_clock = Some(val);
                         break;
                     }
                 }
             }
         }
+        
+        let max_graphics_clock = [card_path.join("gt/gt0/rps_max_freq_mhz"), card_path.join("gt_max_freq_mhz")]
+            .iter()
+            .find_map(|path| fs::read_to_string(path).ok().and_then(|s| s.trim().parse::<u32>().ok()));
              
-        let temperature = self.find_hwmon_temp(device_path).unwrap_or(0);
-        let power_usage = self.find_hwmon_power(device_path).unwrap_or(0);
+        let temperature = self.find_hwmon_temp(device_path);
+        let power_usage = self.find_hwmon_power(device_path);
         
         Ok(GpuInfo {
             name,
             brand: "Intel".to_string(),
-            utilization: 0, 
+            utilization: None, 
             memory_used: 0,
             memory_total: 0,
             temperature,
             memory_temperature: None,
             power_usage,
             graphics_clock,
-            memory_clock: 0,
+            memory_clock: None,
             fan_speed: None,
+            sm_clock: None,
+            video_clock: None,
+            max_graphics_clock,
             pci_link_gen: None,
             pci_link_width: None,
+            pci_link_gen_max: None,
+            pci_link_width_max: None,
+            pcie_rx_bytes: None,
+            pcie_tx_bytes: None,
+            pcie_sample_age: None,
+            pci_bus_id: None,
+            throttle_reasons: Vec::new(),
+            ecc_errors_corrected: None,
+            ecc_errors_uncorrected: None,
+            memory_repair: None,
+            ecc_growth: 0,
             driver_version: "i915".to_string(),
             utilization_history: Vec::new(),
             memory_history: Vec::new(),
+            temperature_history: Vec::new(),
         })
     }
 
@@ -313,16 +407,103 @@ impl GpuMonitor {
         None
     }
     
+    pub fn get_gpu_processes(&mut self, gpus: &[GpuInfo]) -> Vec<GpuProcessInfo> {
+        let gpu_index_of = |bus_id: &str| gpus.iter().position(|g| g.pci_bus_id.as_deref() == Some(bus_id));
+        let mut processes = Vec::new();
+        
+        if gpus.iter().any(|g| g.brand == "NVIDIA") {
+            if let Ok(output) = Command::new("nvidia-smi")
+                .arg("--query-compute-apps=gpu_bus_id,pid,used_memory")
+                .arg("--format=csv,noheader,nounits")
+                .output()
+            {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                for line in stdout.lines() {
+                    let parts: Vec<&str> = line.split(", ").collect();
+                    if parts.len() < 3 {
+                        continue;
+                    }
+                    
+                    let pid = match parts[1].trim().parse::<u32>() {
+                        Ok(pid) => pid,
+                        Err(_) => continue,
+                    };
+                    
+                    processes.push(GpuProcessInfo {
+                        pid,
+                        name: read_process_name(pid),
+                        gpu_index: gpu_index_of(&normalize_bus_id(parts[0])),
+                        kind: "Compute".to_string(),
+                        memory_used: parts[2].trim().parse::<u64>().unwrap_or(0) * 1024 * 1024,
+                    });
+                }
+            }
+            
+            // --query-compute-apps leaves out graphics clients (Xorg,
+            // compositors, games); pmon lists both kinds.
+            if self.nvidia_clients.as_ref().is_none_or(|(at, _)| at.elapsed() >= CLIENT_SCAN_INTERVAL) {
+                let clients = Command::new("nvidia-smi").args(["pmon", "-c", "1", "-s", "m"]).output().ok()
+                    .filter(|output| output.status.success())
+                    .map(|output| parse_nvidia_pmon(&String::from_utf8_lossy(&output.stdout)))
+                    .unwrap_or_default();
+                self.nvidia_clients = Some((std::time::Instant::now(), clients));
+            }
+            // pmon numbers GPUs the way nvidia-smi does, counting NVIDIA ones only.
+            let nvidia_index = |index: usize| gpus.iter().enumerate().filter(|(_, g)| g.brand == "NVIDIA").nth(index).map(|(i, _)| i);
+            let clients = self.nvidia_clients.as_ref().map_or(&[][..], |(_, clients)| clients.as_slice());
+            for (pid, gpu, kind, memory_used) in clients.iter().cloned() {
+                if !kind.contains('G') || !Path::new(&format!("/proc/{}", pid)).exists() {
+                    continue;
+                }
+                match processes.iter_mut().find(|p| p.pid == pid) {
+                    Some(process) => process.kind = "Compute+Graphics".to_string(),
+                    None => processes.push(GpuProcessInfo {
+                        pid,
+                        name: read_process_name(pid),
+                        gpu_index: nvidia_index(gpu),
+                        kind: "Graphics".to_string(),
+                        memory_used: memory_used.unwrap_or(0),
+                    }),
+                }
+            }
+        }
+        
+        if gpus.iter().any(|g| g.brand != "NVIDIA") {
+            // Finding the clients means reading every descriptor of every
+            // process, so the walk is only repeated now and then.
+            if self.drm_clients.as_ref().is_none_or(|(at, _)| at.elapsed() >= CLIENT_SCAN_INTERVAL) {
+                self.drm_clients = Some((std::time::Instant::now(), scan_drm_clients()));
+            }
+            let clients = self.drm_clients.as_ref().map_or(&[][..], |(_, clients)| clients.as_slice());
+            for (pid, bus_id, driver, memory_used) in clients.iter().cloned() {
+                if processes.iter().any(|p| p.pid == pid) || !Path::new(&format!("/proc/{}", pid)).exists() {
+                    continue;
+                }
+                
+                processes.push(GpuProcessInfo {
+                    pid,
+                    name: read_process_name(pid),
+                    gpu_index: gpu_index_of(&bus_id),
+                    kind: driver,
+                    memory_used,
+                });
+            }
+        }
+        
+        processes.sort_by_key(|p| std::cmp::Reverse(p.memory_used));
+        processes
+    }
+    
     pub fn get_primary_gpu_utilization(&self, gpus: &[GpuInfo]) -> Option<u32> {
         if gpus.is_empty() {
             None
         } else {
-            Some(gpus.iter().map(|g| g.utilization).max().unwrap_or(0))
+            Some(gpus.iter().filter_map(|g| g.utilization).max().unwrap_or(0))
         }
     }
     
-    pub fn update_gpu_history(&mut self, gpus: &[GpuInfo], max_history: usize) {
-        let utilizations: Vec<u32> = gpus.iter().map(|g| g.utilization).collect();
+    pub fn update_gpu_history(&mut self, gpus: &mut [GpuInfo], max_history: usize) {
+        let utilizations: Vec<u32> = gpus.iter().map(|g| g.utilization.unwrap_or(0)).collect();
         let memory_usage: Vec<u32> = gpus.iter().map(|g| {
             if g.memory_total > 0 {
                 ((g.memory_used as f64 / g.memory_total as f64) * 100.0) as u32
@@ -330,9 +511,11 @@ impl GpuMonitor {
                 0
             }
         }).collect();
+        let temperatures: Vec<u32> = gpus.iter().map(|g| g.temperature.unwrap_or(0)).collect();
         
         self.gpu_history.push_back(utilizations);
         self.gpu_memory_history.push_back(memory_usage);
+        self.gpu_temperature_history.push_back(temperatures);
         
         while self.gpu_history.len() > max_history {
             self.gpu_history.pop_front();
@@ -340,16 +523,473 @@ impl GpuMonitor {
         while self.gpu_memory_history.len() > max_history {
             self.gpu_memory_history.pop_front();
         }
+        while self.gpu_temperature_history.len() > max_history {
+            self.gpu_temperature_history.pop_front();
+        }
+        
+        self.attach_history(gpus);
     }
     
-    pub fn get_gpu_history_flat(&self) -> Vec<u64> {
+    pub fn get_gpu_history_flat(&self) -> VecDeque<u32> {
         self.gpu_history
             .iter()
-            .map(|frame| frame.iter().cloned().max().unwrap_or(0) as u64)
+            .map(|frame| frame.iter().cloned().max().unwrap_or(0))
             .collect()
     }
     
     pub fn is_available(&self) -> bool {
-        true
+        self.nvidia_smi || (cfg!(target_os = "linux") && Path::new("/sys/class/drm").exists())
+    }
+}
+
+const NVIDIA_REPORT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+const CLIENT_SCAN_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+const NVIDIA_FIELDS: &[&str] = &[
+    "name", "utilization.gpu", "memory.used", "memory.total", "temperature.gpu", "power.draw",
+    "clocks.gr", "clocks.mem", "fan.speed", "driver_version", "pci.bus_id",
+    "clocks.sm", "clocks.video", "clocks.max.gr", "pcie.link.gen.current", "pcie.link.width.current",
+    "clocks_throttle_reasons.active", "ecc.errors.corrected.volatile.total",
+    "ecc.errors.uncorrected.volatile.total", "pcie.link.gen.max", "pcie.link.width.max",
+    "retired_pages.single_bit_ecc.count", "retired_pages.double_bit.count", "retired_pages.pending",
+    "remapped_rows.correctable", "remapped_rows.uncorrectable", "remapped_rows.pending", "remapped_rows.failure",
+];
+
+const NVIDIA_THROTTLE_REASONS: &[(u64, &str)] = &[
+    (0x1, "GPU idle"),
+    (0x2, "Application clocks"),
+    (0x4, "SW power cap"),
+    (0x8, "HW slowdown"),
+    (0x10, "Sync boost"),
+    (0x20, "SW thermal"),
+    (0x40, "HW thermal"),
+    (0x80, "HW power brake"),
+    (0x100, "Display clocks"),
+];
+
+enum NvidiaSmiError {
+    Missing,
+    InvalidField(String),
+    Failed(String),
+}
+
+fn query_nvidia_smi(fields: &[&str]) -> Result<Vec<Vec<String>>, NvidiaSmiError> {
+    let output = Command::new("nvidia-smi")
+        .arg(format!("--query-gpu={}", fields.join(",")))
+        .arg("--format=csv,noheader,nounits")
+        .output()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => NvidiaSmiError::Missing,
+            _ => NvidiaSmiError::Failed(e.to_string()),
+        })?;
+        
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let message = if stderr.trim().is_empty() { stdout } else { stderr };
+        let message = format!("nvidia-smi failed: {}", message.trim());
+        return Err(if message.contains("is not a valid field") {
+            NvidiaSmiError::InvalidField(message)
+        } else {
+            NvidiaSmiError::Failed(message)
+        });
+    }
+    
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.split(", ").map(|part| part.to_string()).collect())
+        .collect())
+}
+
+// Lines a row queried for `fields` up with NVIDIA_FIELDS, with the fields
+// left out reading as N/A.
+fn spread_nvidia_row(row: &[String], fields: &[&str]) -> Vec<String> {
+    NVIDIA_FIELDS.iter()
+        .map(|name| fields.iter()
+            .position(|field| field == name)
+            .and_then(|i| row.get(i).cloned())
+            .unwrap_or_else(|| "[N/A]".to_string()))
+        .collect()
+}
+
+fn nvidia_value(raw: &str) -> Option<&str> {
+    let value = raw.trim();
+    if value.is_empty() || value.starts_with('[') || value.eq_ignore_ascii_case("N/A") {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn parse_nvidia_row(row: &[String]) -> GpuInfo {
+    let field = |i: usize| row.get(i).and_then(|v| nvidia_value(v));
+    let field_u32 = |i: usize| field(i).and_then(|v| v.parse::<f32>().ok()).map(|v| v as u32);
+    let count = |i: usize| field(i).and_then(|v| v.parse::<u64>().ok());
+    let flag = |i: usize| field(i).is_some_and(|v| v.eq_ignore_ascii_case("yes") || v == "1");
+    let repair = GpuMemoryRepair {
+        retired_single_bit: count(21),
+        retired_double_bit: count(22),
+        retired_pending: flag(23),
+        remapped_correctable: count(24),
+        remapped_uncorrectable: count(25),
+        remapped_pending: flag(26),
+        remap_failed: flag(27),
+    };
+    
+    GpuInfo {
+        name: field(0).unwrap_or("NVIDIA GPU").to_string(),
+        brand: "NVIDIA".to_string(),
+        utilization: field_u32(1),
+        memory_used: field(2).and_then(|v| v.parse::<u64>().ok()).unwrap_or(0) * 1024 * 1024,
+        memory_total: field(3).and_then(|v| v.parse::<u64>().ok()).unwrap_or(0) * 1024 * 1024,
+        temperature: field_u32(4),
+        memory_temperature: None,
+        power_usage: field(5).and_then(|v| v.parse::<f32>().ok()).map(|w| (w * 1000.0) as u32),
+        graphics_clock: field_u32(6),
+        memory_clock: field_u32(7),
+        sm_clock: field_u32(11),
+        video_clock: field_u32(12),
+        max_graphics_clock: field_u32(13),
+        fan_speed: field_u32(8),
+        pci_link_gen: field_u32(14),
+        pci_link_width: field_u32(15),
+        pci_link_gen_max: field_u32(19),
+        pci_link_width_max: field_u32(20),
+        pcie_rx_bytes: None,
+        pcie_tx_bytes: None,
+        pcie_sample_age: None,
+        pci_bus_id: field(10).map(normalize_bus_id),
+        throttle_reasons: field(16).map(decode_throttle_reasons).unwrap_or_default(),
+        ecc_errors_corrected: field(17).and_then(|v| v.parse::<u64>().ok()),
+        ecc_errors_uncorrected: field(18).and_then(|v| v.parse::<u64>().ok()),
+        memory_repair: (repair != GpuMemoryRepair::default()).then_some(repair),
+        ecc_growth: 0,
+        driver_version: field(9).unwrap_or("Unknown").to_string(),
+        utilization_history: Vec::new(),
+        memory_history: Vec::new(),
+        temperature_history: Vec::new(),
+    }
+}
+
+// Counts the memory errors added since each GPU was first seen. Volatile ECC
+// counters reset with the driver, so a drop is not counted as negative growth.
+fn track_ecc_growth(baseline: &mut HashMap<String, u64>, gpus: &mut [GpuInfo]) {
+    for (index, gpu) in gpus.iter_mut().enumerate() {
+        let key = gpu.pci_bus_id.clone().unwrap_or_else(|| index.to_string());
+        let total = gpu.ecc_errors_uncorrected.unwrap_or(0) + gpu.memory_repair.as_ref().map_or(0, GpuMemoryRepair::total);
+        let first = *baseline.entry(key).or_insert(total);
+        gpu.ecc_growth = total.saturating_sub(first);
+    }
+}
+
+fn decode_throttle_reasons(mask: &str) -> Vec<String> {
+    let mask = u64::from_str_radix(mask.trim_start_matches("0x"), 16).unwrap_or(0);
+    NVIDIA_THROTTLE_REASONS.iter()
+        .filter(|(bit, _)| mask & bit != 0)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+fn pcie_gen_from_speed(speed: &str) -> Option<u32> {
+    let gts = speed.split_whitespace().next()?.parse::<f32>().ok()?;
+    Some(match gts {
+        s if s >= 64.0 => 6,
+        s if s >= 32.0 => 5,
+        s if s >= 16.0 => 4,
+        s if s >= 8.0 => 3,
+        s if s >= 5.0 => 2,
+        _ => 1,
+    })
+}
+
+// Lines in pp_dpm_* look like "1: 1800Mhz *".
+fn parse_dpm_clock(line: &str) -> Option<u32> {
+    line.split_whitespace()
+        .nth(1)?
+        .to_lowercase()
+        .trim_end_matches("mhz")
+        .parse::<u32>()
+        .ok()
+}
+
+type PcieThroughput = (Option<u64>, Option<u64>);
+
+fn parse_nvidia_pcie_throughput(report: &str) -> HashMap<String, PcieThroughput> {
+    let mut result: HashMap<String, PcieThroughput> = HashMap::new();
+    let mut current: Option<String> = None;
+    
+    for line in report.lines() {
+        if let Some(bus_id) = line.strip_prefix("GPU ") {
+            current = Some(normalize_bus_id(bus_id));
+            continue;
+        }
+        
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => continue,
+        };
+        let bus_id = match &current {
+            Some(bus_id) => bus_id.clone(),
+            None => continue,
+        };
+        
+        let bytes = value.strip_suffix("KB/s")
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(|kb| kb * 1024);
+        match key {
+            "Rx Throughput" => result.entry(bus_id).or_default().0 = bytes,
+            "Tx Throughput" => result.entry(bus_id).or_default().1 = bytes,
+            _ => {}
+        }
+    }
+    
+    result
+}
+
+fn normalize_bus_id(bus_id: &str) -> String {
+    let bus_id = bus_id.trim().to_lowercase();
+    bus_id[bus_id.len().saturating_sub(12)..].to_string()
+}
+
+fn read_process_name(pid: u32) -> String {
+    fs::read_to_string(format!("/proc/{}/comm", pid))
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DrmClient {
+    pub driver: String,
+    pub pdev: String,
+    pub client_id: u64,
+    pub vram_bytes: u64,
+}
+
+pub fn parse_drm_fdinfo(content: &str) -> Option<DrmClient> {
+    let mut driver = None;
+    let mut pdev = None;
+    let mut client_id = None;
+    let mut vram_bytes = 0;
+    
+    for line in content.lines() {
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => continue,
+        };
+        
+        match key {
+            "drm-driver" => driver = Some(value.to_string()),
+            "drm-pdev" => pdev = Some(normalize_bus_id(value)),
+            "drm-client-id" => client_id = value.parse::<u64>().ok(),
+            "drm-memory-vram" | "drm-total-vram0" | "drm-total-local0" => {
+                let mut parts = value.split_whitespace();
+                let amount = parts.next().and_then(|v| v.parse::<u64>().ok()).unwrap_or(0);
+                let multiplier = match parts.next() {
+                    Some("KiB") => 1024,
+                    Some("MiB") => 1024 * 1024,
+                    Some("GiB") => 1024 * 1024 * 1024,
+                    _ => 1,
+                };
+                vram_bytes += amount * multiplier;
+            }
+            _ => {}
+        }
+    }
+    
+    Some(DrmClient {
+        driver: driver?,
+        pdev: pdev?,
+        client_id: client_id?,
+        vram_bytes,
+    })
+}
+
+// PID, PCI device, driver and VRAM held.
+type DrmClientUsage = (u32, String, String, u64);
+
+// PID, nvidia-smi GPU index, type ("C", "G" or "C+G") and framebuffer memory.
+type NvidiaClient = (u32, usize, String, Option<u64>);
+
+// `nvidia-smi pmon -s m` output. Columns are found by the first header line,
+// since drivers differ in which ones they print.
+fn parse_nvidia_pmon(output: &str) -> Vec<NvidiaClient> {
+    let mut lines = output.lines();
+    let Some(header) = lines.next().and_then(|line| line.strip_prefix('#')) else { return Vec::new() };
+    let columns: Vec<&str> = header.split_whitespace().collect();
+    let column = |name: &str| columns.iter().position(|column| *column == name);
+    let (Some(gpu), Some(pid), Some(kind)) = (column("gpu"), column("pid"), column("type")) else { return Vec::new() };
+    let fb = column("fb");
+    lines
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            Some((
+                fields.get(pid)?.parse().ok()?,
+                fields.get(gpu)?.parse().ok()?,
+                fields.get(kind)?.to_string(),
+                fb.and_then(|fb| fields.get(fb)?.parse::<u64>().ok()).map(|mib| mib * 1024 * 1024),
+            ))
+        })
+        .collect()
+}
+
+fn scan_drm_clients() -> Vec<DrmClientUsage> {
+    let mut per_pid: HashMap<(u32, String), (String, u64)> = HashMap::new();
+    let mut seen_clients = std::collections::HashSet::new();
+    
+    let proc_entries = match fs::read_dir("/proc") {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    
+    for entry in proc_entries.flatten() {
+        let pid = match entry.file_name().to_string_lossy().parse::<u32>() {
+            Ok(pid) => pid,
+            Err(_) => continue,
+        };
+        
+        let fd_entries = match fs::read_dir(entry.path().join("fd")) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        
+        for fd in fd_entries.flatten() {
+            let is_drm = fs::read_link(fd.path())
+                .map(|target| target.starts_with("/dev/dri"))
+                .unwrap_or(false);
+            if !is_drm {
+                continue;
+            }
+            
+            let fdinfo_path = entry.path().join("fdinfo").join(fd.file_name());
+            if let Some(client) = fs::read_to_string(fdinfo_path).ok().and_then(|c| parse_drm_fdinfo(&c)) {
+                if !seen_clients.insert((client.pdev.clone(), client.client_id)) {
+                    continue;
+                }
+                let slot = per_pid.entry((pid, client.pdev)).or_insert((client.driver, 0));
+                slot.1 += client.vram_bytes;
+            }
+        }
+    }
+    
+    per_pid.into_iter()
+        .map(|((pid, pdev), (driver, vram))| (pid, pdev, driver, vram))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_parse_drm_fdinfo() {
+        let content = "pos:\t0\nflags:\t02100002\ndrm-driver:\tamdgpu\ndrm-pdev:\t0000:03:00.0\ndrm-client-id:\t42\ndrm-memory-vram:\t2048 KiB\ndrm-memory-gtt:\t512 KiB\n";
+        let client = parse_drm_fdinfo(content).unwrap();
+        assert_eq!(client.driver, "amdgpu");
+        assert_eq!(client.pdev, "0000:03:00.0");
+        assert_eq!(client.client_id, 42);
+        assert_eq!(client.vram_bytes, 2048 * 1024);
+        
+        assert!(parse_drm_fdinfo("pos:\t0\nflags:\t02\n").is_none());
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_parse_nvidia_row_partial() {
+        let row: Vec<String> = ["Tesla T4", "37", "1024", "15360", "[N/A]", "[Not Supported]", "585", "5000", "[N/A]", "535.54", "00000000:3B:00.0"]
+            .iter().map(|s| s.to_string()).collect();
+        let gpu = parse_nvidia_row(&row);
+        assert_eq!(gpu.name, "Tesla T4");
+        assert_eq!(gpu.utilization, Some(37));
+        assert_eq!(gpu.memory_total, 15360 * 1024 * 1024);
+        assert_eq!(gpu.temperature, None);
+        assert_eq!(gpu.power_usage, None);
+        assert_eq!(gpu.fan_speed, None);
+        assert_eq!(gpu.graphics_clock, Some(585));
+        assert_eq!(gpu.pci_bus_id.as_deref(), Some("0000:3b:00.0"));
+        
+        let short: Vec<String> = vec!["GeForce".to_string()];
+        let gpu = parse_nvidia_row(&short);
+        assert_eq!(gpu.utilization, None);
+        assert_eq!(gpu.driver_version, "Unknown");
+    }
+    
+    #[test]
+    fn test_spread_nvidia_row() {
+        let row: Vec<String> = vec!["Tesla T4".to_string(), "3".to_string()];
+        let spread = spread_nvidia_row(&row, &["name", "clocks.mem"]);
+        assert_eq!(spread.len(), NVIDIA_FIELDS.len());
+        assert_eq!(spread[0], "Tesla T4");
+        assert_eq!(spread[1], "[N/A]");
+        assert_eq!(spread[7], "3");
+    }
+    
+    #[test]
+    fn test_ecc_growth() {
+        let mut row: Vec<String> = vec!["[N/A]".to_string(); 28];
+        row[0] = "A100".to_string();
+        row[10] = "00000000:3B:00.0".to_string();
+        row[18] = "0".to_string();
+        row[24] = "1".to_string();
+        row[25] = "0".to_string();
+        row[26] = "No".to_string();
+        row[27] = "No".to_string();
+        let gpu = parse_nvidia_row(&row);
+        let repair = gpu.memory_repair.as_ref().unwrap();
+        assert_eq!((repair.retired_single_bit, repair.remapped_correctable), (None, Some(1)));
+        assert!(!repair.remapped_pending);
+
+        let mut baseline = HashMap::new();
+        let mut gpus = vec![gpu];
+        track_ecc_growth(&mut baseline, &mut gpus);
+        assert_eq!(gpus[0].ecc_growth, 0);
+
+        row[18] = "2".to_string();
+        row[26] = "Yes".to_string();
+        let mut gpus = vec![parse_nvidia_row(&row)];
+        track_ecc_growth(&mut baseline, &mut gpus);
+        assert_eq!(gpus[0].ecc_growth, 2);
+        assert!(gpus[0].memory_repair.as_ref().unwrap().remapped_pending);
+
+        let consumer: Vec<String> = vec!["GeForce".to_string(), "[N/A]".to_string()];
+        assert!(parse_nvidia_row(&consumer).memory_repair.is_none());
+    }
+    
+    #[test]
+    fn test_gpu_detail_parsing() {
+        assert_eq!(decode_throttle_reasons("0x0000000000000044"), vec!["SW power cap", "HW thermal"]);
+        assert!(decode_throttle_reasons("0x0000000000000000").is_empty());
+        assert_eq!(pcie_gen_from_speed("16.0 GT/s PCIe\n"), Some(4));
+        assert_eq!(pcie_gen_from_speed("2.5 GT/s"), Some(1));
+        assert_eq!(pcie_gen_from_speed("Unknown"), None);
+        assert_eq!(parse_dpm_clock("2: 2100Mhz *"), Some(2100));
+    }
+    
+    #[test]
+    fn test_parse_nvidia_pcie_throughput() {
+        let report = "GPU 00000000:01:00.0\n    PCI\n        Tx Throughput                     : 1200 KB/s\n        Rx Throughput                     : 300 KB/s\nGPU 00000000:02:00.0\n        Tx Throughput                     : N/A\n";
+        let throughput = parse_nvidia_pcie_throughput(report);
+        assert_eq!(throughput.get("0000:01:00.0"), Some(&(Some(300 * 1024), Some(1200 * 1024))));
+        assert_eq!(throughput.get("0000:02:00.0"), Some(&(None, None)));
+    }
+    
+    #[test]
+    fn test_parse_nvidia_pmon() {
+        let output = "# gpu         pid   type     fb   ccpm   command\n# Idx           #    C/G     MB     MB   name\n    0       1873     G     412      0   Xorg\n    0       4242   C+G     120      0   python3\n    1          -     -      -      -   -\n";
+        assert_eq!(parse_nvidia_pmon(output), vec![
+            (1873, 0, "G".to_string(), Some(412 * 1024 * 1024)),
+            (4242, 0, "C+G".to_string(), Some(120 * 1024 * 1024)),
+        ]);
+        // Older drivers, without the fb column when memory isn't sampled.
+        assert_eq!(parse_nvidia_pmon("# gpu pid type sm mem enc dec command\n    0  900  G  3  1  -  -  gnome-shell\n"), vec![
+            (900, 0, "G".to_string(), None),
+        ]);
+        assert!(parse_nvidia_pmon("No devices were found\n").is_empty());
+    }
+
+    #[test]
+    fn test_normalize_bus_id() {
+        assert_eq!(normalize_bus_id("00000000:01:00.0"), "0000:01:00.0");
+        assert_eq!(normalize_bus_id("0000:0A:00.0"), "0000:0a:00.0");
+    }
+}
@@ -3,16 +3,33 @@
 pub mod system_monitor;
 pub mod gpu_monitor;
 pub mod container_monitor;
+pub mod cgroup_monitor;
+pub mod security_monitor;
+pub mod auth_monitor;
+pub mod clock_monitor;
+pub mod net_config_monitor;
+pub mod tcp_monitor;
+pub mod scheduler;
 
-pub use system_monitor::SystemMonitor;
+pub use system_monitor::{RefreshScope, SystemMonitor};
 pub use gpu_monitor::GpuMonitor;
 pub use container_monitor::ContainerMonitor;
+pub use cgroup_monitor::CgroupMonitor;
+pub use security_monitor::{PortWatcher, SecurityMonitor};
+pub use auth_monitor::AuthMonitor;
+pub use clock_monitor::ClockMonitor;
+pub use net_config_monitor::{NeighborWatcher, NetConfigMonitor};
+pub use tcp_monitor::TcpMonitor;
 
-use std::sync::Arc;
-use parking_lot::Mutex;
-use tokio::time::{Duration, Instant};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 
-use crate::types::{DynamicData, AppConfig, GlobalUsage};
+use sysinfo::Pid;
+
+use crate::types::{
+    AppConfig, ContainerInfo, ContainerOom, DetailedProcessInfo, DynamicData, GlobalUsage, GpuInfo, GpuProcessInfo,
+    MonitorUpdate, NeighborChange, PortChange, ProcessQuery, SystemSnapshot,
+};
 use crate::utils::update_history;
 
 pub struct DataCollector {
@@ -20,190 +37,551 @@ pub struct DataCollector {
     gpu_monitor: GpuMonitor,
     container_monitor: ContainerMonitor,
     config: AppConfig,
-    last_update: Instant,
 }
 
 impl DataCollector {
     pub fn new(config: AppConfig) -> Self {
+        let mut system_monitor = SystemMonitor::new();
+        system_monitor.set_leak_window(config.leak_window);
         Self {
-            system_monitor: SystemMonitor::new(),
+            system_monitor,
             gpu_monitor: GpuMonitor::new(),
             container_monitor: ContainerMonitor::new(),
             config,
-            last_update: Instant::now(),
         }
     }
     
-    pub async fn collect_data(
-        &mut self,
-        selected_pid: Option<sysinfo::Pid>,
-        show_system_processes: bool,
-        filter: &str,
-        sort_by: &crate::types::ProcessSortBy,
-        sort_ascending: bool,
-        mut prev_global_usage: GlobalUsage,
-    ) -> DynamicData {
-        let now = Instant::now();
-        let collection_start = now;
-        let mut processes = self.system_monitor.update_processes(
-            show_system_processes,
-            filter
-        );
+    pub fn into_collectors(self) -> (SystemCollector, GpuCollector, ContainerCollector) {
+        (
+            SystemCollector {
+                monitor: self.system_monitor,
+                config: self.config.clone(),
+                cgroup_monitor: CgroupMonitor::new(),
+                security_monitor: SecurityMonitor::new(),
+                auth_monitor: AuthMonitor::new(),
+                clock_monitor: ClockMonitor::new(),
+                net_config_monitor: NetConfigMonitor::new(self.config.reach_target.clone(), self.config.public_ip),
+                port_watcher: PortWatcher::new(),
+                neighbor_watcher: NeighborWatcher::new(),
+                tcp_monitor: TcpMonitor::new(),
+                prev_global_usage: GlobalUsage::default(),
+                temperature_history: Vec::new(),
+                disk_history: HashMap::new(),
+                last_disk_io: (0, 0),
+                process_history: None,
+            },
+            GpuCollector {
+                monitor: self.gpu_monitor,
+                config: self.config.clone(),
+            },
+            ContainerCollector {
+                monitor: self.container_monitor,
+                config: self.config,
+            },
+        )
+    }
+    
+    pub fn get_system_info(&self) -> Vec<(String, String)> {
+        let mut info = self.system_monitor.get_system_info();
         
-        crate::monitors::system_monitor::sort_processes(
-            &mut processes,
-            sort_by,
-            sort_ascending,
-            self.system_monitor.get_total_memory()
-        );    
- 
-        let detailed_process = selected_pid
-            .and_then(|pid| self.system_monitor.get_detailed_process(pid));
+        if self.config.safe_mode {
+            info.push(("Mode".to_string(), "Safe Mode".to_string()));
+        }
         
-        let cores = self.system_monitor.get_cores();
+        let profile = match self.config.performance_profile {
+            Some(name) => format!("{} (auto, {} ms refresh)", name, self.config.refresh_rate_ms),
+            None => "off".to_string(),
+        };
+        info.push(("Performance Profile".to_string(), profile));
         
-        let disks = self.system_monitor.get_disks();
+        let locale = self.config.locale.as_deref().unwrap_or("not set");
+        info.push(("Language".to_string(), format!("{} (locale: {})", self.config.language, locale)));
         
-        let networks = if self.config.enable_network_monitoring {
-            self.system_monitor.get_networks()
-        } else {
-            Vec::new()
-        };
+        let mut features = Vec::new();
+        if self.config.enable_docker && self.container_monitor.is_available() {
+            features.push("Docker");
+        }
+        if self.config.enable_gpu_monitoring && self.gpu_monitor.is_available() {
+            features.push("GPU");
+        }
+        if self.config.enable_network_monitoring {
+            features.push("Network");
+        }
+        
+        if !features.is_empty() {
+            info.push(("Features".to_string(), features.join(", ")));
+        }
+        
+        info
+    }
+    
+    pub async fn health_check(&self) -> Vec<(String, bool)> {
+        let mut health = Vec::new();
+        
+        health.push(("System".to_string(), true));
+        
+        if self.config.enable_docker {
+            let docker_health = self.container_monitor.health_check(1000).await;
+            health.push(("Docker".to_string(), docker_health));
+        }
+        
+        if self.config.enable_gpu_monitoring {
+            health.push(("GPU".to_string(), self.gpu_monitor.is_available()));
+        }
         
-        let (total_net_down, total_net_up) = self.system_monitor
-            .calculate_total_network_io(&networks);
+        if self.config.enable_network_monitoring {
+            health.push(("Network".to_string(), !sysinfo::Networks::new_with_refreshed_list().is_empty()));
+        }
         
-        let (total_disk_read, total_disk_write) = self.system_monitor
-            .calculate_total_disk_io(&processes);
+        health
+    }
+}
+
+pub struct SystemCollector {
+    monitor: SystemMonitor,
+    cgroup_monitor: CgroupMonitor,
+    security_monitor: SecurityMonitor,
+    auth_monitor: AuthMonitor,
+    clock_monitor: ClockMonitor,
+    net_config_monitor: NetConfigMonitor,
+    port_watcher: PortWatcher,
+    neighbor_watcher: NeighborWatcher,
+    tcp_monitor: TcpMonitor,
+    config: AppConfig,
+    prev_global_usage: GlobalUsage,
+    temperature_history: Vec<(String, VecDeque<f32>)>,
+    // Read and write rates by mount point.
+    disk_history: HashMap<String, (VecDeque<u64>, VecDeque<u64>)>,
+    last_disk_io: (u64, u64),
+    process_history: Option<ProcessHistory>,
+}
+
+struct ProcessHistory {
+    pid: Pid,
+    cpu: VecDeque<f32>,
+    memory: VecDeque<u64>,
+    thread_times: HashMap<u32, Duration>,
+    last_sample: Instant,
+}
+
+impl SystemCollector {
+    pub fn collect(&mut self, query: &ProcessQuery) -> MonitorUpdate {
+        let scope = RefreshScope::for_tab(query.active_tab);
+        self.monitor.refresh(scope);
         
-        let (containers, docker_error) = if self.config.enable_docker && self.container_monitor.is_available() {
-            match tokio::time::timeout(
-                self.config.get_operation_timeout(),
-                self.container_monitor.get_containers(self.config.get_operation_timeout().as_millis() as u64)
-            ).await {
-                Ok(Ok(containers)) => (containers, None),
-                Ok(Err(e)) => (Vec::new(), Some(e)),
-                Err(_) => (Vec::new(), Some("Container collection timeout".to_string())),
+        let processes = if scope.processes {
+            // The system tab counts and lists zombies across every process,
+            // not just those the process list's filters leave.
+            let mut processes = if query.active_tab == 7 {
+                self.monitor.update_processes(true, "", None, false, false)
+            } else {
+                // Swap is a column of the process table, or what it is sorted by.
+                let with_swap = query.active_tab == 0 || query.sort_by == crate::types::ProcessSortBy::Swap;
+                self.monitor.update_processes(
+                    query.show_system_processes,
+                    &query.filter_text,
+                    query.container_filter.as_deref(),
+                    query.show_state_columns,
+                    with_swap,
+                )
+            };
+            
+            system_monitor::sort_processes(
+                &mut processes,
+                &query.sort_by,
+                query.sort_ascending,
+                self.monitor.get_total_memory()
+            );
+            
+            // Other tabs look for zombies and such in the whole list.
+            if query.tree && query.active_tab == 0 {
+                processes = system_monitor::build_process_tree(processes, &query.collapsed);
             }
+            Some(processes)
         } else {
-            (Vec::new(), None)
+            self.monitor.sample_process_memory();
+            None
         };
         
-        let gpus = if !self.config.enable_gpu_monitoring {
-            Err("GPU monitoring disabled by configuration".to_string())
-        } else if !self.gpu_monitor.is_available() {
-            Err("GPU monitoring unavailable (monitor reports not available)".to_string())
-        } else {
-            self.gpu_monitor.get_gpu_info()
+        let detailed_process = match query.selected_pid {
+            Some(pid) => self.monitor.get_detailed_process(pid).map(|mut process| {
+                self.record_process_history(pid, &mut process);
+                process
+            }),
+            None => None,
         };
+        if detailed_process.is_none() {
+            self.process_history = None;
+        }
         
-        let gpu_util = match &gpus {
-            Ok(gpu_list) => self.gpu_monitor.get_primary_gpu_utilization(gpu_list),
-            Err(_) => None,
+        let cgroups = scope.cgroups.then(|| self.cgroup_monitor.collect());
+        let security = if scope.security { self.security_monitor.collect() } else { None };
+        // Always followed, since they feed alerts.
+        let auth = self.auth_monitor.collect();
+        let clock = self.clock_monitor.collect();
+        let network_config = if scope.network_config && self.config.enable_network_monitoring {
+            self.net_config_monitor.collect()
+        } else {
+            None
+        };
+        let port_changes = self.port_watcher.check();
+        let (neighbors, neighbor_changes) = if self.config.enable_network_monitoring {
+            self.neighbor_watcher.check()
+        } else {
+            (None, Vec::new())
+        };
+        let tcp = if self.config.enable_network_monitoring {
+            self.tcp_monitor.collect(self.config.history_length)
+        } else {
+            None
         };
         
-        if let Ok(ref gpu_list) = gpus {
-            self.gpu_monitor.update_gpu_history(gpu_list, self.config.history_length);
+        let cores = self.monitor.get_cores();
+        let mut disks = self.monitor.get_disks();
+        if let Some(total) = self.monitor.disk_io() {
+            self.last_disk_io = total;
+        } else if let Some(processes) = &processes {
+            self.last_disk_io = self.monitor.calculate_total_disk_io(processes);
         }
         
-        let temperatures = self.system_monitor.get_temperatures();
+        let networks = if self.config.enable_network_monitoring {
+            self.monitor.get_networks()
+        } else {
+            Vec::new()
+        };
+        
+        let (total_net_down, total_net_up) = self.monitor.calculate_total_network_io(&networks);
+        let (total_disk_read, total_disk_write) = self.last_disk_io;
+        
+        let mut temperatures = self.monitor.get_temperatures();
+        let self_usage = self.monitor.get_self_usage(!scope.processes);
         
-        let mut global_usage = self.system_monitor.get_global_usage(
+        // GPU utilisation is published by the GPU collector and merged into
+        // the global usage when the update is applied.
+        let mut global_usage = self.monitor.get_global_usage(
             total_net_down,
             total_net_up,
             total_disk_read,
             total_disk_write,
-            gpu_util,
+            None,
         );
         
-        update_history(&mut prev_global_usage.cpu_history, global_usage.cpu, self.config.history_length);
-        update_history(&mut prev_global_usage.mem_history, 
-            (global_usage.mem_used as f64 / global_usage.mem_total as f64 * 100.0) as f32, 
-            self.config.history_length);
-        update_history(&mut prev_global_usage.net_down_history, total_net_down, self.config.history_length);
-        update_history(&mut prev_global_usage.net_up_history, total_net_up, self.config.history_length);
-        update_history(&mut prev_global_usage.disk_read_history, total_disk_read, self.config.history_length);
-        update_history(&mut prev_global_usage.disk_write_history, total_disk_write, self.config.history_length);
-        
-        if let Some(gpu_util_val) = gpu_util {
-            update_history(&mut prev_global_usage.gpu_history, gpu_util_val, self.config.history_length);
+        let history_length = self.config.history_length;
+        let prev = &mut self.prev_global_usage;
+        update_history(&mut prev.cpu_history, global_usage.cpu, history_length);
+        update_history(&mut prev.mem_history,
+            (global_usage.mem_used as f64 / global_usage.mem_total as f64 * 100.0) as f32,
+            history_length);
+        update_history(&mut prev.net_down_history, total_net_down, history_length);
+        update_history(&mut prev.net_up_history, total_net_up, history_length);
+        update_history(&mut prev.disk_read_history, total_disk_read, history_length);
+        update_history(&mut prev.disk_write_history, total_disk_write, history_length);
+        update_history(&mut prev.load_history, global_usage.load_average, history_length);
+        if let Some(run_queue) = global_usage.run_queue {
+            update_history(&mut prev.run_queue_history, run_queue, history_length);
+        }
+        if let Some(blocked) = global_usage.blocked {
+            update_history(&mut prev.blocked_history, blocked, history_length);
         }
         
-        global_usage.cpu_history = prev_global_usage.cpu_history;
-        global_usage.mem_history = prev_global_usage.mem_history;
-        global_usage.net_down_history = prev_global_usage.net_down_history;
-        global_usage.net_up_history = prev_global_usage.net_up_history;
-        global_usage.disk_read_history = prev_global_usage.disk_read_history;
-        global_usage.disk_write_history = prev_global_usage.disk_write_history;
-        global_usage.gpu_history = prev_global_usage.gpu_history;
-        
-        let collection_end = Instant::now();
-        let collection_duration = collection_end.duration_since(collection_start);
+        global_usage.cpu_history = prev.cpu_history.clone();
+        global_usage.mem_history = prev.mem_history.clone();
+        global_usage.net_down_history = prev.net_down_history.clone();
+        global_usage.net_up_history = prev.net_up_history.clone();
+        global_usage.disk_read_history = prev.disk_read_history.clone();
+        global_usage.disk_write_history = prev.disk_write_history.clone();
+        global_usage.load_history = prev.load_history.clone();
+        global_usage.run_queue_history = prev.run_queue_history.clone();
+        global_usage.blocked_history = prev.blocked_history.clone();
         
-        if collection_duration > Duration::from_millis(self.config.refresh_rate_ms / 2) {
-            eprintln!("Slow data collection: {:?}", collection_duration);
+        // A sensor that stops reporting (a drive removed) drops its history.
+        let readings = temperatures.readings();
+        self.temperature_history.retain(|(label, _)| readings.iter().any(|(name, _)| name == label));
+        for (label, temp) in readings {
+            match self.temperature_history.iter_mut().find(|(name, _)| *name == label) {
+                Some((_, history)) => update_history(history, temp, history_length),
+                None => self.temperature_history.push((label, VecDeque::from([temp]))),
+            }
+        }
+        temperatures.history = self.temperature_history.clone();
+
+        self.disk_history.retain(|mount, _| disks.iter().any(|disk| disk.io_device.is_some() && disk.name == *mount));
+        for disk in disks.iter_mut().filter(|disk| disk.io_device.is_some()) {
+            let (reads, writes) = self.disk_history.entry(disk.name.clone()).or_default();
+            update_history(reads, disk.read_rate, history_length);
+            update_history(writes, disk.write_rate, history_length);
+            disk.read_history = reads.clone();
+            disk.write_history = writes.clone();
         }
         
-        DynamicData {
+        MonitorUpdate::System(Box::new(SystemSnapshot {
             processes,
             detailed_process,
             cores,
             disks,
             networks,
-            containers,
-            gpus,
             global_usage,
             temperatures,
-            last_update: std::time::Instant::now(),
-            docker_error,
-        }
+            self_usage,
+            cgroups,
+            security,
+            auth,
+            clock,
+            network_config,
+            port_changes,
+            neighbors,
+            neighbor_changes,
+            tcp,
+        }))
     }
     
-    pub fn get_system_info(&self) -> Vec<(String, String)> {
-        let mut info = self.system_monitor.get_system_info();
-        
-        if self.config.safe_mode {
-            info.push(("Mode".to_string(), "Safe Mode".to_string()));
+    // Selecting a different process starts its history from scratch.
+    fn record_process_history(&mut self, pid: Pid, process: &mut DetailedProcessInfo) {
+        if self.process_history.as_ref().is_some_and(|history| history.pid != pid) {
+            self.process_history = None;
         }
+        let history = self.process_history.get_or_insert_with(|| ProcessHistory {
+            pid,
+            cpu: VecDeque::new(),
+            memory: VecDeque::new(),
+            thread_times: HashMap::new(),
+            last_sample: Instant::now(),
+        });
         
-        let mut features = Vec::new();
-        if self.config.enable_docker && self.container_monitor.is_available() {
-            features.push("Docker");
-        }
-        if self.config.enable_gpu_monitoring && self.gpu_monitor.is_available() {
-            features.push("GPU");
-        }
-        if self.config.enable_network_monitoring {
-            features.push("Network");
-        }
+        update_history(&mut history.cpu, process.cpu_usage, self.config.history_length);
+        update_history(&mut history.memory, process.memory_rss, self.config.history_length);
+        process.cpu_history = history.cpu.clone();
+        process.memory_history = history.memory.clone();
         
-        if !features.is_empty() {
-            info.push(("Features".to_string(), features.join(", ")));
+        // Threads show no usage until their second sample.
+        let now = Instant::now();
+        let elapsed_secs = now.duration_since(history.last_sample).as_secs_f64();
+        history.last_sample = now;
+        for thread in &mut process.thread_list {
+            if let Some(prev) = history.thread_times.get(&thread.tid) {
+                if elapsed_secs > 0.0 {
+                    let used = thread.cpu_time.saturating_sub(*prev).as_secs_f64();
+                    thread.cpu_usage = (used / elapsed_secs * 100.0).min(100.0) as f32;
+                }
+            }
         }
-        
-        info
+        history.thread_times = process.thread_list.iter().map(|thread| (thread.tid, thread.cpu_time)).collect();
     }
-    
-    pub async fn health_check(&self) -> Vec<(String, bool)> {
-        let mut health = Vec::new();
+}
+
+pub struct GpuCollector {
+    monitor: GpuMonitor,
+    config: AppConfig,
+}
+
+impl GpuCollector {
+    pub fn collect(&mut self) -> MonitorUpdate {
+        let mut gpus: Result<Vec<GpuInfo>, String> = if !self.config.enable_gpu_monitoring {
+            Err("GPU monitoring disabled by configuration".to_string())
+        } else if !self.monitor.is_available() {
+            Err("GPU monitoring unavailable (no nvidia-smi or DRM devices)".to_string())
+        } else {
+            self.monitor.get_gpu_info()
+        };
         
-        health.push(("System".to_string(), true));
+        let utilization = match &gpus {
+            Ok(gpu_list) => self.monitor.get_primary_gpu_utilization(gpu_list),
+            Err(_) => None,
+        };
         
-        if self.config.enable_docker {
-            let docker_health = self.container_monitor.health_check(1000).await;
-            health.push(("Docker".to_string(), docker_health));
+        if let Ok(ref mut gpu_list) = gpus {
+            self.monitor.update_gpu_history(gpu_list, self.config.history_length);
         }
         
-        if self.config.enable_gpu_monitoring {
-            health.push(("GPU".to_string(), self.gpu_monitor.is_available()));
-        }
+        let processes: Vec<GpuProcessInfo> = match &gpus {
+            Ok(gpu_list) => self.monitor.get_gpu_processes(gpu_list),
+            Err(_) => Vec::new(),
+        };
         
-        if self.config.enable_network_monitoring {
-            health.push(("Network".to_string(), true));
+        let history: Option<VecDeque<u32>> = utilization.map(|_| self.monitor.get_gpu_history_flat());
+        
+        MonitorUpdate::Gpus { gpus, processes, utilization, history }
+    }
+}
+
+pub struct ContainerCollector {
+    monitor: ContainerMonitor,
+    config: AppConfig,
+}
+
+impl ContainerCollector {
+    pub async fn collect(&mut self) -> MonitorUpdate {
+        if !self.config.enable_docker || !self.monitor.is_available() {
+            return MonitorUpdate::Containers { containers: Vec::new(), error: None, ooms: Vec::new() };
         }
         
-        health
+        let timeout = self.config.get_operation_timeout();
+        let (containers, error): (Vec<ContainerInfo>, Option<String>) = match tokio::time::timeout(
+            timeout,
+            self.monitor.get_containers(timeout.as_millis() as u64)
+        ).await {
+            Ok(Ok(containers)) => (containers, None),
+            Ok(Err(e)) => (Vec::new(), Some(e)),
+            Err(_) => (Vec::new(), Some("Container collection timeout".to_string())),
+        };
+        let ooms = self.monitor.take_ooms();
+        
+        MonitorUpdate::Containers { containers, error, ooms }
+    }
+}
+
+impl DynamicData {
+    pub fn apply(&mut self, update: MonitorUpdate) {
+        let now = std::time::Instant::now();
+        match update {
+            MonitorUpdate::System(snapshot) => {
+                let SystemSnapshot { processes, detailed_process, cores, disks, networks, mut global_usage, temperatures, self_usage, cgroups, security, auth, clock, network_config, port_changes, neighbors, neighbor_changes, tcp } = *snapshot;
+                global_usage.gpu_util = self.global_usage.gpu_util;
+                global_usage.gpu_history = std::mem::take(&mut self.global_usage.gpu_history);
+                
+                if let Some(processes) = processes {
+                    self.processes = processes;
+                }
+                if cgroups.is_some() {
+                    self.cgroups = cgroups;
+                }
+                if security.is_some() {
+                    self.security = security;
+                }
+                if let Some(auth) = auth {
+                    self.auth = auth;
+                }
+                if clock.is_some() {
+                    self.clock = clock;
+                }
+                if network_config.is_some() {
+                    self.network_config = network_config;
+                }
+                self.port_changes.extend(port_changes);
+                while self.port_changes.len() > PortChange::RETAINED {
+                    self.port_changes.pop_front();
+                }
+                if let Some(neighbors) = neighbors {
+                    self.neighbors = neighbors;
+                }
+                if tcp.is_some() {
+                    self.tcp = tcp;
+                }
+                self.neighbor_changes.extend(neighbor_changes);
+                while self.neighbor_changes.len() > NeighborChange::RETAINED {
+                    self.neighbor_changes.pop_front();
+                }
+                self.detailed_process = detailed_process;
+                self.cores = cores;
+                self.disks = disks;
+                self.networks = networks;
+                self.global_usage = global_usage;
+                self.temperatures = temperatures;
+                self.self_usage = self_usage;
+                self.updated.system = Some(now);
+            }
+            MonitorUpdate::Gpus { gpus, processes, utilization, history } => {
+                match (&self.gpus, &gpus) {
+                    (Ok(_), Err(e)) => log::warn!("GPU monitoring unavailable: {}", e),
+                    (Err(_), Ok(_)) => log::info!("GPU monitoring available again"),
+                    _ => {}
+                }
+                self.gpus = gpus;
+                self.gpu_processes = processes;
+                self.global_usage.gpu_util = utilization;
+                if let Some(history) = history {
+                    self.global_usage.gpu_history = history;
+                }
+                self.updated.gpu = Some(now);
+            }
+            MonitorUpdate::Containers { containers, error, ooms } => {
+                match (&self.docker_error, &error) {
+                    (None, Some(e)) => log::warn!("Docker unavailable: {}", e),
+                    (Some(_), None) => log::info!("Docker available again"),
+                    _ => {}
+                }
+                self.containers = containers;
+                self.docker_error = error;
+                self.container_ooms.extend(ooms);
+                while self.container_ooms.len() > ContainerOom::RETAINED {
+                    self.container_ooms.pop_front();
+                }
+                self.updated.containers = Some(now);
+            }
+        }
+        self.last_update = now;
     }
 }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CollectorKind, ProcessInfo, SystemTemperatures};
 
-pub type SharedDataCollector = Arc<Mutex<DataCollector>>;
\ No newline at end of file
+    #[test]
+    fn test_partial_updates_merge() {
+        let mut data = DynamicData {
+            processes: vec![ProcessInfo { pid: "1".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+        assert!(data.updated.get(CollectorKind::Gpu).is_none());
+        data.apply(MonitorUpdate::Gpus {
+            gpus: Ok(vec![GpuInfo::default()]),
+            processes: Vec::new(),
+            utilization: Some(42),
+            history: Some(VecDeque::from(vec![40, 42])),
+        });
+        
+        let global_usage = GlobalUsage { cpu: 12.5, ..Default::default() };
+        data.apply(MonitorUpdate::System(Box::new(SystemSnapshot {
+            processes: None,
+            detailed_process: None,
+            cores: Vec::new(),
+            disks: Vec::new(),
+            networks: Vec::new(),
+            global_usage,
+            temperatures: SystemTemperatures::default(),
+            self_usage: Default::default(),
+            cgroups: None,
+            security: None,
+            auth: None,
+            clock: None,
+            network_config: None,
+            port_changes: Vec::new(),
+            neighbors: None,
+            neighbor_changes: Vec::new(),
+            tcp: None,
+        })));
+        
+        assert_eq!(data.global_usage.cpu, 12.5);
+        assert_eq!(data.processes.len(), 1);
+        assert_eq!(data.global_usage.gpu_util, Some(42));
+        assert_eq!(data.global_usage.gpu_history, VecDeque::from(vec![40, 42]));
+        assert_eq!(data.gpus.as_ref().map(|g| g.len()), Ok(1));
+        assert!(data.updated.system.is_some() && data.updated.gpu.is_some());
+        assert!(data.updated.containers.is_none());
+        
+        data.apply(MonitorUpdate::Containers { containers: Vec::new(), error: Some("down".to_string()), ooms: Vec::new() });
+        assert_eq!(data.docker_error.as_deref(), Some("down"));
+        assert_eq!(data.global_usage.gpu_util, Some(42));
+    }
+
+    #[test]
+    fn test_selected_process_history() {
+        let (mut system, _, _) = DataCollector::new(AppConfig::default()).into_collectors();
+        let detail = |update: MonitorUpdate| match update {
+            MonitorUpdate::System(snapshot) => snapshot.detailed_process,
+            _ => None,
+        };
+        
+        let mut query = ProcessQuery { selected_pid: Some(Pid::from_u32(std::process::id())), ..Default::default() };
+        detail(system.collect(&query));
+        let process = detail(system.collect(&query)).expect("own process is visible");
+        assert_eq!(process.cpu_history.len(), 2);
+        assert_eq!(process.memory_history.len(), 2);
+        
+        query.selected_pid = Some(Pid::from_u32(1));
+        if let Some(process) = detail(system.collect(&query)) {
+            assert_eq!(process.cpu_history.len(), 1);
+        }
+    }
+}
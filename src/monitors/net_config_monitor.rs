@@ -0,0 +1,388 @@
+use std::collections::HashMap;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, TcpStream, ToSocketAddrs};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::types::{Neighbor, NeighborChange, NeighborChangeKind, NetworkConfig, Reachability};
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+const NEIGHBOR_INTERVAL: Duration = Duration::from_secs(10);
+// The public address rarely changes and costs a request to a third party.
+const PUBLIC_IP_INTERVAL: Duration = Duration::from_secs(600);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+const PUBLIC_IP_URL: &str = "https://api.ipify.org";
+
+// systemd-resolved's local stub; the servers it forwards to are listed in
+// its own copy of resolv.conf.
+const RESOLVED_STUB: &str = "127.0.0.53";
+const RESOLVED_UPSTREAM: &str = "/run/systemd/resolve/resolv.conf";
+
+// Default gateways, resolvers and whether the outside world answers. Only
+// gathered while the network tab is open.
+pub struct NetConfigMonitor {
+    reach_target: Option<String>,
+    public_ip: bool,
+    last_refresh: Option<Instant>,
+    last_reach: Option<Instant>,
+    last_public_ip: Option<Instant>,
+    // The network probes block for seconds when the network is down, so they
+    // run on a thread of their own and land here.
+    probes: Arc<Mutex<Probes>>,
+    probing: Arc<AtomicBool>,
+}
+
+#[derive(Default)]
+struct Probes {
+    reachability: Option<Reachability>,
+    public_ip: Option<Result<IpAddr, String>>,
+    // Set when a probe finished since the last collect.
+    fresh: bool,
+}
+
+impl NetConfigMonitor {
+    pub fn new(reach_target: Option<String>, public_ip: bool) -> Self {
+        Self {
+            reach_target,
+            public_ip,
+            last_refresh: None,
+            last_reach: None,
+            last_public_ip: None,
+            probes: Arc::default(),
+            probing: Arc::default(),
+        }
+    }
+
+    // None until the previous result is due for a refresh or a probe has
+    // finished.
+    pub fn collect(&mut self) -> Option<NetworkConfig> {
+        self.start_probes();
+        let due = self.last_refresh.is_none_or(|at| at.elapsed() >= REFRESH_INTERVAL);
+        let mut probes = self.probes.lock().unwrap_or_else(|e| e.into_inner());
+        if !due && !probes.fresh {
+            return None;
+        }
+        probes.fresh = false;
+        if due {
+            self.last_refresh = Some(Instant::now());
+        }
+
+        Some(NetworkConfig {
+            gateways: read_gateways(),
+            dns_servers: read_dns_servers(),
+            reachability: probes.reachability.clone(),
+            public_ip: probes.public_ip.clone(),
+        })
+    }
+
+    fn start_probes(&mut self) {
+        let reach_target = self.reach_target.clone()
+            .filter(|_| self.last_reach.is_none_or(|at| at.elapsed() >= REFRESH_INTERVAL));
+        let public_ip = self.public_ip && self.last_public_ip.is_none_or(|at| at.elapsed() >= PUBLIC_IP_INTERVAL);
+        if (reach_target.is_none() && !public_ip) || self.probing.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        if reach_target.is_some() {
+            self.last_reach = Some(Instant::now());
+        }
+        if public_ip {
+            self.last_public_ip = Some(Instant::now());
+        }
+
+        let (probes, probing) = (self.probes.clone(), self.probing.clone());
+        std::thread::spawn(move || {
+            let reachability = reach_target.as_deref().map(check_reachability);
+            let ip = public_ip.then(fetch_public_ip);
+            let mut probes = probes.lock().unwrap_or_else(|e| e.into_inner());
+            if reachability.is_some() {
+                probes.reachability = reachability;
+            }
+            if ip.is_some() {
+                probes.public_ip = ip;
+            }
+            probes.fresh = true;
+            probing.store(false, Ordering::SeqCst);
+        });
+    }
+}
+
+// Follows the neighbor table for devices showing up, addresses moving to
+// another MAC, and neighbors that stop and start answering. Entries the
+// kernel drops once unused are remembered, so a device that is merely quiet
+// isn't new again when it comes back. The first check only takes a baseline.
+pub struct NeighborWatcher {
+    known: Option<HashMap<(IpAddr, String), Neighbor>>,
+    last_check: Option<Instant>,
+}
+
+impl NeighborWatcher {
+    pub fn new() -> Self {
+        Self { known: None, last_check: None }
+    }
+
+    // The current table, None until it is due, and what changed.
+    pub fn check(&mut self) -> (Option<Vec<Neighbor>>, Vec<NeighborChange>) {
+        if !cfg!(target_os = "linux") || self.last_check.is_some_and(|at| at.elapsed() < NEIGHBOR_INTERVAL) {
+            return (None, Vec::new());
+        }
+        self.last_check = Some(Instant::now());
+
+        let current = read_neighbors();
+        let changes = match self.known.as_mut() {
+            Some(known) => diff_neighbors(known, &current, chrono::Local::now()),
+            None => {
+                let mut known = HashMap::new();
+                diff_neighbors(&mut known, &current, chrono::Local::now());
+                self.known = Some(known);
+                Vec::new()
+            }
+        };
+        for change in &changes {
+            let neighbor = &change.neighbor;
+            let mac = neighbor.mac.as_deref().unwrap_or("-");
+            match &change.kind {
+                NeighborChangeKind::New => log::info!("New neighbor: {} ({}) on {}", neighbor.address, mac, neighbor.interface),
+                NeighborChangeKind::MacChanged(from) => log::warn!("Neighbor {} moved from {} to {}", neighbor.address, from, mac),
+                NeighborChangeKind::Failed => log::info!("Neighbor {} stopped answering", neighbor.address),
+                NeighborChangeKind::Recovered => log::info!("Neighbor {} answers again", neighbor.address),
+            }
+        }
+        (Some(current), changes)
+    }
+}
+
+// Updates `known` with `current` and returns the changes, all stamped `at`.
+pub fn diff_neighbors(known: &mut HashMap<(IpAddr, String), Neighbor>, current: &[Neighbor], at: chrono::DateTime<chrono::Local>) -> Vec<NeighborChange> {
+    let mut changes = Vec::new();
+    for neighbor in current {
+        let key = (neighbor.address, neighbor.interface.clone());
+        let previous = known.get(&key);
+        let kind = match (previous.and_then(|p| p.mac.as_deref()), neighbor.mac.as_deref()) {
+            (None, Some(_)) => Some(NeighborChangeKind::New),
+            (Some(from), Some(to)) if from != to => Some(NeighborChangeKind::MacChanged(from.to_string())),
+            // Failed lookups lose the MAC; only one that resolved before can fail.
+            (Some(_), _) => match previous {
+                Some(p) if !p.is_failed() && neighbor.is_failed() => Some(NeighborChangeKind::Failed),
+                Some(p) if p.is_failed() && !neighbor.is_failed() => Some(NeighborChangeKind::Recovered),
+                _ => None,
+            },
+            (None, None) => None,
+        };
+        if let Some(kind) = kind {
+            changes.push(NeighborChange { at, kind, neighbor: neighbor.clone() });
+        }
+        let mac = neighbor.mac.clone().or_else(|| previous.and_then(|p| p.mac.clone()));
+        known.insert(key, Neighbor { mac, ..neighbor.clone() });
+    }
+    changes
+}
+
+// `ip neigh` covers IPv6 and names the states; /proc/net/arp is the fallback.
+fn read_neighbors() -> Vec<Neighbor> {
+    match Command::new("ip").args(["neigh", "show"]).output() {
+        Ok(output) if output.status.success() => parse_ip_neigh(&String::from_utf8_lossy(&output.stdout)),
+        _ => fs::read_to_string("/proc/net/arp").map(|c| parse_proc_arp(&c)).unwrap_or_default(),
+    }
+}
+
+// "192.168.1.1 dev eth0 lladdr 52:54:00:12:35:02 router REACHABLE"; the state
+// comes last.
+pub fn parse_ip_neigh(content: &str) -> Vec<Neighbor> {
+    content.lines().filter_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let address = fields.first()?.parse().ok()?;
+        let after = |key: &str| fields.iter().position(|field| *field == key).and_then(|i| fields.get(i + 1));
+        Some(Neighbor {
+            address,
+            mac: after("lladdr").map(|mac| mac.to_string()),
+            state: fields.last().filter(|state| state.chars().all(|c| c.is_ascii_uppercase())).map_or("NONE", |state| state).to_string(),
+            interface: after("dev")?.to_string(),
+        })
+    }).collect()
+}
+
+// /proc/net/arp: "IP address HW type Flags HW address Mask Device", with
+// flags 0x2 for a resolved entry and 0x4 for a permanent one.
+pub fn parse_proc_arp(content: &str) -> Vec<Neighbor> {
+    content.lines().skip(1).filter_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let flags = u32::from_str_radix(fields.get(2)?.trim_start_matches("0x"), 16).ok()?;
+        let state = if flags & 0x4 != 0 { "PERMANENT" } else if flags & 0x2 != 0 { "REACHABLE" } else { "INCOMPLETE" };
+        Some(Neighbor {
+            address: fields.first()?.parse().ok()?,
+            mac: fields.get(3).filter(|_| flags & 0x2 != 0).map(|mac| mac.to_string()),
+            state: state.to_string(),
+            interface: fields.get(5)?.to_string(),
+        })
+    }).collect()
+}
+
+fn read_gateways() -> Vec<(String, IpAddr)> {
+    let mut gateways = fs::read_to_string("/proc/net/route").map(|c| parse_ipv4_routes(&c)).unwrap_or_default();
+    gateways.extend(fs::read_to_string("/proc/net/ipv6_route").map(|c| parse_ipv6_routes(&c)).unwrap_or_default());
+    gateways
+}
+
+// /proc/net/route: "Iface Destination Gateway Flags ... Mask ...", addresses
+// as hex words in host byte order. Default routes have a zero destination
+// and mask.
+pub fn parse_ipv4_routes(content: &str) -> Vec<(String, IpAddr)> {
+    content.lines().skip(1).filter_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let (iface, destination, gateway, mask) = (fields.first()?, fields.get(1)?, fields.get(2)?, fields.get(7)?);
+        if *destination != "00000000" || *mask != "00000000" {
+            return None;
+        }
+        let gateway = u32::from_str_radix(gateway, 16).ok().filter(|&word| word != 0)?;
+        Some((iface.to_string(), IpAddr::V4(Ipv4Addr::from(gateway.to_ne_bytes()))))
+    }).collect()
+}
+
+// /proc/net/ipv6_route: "dest dest_len src src_len next_hop metric refcnt use
+// flags iface", addresses as 32 hex digits in network order.
+pub fn parse_ipv6_routes(content: &str) -> Vec<(String, IpAddr)> {
+    let mut gateways: Vec<(String, IpAddr)> = Vec::new();
+    for line in content.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 10 || !fields[0].trim_matches('0').is_empty() || fields[1] != "00" {
+            continue;
+        }
+        let Ok(next_hop) = u128::from_str_radix(fields[4], 16) else { continue };
+        let gateway = (fields[9].to_string(), IpAddr::V6(Ipv6Addr::from(next_hop)));
+        if next_hop != 0 && !gateways.contains(&gateway) {
+            gateways.push(gateway);
+        }
+    }
+    gateways
+}
+
+fn read_dns_servers() -> Vec<String> {
+    let servers = fs::read_to_string("/etc/resolv.conf").map(|c| parse_nameservers(&c)).unwrap_or_default();
+    if servers.iter().all(|server| server == RESOLVED_STUB) {
+        if let Ok(upstream) = fs::read_to_string(RESOLVED_UPSTREAM) {
+            let upstream = parse_nameservers(&upstream);
+            if !upstream.is_empty() {
+                return upstream;
+            }
+        }
+    }
+    servers
+}
+
+pub fn parse_nameservers(content: &str) -> Vec<String> {
+    content.lines()
+        .filter_map(|line| line.trim().strip_prefix("nameserver"))
+        .filter_map(|rest| rest.split_whitespace().next())
+        .map(str::to_string)
+        .collect()
+}
+
+// A TCP handshake rather than a ping, which would need a raw socket.
+fn check_reachability(target: &str) -> Reachability {
+    let started = Instant::now();
+    let result = target.to_socket_addrs()
+        .map_err(|e| e.to_string())
+        .and_then(|mut addrs| addrs.next().ok_or_else(|| "no address".to_string()))
+        .and_then(|addr| TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT).map_err(|e| e.to_string()))
+        .map(|_| started.elapsed().as_secs_f64() * 1000.0);
+    Reachability { target: target.to_string(), latency_ms: result }
+}
+
+fn fetch_public_ip() -> Result<IpAddr, String> {
+    let output = Command::new("curl")
+        .args(["-fsS", "--max-time", "3", PUBLIC_IP_URL])
+        .output()
+        .map_err(|_| "curl not available".to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    String::from_utf8_lossy(&output.stdout).trim().parse().map_err(|_| "unexpected reply".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probes_do_not_block_collect() {
+        // TEST-NET-1, which nothing answers.
+        let mut monitor = NetConfigMonitor::new(Some("192.0.2.1:9".to_string()), false);
+        let started = Instant::now();
+        assert!(monitor.collect().is_some());
+        assert!(started.elapsed() < CONNECT_TIMEOUT);
+    }
+
+    #[test]
+    fn test_parse_routes() {
+        let gateway = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
+        let word = u32::from_ne_bytes([192, 168, 1, 1]);
+        let subnet = u32::from_ne_bytes([192, 168, 1, 0]);
+        let routes = format!("\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+eth0\t00000000\t{:08X}\t0003\t0\t0\t100\t00000000\t0\t0\t0
+eth0\t{:08X}\t00000000\t0001\t0\t0\t100\t00FFFFFF\t0\t0\t0
+", word, subnet);
+        assert_eq!(parse_ipv4_routes(&routes), vec![("eth0".to_string(), gateway)]);
+
+        let routes6 = "\
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 fe800000000000000000000000000001 00000400 00000001 00000000 00000003     eth0
+20010db8000000000000000000000000 40 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000001 00000000 00000001     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo
+";
+        assert_eq!(parse_ipv6_routes(routes6), vec![("eth0".to_string(), "fe80::1".parse().unwrap())]);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let table = "\
+192.168.1.1 dev eth0 lladdr 52:54:00:12:35:02 router REACHABLE
+192.168.1.7 dev eth0 FAILED
+fe80::1 dev eth0 lladdr 52:54:00:12:35:02 router STALE
+";
+        let neighbors = parse_ip_neigh(table);
+        assert_eq!(neighbors.len(), 3);
+        assert_eq!(neighbors[0].mac.as_deref(), Some("52:54:00:12:35:02"));
+        assert_eq!((neighbors[1].mac.as_deref(), neighbors[1].state.as_str()), (None, "FAILED"));
+        assert_eq!(neighbors[2].address, "fe80::1".parse::<IpAddr>().unwrap());
+
+        let arp = "\
+IP address       HW type     Flags       HW address            Mask     Device
+192.168.1.1      0x1         0x2         52:54:00:12:35:02     *        eth0
+192.168.1.9      0x1         0x0         00:00:00:00:00:00     *        eth0
+";
+        let neighbors = parse_proc_arp(arp);
+        assert_eq!(neighbors[0].state, "REACHABLE");
+        assert!(neighbors[1].is_failed() && neighbors[1].mac.is_none());
+
+        let at = chrono::Local::now();
+        let neighbor = |address: &str, mac: Option<&str>, state: &str| Neighbor {
+            address: address.parse().unwrap(),
+            mac: mac.map(str::to_string),
+            state: state.to_string(),
+            interface: "eth0".to_string(),
+        };
+        let kinds = |known: &mut HashMap<_, _>, current: &[Neighbor]| -> Vec<NeighborChangeKind> {
+            diff_neighbors(known, current, at).into_iter().map(|change| change.kind).collect()
+        };
+        let mut known = HashMap::new();
+        assert_eq!(kinds(&mut known, &[neighbor("10.0.0.2", None, "INCOMPLETE")]), vec![]);
+        assert_eq!(kinds(&mut known, &[neighbor("10.0.0.2", Some("aa:01"), "REACHABLE")]), vec![NeighborChangeKind::New]);
+        // Dropped from the table while quiet, then back.
+        assert_eq!(kinds(&mut known, &[]), vec![]);
+        assert_eq!(kinds(&mut known, &[neighbor("10.0.0.2", Some("aa:01"), "STALE")]), vec![]);
+        assert_eq!(kinds(&mut known, &[neighbor("10.0.0.2", None, "FAILED")]), vec![NeighborChangeKind::Failed]);
+        assert_eq!(kinds(&mut known, &[neighbor("10.0.0.2", Some("aa:01"), "REACHABLE")]), vec![NeighborChangeKind::Recovered]);
+        assert_eq!(
+            kinds(&mut known, &[neighbor("10.0.0.2", Some("bb:02"), "REACHABLE")]),
+            vec![NeighborChangeKind::MacChanged("aa:01".to_string())],
+        );
+    }
+
+    #[test]
+    fn test_parse_nameservers() {
+        let resolv = "# generated\nnameserver 1.1.1.1\nnameserver  2606:4700:4700::1111 \nsearch example.org\noptions edns0\n";
+        assert_eq!(parse_nameservers(resolv), vec!["1.1.1.1", "2606:4700:4700::1111"]);
+    }
+}
@@ -0,0 +1,90 @@
+use std::time::Duration;
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+
+use crate::types::CollectorTiming;
+
+// Weight of the newest sample in the smoothed jitter figure.
+const JITTER_SMOOTHING: f64 = 0.2;
+
+// Fixed-rate ticker for a collector. Ticks are scheduled from the start time
+// rather than from the end of the previous collection, so a collection that
+// takes 300ms does not stretch a 1s period to 1.3s. A collection that overruns
+// the period skips the missed ticks instead of bursting to catch up.
+pub struct Scheduler {
+    interval: Interval,
+    last_tick: Option<Instant>,
+    timing: CollectorTiming,
+}
+
+impl Scheduler {
+    pub fn new(period: Duration) -> Self {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        Self {
+            interval,
+            last_tick: None,
+            timing: CollectorTiming { target: period, ..Default::default() },
+        }
+    }
+
+    pub async fn tick(&mut self) -> Instant {
+        let now = self.interval.tick().await;
+        if let Some(last) = self.last_tick {
+            record_period(&mut self.timing, now.duration_since(last));
+        }
+        self.last_tick = Some(now);
+        now
+    }
+
+    // Pausing breaks the cadence; the first tick after resuming is not a sample.
+    pub fn pause(&mut self) {
+        self.last_tick = None;
+    }
+
+    pub fn finish(&mut self, started: Instant) -> CollectorTiming {
+        record_duration(&mut self.timing, started.elapsed());
+        self.timing.clone()
+    }
+}
+
+pub fn record_period(timing: &mut CollectorTiming, actual: Duration) {
+    let deviation = actual.abs_diff(timing.target);
+
+    timing.last_period = actual;
+    timing.jitter = if timing.samples == 0 {
+        deviation
+    } else {
+        timing.jitter.mul_f64(1.0 - JITTER_SMOOTHING) + deviation.mul_f64(JITTER_SMOOTHING)
+    };
+    timing.samples += 1;
+}
+
+pub fn record_duration(timing: &mut CollectorTiming, duration: Duration) {
+    timing.last_duration = duration;
+    if duration > timing.target {
+        timing.overruns += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jitter_tracking() {
+        let mut timing = CollectorTiming { target: Duration::from_millis(1000), ..Default::default() };
+
+        record_period(&mut timing, Duration::from_millis(1100));
+        assert_eq!(timing.jitter, Duration::from_millis(100));
+
+        record_period(&mut timing, Duration::from_millis(1000));
+        assert_eq!(timing.jitter, Duration::from_millis(80));
+        assert_eq!(timing.last_period, Duration::from_millis(1000));
+
+        record_duration(&mut timing, Duration::from_millis(400));
+        record_duration(&mut timing, Duration::from_millis(1200));
+        assert_eq!(timing.overruns, 1);
+        assert_eq!(timing.last_duration, Duration::from_millis(1200));
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::types::{FirewallStatus, ListeningPort, PortChange, PortChangeKind, SecurityOverview, UserSession};
+
+// Firewall tools are slow to query and their state changes rarely.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+const PORT_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+// TCP_LISTEN, and TCP_CLOSE for bound UDP sockets.
+const TCP_LISTEN: &str = "0A";
+const UDP_UNCONNECTED: &str = "07";
+
+pub struct SecurityMonitor {
+    last_refresh: Option<Instant>,
+}
+
+impl SecurityMonitor {
+    pub fn new() -> Self {
+        Self { last_refresh: None }
+    }
+
+    // None until the previous overview is due for a refresh.
+    pub fn collect(&mut self) -> Option<SecurityOverview> {
+        if self.last_refresh.is_some_and(|at| at.elapsed() < REFRESH_INTERVAL) {
+            return None;
+        }
+        self.last_refresh = Some(Instant::now());
+
+        Some(SecurityOverview {
+            ports: listening_ports(),
+            firewalls: firewall_status(),
+            sessions: read_sessions(),
+        })
+    }
+}
+
+// Notices listening sockets coming and going. The first check only takes a
+// baseline. Unconnected UDP sockets on ephemeral ports are mostly clients
+// waiting on a reply, so they are left out.
+pub struct PortWatcher {
+    known: Option<Vec<ListeningPort>>,
+    last_check: Option<Instant>,
+    ephemeral: RangeInclusive<u16>,
+}
+
+impl PortWatcher {
+    pub fn new() -> Self {
+        let ephemeral = fs::read_to_string("/proc/sys/net/ipv4/ip_local_port_range").ok()
+            .and_then(|range| {
+                let mut bounds = range.split_whitespace().map(|n| n.parse::<u16>().ok());
+                Some(bounds.next()??..=bounds.next()??)
+            })
+            .unwrap_or(32768..=60999);
+        Self { known: None, last_check: None, ephemeral }
+    }
+
+    pub fn check(&mut self) -> Vec<PortChange> {
+        if !cfg!(target_os = "linux") || self.last_check.is_some_and(|at| at.elapsed() < PORT_CHECK_INTERVAL) {
+            return Vec::new();
+        }
+        self.last_check = Some(Instant::now());
+
+        let current: Vec<ListeningPort> = listening_sockets().into_iter()
+            .filter(|port| !(port.protocol.starts_with("udp") && self.ephemeral.contains(&port.port)))
+            .collect();
+        let Some(known) = self.known.as_mut() else {
+            self.known = Some(with_owners(current));
+            return Vec::new();
+        };
+
+        let (opened, closed) = diff_ports(known, &current);
+        if opened.is_empty() && closed.is_empty() {
+            return Vec::new();
+        }
+
+        let now = chrono::Local::now();
+        let opened = with_owners(opened);
+        let mut changes = Vec::new();
+        for port in closed {
+            log::warn!("Port no longer listening: {}", port);
+            changes.push(PortChange { at: now, kind: PortChangeKind::Closed, port });
+        }
+        for port in &opened {
+            log::warn!("New listening port: {}", port);
+            changes.push(PortChange { at: now, kind: PortChangeKind::Opened, port: port.clone() });
+        }
+
+        known.retain(|port| current.iter().any(|c| same_socket(c, port)));
+        known.extend(opened);
+        changes
+    }
+}
+
+fn same_socket(a: &ListeningPort, b: &ListeningPort) -> bool {
+    a.protocol == b.protocol && a.address == b.address && a.port == b.port
+}
+
+// Sockets in `current` but not `known`, and those in `known` that are gone.
+pub fn diff_ports(known: &[ListeningPort], current: &[ListeningPort]) -> (Vec<ListeningPort>, Vec<ListeningPort>) {
+    let opened = current.iter()
+        .filter(|port| !known.iter().any(|k| same_socket(k, port)))
+        .cloned()
+        .collect();
+    let closed = known.iter()
+        .filter(|port| !current.iter().any(|c| same_socket(c, port)))
+        .cloned()
+        .collect();
+    (opened, closed)
+}
+
+fn with_owners(mut ports: Vec<ListeningPort>) -> Vec<ListeningPort> {
+    if ports.is_empty() {
+        return ports;
+    }
+    let inodes: HashSet<u64> = ports.iter().map(|port| port.inode).collect();
+    let owners = socket_owners(&inodes);
+    for port in &mut ports {
+        port.process = owners.get(&port.inode).cloned();
+    }
+    ports
+}
+
+fn listening_sockets() -> Vec<ListeningPort> {
+    let mut ports: Vec<ListeningPort> = [("tcp", TCP_LISTEN), ("tcp6", TCP_LISTEN), ("udp", UDP_UNCONNECTED), ("udp6", UDP_UNCONNECTED)]
+        .into_iter()
+        .filter_map(|(protocol, state)| {
+            let content = fs::read_to_string(format!("/proc/net/{}", protocol)).ok()?;
+            Some(parse_proc_net(&content, protocol, state))
+        })
+        .flatten()
+        .collect();
+    ports.sort_by(|a, b| (a.port, &a.protocol).cmp(&(b.port, &b.protocol)));
+    ports.dedup_by(|a, b| same_socket(a, b));
+    ports
+}
+
+fn listening_ports() -> Vec<ListeningPort> {
+    with_owners(listening_sockets())
+}
+
+// Sockets listening on one port, with their owners.
+pub fn listeners_on(port: u16) -> Vec<ListeningPort> {
+    with_owners(listening_sockets().into_iter().filter(|socket| socket.port == port).collect())
+}
+
+// Sockets in the wanted state from /proc/net/{tcp,udp}[6]. UDP sockets count
+// when they have no peer.
+pub fn parse_proc_net(content: &str, protocol: &str, state: &str) -> Vec<ListeningPort> {
+    content.lines().skip(1).filter_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 10 || fields[3] != state {
+            return None;
+        }
+        if protocol.starts_with("udp") && !fields[2].ends_with(":0000") {
+            return None;
+        }
+        let (address, port) = fields[1].split_once(':')?;
+        Some(ListeningPort {
+            protocol: protocol.to_string(),
+            address: parse_hex_address(address)?,
+            port: u16::from_str_radix(port, 16).ok()?,
+            inode: fields[9].parse().ok()?,
+            process: None,
+        })
+    }).collect()
+}
+
+// The kernel prints each 32-bit word of the address in host byte order.
+fn parse_hex_address(hex: &str) -> Option<IpAddr> {
+    let word = |i: usize| u32::from_str_radix(hex.get(i * 8..i * 8 + 8)?, 16).ok().map(u32::to_ne_bytes);
+    match hex.len() {
+        8 => Some(IpAddr::V4(Ipv4Addr::from(word(0)?))),
+        32 => {
+            let mut bytes = [0u8; 16];
+            for i in 0..4 {
+                bytes[i * 4..i * 4 + 4].copy_from_slice(&word(i)?);
+            }
+            let address = Ipv6Addr::from(bytes);
+            Some(address.to_ipv4_mapped().map_or(IpAddr::V6(address), IpAddr::V4))
+        }
+        _ => None,
+    }
+}
+
+// Owning process of each wanted socket inode, from every readable
+// /proc/<pid>/fd.
+fn socket_owners(inodes: &HashSet<u64>) -> HashMap<u64, (u32, String)> {
+    let mut owners = HashMap::new();
+    let Ok(entries) = fs::read_dir("/proc") else { return owners };
+    for entry in entries.filter_map(Result::ok) {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else { continue };
+        let Ok(fds) = fs::read_dir(entry.path().join("fd")) else { continue };
+        let mut name = None;
+        for fd in fds.filter_map(Result::ok) {
+            let Ok(target) = fs::read_link(fd.path()) else { continue };
+            let Some(inode) = target.to_str()
+                .and_then(|t| t.strip_prefix("socket:["))
+                .and_then(|t| t.strip_suffix(']'))
+                .and_then(|t| t.parse::<u64>().ok())
+                .filter(|inode| inodes.contains(inode)) else { continue };
+            let name = name.get_or_insert_with(|| {
+                fs::read_to_string(entry.path().join("comm")).map(|c| c.trim().to_string()).unwrap_or_default()
+            });
+            owners.entry(inode).or_insert_with(|| (pid, name.clone()));
+        }
+    }
+    owners
+}
+
+// Only tools that are installed are reported.
+fn firewall_status() -> Vec<FirewallStatus> {
+    let mut firewalls = Vec::new();
+
+    if let Ok(output) = Command::new("nft").args(["list", "ruleset"]).output() {
+        firewalls.push(if output.status.success() {
+            let (tables, base_chains) = parse_nft_ruleset(&String::from_utf8_lossy(&output.stdout));
+            FirewallStatus {
+                name: "nftables".to_string(),
+                active: Some(base_chains > 0),
+                detail: format!("{} tables, {} base chains", tables, base_chains),
+            }
+        } else {
+            unreadable("nftables", &output.stderr)
+        });
+    }
+
+    if let Ok(output) = Command::new("ufw").arg("status").output() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        firewalls.push(match parse_ufw_status(&stdout) {
+            Some(active) => FirewallStatus { name: "ufw".to_string(), active: Some(active), detail: String::new() },
+            None => unreadable("ufw", &output.stderr),
+        });
+    }
+
+    if let Ok(output) = Command::new("firewall-cmd").arg("--state").output() {
+        let state = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        firewalls.push(FirewallStatus {
+            name: "firewalld".to_string(),
+            active: Some(state == "running"),
+            detail: state,
+        });
+    }
+
+    firewalls
+}
+
+fn unreadable(name: &str, stderr: &[u8]) -> FirewallStatus {
+    let error = String::from_utf8_lossy(stderr);
+    FirewallStatus {
+        name: name.to_string(),
+        active: None,
+        detail: error.lines().next().unwrap_or("unknown").trim().to_string(),
+    }
+}
+
+// Table and base chain counts; a ruleset without base chains filters nothing.
+pub fn parse_nft_ruleset(ruleset: &str) -> (usize, usize) {
+    let lines = ruleset.lines().map(str::trim);
+    let tables = lines.clone().filter(|line| line.starts_with("table ")).count();
+    let base_chains = lines.filter(|line| line.starts_with("type ") && line.contains(" hook ")).count();
+    (tables, base_chains)
+}
+
+pub fn parse_ufw_status(output: &str) -> Option<bool> {
+    let status = output.lines().find_map(|line| line.strip_prefix("Status:"))?;
+    Some(status.trim() == "active")
+}
+
+fn read_sessions() -> Vec<UserSession> {
+    Command::new("who")
+        .env("LC_ALL", "C")
+        .output()
+        .map(|output| parse_who(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or_default()
+}
+
+// "user  pts/0  2024-05-01 09:12 (10.0.0.5)"
+pub fn parse_who(output: &str) -> Vec<UserSession> {
+    output.lines().filter_map(|line| {
+        let mut fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 3 {
+            return None;
+        }
+        let from = match fields.last() {
+            Some(last) if last.starts_with('(') => {
+                let from = last.trim_start_matches('(').trim_end_matches(')').to_string();
+                fields.pop();
+                from
+            }
+            _ => String::new(),
+        };
+        Some(UserSession {
+            user: fields[0].to_string(),
+            terminal: fields[1].to_string(),
+            since: fields[2..].join(" "),
+            from,
+        })
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_net() {
+        let tcp = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n\
+                   0: 0100007F:0CEA 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 1001 1 0 100 0 0 10 0\n\
+                   1: 00000000:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 1002 1 0 100 0 0 10 0\n\
+                   2: 0100007F:0CEA 0100007F:D2F0 01 00000000:00000000 00:00000000 00000000     0        0 1003 1 0 100 0 0 10 0\n";
+        let ports = parse_proc_net(tcp, "tcp", TCP_LISTEN);
+        assert_eq!(ports.len(), 2);
+        assert_eq!((ports[0].address, ports[0].port, ports[0].inode), (IpAddr::V4(Ipv4Addr::LOCALHOST), 3306, 1001));
+        assert!(!ports[0].is_exposed());
+        assert_eq!(ports[1].port, 22);
+        assert!(ports[1].is_exposed());
+
+        let tcp6 = "  sl  local_address                         remote_address                        st\n\
+                    0: 00000000000000000000000000000000:0050 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 2001 1\n\
+                    1: 00000000000000000000000001000000:0277 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 2002 1\n";
+        let ports = parse_proc_net(tcp6, "tcp6", TCP_LISTEN);
+        assert_eq!(ports[0].address, IpAddr::V6(Ipv6Addr::UNSPECIFIED));
+        assert_eq!((ports[1].address, ports[1].port), (IpAddr::V6(Ipv6Addr::LOCALHOST), 631));
+
+        let udp = "  sl  local_address rem_address   st\n\
+                   0: 3500007F:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 3001 2\n\
+                   1: 0F02000A:A3B6 08080808:0035 01 00000000:00000000 00:00000000 00000000  1000        0 3002 2\n";
+        let ports = parse_proc_net(udp, "udp", UDP_UNCONNECTED);
+        assert_eq!(ports.len(), 1);
+        assert_eq!((ports[0].address, ports[0].port), (IpAddr::V4(Ipv4Addr::new(127, 0, 0, 53)), 53));
+    }
+
+    #[test]
+    fn test_diff_ports() {
+        let port = |protocol: &str, port: u16, inode: u64| ListeningPort {
+            protocol: protocol.to_string(),
+            address: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            port,
+            inode,
+            process: None,
+        };
+        let known = vec![port("tcp", 22, 1), port("tcp", 80, 2), port("udp", 53, 3)];
+        // A restarted listener keeps its address but gets a new inode.
+        let current = vec![port("tcp", 22, 10), port("udp", 53, 3), port("tcp", 8080, 11)];
+
+        let (opened, closed) = diff_ports(&known, &current);
+        assert_eq!(opened, vec![port("tcp", 8080, 11)]);
+        assert_eq!(closed, vec![port("tcp", 80, 2)]);
+        assert_eq!(diff_ports(&current, &current), (Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn test_parse_firewall_status() {
+        let ruleset = "table inet filter {\n\tchain input {\n\t\ttype filter hook input priority filter; policy drop;\n\t\tct state established accept\n\t}\n}\ntable ip nat {\n}\n";
+        assert_eq!(parse_nft_ruleset(ruleset), (2, 1));
+        assert_eq!(parse_nft_ruleset(""), (0, 0));
+
+        assert_eq!(parse_ufw_status("Status: active\n\nTo Action From\n"), Some(true));
+        assert_eq!(parse_ufw_status("Status: inactive\n"), Some(false));
+        assert_eq!(parse_ufw_status("ERROR: You need to be root to run this script\n"), None);
+    }
+
+    #[test]
+    fn test_parse_who() {
+        let output = "root     tty1         2024-05-01 09:00\nalice    pts/0        2024-05-01 09:12 (10.0.0.5)\n";
+        let sessions = parse_who(output);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0], UserSession {
+            user: "root".to_string(),
+            terminal: "tty1".to_string(),
+            since: "2024-05-01 09:00".to_string(),
+            from: String::new(),
+        });
+        assert_eq!(sessions[1].from, "10.0.0.5");
+    }
+}
@@ -1,38 +1,217 @@
-use std::collections::HashMap;
-use std::time::Instant;
-use sysinfo::{DiskUsage, Networks, Pid, System};
-use users::{Users, UsersCache};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use std::path::Path;
+use sysinfo::{
+    Components, CpuRefreshKind, DiskRefreshKind, DiskUsage, Disks, MemoryRefreshKind, Networks, Pid,
+    ProcessRefreshKind, ProcessesToUpdate, RefreshKind, System, UpdateKind, Users,
+};
 use chrono::prelude::*;
 
 use crate::types::*;
 use crate::utils::*;
 
+// Which parts of the system the visible tab needs this cycle. CPU usage and
+// memory are always refreshed since the header and alerts use them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RefreshScope {
+    pub processes: bool,
+    pub cpu_frequency: bool,
+    pub cgroups: bool,
+    pub security: bool,
+    pub network_config: bool,
+}
+
+impl RefreshScope {
+    pub fn for_tab(active_tab: usize) -> Self {
+        Self {
+            // Dashboard, process detail, memory (leak suspects) and system
+            // info (process state counts).
+            processes: matches!(active_tab, 0 | 1 | 3 | 7),
+            cpu_frequency: active_tab == 2,
+            cgroups: active_tab == 12,
+            security: active_tab == 13,
+            network_config: active_tab == 5,
+        }
+    }
+}
+
+// Per-process state kept between refreshes. Entries are keyed by PID but only
+// reused while the start time matches, so a recycled PID gets a fresh entry.
+// Name, parent and user can still change under the same start time (execve,
+// reparenting, setuid) and are checked again on every refresh.
+struct CachedProcess {
+    start_time: u64,
+    name: String,
+    uid: Option<sysinfo::Uid>,
+    user: String,
+    parent: Option<String>,
+    is_system: bool,
+    container: Option<String>,
+    memory: MemoryTrend,
+}
+
+// Samples kept per leak window; RSS is recorded at most this often.
+const LEAK_SAMPLES: u32 = 30;
+
+// Resident memory of one process over the leak window, thinned out so long
+// windows stay cheap across hundreds of processes.
+#[derive(Default)]
+struct MemoryTrend {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl MemoryTrend {
+    fn record(&mut self, now: Instant, rss: u64, window: Duration) {
+        let spacing = window / LEAK_SAMPLES;
+        if self.samples.back().is_none_or(|(at, _)| now.duration_since(*at) >= spacing) {
+            self.samples.push_back((now, rss));
+        }
+        // Keep one sample at or beyond the window as the starting point.
+        while self.samples.len() > 1 && now.duration_since(self.samples[1].0) >= window {
+            self.samples.pop_front();
+        }
+    }
+
+    fn growth_per_min(&self) -> i64 {
+        match (self.samples.front(), self.samples.back()) {
+            (Some((start, first)), Some((end, last))) if end > start => {
+                let minutes = end.duration_since(*start).as_secs_f64() / 60.0;
+                ((*last as f64 - *first as f64) / minutes) as i64
+            }
+            _ => 0,
+        }
+    }
+
+    fn is_leak_suspect(&self, window: Duration) -> bool {
+        let (Some((start, first)), Some((end, last))) = (self.samples.front(), self.samples.back()) else {
+            return false;
+        };
+        end.duration_since(*start) >= window
+            && last > first
+            && self.samples.iter().zip(self.samples.iter().skip(1)).all(|((_, a), (_, b))| b >= a)
+    }
+}
+
+// Usage of each mount is sampled this often and kept this long for the
+// days-until-full projection, which needs at least DISK_TREND_MIN_SPAN.
+const DISK_TREND_SPACING: Duration = Duration::from_secs(30);
+const DISK_TREND_WINDOW: Duration = Duration::from_secs(60 * 60);
+const DISK_TREND_MIN_SPAN: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Default)]
+struct DiskTrend {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl DiskTrend {
+    fn record(&mut self, now: Instant, used: u64) {
+        if self.samples.back().is_none_or(|(at, _)| now.duration_since(*at) >= DISK_TREND_SPACING) {
+            self.samples.push_back((now, used));
+        }
+        while self.samples.len() > 1 && now.duration_since(self.samples[1].0) >= DISK_TREND_WINDOW {
+            self.samples.pop_front();
+        }
+    }
+
+    // Bytes per second, fitted by least squares so one burst of writes or a
+    // deleted file doesn't swing the estimate the way the first and last
+    // sample alone would.
+    fn growth_per_sec(&self) -> Option<f64> {
+        let (start, _) = *self.samples.front()?;
+        let (end, _) = *self.samples.back()?;
+        if end.duration_since(start) < DISK_TREND_MIN_SPAN {
+            return None;
+        }
+        let points: Vec<(f64, f64)> = self.samples.iter()
+            .map(|(at, used)| (at.duration_since(start).as_secs_f64(), *used as f64))
+            .collect();
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let covariance: f64 = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+        let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        (variance > 0.0).then(|| covariance / variance)
+    }
+
+    // None while usage is flat or shrinking.
+    fn time_to_full(&self, free: u64) -> Option<Duration> {
+        let rate = self.growth_per_sec().filter(|rate| *rate > 0.0)?;
+        Some(Duration::from_secs_f64((free as f64 / rate).min(u32::MAX as f64)))
+    }
+}
+
+fn process_refresh_kind() -> ProcessRefreshKind {
+    ProcessRefreshKind::nothing()
+        .with_cpu()
+        .with_memory()
+        .with_disk_usage()
+        .with_user(UpdateKind::OnlyIfNotSet)
+}
+
 pub struct SystemMonitor {
     system: System,
-    users_cache: UsersCache,
+    users: Users,
+    disks: Disks,
+    networks: Networks,
+    components: Components,
     prev_disk_usage: HashMap<Pid, DiskUsage>,
     prev_net_usage: HashMap<String, NetworkStats>,
+    process_cache: HashMap<Pid, CachedProcess>,
     last_update: Instant,
+    last_net_update: Instant,
     self_pid: u32,
+    wsl_version: Option<u8>,
+    leak_window: Duration,
+    // When the process cache last recorded resident memory.
+    last_memory_sample: Option<Instant>,
+    prev_cpu_times: HashMap<String, CpuTimes>,
+    prev_disk_counters: HashMap<String, DiskCounters>,
+    // Read and write bytes per second over all physical disks; None without
+    // /proc/diskstats.
+    disk_io: Option<(u64, u64)>,
+    last_disk_update: Instant,
+    disk_trends: HashMap<String, DiskTrend>,
 }
 
 impl SystemMonitor {
     pub fn new() -> Self {
-        let mut system = System::new_all();
-        system.refresh_all();
+        let system = System::new_with_specifics(
+            RefreshKind::nothing()
+                .with_cpu(CpuRefreshKind::everything())
+                .with_memory(MemoryRefreshKind::everything())
+                .with_processes(process_refresh_kind()),
+        );
+        let wsl_version = detect_wsl();
         
         Self {
             system,
-            users_cache: UsersCache::new(),
+            users: Users::new_with_refreshed_list(),
+            disks: Disks::new_with_refreshed_list_specifics(DiskRefreshKind::nothing().with_storage()),
+            networks: Networks::new_with_refreshed_list(),
+            components: if wsl_version.is_some() { Components::new() } else { Components::new_with_refreshed_list() },
             prev_disk_usage: HashMap::new(),
             prev_net_usage: HashMap::new(),
+            process_cache: HashMap::new(),
             last_update: Instant::now(),
+            last_net_update: Instant::now(),
             self_pid: std::process::id(),
+            wsl_version,
+            leak_window: Duration::from_secs(600),
+            last_memory_sample: None,
+            prev_cpu_times: HashMap::new(),
+            prev_disk_counters: HashMap::new(),
+            disk_io: None,
+            last_disk_update: Instant::now(),
+            disk_trends: HashMap::new(),
         }
     }
     
+    pub fn set_leak_window(&mut self, window: Duration) {
+        self.leak_window = window;
+    }
+    
     pub fn get_system_info(&self) -> Vec<(String, String)> {
-        vec![
+        let mut info = vec![
             ("OS".into(), System::long_os_version().unwrap_or_default()),
             ("Kernel".into(), System::kernel_version().unwrap_or_default()),
             ("Hostname".into(), System::host_name().unwrap_or_default()),
@@ -64,38 +243,59 @@ impl SystemMonitor {
                 let load = System::load_average();
                 format!("{:.2}, {:.2}, {:.2}", load.one, load.five, load.fifteen)
             }),
-        ]
+        ];
+        
+        if let Some(version) = self.wsl_version {
+            let distro = std::env::var("WSL_DISTRO_NAME").unwrap_or_default();
+            let environment = if distro.is_empty() {
+                format!("WSL {}", version)
+            } else {
+                format!("WSL {} ({})", version, distro)
+            };
+            info.push(("Environment".into(), environment));
+        }
+        
+        info
     }
 
+
     pub fn get_total_memory(&self) -> u64 {
         self.system.total_memory()
     }
     
-    pub fn update_processes(&mut self, show_system: bool, filter: &str) -> Vec<ProcessInfo> {
+    // CPU time and swap each take another read of a file in /proc/<pid> per
+    // process, so they are only filled in when asked for.
+    pub fn update_processes(&mut self, show_system: bool, filter: &str, container_filter: Option<&str>, with_cpu_time: bool, with_swap: bool) -> Vec<ProcessInfo> {
         let now = Instant::now();
         let elapsed_secs = now.duration_since(self.last_update).as_secs_f64().max(0.1);
         self.last_update = now;
-        self.system.refresh_cpu_all();
-        self.system.refresh_memory();
-        self.system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        self.system.refresh_processes_specifics(ProcessesToUpdate::All, true, process_refresh_kind());
+        self.refresh_process_cache();
         
         let total_cpu_count = self.system.cpus().len() as f32;
         let mut current_disk_usage = HashMap::new();
         let processes: Vec<ProcessInfo> = self.system.processes()
             .iter()
-            .filter(|(_pid, process)| {
+            .filter_map(|(pid, process)| self.process_cache.get(pid).map(|cached| (pid, process, cached)))
+            .filter(|(pid, _, cached)| {
                 /*
                 if pid.as_u32() == self.self_pid {
                     return false;
                 }
                 */
                 
-                if !show_system && is_system_process(&process.name().to_string_lossy()) {
+                if !show_system && cached.is_system {
                     return false;
                 }
                 
+                if let Some(container_id) = container_filter {
+                    if cached.container.as_deref() != Some(container_id) {
+                        return false;
+                    }
+                }
+                
                 if !filter.is_empty() {
-                    let search_text = format!("{} {}", process.name().to_string_lossy(), process.pid());
+                    let search_text = format!("{} {}", cached.name, pid);
                     if !matches_filter(&search_text, filter) {
                         return false;
                     }
@@ -103,7 +303,7 @@ impl SystemMonitor {
                 
                 true
             })
-            .map(|(pid, process)| {
+            .map(|(pid, process, cached)| {
                 let disk_usage = process.disk_usage();
                 let (read_rate, write_rate) = if let Some(prev) = self.prev_disk_usage.get(pid) {
                     let read_bytes = calculate_rate(
@@ -123,30 +323,42 @@ impl SystemMonitor {
                 
                 current_disk_usage.insert(*pid, disk_usage);
                 
-                let user = process.user_id()
-                    .and_then(|uid| self.users_cache.get_user_by_uid(**uid))
-                    .map_or("N/A".to_string(), |u| u.name().to_string_lossy().into_owned());
-                
                 let raw_cpu = process.cpu_usage();
                 let normalized_cpu = (raw_cpu / total_cpu_count).clamp(0.0, 100.0);
                 
                 let mut status = process.status().to_string();
                 
-                if pid.as_u32() == self.self_pid || normalized_cpu > 0.0 {
+                // A process stopped mid-sample still shows the CPU it used
+                // before the signal; it should read as stopped right away.
+                // One blocked on I/O may have used some CPU too.
+                let keep = matches!(process.status(), sysinfo::ProcessStatus::Stop | sysinfo::ProcessStatus::UninterruptibleDiskSleep);
+                if !keep && (pid.as_u32() == self.self_pid || normalized_cpu > 0.0) {
                      status = "Running".to_string();
                 }
 
                 ProcessInfo {
                     pid: pid.to_string(),
-                    name: process.name().to_string_lossy().to_string(),
+                    name: cached.name.clone(),
                     cpu: normalized_cpu,
                     cpu_display: format!("{:.2}%", normalized_cpu),
                     mem: process.memory(),
                     mem_display: format_size(process.memory()),
                     disk_read: format_rate(read_rate),
                     disk_write: format_rate(write_rate),
-                    user,
+                    disk_read_rate: read_rate,
+                    disk_write_rate: write_rate,
+                    mem_growth: cached.memory.growth_per_min(),
+                    leak_suspect: cached.memory.is_leak_suspect(self.leak_window),
+                    user: cached.user.clone(),
                     status,
+                    container: cached.container.clone(),
+                    parent: cached.parent.clone(),
+                    threads: thread_count(process).unwrap_or(0),
+                    start_time: process.start_time(),
+                    cpu_time: if cfg!(target_os = "linux") && with_cpu_time { read_cpu_time(*pid) } else { None },
+                    tree_prefix: String::new(),
+                    hidden_children: 0,
+                    swap: if cfg!(target_os = "linux") && with_swap { read_swap(*pid) } else { None },
                 }
             })
             .collect();
@@ -155,7 +367,65 @@ impl SystemMonitor {
         processes
     }
     
-    pub fn get_detailed_process(&self, pid: Pid) -> Option<DetailedProcessInfo> {
+    // Keeps the leak trends going on tabs that don't list processes. Only
+    // memory is refreshed, and only once a trend is due another sample.
+    pub fn sample_process_memory(&mut self) {
+        if self.last_memory_sample.is_some_and(|at| at.elapsed() < self.leak_window / LEAK_SAMPLES) {
+            return;
+        }
+        self.system.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::nothing().with_memory());
+        self.refresh_process_cache();
+    }
+    
+    fn refresh_process_cache(&mut self) {
+        let now = Instant::now();
+        self.last_memory_sample = Some(now);
+        let mut process_cache = HashMap::with_capacity(self.system.processes().len());
+        for (pid, process) in self.system.processes() {
+            let mut cached = match self.process_cache.remove(pid) {
+                Some(cached) if cached.start_time == process.start_time() => cached,
+                _ => CachedProcess {
+                    start_time: process.start_time(),
+                    name: String::new(),
+                    uid: None,
+                    user: "N/A".to_string(),
+                    parent: None,
+                    is_system: false,
+                    container: container_of_pid(*pid),
+                    memory: MemoryTrend::default(),
+                },
+            };
+            let name = process.name().to_string_lossy();
+            if cached.name != name || cached.name.is_empty() {
+                cached.name = name.into_owned();
+                cached.is_system = is_system_process(&cached.name);
+            }
+            let uid = process.user_id();
+            if cached.uid.as_ref() != uid {
+                cached.uid = uid.cloned();
+                cached.user = uid
+                    .and_then(|uid| self.users.get_user_by_id(uid))
+                    .map_or("N/A".to_string(), |u| u.name().to_string());
+            }
+            cached.parent = process.parent().map(|p| p.to_string());
+            cached.memory.record(now, process.memory(), self.leak_window);
+            process_cache.insert(*pid, cached);
+        }
+        self.process_cache = process_cache;
+    }
+    
+    pub fn get_detailed_process(&mut self, pid: Pid) -> Option<DetailedProcessInfo> {
+        // Command line, environment and cwd are only fetched for the process
+        // being inspected.
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            process_refresh_kind()
+                .with_cmd(UpdateKind::OnlyIfNotSet)
+                .with_environ(UpdateKind::Always)
+                .with_cwd(UpdateKind::Always),
+        );
+        
         self.system.process(pid).map(|process| {
             let start_time = if let chrono::LocalResult::Single(dt) = 
                 Utc.timestamp_opt(process.start_time() as i64, 0) {
@@ -164,9 +434,13 @@ impl SystemMonitor {
                 "Invalid time".to_string()
             };
             
-            let user = process.user_id()
-                .and_then(|uid| self.users_cache.get_user_by_uid(**uid))
-                .map_or("N/A".to_string(), |u| u.name().to_string_lossy().into_owned());
+            let cached = self.process_cache.get(&pid);
+            let user = match cached {
+                Some(cached) => cached.user.clone(),
+                None => process.user_id()
+                    .and_then(|uid| self.users.get_user_by_id(uid))
+                    .map_or("N/A".to_string(), |u| u.name().to_string()),
+            };
             
             DetailedProcessInfo {
                 pid: process.pid().to_string(),
@@ -176,54 +450,101 @@ impl SystemMonitor {
                 cpu_usage: process.cpu_usage(),
                 memory_rss: process.memory(),
                 memory_vms: process.virtual_memory(),
+                memory_swap: if cfg!(target_os = "linux") { read_swap(pid) } else { None },
                 command: process.cmd().iter().map(|s| s.to_string_lossy().to_string()).collect::<Vec<String>>().join(" "),
+                args: process.cmd().iter().map(|s| s.to_string_lossy().to_string()).collect(),
                 start_time,
-                parent: process.parent().map(|p| p.to_string()),
+                parent: match cached {
+                    Some(cached) => cached.parent.clone(),
+                    None => process.parent().map(|p| p.to_string()),
+                },
                 environ: process.environ().iter().map(|s| s.to_string_lossy().to_string()).collect(),
-                threads: process.tasks().map(|t| t.len() as u32).unwrap_or(0),
+                threads: thread_count(process).unwrap_or(0),
                 file_descriptors: None,
                 cwd: process.cwd().map(|p| p.to_string_lossy().into_owned()),
+                io: read_process_io(pid),
+                security: read_process_security(pid),
+                thread_list: read_threads(pid),
+                io_priority: read_io_priority(pid),
+                ..Default::default()
             }
         })
     }
     
-    pub fn get_cores(&self) -> Vec<CoreInfo> {
+    pub fn get_cores(&mut self) -> Vec<CoreInfo> {
+        let times = std::fs::read_to_string("/proc/stat").map(|stat| parse_proc_stat_cpus(&stat)).unwrap_or_default();
+        let prev = std::mem::replace(&mut self.prev_cpu_times, times);
         self.system.cpus().iter().map(|cpu| CoreInfo {
             usage: cpu.cpu_usage(),
             freq: cpu.frequency(),
             temp: None,
+            breakdown: self.prev_cpu_times.get(cpu.name())
+                .zip(prev.get(cpu.name()))
+                .map(|(now, before)| now.breakdown_since(before)),
         }).collect()
     }
     
-    pub fn get_disks(&self) -> Vec<DetailedDiskInfo> {
-        let disks = sysinfo::Disks::new_with_refreshed_list();
-        disks.iter().map(|disk| {
+    pub fn get_disks(&mut self) -> Vec<DetailedDiskInfo> {
+        self.disks.refresh_specifics(true, DiskRefreshKind::nothing().with_storage());
+        let now = Instant::now();
+        let elapsed_secs = now.duration_since(self.last_disk_update).as_secs_f64().max(0.1);
+        self.last_disk_update = now;
+        let counters = std::fs::read_to_string("/proc/diskstats").map(|stats| parse_diskstats(&stats)).unwrap_or_default();
+        let prev_counters = std::mem::replace(&mut self.prev_disk_counters, counters);
+        let counters = &self.prev_disk_counters;
+        self.disk_io = (!prev_counters.is_empty()).then(|| total_disk_rates(counters, &prev_counters, elapsed_secs, is_physical_disk));
+        let trends = &mut self.disk_trends;
+        trends.retain(|mount, _| self.disks.iter().any(|disk| disk.mount_point().to_string_lossy() == mount.as_str()));
+
+        self.disks.iter().filter_map(|disk| {
             let used = disk.total_space().saturating_sub(disk.available_space());
+            let mount_point = disk.mount_point().to_string_lossy().into_owned();
+            let mut device = disk.name().to_string_lossy().into_owned();
+            
+            if self.wsl_version.is_some() {
+                device = wsl_disk_label(&mount_point, &disk.file_system().to_string_lossy())?;
+            }
             
-            DetailedDiskInfo {
-                name: disk.mount_point().to_string_lossy().into_owned(),
-                device: disk.name().to_string_lossy().into_owned(),
+            let io_device = diskstats_name(&disk.name().to_string_lossy()).filter(|name| counters.contains_key(name));
+            let rates = io_device.as_ref()
+                .and_then(|name| Some(counters[name].rates_since(prev_counters.get(name)?, elapsed_secs)))
+                .unwrap_or_default();
+
+            let trend = trends.entry(mount_point.clone()).or_default();
+            trend.record(now, used);
+            let time_to_full = trend.time_to_full(disk.available_space()).map(|eta| eta.as_secs());
+
+            Some(DetailedDiskInfo {
+                name: mount_point,
+                device,
                 fs: disk.file_system().to_string_lossy().to_string(),
                 total: disk.total_space(),
                 free: disk.available_space(),
                 used,
-                read_rate: 0,
-                write_rate: 0,
-                read_ops: 0,
-                write_ops: 0,
+                read_rate: rates.read_bytes,
+                write_rate: rates.write_bytes,
+                read_ops: rates.reads,
+                write_ops: rates.writes,
                 is_ssd: None,
-            }
+                io_device,
+                read_history: VecDeque::new(),
+                write_history: VecDeque::new(),
+                time_to_full,
+            })
         }).collect()
     }
     
     pub fn get_networks(&mut self) -> Vec<DetailedNetInfo> {
         let now = Instant::now();
-        let elapsed_secs = now.duration_since(self.last_update).as_secs_f64().max(0.1);
+        let elapsed_secs = now.duration_since(self.last_net_update).as_secs_f64().max(0.1);
+        self.last_net_update = now;
         
         let mut current_net_usage = HashMap::new();
-        let networks = Networks::new_with_refreshed_list();
-        let networks: Vec<DetailedNetInfo> = networks
+        self.networks.refresh(true);
+        let wsl = self.wsl_version.is_some();
+        let networks: Vec<DetailedNetInfo> = self.networks
             .iter()
+            .filter(|(interface_name, _)| !wsl || !is_wsl_placeholder_interface(interface_name))
             .map(|(interface_name, data)| {
                 let (down_rate, up_rate) = if let Some(prev) = self.prev_net_usage.get(interface_name) {
                     let rx_rate = calculate_rate(data.total_received(), prev.rx, elapsed_secs);
@@ -251,7 +572,11 @@ impl SystemMonitor {
                     packets_tx: data.total_packets_transmitted(),
                     errors_rx: data.total_errors_on_received(),
                     errors_tx: data.total_errors_on_transmitted(),
-                    interface_type: "Unknown".to_string(),
+                    interface_type: if wsl && interface_name.starts_with("eth") {
+                        "Hyper-V virtual".to_string()
+                    } else {
+                        "Unknown".to_string()
+                    },
                     is_up: true, 
                 }
             })
@@ -271,6 +596,7 @@ impl SystemMonitor {
         let mem_available = self.system.available_memory();
         let mem_free = self.system.free_memory();
         let mem_cached = mem_available.saturating_sub(mem_free);
+        let tasks = std::fs::read_to_string("/proc/stat").map(|stat| parse_proc_stat_tasks(&stat)).unwrap_or_default();
 
         GlobalUsage {
             cpu: self.system.global_cpu_usage(),
@@ -289,33 +615,74 @@ impl SystemMonitor {
             load_average: (load.one, load.five, load.fifteen),
             uptime,
             boot_time,
+            memory: read_meminfo(),
+            run_queue: tasks.0.or_else(|| std::fs::read_to_string("/proc/loadavg").ok().and_then(|c| parse_run_queue(&c))),
+            blocked: tasks.1,
             ..Default::default()
         }
     }
     
-    pub fn get_temperatures(&self) -> SystemTemperatures {
-        SystemTemperatures {
-            cpu_temp: None,
-            gpu_temps: Vec::new(),
-            motherboard_temp: None,
+    pub fn get_temperatures(&mut self) -> SystemTemperatures {
+        // The WSL kernel has no hwmon devices; the host's sensors stay with Windows.
+        if self.wsl_version.is_some() {
+            return SystemTemperatures {
+                unavailable: Some("Sensors are not available under WSL".to_string()),
+                ..Default::default()
+            };
+        }
+        self.components.refresh(false);
+        let sensors: Vec<(&str, f32)> = self.components.list().iter()
+            .filter_map(|component| Some((component.label(), component.temperature()?)))
+            .collect();
+        classify_sensors(&sensors, read_nvme_sensors())
+    }
+    
+    // `refresh` is only needed when the full process list wasn't refreshed this
+    // cycle; refreshing twice would shrink the CPU sampling window to nothing.
+    pub fn get_self_usage(&mut self, refresh: bool) -> SelfUsage {
+        let pid = Pid::from_u32(self.self_pid);
+        if refresh {
+            self.system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, process_refresh_kind());
+        }
+        
+        let open_fds = if cfg!(target_os = "linux") {
+            std::fs::read_dir("/proc/self/fd").ok().map(|entries| entries.count() as u64)
+        } else {
+            None
+        };
+        
+        let cpu_count = self.system.cpus().len().max(1) as f32;
+        match self.system.process(pid) {
+            Some(process) => SelfUsage {
+                cpu: process.cpu_usage() / cpu_count,
+                memory: process.memory(),
+                virtual_memory: process.virtual_memory(),
+                threads: thread_count(process).unwrap_or(1),
+                open_fds,
+            },
+            None => SelfUsage { open_fds, ..Default::default() },
+        }
+    }
+    
+    pub fn refresh(&mut self, scope: RefreshScope) {
+        let mut cpu = CpuRefreshKind::nothing().with_cpu_usage();
+        if scope.cpu_frequency {
+            cpu = cpu.with_frequency();
         }
+        self.system.refresh_cpu_specifics(cpu);
+        self.system.refresh_memory_specifics(MemoryRefreshKind::everything());
     }
     
-    pub fn refresh(&mut self) {
-        self.system.refresh_cpu_all();
-        self.system.refresh_memory();
+    // From /proc/diskstats as of the last get_disks, so it keeps up on tabs
+    // that don't refresh processes.
+    pub fn disk_io(&self) -> Option<(u64, u64)> {
+        self.disk_io
     }
     
+    // Where there is no /proc/diskstats: the processes' own I/O rates.
     pub fn calculate_total_disk_io(&self, processes: &[ProcessInfo]) -> (u64, u64) {
-        let total_read = processes.iter()
-            .map(|p| p.disk_read.trim_end_matches(" B/s").trim_end_matches(" KB/s").trim_end_matches(" MB/s")
-                .parse::<f64>().unwrap_or(0.0) as u64)
-            .sum();
-        let total_write = processes.iter()
-            .map(|p| p.disk_write.trim_end_matches(" B/s").trim_end_matches(" KB/s").trim_end_matches(" MB/s")
-                .parse::<f64>().unwrap_or(0.0) as u64)
-            .sum();
-        
+        let total_read = processes.iter().map(|p| p.disk_read_rate).sum();
+        let total_write = processes.iter().map(|p| p.disk_write_rate).sum();
         (total_read, total_write)
     }
     
@@ -332,6 +699,457 @@ impl Default for SystemMonitor {
     }
 }
 
+// Container membership comes from cgroups, which only exist on Linux.
+pub fn container_of_pid(pid: Pid) -> Option<String> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    
+    std::fs::read_to_string(format!("/proc/{}/cgroup", pid))
+        .ok()
+        .and_then(|content| container_id_from_cgroup(&content))
+}
+
+pub fn container_id_from_cgroup(content: &str) -> Option<String> {
+    const PREFIXES: &[&str] = &["docker-", "libpod-", "cri-containerd-", "crio-"];
+    
+    for line in content.lines() {
+        let path = line.splitn(3, ':').nth(2).unwrap_or("");
+        for segment in path.rsplit('/') {
+            let mut id = segment.strip_suffix(".scope").unwrap_or(segment);
+            for prefix in PREFIXES {
+                if let Some(stripped) = id.strip_prefix(prefix) {
+                    id = stripped;
+                    break;
+                }
+            }
+            
+            if id.len() == 64 && id.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Some(id[..12].to_string());
+            }
+        }
+    }
+    
+    None
+}
+
+fn read_process_io(pid: Pid) -> Option<ProcessIo> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    
+    std::fs::read_to_string(format!("/proc/{}/io", pid))
+        .ok()
+        .map(|content| parse_proc_io(&content))
+}
+
+// ionice wraps ioprio_get/ioprio_set, which have no std binding.
+fn read_io_priority(pid: Pid) -> Option<IoPriority> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    
+    let output = std::process::Command::new("ionice").args(["-p", &pid.to_string()]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_ionice(&String::from_utf8_lossy(&output.stdout))
+}
+
+// "best-effort: prio 4", "none: prio 0", "idle", ...
+pub fn parse_ionice(output: &str) -> Option<IoPriority> {
+    let line = output.lines().next()?.trim();
+    let (class, level) = match line.split_once(':') {
+        Some((class, rest)) => (class, rest.trim().strip_prefix("prio ")?.parse().ok()?),
+        None => (line, 0),
+    };
+    let class = IoClass::ALL.into_iter().find(|c| c.label() == class)?;
+    Some(IoPriority { class, level })
+}
+
+pub fn set_io_priority(pid: Pid, priority: IoPriority) -> Result<(), String> {
+    let class = IoClass::ALL.iter().position(|c| *c == priority.class).unwrap_or(0).to_string();
+    let mut command = std::process::Command::new("ionice");
+    command.args(["-c", &class]);
+    if priority.class.has_level() {
+        command.args(["-n", &priority.level.to_string()]);
+    }
+    let output = command.args(["-p", &pid.to_string()]).output().map_err(|e| e.to_string())?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+// The process followed by all of its descendants, from a fresh process list.
+pub fn process_subtree(root: Pid) -> Vec<(Pid, String)> {
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::nothing());
+    let processes: Vec<(Pid, Option<Pid>, String)> = system.processes().values()
+        .filter(|process| process.thread_kind().is_none())
+        .map(|process| (process.pid(), process.parent(), process.name().to_string_lossy().into_owned()))
+        .collect();
+    subtree_of(root, &processes)
+}
+
+// Depth-first, each parent before its children; empty if the root is gone.
+pub fn subtree_of(root: Pid, processes: &[(Pid, Option<Pid>, String)]) -> Vec<(Pid, String)> {
+    let mut children: HashMap<Pid, Vec<usize>> = HashMap::new();
+    for (idx, (_, parent, _)) in processes.iter().enumerate() {
+        if let Some(parent) = parent {
+            children.entry(*parent).or_default().push(idx);
+        }
+    }
+    
+    let mut subtree = Vec::new();
+    let mut stack: Vec<usize> = processes.iter().position(|p| p.0 == root).into_iter().collect();
+    while let Some(idx) = stack.pop() {
+        let (pid, _, name) = &processes[idx];
+        subtree.push((*pid, name.clone()));
+        if let Some(kids) = children.get_mut(pid) {
+            kids.sort_by_key(|&kid| std::cmp::Reverse(processes[kid].0));
+            stack.extend(kids.iter());
+        }
+    }
+    subtree
+}
+
+// /proc reports CPU times in USER_HZ, which the kernel fixes at 100 for userspace.
+const CLOCK_TICKS_PER_SEC: u64 = 100;
+
+fn read_threads(pid: Pid) -> Vec<ThreadInfo> {
+    let Ok(entries) = std::fs::read_dir(format!("/proc/{}/task", pid)) else {
+        return Vec::new();
+    };
+    
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let tid = entry.file_name().to_str()?.parse().ok()?;
+            let stat = std::fs::read_to_string(entry.path().join("stat")).ok()?;
+            parse_task_stat(tid, &stat)
+        })
+        .collect()
+}
+
+// /proc/<pid>/stat has the same layout as a thread's.
+fn read_swap(pid: Pid) -> Option<u64> {
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    parse_vm_swap(&status)
+}
+
+// "VmSwap:     1234 kB" from /proc/<pid>/status; kernel threads have none.
+pub fn parse_vm_swap(content: &str) -> Option<u64> {
+    let kb: u64 = content.lines()
+        .find_map(|line| line.strip_prefix("VmSwap:"))?
+        .split_whitespace().next()?
+        .parse().ok()?;
+    Some(kb * 1024)
+}
+
+// sysinfo lists the threads in /proc/<pid>/task other than the main one.
+fn thread_count(process: &sysinfo::Process) -> Option<u32> {
+    process.tasks().map(|tasks| tasks.len() as u32 + 1)
+}
+
+fn read_cpu_time(pid: Pid) -> Option<u64> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    parse_task_stat(pid.as_u32(), &stat).map(|process| process.cpu_time.as_secs())
+}
+
+// Name, state and utime + stime from /proc/<pid>/task/<tid>/stat. The name
+// is wrapped in parentheses and may itself contain spaces or parentheses.
+pub fn parse_task_stat(tid: u32, content: &str) -> Option<ThreadInfo> {
+    let (head, rest) = content.rsplit_once(')')?;
+    let name = head.split_once('(')?.1.to_string();
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    let state = match *fields.first()? {
+        "R" => "Running",
+        "S" => "Sleeping",
+        "D" => "Disk Sleep",
+        "T" | "t" => "Stopped",
+        "Z" => "Zombie",
+        "I" => "Idle",
+        other => other,
+    };
+    
+    Some(ThreadInfo {
+        tid,
+        name,
+        state: state.to_string(),
+        cpu_usage: 0.0,
+        cpu_time: Duration::from_millis((utime + stime) * 1000 / CLOCK_TICKS_PER_SEC),
+    })
+}
+
+// Names and TIDs sort ascending, CPU figures busiest first, unless reversed.
+pub fn sort_threads(threads: &mut [ThreadInfo], key: ThreadSortBy, reversed: bool) {
+    threads.sort_by(|a, b| {
+        let ordering = match key {
+            ThreadSortBy::Cpu => b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal),
+            ThreadSortBy::CpuTime => b.cpu_time.cmp(&a.cpu_time),
+            ThreadSortBy::Tid => a.tid.cmp(&b.tid),
+            ThreadSortBy::Name => a.name.cmp(&b.name).then(a.tid.cmp(&b.tid)),
+        };
+        if reversed { ordering.reverse() } else { ordering }
+    });
+}
+
+fn read_process_security(pid: Pid) -> Option<ProcessSecurity> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let mut security = parse_status_security(&status);
+    
+    let lsm = if Path::new("/sys/fs/selinux/enforce").exists() {
+        Some(("SELinux", "current"))
+    } else if Path::new("/sys/kernel/security/apparmor").exists() {
+        // Kernels with LSM stacking keep the AppArmor view in its own directory.
+        Some(("AppArmor", if Path::new(&format!("/proc/{}/attr/apparmor", pid)).exists() { "apparmor/current" } else { "current" }))
+    } else {
+        None
+    };
+    if let Some((name, attr)) = lsm {
+        security.lsm = Some(name.to_string());
+        security.label = std::fs::read_to_string(format!("/proc/{}/attr/{}", pid, attr))
+            .ok()
+            .map(|label| label.trim_end_matches(['\0', '\n']).to_string())
+            .filter(|label| !label.is_empty());
+    }
+    Some(security)
+}
+
+// CapEff, NoNewPrivs and Seccomp from /proc/<pid>/status.
+pub fn parse_status_security(content: &str) -> ProcessSecurity {
+    let mut security = ProcessSecurity::default();
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(':') else { continue };
+        let value = value.trim();
+        match key {
+            "CapEff" => security.cap_eff = u64::from_str_radix(value, 16).unwrap_or(0),
+            "NoNewPrivs" => security.no_new_privs = value == "1",
+            "Seccomp" => security.seccomp = value.parse().unwrap_or(0),
+            _ => {}
+        }
+    }
+    security
+}
+
+pub fn parse_proc_io(content: &str) -> ProcessIo {
+    let mut io = ProcessIo::default();
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(':') else { continue };
+        let Ok(value) = value.trim().parse::<u64>() else { continue };
+        match key {
+            "rchar" => io.rchar = value,
+            "wchar" => io.wchar = value,
+            "syscr" => io.syscr = value,
+            "syscw" => io.syscw = value,
+            "read_bytes" => io.read_bytes = value,
+            "write_bytes" => io.write_bytes = value,
+            "cancelled_write_bytes" => io.cancelled_write_bytes = value,
+            _ => {}
+        }
+    }
+    io
+}
+
+// "0.64 0.40 0.17 2/73 27498": the fourth field is runnable/total tasks.
+pub fn parse_run_queue(loadavg: &str) -> Option<u32> {
+    loadavg.split_whitespace().nth(3)?.split_once('/')?.0.parse().ok()
+}
+
+// "procs_running" and "procs_blocked": tasks on a run queue right now and
+// tasks in uninterruptible sleep.
+pub fn parse_proc_stat_tasks(stat: &str) -> (Option<u32>, Option<u32>) {
+    let field = |name: &str| stat.lines()
+        .find_map(|line| line.strip_prefix(name)?.trim().parse().ok());
+    (field("procs_running "), field("procs_blocked "))
+}
+
+// The per-CPU "cpuN user nice system idle iowait irq softirq steal ..." lines
+// by "cpuN", as sysinfo names the CPUs; offline CPUs have no line, so the
+// numbering can have gaps. The aggregate "cpu" line is skipped.
+pub fn parse_proc_stat_cpus(stat: &str) -> HashMap<String, CpuTimes> {
+    stat.lines()
+        .filter(|line| line.starts_with("cpu") && line.as_bytes().get(3).is_some_and(u8::is_ascii_digit))
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            let name = words.next()?.to_string();
+            let fields: Vec<u64> = words.map(|v| v.parse().unwrap_or(0)).collect();
+            let field = |i: usize| fields.get(i).copied().unwrap_or(0);
+            (fields.len() >= 4).then(|| (name, CpuTimes {
+                user: field(0),
+                nice: field(1),
+                system: field(2),
+                idle: field(3),
+                iowait: field(4),
+                irq: field(5),
+                softirq: field(6),
+                steal: field(7),
+            }))
+        })
+        .collect()
+}
+
+// Cumulative counters of one /proc/diskstats line.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DiskCounters {
+    pub reads: u64,
+    pub read_sectors: u64,
+    pub writes: u64,
+    pub write_sectors: u64,
+}
+
+impl DiskCounters {
+    // Sectors are 512 bytes here whatever the device's own sector size.
+    pub fn rates_since(&self, before: &DiskCounters, elapsed_secs: f64) -> DiskRates {
+        let rate = |now: u64, then: u64| (now.saturating_sub(then) as f64 / elapsed_secs) as u64;
+        DiskRates {
+            read_bytes: rate(self.read_sectors, before.read_sectors) * 512,
+            write_bytes: rate(self.write_sectors, before.write_sectors) * 512,
+            reads: rate(self.reads, before.reads),
+            writes: rate(self.writes, before.writes),
+        }
+    }
+}
+
+// Per second.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DiskRates {
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+    pub reads: u64,
+    pub writes: u64,
+}
+
+// "major minor name reads merged sectors ms writes merged sectors ...", by
+// device name.
+pub fn parse_diskstats(stats: &str) -> HashMap<String, DiskCounters> {
+    stats.lines().filter_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let field = |i: usize| fields.get(i).and_then(|v| v.parse().ok());
+        Some((fields.get(2)?.to_string(), DiskCounters {
+            reads: field(3)?,
+            read_sectors: field(5)?,
+            writes: field(7)?,
+            write_sectors: field(9)?,
+        }))
+    }).collect()
+}
+
+// Summed over the devices `counted` accepts, so partitions, device-mapper
+// volumes and loop devices don't count the same I/O twice.
+pub fn total_disk_rates(
+    now: &HashMap<String, DiskCounters>,
+    before: &HashMap<String, DiskCounters>,
+    elapsed_secs: f64,
+    counted: impl Fn(&str) -> bool,
+) -> (u64, u64) {
+    now.iter()
+        .filter(|(name, _)| counted(name))
+        .filter_map(|(name, counters)| Some(counters.rates_since(before.get(name)?, elapsed_secs)))
+        .fold((0, 0), |(read, write), rates| (read + rates.read_bytes, write + rates.write_bytes))
+}
+
+// Whole disks have a /sys/block entry; stacked devices (dm, md) list the
+// devices under them in `slaves`.
+fn is_physical_disk(name: &str) -> bool {
+    if ["loop", "ram", "zram", "sr", "fd"].iter().any(|prefix| name.starts_with(prefix)) {
+        return false;
+    }
+    let block = Path::new("/sys/block").join(name);
+    block.exists() && std::fs::read_dir(block.join("slaves")).map_or(true, |mut slaves| slaves.next().is_none())
+}
+
+// The /proc/diskstats name of a mounted device: "/dev/nvme0n1p2" is
+// "nvme0n1p2", and "/dev/mapper/root" resolves to its "dm-0".
+fn diskstats_name(device: &str) -> Option<String> {
+    if !device.starts_with("/dev/") {
+        return None;
+    }
+    let path = std::fs::canonicalize(device).unwrap_or_else(|_| device.into());
+    path.file_name().map(|name| name.to_string_lossy().into_owned())
+}
+
+// The Composite temperature of every NVMe drive as (device path, model, °C).
+// sysinfo merges sensors with the same label, so two drives of one model
+// would show up as one; each hwmon directory is read here instead.
+fn read_nvme_sensors() -> Vec<(String, String, f32)> {
+    let Ok(entries) = std::fs::read_dir("/sys/class/hwmon") else {
+        return Vec::new();
+    };
+    let read = |path: &Path| std::fs::read_to_string(path).ok().map(|s| s.trim().to_string());
+    entries.flatten()
+        .map(|entry| entry.path())
+        .filter(|dir| read(&dir.join("name")).as_deref() == Some("nvme"))
+        .filter_map(|dir| {
+            let device = std::fs::canonicalize(dir.join("device")).ok()?.to_string_lossy().into_owned();
+            let model = read(&dir.join("device/model")).filter(|model| !model.is_empty()).unwrap_or_else(|| "NVMe".to_string());
+            let channel = (1..=8)
+                .find(|n| read(&dir.join(format!("temp{}_label", n))).as_deref() == Some("Composite"))
+                .unwrap_or(1);
+            let millidegrees: f32 = read(&dir.join(format!("temp{}_input", channel)))?.parse().ok()?;
+            Some((device, model, millidegrees / 1000.0))
+        })
+        .collect()
+}
+
+fn read_meminfo() -> Option<MemoryBreakdown> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    
+    std::fs::read_to_string("/proc/meminfo")
+        .ok()
+        .map(|content| parse_meminfo(&content))
+}
+
+pub fn parse_meminfo(content: &str) -> MemoryBreakdown {
+    let mut memory = MemoryBreakdown::default();
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(':') else { continue };
+        let Some(kib) = value.split_whitespace().next().and_then(|v| v.parse::<u64>().ok()) else { continue };
+        let bytes = kib * 1024;
+        match key {
+            "MemTotal" => memory.total = bytes,
+            "MemFree" => memory.free = bytes,
+            "MemAvailable" => memory.available = bytes,
+            "Buffers" => memory.buffers = bytes,
+            "Cached" => memory.cached = bytes,
+            "Shmem" => memory.shmem = bytes,
+            "SReclaimable" => memory.slab_reclaimable = bytes,
+            "SUnreclaim" => memory.slab_unreclaimable = bytes,
+            "Dirty" => memory.dirty = bytes,
+            "Writeback" => memory.writeback = bytes,
+            "SwapTotal" => memory.swap_total = bytes,
+            "SwapFree" => memory.swap_free = bytes,
+            "SwapCached" => memory.swap_cached = bytes,
+            _ => {}
+        }
+    }
+    memory
+}
+
+// Tells a process from a later one that got its PID.
+pub fn process_start_time(pid: Pid) -> Option<u64> {
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, ProcessRefreshKind::nothing());
+    system.process(pid).map(|process| process.start_time())
+}
+
+pub fn process_exists(pid: u32) -> bool {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, ProcessRefreshKind::nothing());
+    system.process(pid).is_some()
+}
+
 pub fn sort_processes(processes: &mut Vec<ProcessInfo>, sort_by: &ProcessSortBy, ascending: bool, total_memory: u64) {
     match sort_by {
         ProcessSortBy::Cpu => {
@@ -346,6 +1164,12 @@ pub fn sort_processes(processes: &mut Vec<ProcessInfo>, sort_by: &ProcessSortBy,
                 if ascending { cmp } else { cmp.reverse() }
             });
         },
+        ProcessSortBy::Swap => {
+            processes.sort_by(|a, b| {
+                let cmp = a.swap.cmp(&b.swap);
+                if ascending { cmp } else { cmp.reverse() }
+            });
+        },
         ProcessSortBy::Name => {
             processes.sort_by(|a, b| {
                 let cmp = a.name.cmp(&b.name);
@@ -360,9 +1184,15 @@ pub fn sort_processes(processes: &mut Vec<ProcessInfo>, sort_by: &ProcessSortBy,
                 if ascending { cmp } else { cmp.reverse() }
             });
         },
-        ProcessSortBy::DiskRead | ProcessSortBy::DiskWrite => {
+        ProcessSortBy::DiskRead => {
             processes.sort_by(|a, b| {
-                let cmp = a.cpu.partial_cmp(&b.cpu).unwrap_or(std::cmp::Ordering::Equal);
+                let cmp = a.disk_read_rate.cmp(&b.disk_read_rate);
+                if ascending { cmp } else { cmp.reverse() }
+            });
+        },
+        ProcessSortBy::DiskWrite => {
+            processes.sort_by(|a, b| {
+                let cmp = a.disk_write_rate.cmp(&b.disk_write_rate);
                 if ascending { cmp } else { cmp.reverse() }
             });
         },
@@ -377,16 +1207,196 @@ pub fn sort_processes(processes: &mut Vec<ProcessInfo>, sort_by: &ProcessSortBy,
     }
 }
 
+// Reorders sorted processes into a tree: each process follows its parent,
+// siblings keep their sorted order, and processes whose parent isn't listed
+// (filtered out or gone) start trees of their own. The descendants of a
+// collapsed pid are left out and counted on it instead.
+pub fn build_process_tree(processes: Vec<ProcessInfo>, collapsed: &std::collections::HashSet<String>) -> Vec<ProcessInfo> {
+    let index: HashMap<&str, usize> = processes.iter().enumerate().map(|(i, p)| (p.pid.as_str(), i)).collect();
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); processes.len()];
+    let mut roots = Vec::new();
+    for (i, process) in processes.iter().enumerate() {
+        match process.parent.as_deref().and_then(|parent| index.get(parent)).filter(|&&parent| parent != i) {
+            Some(&parent) => children[parent].push(i),
+            None => roots.push(i),
+        }
+    }
+
+    let mut visited = vec![false; processes.len()];
+    // Index, prefix and hidden descendants of each row, in display order.
+    let mut rows: Vec<(usize, String, usize)> = Vec::with_capacity(processes.len());
+    // Processes in a parent cycle are never reached from a root, so every
+    // process left over starts a tree too.
+    for start in roots.into_iter().chain(0..processes.len()) {
+        // Index, the guides its descendants inherit, and its own connector.
+        let mut stack = vec![(start, String::new(), "")];
+        while let Some((i, guides, connector)) = stack.pop() {
+            if visited[i] {
+                continue;
+            }
+            visited[i] = true;
+            let kids: Vec<usize> = children[i].iter().copied().filter(|&kid| !visited[kid]).collect();
+            let prefix = format!("{}{}", guides, connector);
+            if collapsed.contains(&processes[i].pid) && !kids.is_empty() {
+                let mut hidden = 0;
+                let mut pending = kids;
+                while let Some(kid) = pending.pop() {
+                    if !visited[kid] {
+                        visited[kid] = true;
+                        hidden += 1;
+                        pending.extend(children[kid].iter().copied());
+                    }
+                }
+                rows.push((i, prefix, hidden));
+                continue;
+            }
+            rows.push((i, prefix, 0));
+            let guides = match connector {
+                "├─ " => format!("{}│  ", guides),
+                "└─ " => format!("{}   ", guides),
+                _ => guides,
+            };
+            for (n, &kid) in kids.iter().enumerate().rev() {
+                let connector = if n + 1 == kids.len() { "└─ " } else { "├─ " };
+                stack.push((kid, guides.clone(), connector));
+            }
+        }
+    }
+
+    let mut processes: Vec<Option<ProcessInfo>> = processes.into_iter().map(Some).collect();
+    rows.into_iter()
+        .filter_map(|(i, prefix, hidden)| {
+            let mut process = processes[i].take()?;
+            process.tree_prefix = prefix;
+            process.hidden_children = hidden;
+            Some(process)
+        })
+        .collect()
+}
+
+// WSL exposes its plumbing as mounts: the distro's ext4.vhdx at /, Windows
+// drives through drvfs/9p under /mnt, and a handful of internal mounts that
+// only confuse the disk view. Returns None for mounts that should be hidden.
+pub fn wsl_disk_label(mount_point: &str, file_system: &str) -> Option<String> {
+    const INTERNAL_PREFIXES: &[&str] = &["/mnt/wsl", "/usr/lib/wsl", "/init", "/mnt/wslg"];
+    if INTERNAL_PREFIXES.iter().any(|p| mount_point.starts_with(p)) {
+        return None;
+    }
+    
+    if file_system == "9p" || file_system == "drvfs" {
+        let drive = mount_point.strip_prefix("/mnt/")
+            .filter(|d| d.len() == 1)
+            .map(|d| format!("{}:", d.to_uppercase()));
+        return Some(match drive {
+            Some(drive) => format!("Windows drive {}", drive),
+            None => "Windows share".to_string(),
+        });
+    }
+    
+    if mount_point == "/" {
+        return Some("Virtual disk (ext4.vhdx)".to_string());
+    }
+    
+    Some(format!("Virtual disk ({})", file_system))
+}
+
+// Tunnel and bonding devices created by the WSL2 kernel that are never up.
+fn is_wsl_placeholder_interface(name: &str) -> bool {
+    ["bond0", "dummy0", "sit0", "tunl0"].contains(&name)
+}
+
+// Sorts hwmon sensors, labelled "<driver> <label> [<model>] [tempN]", into
+// the CPU package (the hottest CPU sensor) and the board, and names NVMe
+// drives by model. Drives of the same model are numbered in device path
+// order, so "#2" stays on the same drive from one run to the next. GPU
+// sensors are left to the GPU monitor.
+fn classify_sensors(sensors: &[(&str, f32)], mut drives: Vec<(String, String, f32)>) -> SystemTemperatures {
+    const CPU_DRIVERS: [&str; 5] = ["coretemp", "k10temp", "zenpower", "cpu_thermal", "cpu-thermal"];
+    let hottest = |a: Option<f32>, b: f32| Some(a.map_or(b, |a| a.max(b)));
+    let mut temps = SystemTemperatures::default();
+    for &(label, temp) in sensors {
+        // Unconnected sensors read 0 or absurd values.
+        if !(1.0..=150.0).contains(&temp) {
+            continue;
+        }
+        let driver = label.split_whitespace().next().unwrap_or_default();
+        if CPU_DRIVERS.contains(&driver) {
+            temps.cpu_temp = hottest(temps.cpu_temp, temp);
+        } else if driver == "acpitz" {
+            temps.motherboard_temp = hottest(temps.motherboard_temp, temp);
+        }
+    }
+    drives.sort_by(|a, b| a.0.cmp(&b.0));
+    for (_, model, temp) in drives.iter().filter(|(_, _, temp)| (1.0..=150.0).contains(temp)) {
+        let same = temps.drives.iter()
+            .filter(|(drive, _)| drive == model || drive.starts_with(&format!("{} #", model)))
+            .count();
+        let name = if same > 0 { format!("{} #{}", model, same + 1) } else { model.clone() };
+        temps.drives.push((name, *temp));
+    }
+    temps
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_wsl_disk_label() {
+        assert_eq!(wsl_disk_label("/mnt/c", "9p").as_deref(), Some("Windows drive C:"));
+        assert_eq!(wsl_disk_label("/", "ext4").as_deref(), Some("Virtual disk (ext4.vhdx)"));
+        assert_eq!(wsl_disk_label("/mnt/wslg/distro", "ext4"), None);
+        assert_eq!(wsl_disk_label("/usr/lib/wsl/drivers", "9p"), None);
+    }
+    
     #[test]
     fn test_system_monitor_creation() {
         let monitor = SystemMonitor::new();
         assert!(monitor.system.cpus().len() > 0);
     }
     
+    #[test]
+    fn test_self_usage() {
+        let mut monitor = SystemMonitor::new();
+        let usage = monitor.get_self_usage(true);
+        assert!(usage.memory > 0);
+        assert!(usage.threads >= 1);
+        if cfg!(target_os = "linux") {
+            // The test harness's main thread and this test's.
+            assert!(usage.threads >= 2);
+            assert!(usage.open_fds.unwrap_or(0) > 0);
+        }
+    }
+    
+    #[test]
+    fn test_sample_process_memory() {
+        let mut monitor = SystemMonitor::new();
+        let pid = Pid::from_u32(std::process::id());
+        monitor.sample_process_memory();
+        assert_eq!(monitor.process_cache[&pid].memory.samples.len(), 1);
+        assert!(monitor.process_cache[&pid].memory.samples[0].1 > 0);
+    }
+    
+    #[test]
+    fn test_process_cache_detects_pid_reuse() {
+        let mut monitor = SystemMonitor::new();
+        let pid = Pid::from_u32(std::process::id());
+        monitor.refresh_process_cache();
+        let start_time = monitor.process_cache[&pid].start_time;
+        
+        // The name can change under the same PID (execve), the container can't.
+        let cached = monitor.process_cache.get_mut(&pid).unwrap();
+        cached.name = "cached".to_string();
+        cached.container = Some("cached".to_string());
+        monitor.refresh_process_cache();
+        assert_ne!(monitor.process_cache[&pid].name, "cached");
+        assert_eq!(monitor.process_cache[&pid].container.as_deref(), Some("cached"));
+        
+        monitor.process_cache.get_mut(&pid).unwrap().start_time = start_time + 1;
+        monitor.refresh_process_cache();
+        assert_ne!(monitor.process_cache[&pid].container.as_deref(), Some("cached"));
+    }
+    
     #[test]
     fn test_process_sorting() {
         let mut processes = vec![
@@ -399,8 +1409,20 @@ mod tests {
                 mem_display: "1.0 KiB".to_string(),
                 disk_read: "0 B/s".to_string(),
                 disk_write: "0 B/s".to_string(),
+                disk_read_rate: 0,
+                disk_write_rate: 4096,
+                mem_growth: 0,
+                leak_suspect: false,
                 user: "root".to_string(),
                 status: "Running".to_string(),
+                container: None,
+                parent: None,
+                threads: 1,
+                start_time: 0,
+                cpu_time: None,
+                tree_prefix: String::new(),
+                hidden_children: 0,
+                swap: Some(8192),
             },
             ProcessInfo {
                 pid: "2".to_string(),
@@ -411,8 +1433,20 @@ mod tests {
                 mem_display: "2.0 KiB".to_string(),
                 disk_read: "0 B/s".to_string(),
                 disk_write: "0 B/s".to_string(),
+                disk_read_rate: 0,
+                disk_write_rate: 0,
+                mem_growth: 0,
+                leak_suspect: false,
                 user: "root".to_string(),
                 status: "Running".to_string(),
+                container: None,
+                parent: None,
+                threads: 1,
+                start_time: 0,
+                cpu_time: None,
+                tree_prefix: String::new(),
+                hidden_children: 0,
+                swap: None,
             },
         ];
         
@@ -421,5 +1455,310 @@ mod tests {
         
         sort_processes(&mut processes, &ProcessSortBy::Memory, false, 8192 * 1024 * 1024);
         assert_eq!(processes[0].name, "kthreadd");
+        
+        sort_processes(&mut processes, &ProcessSortBy::DiskWrite, false, 8192 * 1024 * 1024);
+        assert_eq!(processes[0].name, "init");
+        
+        sort_processes(&mut processes, &ProcessSortBy::Cpu, false, 8192 * 1024 * 1024);
+        sort_processes(&mut processes, &ProcessSortBy::Swap, false, 8192 * 1024 * 1024);
+        assert_eq!(processes[0].name, "init");
+        
+        let mut key = ProcessSortBy::Cpu;
+        for _ in 0..8 {
+            key = key.next();
+        }
+        assert_eq!(key, ProcessSortBy::Cpu);
+        assert_eq!(ProcessSortBy::General.next(), ProcessSortBy::Pid);
+    }
+
+    #[test]
+    fn test_build_process_tree() {
+        let process = |pid: &str, parent: &str| ProcessInfo {
+            pid: pid.to_string(),
+            parent: Some(parent.to_string()),
+            ..Default::default()
+        };
+        // Already sorted; siblings keep this order. 7 and 8 are each other's
+        // parent and 30's parent isn't listed.
+        let processes = vec![
+            process("12", "10"),
+            process("1", "0"),
+            process("10", "1"),
+            process("30", "99"),
+            process("11", "10"),
+            process("7", "8"),
+            process("20", "1"),
+            process("8", "7"),
+            process("13", "12"),
+        ];
+        let rows = |collapsed: &[&str]| -> Vec<String> {
+            let collapsed = collapsed.iter().map(|pid| pid.to_string()).collect();
+            build_process_tree(processes.clone(), &collapsed).iter()
+                .map(|p| format!("{}{}+{}", p.tree_prefix, p.pid, p.hidden_children))
+                .collect()
+        };
+        assert_eq!(rows(&[]), vec![
+            "1+0", "├─ 10+0", "│  ├─ 12+0", "│  │  └─ 13+0", "│  └─ 11+0", "└─ 20+0", "30+0", "7+0", "└─ 8+0",
+        ]);
+        assert_eq!(rows(&["10", "20"]), vec!["1+0", "├─ 10+3", "└─ 20+0", "30+0", "7+0", "└─ 8+0"]);
+    }
+    
+    #[test]
+    fn test_memory_trend() {
+        let window = Duration::from_secs(600);
+        let start = Instant::now();
+        let mut trend = MemoryTrend::default();
+        for minute in 0..=10u64 {
+            trend.record(start + Duration::from_secs(minute * 60), 100 + minute * 6, window);
+        }
+        assert_eq!(trend.growth_per_min(), 6);
+        assert!(trend.is_leak_suspect(window));
+        
+        // Samples closer than window / LEAK_SAMPLES apart are skipped.
+        trend.record(start + Duration::from_secs(610), 50, window);
+        assert!(trend.is_leak_suspect(window));
+        
+        // A dip anywhere in the window clears the suspicion.
+        trend.record(start + Duration::from_secs(660), 50, window);
+        assert!(!trend.is_leak_suspect(window));
+        
+        // Growing, but not watched for a full window yet.
+        let mut young = MemoryTrend::default();
+        young.record(start, 100, window);
+        young.record(start + Duration::from_secs(60), 200, window);
+        assert_eq!(young.growth_per_min(), 100);
+        assert!(!young.is_leak_suspect(window));
+    }
+    
+    #[test]
+    fn test_parse_proc_io() {
+        let content = "rchar: 1000000\nwchar: 4096\nsyscr: 250\nsyscw: 2\n\
+                       read_bytes: 250000\nwrite_bytes: 8192\ncancelled_write_bytes: 4096\n";
+        let io = parse_proc_io(content);
+        assert_eq!(io.syscr, 250);
+        assert_eq!(io.cancelled_write_bytes, 4096);
+        assert_eq!(io.cache_hit_percent(), Some(75.0));
+        assert_eq!(ProcessIo::default().cache_hit_percent(), None);
+        
+        // Our own process is always readable.
+        if cfg!(target_os = "linux") {
+            assert!(read_process_io(Pid::from_u32(std::process::id())).is_some());
+        }
+    }
+    
+    #[test]
+    fn test_parse_run_queue() {
+        assert_eq!(parse_run_queue("0.64 0.40 0.17 2/73 27498\n"), Some(2));
+        assert_eq!(parse_run_queue("0.64 0.40 0.17"), None);
+    }
+    
+    #[test]
+    fn test_disk_trend() {
+        let start = Instant::now();
+        let mut trend = DiskTrend::default();
+        // 1 MB a minute, with a burst in the middle.
+        for minute in 0..10u64 {
+            let burst = if minute == 5 { 20_000_000 } else { 0 };
+            trend.record(start + Duration::from_secs(minute * 60), minute * 1_000_000 + burst);
+            if minute < 5 {
+                assert_eq!(trend.time_to_full(1), None, "too little history after {} minutes", minute);
+            }
+        }
+        let eta = trend.time_to_full(1_000_000_000).unwrap();
+        let days = eta.as_secs_f64() / 86400.0;
+        assert!((0.6..0.8).contains(&days), "{} days", days);
+
+        let mut shrinking = DiskTrend::default();
+        for minute in 0..10u64 {
+            shrinking.record(start + Duration::from_secs(minute * 60), 50_000_000 - minute * 1_000_000);
+        }
+        assert_eq!(shrinking.time_to_full(1_000_000_000), None);
+    }
+
+    #[test]
+    fn test_parse_diskstats() {
+        let stats = "   8       0 sda 1000 20 80000 300 500 10 40000 200 0 400 500 0 0 0 0\n 253       0 dm-0 10 0 160 1 0 0 0 0 0 1 1\n   7       0 loop0 bad\n";
+        let counters = parse_diskstats(stats);
+        assert_eq!(counters.len(), 2);
+        assert_eq!(counters["sda"], DiskCounters { reads: 1000, read_sectors: 80000, writes: 500, write_sectors: 40000 });
+
+        let later = DiskCounters { reads: 1100, read_sectors: 82000, writes: 700, write_sectors: 40000 };
+        assert_eq!(later.rates_since(&counters["sda"], 2.0), DiskRates { read_bytes: 512_000, write_bytes: 0, reads: 50, writes: 100 });
+        assert_eq!(diskstats_name("overlay"), None);
+
+        let now = HashMap::from([("sda".to_string(), later), ("sda1".to_string(), later)]);
+        assert_eq!(total_disk_rates(&now, &counters, 2.0, |name| name == "sda"), (512_000, 0));
+        assert_eq!(total_disk_rates(&now, &counters, 2.0, |name| name == "sda1"), (0, 0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_proc_stat_cpus() {
+        let before = parse_proc_stat_cpus("\
+cpu  200 0 100 1000 20 0 0 0 0 0
+cpu0 100 0 50 500 10 0 0 0 0 0
+cpu1 100 0 50 500 10 0 0 0 0 0
+intr 12345
+");
+        assert_eq!(before.len(), 2);
+        // cpu1 went offline and cpu2 came up in between.
+        let after = parse_proc_stat_cpus("\
+cpu0 130 10 66 512 18 3 1 20 0 0
+cpu2 100 0 50 600 10 0 0 0 0 0
+");
+        let breakdown = after["cpu0"].breakdown_since(&before["cpu0"]);
+        assert_eq!(breakdown, CpuBreakdown { user: 40.0, system: 16.0, iowait: 8.0, irq: 4.0, steal: 20.0 });
+        assert!(!after.contains_key("cpu1"));
+        assert!(!before.contains_key("cpu2"));
+    }
+
+    #[test]
+    fn test_parse_proc_stat_tasks() {
+        assert_eq!(parse_proc_stat_tasks("cpu  1 2 3 4\nprocs_running 3\nprocs_blocked 12\n"), (Some(3), Some(12)));
+        assert_eq!(parse_proc_stat_tasks("cpu  1 2 3 4\n"), (None, None));
+    }
+
+    #[test]
+    fn test_parse_ionice() {
+        assert_eq!(parse_ionice("best-effort: prio 4\n"), Some(IoPriority { class: IoClass::BestEffort, level: 4 }));
+        assert_eq!(parse_ionice("realtime: prio 0"), Some(IoPriority { class: IoClass::Realtime, level: 0 }));
+        assert_eq!(parse_ionice("idle\n"), Some(IoPriority { class: IoClass::Idle, level: 0 }));
+        assert_eq!(parse_ionice("ionice: ioprio_get failed"), None);
+        assert_eq!(IoPriority { class: IoClass::BestEffort, level: 7 }.to_string(), "best-effort 7");
+        assert_eq!(IoPriority { class: IoClass::Idle, level: 3 }.to_string(), "idle");
+    }
+    
+    #[test]
+    fn test_subtree_of() {
+        let pid = Pid::from_u32;
+        let processes = vec![
+            (pid(1), None, "init".to_string()),
+            (pid(10), Some(pid(1)), "make".to_string()),
+            (pid(12), Some(pid(10)), "cc".to_string()),
+            (pid(11), Some(pid(10)), "sh".to_string()),
+            (pid(13), Some(pid(11)), "ld".to_string()),
+            (pid(20), Some(pid(1)), "sshd".to_string()),
+        ];
+        let pids = |root| subtree_of(pid(root), &processes).into_iter().map(|(p, _)| p.as_u32()).collect::<Vec<_>>();
+        assert_eq!(pids(10), vec![10, 11, 13, 12]);
+        assert_eq!(pids(20), vec![20]);
+        assert!(pids(99).is_empty());
+        
+        assert_eq!(process_subtree(pid(std::process::id())).first().map(|(p, _)| *p), Some(pid(std::process::id())));
+        assert!(process_start_time(pid(std::process::id())).is_some());
+    }
+    
+    #[test]
+    fn test_threads() {
+        let stat = "4242 (tokio-runtime (w)) S 4200 4200 4200 0 -1 4194368 10 0 0 0 250 50 0 0 20 0 12 0 100 0 0";
+        let thread = parse_task_stat(4242, stat).unwrap();
+        assert_eq!(thread.name, "tokio-runtime (w)");
+        assert_eq!(thread.state, "Sleeping");
+        assert_eq!(thread.cpu_time, Duration::from_secs(3));
+        assert!(parse_task_stat(1, "1 (init").is_none());
+        
+        let mut threads = vec![
+            ThreadInfo { tid: 3, name: "worker".to_string(), cpu_usage: 5.0, ..Default::default() },
+            ThreadInfo { tid: 1, name: "main".to_string(), cpu_usage: 80.0, ..Default::default() },
+            ThreadInfo { tid: 2, name: "io".to_string(), cpu_usage: 20.0, ..Default::default() },
+        ];
+        let tids = |threads: &[ThreadInfo]| threads.iter().map(|t| t.tid).collect::<Vec<_>>();
+        sort_threads(&mut threads, ThreadSortBy::Cpu, false);
+        assert_eq!(tids(&threads), vec![1, 2, 3]);
+        sort_threads(&mut threads, ThreadSortBy::Name, false);
+        assert_eq!(tids(&threads), vec![2, 1, 3]);
+        sort_threads(&mut threads, ThreadSortBy::Tid, true);
+        assert_eq!(tids(&threads), vec![3, 2, 1]);
+        
+        if cfg!(target_os = "linux") {
+            assert!(read_threads(Pid::from_u32(std::process::id())).iter().any(|t| t.tid == std::process::id()));
+            assert!(read_cpu_time(Pid::from_u32(std::process::id())).is_some());
+        }
+    }
+    
+    #[test]
+    fn test_parse_status_security() {
+        let content = "Name:\tnginx\nCapEff:\t0000000000003000\nNoNewPrivs:\t1\nSeccomp:\t2\n";
+        let security = parse_status_security(content);
+        assert_eq!(security.capabilities(), vec!["cap_net_admin", "cap_net_raw"]);
+        assert_eq!(security.capabilities_summary(), "cap_net_admin, cap_net_raw");
+        assert!(security.no_new_privs);
+        assert_eq!(security.seccomp_mode(), "filter");
+        
+        let root = parse_status_security("CapEff:\t000001fffeffffff\n");
+        assert_eq!(root.capabilities_summary(), "all except cap_sys_resource");
+        assert_eq!(parse_status_security("CapEff:\t0000000000000000\n").capabilities_summary(), "none");
+        
+        assert_eq!(parse_vm_swap("Name:\tjava\nVmRSS:\t  204800 kB\nVmSwap:\t   51200 kB\n"), Some(50 * 1024 * 1024));
+        assert_eq!(parse_vm_swap("Name:\tkthreadd\nThreads:\t1\n"), None);
+    }
+    
+    #[test]
+    fn test_parse_meminfo() {
+        let content = "MemTotal:        8000000 kB\n\
+                       MemFree:         1000000 kB\n\
+                       MemAvailable:    5000000 kB\n\
+                       Buffers:          200000 kB\n\
+                       Cached:          3000000 kB\n\
+                       SwapCached:         1000 kB\n\
+                       SwapTotal:       2000000 kB\n\
+                       SwapFree:        1500000 kB\n\
+                       Dirty:              4000 kB\n\
+                       Writeback:             0 kB\n\
+                       Shmem:            100000 kB\n\
+                       SReclaimable:     400000 kB\n\
+                       SUnreclaim:       100000 kB\n\
+                       HugePages_Total:       0\n";
+        let memory = parse_meminfo(content);
+        assert_eq!(memory.total, 8_000_000 * 1024);
+        assert_eq!(memory.available, 5_000_000 * 1024);
+        assert_eq!(memory.slab_reclaimable, 400_000 * 1024);
+        assert_eq!(memory.swap_cached, 1000 * 1024);
+        assert_eq!(memory.apps(), 3_300_000 * 1024);
+        
+        let total: u64 = memory.segments().iter().map(|(_, size)| size).sum();
+        assert_eq!(total, memory.total);
+    }
+    
+    #[test]
+    fn test_container_id_from_cgroup() {
+        let id = "4f1c2d3e4b5a69788796a5b4c3d2e1f0a1b2c3d4e5f60718293a4b5c6d7e8f90";
+        
+        let v2 = format!("0::/system.slice/docker-{}.scope\n", id);
+        assert_eq!(container_id_from_cgroup(&v2), Some("4f1c2d3e4b5a".to_string()));
+        
+        let v1 = format!("12:memory:/docker/{}\n11:cpu:/docker/{}\n", id, id);
+        assert_eq!(container_id_from_cgroup(&v1), Some("4f1c2d3e4b5a".to_string()));
+        
+        let podman = format!("0::/user.slice/libpod-{}.scope/container\n", id);
+        assert_eq!(container_id_from_cgroup(&podman), Some("4f1c2d3e4b5a".to_string()));
+        
+        assert_eq!(container_id_from_cgroup("0::/user.slice/user-1000.slice/session-2.scope\n"), None);
+    }
+
+    #[test]
+    fn test_classify_sensors() {
+        let drive = |device: &str, model: &str, temp: f32| (device.to_string(), model.to_string(), temp);
+        let temps = classify_sensors(&[
+            ("coretemp Package id 0", 64.0),
+            ("coretemp Core 0", 71.0),
+            ("acpitz temp1", 40.0),
+            ("nvme Composite Samsung SSD 980 temp1", 45.0),
+            ("amdgpu edge", 55.0),
+            ("iwlwifi_1 temp1", 0.0),
+        ], vec![
+            drive("/sys/devices/pci0000:00/0000:00:1d.0/0000:3e:00.0/nvme/nvme1", "Samsung SSD 980", 47.0),
+            drive("/sys/devices/pci0000:00/0000:00:1c.4/0000:3c:00.0/nvme/nvme0", "WD Blue SN570", 39.0),
+            drive("/sys/devices/pci0000:00/0000:00:1d.0/0000:3d:00.0/nvme/nvme2", "Samsung SSD 980", 45.0),
+        ]);
+        assert_eq!(temps.cpu_temp, Some(71.0));
+        assert_eq!(temps.motherboard_temp, Some(40.0));
+        // Numbered by device path, not by the order the drives were found in.
+        assert_eq!(temps.drives, vec![
+            ("WD Blue SN570".to_string(), 39.0),
+            ("Samsung SSD 980".to_string(), 45.0),
+            ("Samsung SSD 980 #2".to_string(), 47.0),
+        ]);
+        assert_eq!(temps.readings().len(), 5);
+        assert!(classify_sensors(&[], vec![drive("/sys/devices/nvme0", "NVMe", 0.0)]).drives.is_empty());
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::time::{Duration, Instant};
+
+use crate::types::TcpStats;
+use crate::utils::update_history;
+
+// Fewer segments than this in a sample make the retransmission share noise.
+const MIN_SEGMENTS: u64 = 100;
+const DROP_WINDOW: Duration = Duration::from_secs(60);
+
+// The TCP counters behind "the network feels slow": retransmissions, resets
+// and connections dropped at listening sockets from /proc/net/snmp and
+// /proc/net/netstat, and socket counts and buffer memory from
+// /proc/net/sockstat. The first sample only primes the counters.
+pub struct TcpMonitor {
+    previous: Option<(Instant, TcpCounters)>,
+    page_size: u64,
+    retrans_history: VecDeque<u64>,
+    reset_history: VecDeque<u64>,
+    drop_history: VecDeque<u64>,
+    // Listen drops per sample over the last minute, for the alert.
+    recent_drops: VecDeque<(Instant, u64)>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TcpCounters {
+    pub out_segments: u64,
+    pub retransmitted: u64,
+    pub resets_sent: u64,
+    pub listen_drops: u64,
+    pub prunes: u64,
+}
+
+impl TcpCounters {
+    pub fn parse(snmp: &str, netstat: &str) -> Option<Self> {
+        let tcp = parse_counters(snmp, "Tcp");
+        let ext = parse_counters(netstat, "TcpExt");
+        Some(Self {
+            out_segments: *tcp.get("OutSegs")?,
+            retransmitted: *tcp.get("RetransSegs")?,
+            resets_sent: *tcp.get("OutRsts")?,
+            // ListenOverflows is counted in ListenDrops as well.
+            listen_drops: ext.get("ListenDrops").copied().unwrap_or(0),
+            prunes: ext.get("PruneCalled").copied().unwrap_or(0),
+        })
+    }
+}
+
+impl TcpMonitor {
+    pub fn new() -> Self {
+        Self {
+            previous: None,
+            page_size: read_page_size().unwrap_or(4096),
+            retrans_history: VecDeque::new(),
+            reset_history: VecDeque::new(),
+            drop_history: VecDeque::new(),
+            recent_drops: VecDeque::new(),
+        }
+    }
+
+    // None off Linux, or until there are two samples to compare.
+    pub fn collect(&mut self, history_length: usize) -> Option<TcpStats> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+        let snmp = fs::read_to_string("/proc/net/snmp").ok()?;
+        let netstat = fs::read_to_string("/proc/net/netstat").unwrap_or_default();
+        let counters = TcpCounters::parse(&snmp, &netstat)?;
+        let now = Instant::now();
+        let (at, previous) = self.previous.replace((now, counters))?;
+
+        let seconds = now.duration_since(at).as_secs_f64().max(0.1);
+        let delta = |current: u64, previous: u64| current.saturating_sub(previous);
+        let segments = delta(counters.out_segments, previous.out_segments);
+        let retransmitted = delta(counters.retransmitted, previous.retransmitted);
+        let resets = delta(counters.resets_sent, previous.resets_sent);
+        let drops = delta(counters.listen_drops, previous.listen_drops);
+        let retrans_percent = (segments >= MIN_SEGMENTS).then(|| retransmitted as f64 / segments as f64 * 100.0);
+
+        // Hundredths of a percent, so the chart keeps small shares.
+        update_history(&mut self.retrans_history, retrans_percent.map_or(0, |percent| (percent * 100.0) as u64), history_length);
+        update_history(&mut self.reset_history, (resets as f64 / seconds).round() as u64, history_length);
+        update_history(&mut self.drop_history, drops, history_length);
+        self.recent_drops.push_back((now, drops));
+        while self.recent_drops.front().is_some_and(|(at, _)| now.duration_since(*at) > DROP_WINDOW) {
+            self.recent_drops.pop_front();
+        }
+
+        let sockstat = fs::read_to_string("/proc/net/sockstat").map(|c| parse_counters_line(&c, "TCP:")).unwrap_or_default();
+        let sockets = |key: &str| sockstat.get(key).copied().unwrap_or(0);
+        Some(TcpStats {
+            retrans_percent,
+            resets_per_sec: resets as f64 / seconds,
+            listen_drops_per_sec: drops as f64 / seconds,
+            listen_drops_last_minute: self.recent_drops.iter().map(|(_, drops)| drops).sum(),
+            listen_drops_total: counters.listen_drops,
+            prunes_per_sec: delta(counters.prunes, previous.prunes) as f64 / seconds,
+            sockets_in_use: sockets("inuse"),
+            orphaned: sockets("orphan"),
+            time_wait: sockets("tw"),
+            buffer_bytes: sockets("mem") * self.page_size,
+            retrans_history: self.retrans_history.clone(),
+            reset_history: self.reset_history.clone(),
+            drop_history: self.drop_history.clone(),
+        })
+    }
+}
+
+// /proc/net/snmp and /proc/net/netstat come in line pairs, a header of
+// counter names and then their values, both starting with "Proto:".
+// Negative values (Tcp MaxConn is -1) are left out.
+pub fn parse_counters(content: &str, protocol: &str) -> HashMap<String, u64> {
+    let prefix = format!("{}:", protocol);
+    let mut lines = content.lines().filter(|line| line.split_whitespace().next() == Some(prefix.as_str()));
+    let (Some(names), Some(values)) = (lines.next(), lines.next()) else { return HashMap::new() };
+    names.split_whitespace().zip(values.split_whitespace())
+        .skip(1)
+        .filter_map(|(name, value)| Some((name.to_string(), value.parse().ok()?)))
+        .collect()
+}
+
+// /proc/net/sockstat: "TCP: inuse 4 orphan 0 tw 0 alloc 4 mem 0".
+fn parse_counters_line(content: &str, prefix: &str) -> HashMap<String, u64> {
+    let Some(line) = content.lines().find_map(|line| line.strip_prefix(prefix)) else { return HashMap::new() };
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    fields.chunks(2)
+        .filter_map(|pair| Some((pair.first()?.to_string(), pair.get(1)?.parse().ok()?)))
+        .collect()
+}
+
+// sockstat counts buffer memory in pages.
+fn read_page_size() -> Option<u64> {
+    let smaps = fs::read_to_string("/proc/self/smaps").ok()?;
+    let kb: u64 = smaps.lines()
+        .find_map(|line| line.strip_prefix("KernelPageSize:"))?
+        .split_whitespace().next()?
+        .parse().ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tcp_counters() {
+        let snmp = "\
+Ip: Forwarding DefaultTTL InReceives
+Ip: 1 64 12345
+Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens PassiveOpens AttemptFails EstabResets CurrEstab InSegs OutSegs RetransSegs InErrs OutRsts InCsumErrors
+Tcp: 1 200 120000 -1 500 20 3 7 4 90000 88000 1200 0 310 0
+Udp: InDatagrams NoPorts
+Udp: 10 2
+";
+        let netstat = "\
+TcpExt: SyncookiesSent PruneCalled ListenOverflows ListenDrops
+TcpExt: 0 5 40 42
+IpExt: InNoRoutes
+IpExt: 0
+";
+        assert!(!parse_counters(snmp, "Tcp").contains_key("MaxConn"));
+        assert_eq!(TcpCounters::parse(snmp, netstat), Some(TcpCounters {
+            out_segments: 88000,
+            retransmitted: 1200,
+            resets_sent: 310,
+            listen_drops: 42,
+            prunes: 5,
+        }));
+        assert_eq!(TcpCounters::parse("Ip: Forwarding\nIp: 1\n", netstat), None);
+
+        let sockstat = parse_counters_line("sockets: used 19\nTCP: inuse 4 orphan 1 tw 9 alloc 4 mem 3\n", "TCP:");
+        assert_eq!((sockstat["inuse"], sockstat["tw"], sockstat["mem"]), (4, 9, 3));
+    }
+}
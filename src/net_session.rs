@@ -0,0 +1,77 @@
+// Traffic per interface since puls started, next to the interface's own
+// totals, which count from when it came up and are no help for "how much did
+// that transfer just move". `r` on the Network tab starts the count again.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Local};
+
+use crate::types::DetailedNetInfo;
+
+#[derive(Clone, Debug, Default)]
+pub struct NetSession {
+    // None until the first network sample.
+    pub since: Option<DateTime<Local>>,
+    // Received and transmitted totals of each interface when the count began.
+    start: HashMap<String, (u64, u64)>,
+}
+
+impl NetSession {
+    pub fn observe(&mut self, networks: &[DetailedNetInfo]) {
+        if networks.is_empty() {
+            return;
+        }
+        if self.since.is_none() {
+            self.reset(networks);
+            return;
+        }
+        for net in networks {
+            let start = self.start.entry(net.name.clone()).or_insert((0, 0));
+            // Interfaces that show up later, or whose counters went back
+            // because they were recreated, moved all of it this session.
+            if net.total_down < start.0 || net.total_up < start.1 {
+                *start = (0, 0);
+            }
+        }
+    }
+
+    pub fn reset(&mut self, networks: &[DetailedNetInfo]) {
+        self.since = Some(Local::now());
+        self.start = networks.iter().map(|net| (net.name.clone(), (net.total_down, net.total_up))).collect();
+    }
+
+    // Bytes received and transmitted since the count began.
+    pub fn traffic(&self, net: &DetailedNetInfo) -> (u64, u64) {
+        let (down, up) = self.start.get(&net.name).copied().unwrap_or_default();
+        (net.total_down.saturating_sub(down), net.total_up.saturating_sub(up))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_net_session() {
+        let net = |name: &str, down: u64, up: u64| DetailedNetInfo {
+            name: name.to_string(),
+            total_down: down,
+            total_up: up,
+            ..Default::default()
+        };
+        let mut session = NetSession::default();
+        session.observe(&[]);
+        assert!(session.since.is_none());
+
+        session.observe(&[net("eth0", 1000, 500)]);
+        assert_eq!(session.traffic(&net("eth0", 1800, 700)), (800, 200));
+
+        // A new interface and one recreated with fresh counters.
+        session.observe(&[net("eth0", 300, 100), net("wg0", 40, 20)]);
+        assert_eq!(session.traffic(&net("eth0", 350, 100)), (350, 100));
+        assert_eq!(session.traffic(&net("wg0", 40, 20)), (40, 20));
+
+        session.reset(&[net("eth0", 350, 100)]);
+        assert_eq!(session.traffic(&net("eth0", 400, 100)), (50, 0));
+    }
+}
@@ -0,0 +1,129 @@
+// "Who has this open?" for the open files popup: walks /proc for processes
+// whose descriptors, working directory, root, executable or memory maps
+// point at a path or anything below it, which is what keeps a filesystem
+// busy when it won't unmount.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileHolder {
+    pub pid: u32,
+    pub name: String,
+    // "fd 3", "cwd", "root", "exe" or "mmap".
+    pub how: String,
+    pub path: PathBuf,
+}
+
+// Relative paths and symlinks are resolved first, since the kernel reports
+// canonical paths.
+pub fn find_holders(target: &str) -> Result<Vec<FileHolder>, String> {
+    let target = fs::canonicalize(target.trim()).map_err(|e| format!("{}: {}", target.trim(), e))?;
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return Err("/proc is not available".to_string());
+    };
+
+    let mut holders = Vec::new();
+    for entry in entries.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else { continue };
+        let dir = entry.path();
+        let name = fs::read_to_string(dir.join("comm")).map(|comm| comm.trim().to_string()).unwrap_or_default();
+        let mut add = |how: String, path: PathBuf| {
+            if is_under(&path, &target) && !holders.iter().any(|h: &FileHolder| h.pid == pid && h.how == how) {
+                holders.push(FileHolder { pid, name: name.clone(), how, path });
+            }
+        };
+
+        for link in ["cwd", "root", "exe"] {
+            if let Ok(path) = fs::read_link(dir.join(link)) {
+                add(link.to_string(), path);
+            }
+        }
+        if let Ok(fds) = fs::read_dir(dir.join("fd")) {
+            for fd in fds.flatten() {
+                if let Ok(path) = fs::read_link(fd.path()) {
+                    add(format!("fd {}", fd.file_name().to_string_lossy()), path);
+                }
+            }
+        }
+        if let Ok(maps) = fs::read_to_string(dir.join("maps")) {
+            for path in parse_mapped_files(&maps) {
+                add("mmap".to_string(), path);
+            }
+        }
+    }
+    holders.sort_by(|a, b| a.pid.cmp(&b.pid).then_with(|| a.how.cmp(&b.how)));
+    Ok(holders)
+}
+
+// Deleted files keep their path with a " (deleted)" suffix; sockets and
+// pipes ("socket:[1234]") never match.
+fn is_under(path: &Path, target: &Path) -> bool {
+    let path = path.to_str()
+        .and_then(|p| p.strip_suffix(" (deleted)"))
+        .map_or(path, Path::new);
+    path.is_absolute() && path.starts_with(target)
+}
+
+// File-backed mappings in /proc/PID/maps, e.g.
+// "7f2c..-7f2c.. r-xp 00000000 08:01 1234   /usr/lib/libc.so.6".
+pub fn parse_mapped_files(maps: &str) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for line in maps.lines() {
+        let Some(path) = mapped_path(line) else { continue };
+        let path = PathBuf::from(path);
+        if path.is_absolute() && !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+// Everything after the fifth field, since paths may contain spaces.
+fn mapped_path(line: &str) -> Option<&str> {
+    let mut rest = line;
+    for _ in 0..5 {
+        rest = rest.trim_start();
+        rest = &rest[rest.find(char::is_whitespace)?..];
+    }
+    Some(rest.trim()).filter(|path| !path.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mapped_files() {
+        let maps = "\
+55d0c0a00000-55d0c0a02000 r--p 00000000 08:01 131090                     /usr/bin/cat
+55d0c0a02000-55d0c0a06000 r-xp 00002000 08:01 131090                     /usr/bin/cat
+55d0c1a3e000-55d0c1a5f000 rw-p 00000000 00:00 0                          [heap]
+7f1e2b200000-7f1e2b222000 r--p 00000000 08:01 140123                     /mnt/data/my lib.so (deleted)
+7ffd5b5e1000-7ffd5b602000 rw-p 00000000 00:00 0                          [stack]
+7f1e2b400000-7f1e2b401000 rw-p 00000000 00:00 0
+";
+        assert_eq!(parse_mapped_files(maps), vec![
+            PathBuf::from("/usr/bin/cat"),
+            PathBuf::from("/mnt/data/my lib.so (deleted)"),
+        ]);
+        assert!(is_under(Path::new("/mnt/data/my lib.so (deleted)"), Path::new("/mnt/data")));
+        assert!(!is_under(Path::new("/mnt/database/x"), Path::new("/mnt/data")));
+        assert!(!is_under(Path::new("socket:[1234]"), Path::new("/")));
+    }
+
+    #[test]
+    fn test_find_holders_sees_own_file() {
+        if !cfg!(target_os = "linux") {
+            return;
+        }
+        let path = std::env::temp_dir().join(format!("puls-open-files-{}", std::process::id()));
+        let file = fs::File::create(&path).unwrap();
+        let holders = find_holders(path.to_str().unwrap()).unwrap();
+        drop(file);
+        fs::remove_file(&path).unwrap();
+
+        assert!(holders.iter().any(|h| h.pid == std::process::id() && h.how.starts_with("fd ")));
+        assert!(find_holders("/definitely/not/here").is_err());
+    }
+}
@@ -0,0 +1,453 @@
+// Site-specific metrics from plugins: executables in ~/.config/puls/plugins
+// (or --plugin-dir) that puls runs in the background, each on a thread of its
+// own. A plugin writes one JSON object per line to stdout, each replacing its
+// previous report:
+//
+//   {"title": "Job queue", "metrics": [{"name": "depth", "value": 42,
+//    "unit": "jobs", "warn": 100, "critical": 500}], "text": ["3 workers"]}
+//
+// Every field is optional. Metrics at or past `warn` or `critical` are raised
+// as footer alerts. A plugin can keep running and print a line whenever its
+// values change, or print once and exit; either way it is started again
+// RERUN_INTERVAL after it exits. Plugins still running when puls exits are
+// killed.
+//
+// Plugins run with puls's privileges. Running as root, puls only runs plugins
+// that root owns and only root can write, and only when every directory above
+// them is likewise, since sudo can keep the invoking user's HOME and with it
+// their plugin directory. This is checked again before every run.
+
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use serde::Deserialize;
+use tokio::sync::mpsc;
+
+use crate::alerts::Alert;
+use crate::types::AppMessage;
+
+const RERUN_INTERVAL: Duration = Duration::from_secs(5);
+
+// Plugins running now; None once `stop_all` ran, so none start after it.
+static RUNNING: Mutex<Option<Vec<Arc<Mutex<Child>>>>> = Mutex::new(Some(Vec::new()));
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MetricLevel {
+    Normal,
+    Warn,
+    Critical,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PluginMetric {
+    pub name: String,
+    pub value: f64,
+    pub unit: String,
+    pub warn: Option<f64>,
+    pub critical: Option<f64>,
+}
+
+impl PluginMetric {
+    pub fn level(&self) -> MetricLevel {
+        if self.critical.is_some_and(|limit| self.value >= limit) {
+            MetricLevel::Critical
+        } else if self.warn.is_some_and(|limit| self.value >= limit) {
+            MetricLevel::Warn
+        } else {
+            MetricLevel::Normal
+        }
+    }
+
+    pub fn value_text(&self) -> String {
+        let value = format_number(self.value);
+        if self.unit.is_empty() { value } else { format!("{} {}", value, self.unit) }
+    }
+
+    pub fn limits_text(&self) -> String {
+        match (self.warn, self.critical) {
+            (None, None) => String::new(),
+            (warn, critical) => format!(
+                "{} / {}",
+                warn.map_or("-".to_string(), format_number),
+                critical.map_or("-".to_string(), format_number),
+            ),
+        }
+    }
+}
+
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 { format!("{}", value as i64) } else { format!("{:.2}", value) }
+}
+
+// One line of plugin output.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PluginOutput {
+    pub title: Option<String>,
+    pub metrics: Vec<PluginMetric>,
+    pub text: Vec<String>,
+}
+
+pub fn parse_line(line: &str) -> Result<PluginOutput, String> {
+    serde_json::from_str(line).map_err(|e| format!("Invalid output: {}", e))
+}
+
+#[derive(Clone, Debug)]
+pub struct PluginUpdate {
+    pub name: String,
+    pub at: DateTime<Local>,
+    // A failed run or unreadable line; the previous output stays on screen.
+    pub result: Result<PluginOutput, String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PluginReport {
+    pub name: String,
+    pub path: PathBuf,
+    // None until the plugin's first line.
+    pub output: Option<PluginOutput>,
+    pub at: Option<DateTime<Local>>,
+    pub error: Option<String>,
+}
+
+impl PluginReport {
+    pub fn title(&self) -> &str {
+        self.output.as_ref().and_then(|output| output.title.as_deref()).unwrap_or(&self.name)
+    }
+}
+
+// The latest report from every plugin found at startup, for the panel on the
+// System tab.
+#[derive(Clone, Debug, Default)]
+pub struct PluginReports {
+    pub plugins: Vec<PluginReport>,
+}
+
+impl PluginReports {
+    pub fn new(paths: &[PathBuf]) -> Self {
+        let plugins = paths.iter().map(|path| PluginReport {
+            name: path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default(),
+            path: path.clone(),
+            output: None,
+            at: None,
+            error: None,
+        }).collect();
+        Self { plugins }
+    }
+
+    pub fn apply(&mut self, update: PluginUpdate) {
+        let Some(report) = self.plugins.iter_mut().find(|report| report.name == update.name) else { return };
+        report.at = Some(update.at);
+        match update.result {
+            Ok(output) => {
+                report.output = Some(output);
+                report.error = None;
+            }
+            Err(e) => report.error = Some(e),
+        }
+    }
+
+    pub fn alerts(&self) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+        for report in &self.plugins {
+            let Some(output) = &report.output else { continue };
+            for metric in &output.metrics {
+                let critical = match metric.level() {
+                    MetricLevel::Normal => continue,
+                    MetricLevel::Warn => false,
+                    MetricLevel::Critical => true,
+                };
+                alerts.push(Alert::PluginMetric {
+                    metric: format!("{} {}", report.title(), metric.name).trim_end().to_string(),
+                    value: metric.value_text(),
+                    critical,
+                });
+            }
+        }
+        alerts
+    }
+}
+
+pub fn default_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("puls").join("plugins"))
+}
+
+// Executables directly inside `dir`, by name. Hidden files are skipped, and
+// as root so is anything not safe to run as root.
+pub fn discover(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    if !is_trusted(dir) {
+        log::warn!("Not running plugins from {}: as root, it and every directory above it must be owned by root and not group or world writable", dir.display());
+        return Vec::new();
+    }
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| !path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.')))
+        .filter(|path| is_executable(path))
+        .filter(|path| {
+            let trusted = is_trusted(path);
+            if !trusted {
+                log::warn!("Not running plugin {}: as root, it and every directory above it must be owned by root and not group or world writable", path.display());
+            }
+            trusted
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[cfg(unix)]
+fn is_trusted(path: &Path) -> bool {
+    users::get_effective_uid() != 0 || owned_by_root_only(path)
+}
+
+// Both the path as given and the one it resolves to: a symlink on the way
+// could otherwise be pointed anywhere by whoever owns the directory it is in.
+#[cfg(unix)]
+fn owned_by_root_only(path: &Path) -> bool {
+    let Ok(given) = std::path::absolute(path) else { return false };
+    let Ok(resolved) = std::fs::canonicalize(path) else { return false };
+    given.ancestors().all(|ancestor| root_only_entry(ancestor, false))
+        && resolved.ancestors().all(|ancestor| root_only_entry(ancestor, true))
+}
+
+#[cfg(not(unix))]
+fn is_trusted(_path: &Path) -> bool {
+    true
+}
+
+// Owned by root, and only root can replace or change it. Symlinks always
+// read as writable by all, so only their owner counts; a sticky directory like
+// /tmp keeps others from renaming what root owns in it.
+#[cfg(unix)]
+fn root_only_entry(path: &Path, follow: bool) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    let meta = if follow { std::fs::metadata(path) } else { std::fs::symlink_metadata(path) };
+    meta.is_ok_and(|meta| {
+        meta.uid() == 0
+            && (meta.file_type().is_symlink() || meta.mode() & 0o022 == 0 || (meta.is_dir() && meta.mode() & 0o1000 != 0))
+    })
+}
+
+// Kills the plugins still running and keeps new ones from starting.
+pub fn stop_all() {
+    let running = RUNNING.lock().unwrap_or_else(|e| e.into_inner()).take().unwrap_or_default();
+    for child in running {
+        let mut child = child.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file() && path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("exe"))
+}
+
+pub fn spawn_plugins(tx: mpsc::UnboundedSender<AppMessage>, paths: Vec<PathBuf>) {
+    for path in paths {
+        let tx = tx.clone();
+        std::thread::spawn(move || {
+            let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+            // Logged once, not on every rerun of a plugin that keeps failing.
+            let mut last_error = None;
+            loop {
+                let result = run_once(&tx, &path, &name);
+                if result.as_ref().err() != last_error.as_ref() {
+                    if let Err(e) = &result {
+                        log::warn!("Plugin {}: {}", name, e);
+                    }
+                    last_error = result.as_ref().err().cloned();
+                }
+                if let Err(e) = result {
+                    let update = PluginUpdate { name: name.clone(), at: Local::now(), result: Err(e) };
+                    if tx.send(AppMessage::Plugin(update)).is_err() {
+                        return;
+                    }
+                }
+                if tx.is_closed() {
+                    return;
+                }
+                std::thread::sleep(RERUN_INTERVAL);
+            }
+        });
+    }
+}
+
+// Runs the plugin until it exits, forwarding each line it prints.
+fn run_once(tx: &mpsc::UnboundedSender<AppMessage>, path: &Path, name: &str) -> Result<(), String> {
+    // Started under the lock, so `stop_all` either sees it or it never runs.
+    let mut running = RUNNING.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(list) = running.as_mut() else { return Ok(()) };
+    // The directory may have changed hands or contents since discovery.
+    if !is_trusted(path) {
+        return Err("Not started: as root, the plugin and every directory above it must be owned by root and not group or world writable".to_string());
+    }
+    let mut child = Command::new(path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Cannot start: {}", e))?;
+    // Held until the plugin exits, so it sees EOF if puls dies without
+    // killing it.
+    let _stdin = child.stdin.take();
+    let stdout = child.stdout.take();
+    // Drained on the side so a chatty plugin never blocks on a full pipe; the
+    // last line explains a failed exit.
+    let stderr = child.stderr.take().map(|stderr| std::thread::spawn(move || {
+        BufReader::new(stderr).lines()
+            .map_while(Result::ok)
+            .filter(|line| !line.trim().is_empty())
+            .last()
+    }));
+    let child = Arc::new(Mutex::new(child));
+    list.push(child.clone());
+    drop(running);
+
+    let mut finished = false;
+    if let Some(stdout) = stdout {
+        for line in BufReader::new(stdout).lines() {
+            let Ok(line) = line else { break };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let update = PluginUpdate { name: name.to_string(), at: Local::now(), result: parse_line(&line) };
+            if tx.send(AppMessage::Plugin(update)).is_err() {
+                finished = true;
+                break;
+            }
+        }
+    }
+
+    // Polled rather than waited on, so `stop_all` can still kill a plugin
+    // that closed its stdout but keeps running.
+    let status = loop {
+        let mut child = child.lock().unwrap_or_else(|e| e.into_inner());
+        if finished {
+            let _ = child.kill();
+        }
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break status;
+        }
+        drop(child);
+        std::thread::sleep(Duration::from_millis(100));
+    };
+    if let Some(list) = RUNNING.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        list.retain(|running| !Arc::ptr_eq(running, &child));
+    }
+    if finished {
+        return Ok(());
+    }
+    let last_error = stderr.and_then(|reader| reader.join().ok()).flatten();
+    if status.success() {
+        return Ok(());
+    }
+    Err(match last_error {
+        Some(line) => format!("{}: {}", status, line.trim()),
+        None => status.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_reports() {
+        let output = parse_line(r#"{"title": "Job queue", "metrics": [
+            {"name": "depth", "value": 620, "unit": "jobs", "warn": 100, "critical": 500},
+            {"name": "latency", "value": 0.25, "unit": "s", "warn": 1},
+            {"name": "workers", "value": 3}
+        ], "extra": true}"#).unwrap();
+        assert_eq!(output.metrics[0].level(), MetricLevel::Critical);
+        assert_eq!(output.metrics[1].level(), MetricLevel::Normal);
+        assert_eq!(output.metrics[1].value_text(), "0.25 s");
+        assert_eq!(output.metrics[1].limits_text(), "1 / -");
+        assert_eq!(output.metrics[2].limits_text(), "");
+        assert!(parse_line("depth=4").is_err());
+
+        let mut reports = PluginReports::new(&[PathBuf::from("/plugins/queue.sh")]);
+        assert_eq!(reports.plugins[0].title(), "queue.sh");
+        let update = |result| PluginUpdate { name: "queue.sh".to_string(), at: Local::now(), result };
+        reports.apply(update(Ok(output)));
+        assert_eq!(reports.alerts(), vec![Alert::PluginMetric {
+            metric: "Job queue depth".to_string(),
+            value: "620 jobs".to_string(),
+            critical: true,
+        }]);
+
+        // A failed run keeps the last output on screen.
+        reports.apply(update(Err("exit status: 1".to_string())));
+        assert_eq!(reports.plugins[0].title(), "Job queue");
+        assert_eq!(reports.plugins[0].error.as_deref(), Some("exit status: 1"));
+        assert_eq!(reports.alerts().len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_discover_as_root() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("puls-plugins-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let plugin = dir.join("queue.sh");
+        std::fs::write(&plugin, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&plugin, std::fs::Permissions::from_mode(0o777)).unwrap();
+        let writable = discover(&dir);
+        std::fs::set_permissions(&plugin, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let private = discover(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let root = users::get_effective_uid() == 0;
+        assert_eq!(writable.is_empty(), root);
+        assert_eq!(private, vec![plugin]);
+    }
+
+    // A user who can write any directory above the plugin can swap it out.
+    #[cfg(unix)]
+    #[test]
+    fn test_trust_covers_parent_directories() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let parent = std::env::temp_dir().join(format!("puls-plugins-parent-{}", std::process::id()));
+        let dir = parent.join("plugins");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::set_permissions(&parent, std::fs::Permissions::from_mode(0o755)).unwrap();
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let plugin = dir.join("queue.sh");
+        std::fs::write(&plugin, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&plugin, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let root = users::get_effective_uid() == 0;
+        let private = owned_by_root_only(&plugin);
+
+        // Writable by everyone, then owned by a user.
+        std::fs::set_permissions(&parent, std::fs::Permissions::from_mode(0o777)).unwrap();
+        let world_writable = owned_by_root_only(&plugin);
+        std::fs::set_permissions(&parent, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let user_owned = root && std::os::unix::fs::chown(&parent, Some(1000), Some(1000)).is_ok() && owned_by_root_only(&plugin);
+        // A symlink in a user's directory pointing at a root-only plugin.
+        let link = parent.join("link");
+        std::os::unix::fs::symlink(&dir, &link).unwrap();
+        let through_link = owned_by_root_only(&link.join("queue.sh"));
+        std::fs::remove_dir_all(&parent).unwrap();
+
+        assert_eq!(private, root);
+        assert!(!world_writable);
+        assert!(!user_owned);
+        assert!(!through_link);
+    }
+}
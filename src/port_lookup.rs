@@ -0,0 +1,130 @@
+// "Who owns :8080": the processes listening on a port, and the container
+// behind each one. A published container port is usually held by
+// docker-proxy on the host, or by nothing at all when Docker forwards it with
+// NAT rules, so containers publishing the port are matched too.
+
+use crate::monitors::container_monitor::ContainerMonitor;
+use crate::monitors::{security_monitor, system_monitor};
+use crate::types::{AppConfig, ContainerInfo, ListeningPort};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortOwner {
+    // None for a published port with no socket on the host.
+    pub socket: Option<ListeningPort>,
+    // Container name, or the short ID when Docker didn't report it.
+    pub container: Option<String>,
+}
+
+impl std::fmt::Display for PortOwner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.socket {
+            Some(socket) => write!(f, "{}", socket)?,
+            None => write!(f, "forwarded")?,
+        }
+        if let Some(container) = &self.container {
+            write!(f, " [container {}]", container)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn lookup(port: u16, containers: &[ContainerInfo]) -> Vec<PortOwner> {
+    attach_containers(security_monitor::listeners_on(port), port, containers, |pid| {
+        system_monitor::container_of_pid(sysinfo::Pid::from_u32(pid))
+    })
+}
+
+// A socket opened inside a container belongs to it; one held by another
+// process (docker-proxy) goes to the container publishing the port.
+fn attach_containers(
+    sockets: Vec<ListeningPort>,
+    port: u16,
+    containers: &[ContainerInfo],
+    container_of: impl Fn(u32) -> Option<String>,
+) -> Vec<PortOwner> {
+    let name = |id: String| containers.iter().find(|c| c.id == id).map_or(id, |c| c.name.clone());
+    let publisher = containers.iter().find(|c| publishes(&c.ports, port)).map(|c| c.name.clone());
+
+    let mut owners: Vec<PortOwner> = sockets.into_iter().map(|socket| {
+        let container = socket.process.as_ref()
+            .and_then(|(pid, _)| container_of(*pid))
+            .map(name)
+            .or_else(|| publisher.clone());
+        PortOwner { socket: Some(socket), container }
+    }).collect();
+    if owners.is_empty() && publisher.is_some() {
+        owners.push(PortOwner { socket: None, container: publisher });
+    }
+    owners
+}
+
+// Docker's port list as formatted by the container monitor: "8080:80, 443",
+// host port first for published ones.
+fn publishes(ports: &str, port: u16) -> bool {
+    ports.split(',')
+        .filter_map(|mapping| mapping.trim().split_once(':'))
+        .any(|(host, _)| host.parse() == Ok(port))
+}
+
+// `puls port 8080`: prints one owner per line, and fails when nothing listens.
+pub async fn run(config: &AppConfig, port: u16) -> bool {
+    let containers = if config.enable_docker {
+        let timeout = config.get_operation_timeout();
+        let mut monitor = ContainerMonitor::new();
+        match tokio::time::timeout(timeout, monitor.get_containers(timeout.as_millis() as u64)).await {
+            Ok(Ok(containers)) => containers,
+            _ => Vec::new(),
+        }
+    } else {
+        Vec::new()
+    };
+
+    let owners = lookup(port, &containers);
+    if owners.is_empty() {
+        eprintln!("puls: nothing is listening on port {}", port);
+        return false;
+    }
+    for owner in owners {
+        println!("{}", owner);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn socket(port: u16, process: Option<(u32, &str)>) -> ListeningPort {
+        ListeningPort {
+            protocol: "tcp".to_string(),
+            address: "0.0.0.0".parse().unwrap(),
+            port,
+            inode: 1,
+            process: process.map(|(pid, name)| (pid, name.to_string())),
+        }
+    }
+
+    #[test]
+    fn test_attach_containers() {
+        let containers = vec![
+            ContainerInfo { id: "aaaaaaaaaaaa".to_string(), name: "web".to_string(), ports: "8080:80, 8443:443".to_string(), ..Default::default() },
+            ContainerInfo { id: "bbbbbbbbbbbb".to_string(), name: "db".to_string(), ports: "5432".to_string(), ..Default::default() },
+        ];
+        let container_of = |pid: u32| (pid == 42).then(|| "bbbbbbbbbbbb".to_string());
+
+        // Host networking: the listener itself runs in the container.
+        let owners = attach_containers(vec![socket(5432, Some((42, "postgres")))], 5432, &containers, container_of);
+        assert_eq!(owners[0].container.as_deref(), Some("db"));
+
+        let owners = attach_containers(vec![socket(8080, Some((7, "docker-proxy")))], 8080, &containers, container_of);
+        assert_eq!(owners[0].container.as_deref(), Some("web"));
+
+        let owners = attach_containers(Vec::new(), 8443, &containers, container_of);
+        assert_eq!(owners, vec![PortOwner { socket: None, container: Some("web".to_string()) }]);
+
+        assert!(attach_containers(Vec::new(), 80, &containers, container_of).is_empty());
+        let owners = attach_containers(vec![socket(22, Some((1, "sshd")))], 22, &containers, container_of);
+        assert_eq!(owners[0].container, None);
+        assert_eq!(owners[0].to_string(), "tcp 0.0.0.0:22 (sshd, pid 1)");
+    }
+}
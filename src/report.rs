@@ -0,0 +1,467 @@
+// `puls report`: one pass over every collector, written up as plain text,
+// Markdown or HTML for attaching to a support ticket. The report is built as
+// sections first and rendered afterwards, so the three formats always carry
+// the same content. `puls --once` prints the same sections, minus services
+// and logs, as text or JSON for scripts.
+
+use std::error::Error;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::config::{OnceFormat, ReportFormat};
+use crate::headless::headless_control;
+use crate::monitors::DataCollector;
+use crate::system_service::SystemManager;
+use crate::types::{AppConfig, DynamicData, LogEntry, ServiceInfo};
+use crate::utils::{format_rate, format_size, pad_to_width};
+
+const TOP_PROCESSES: usize = 10;
+// Journal lines read to find the recent errors, and errors kept.
+const LOG_LINES: usize = 500;
+const RECENT_ERRORS: usize = 25;
+
+// A field or cell. The text formats show it formatted; JSON gets the plain
+// number, in bytes, bytes per second, percent, °C or watts.
+enum Value {
+    Text(String),
+    Count(u64),
+    // With the decimals shown.
+    Percent(f64, usize),
+    Bytes(u64),
+    Rate(u64),
+    UsedOf(u64, u64),
+    Celsius(u32),
+    Watts(f64),
+    Missing,
+}
+
+impl Value {
+    fn json(&self) -> serde_json::Value {
+        match self {
+            Value::Text(text) => text.as_str().into(),
+            Value::Count(count) | Value::Bytes(count) | Value::Rate(count) => (*count).into(),
+            // Most figures are f32 underneath; two decimals keep the float
+            // noise out.
+            Value::Percent(value, _) | Value::Watts(value) => ((value * 100.0).round() / 100.0).into(),
+            Value::UsedOf(used, total) => serde_json::json!({ "used": used, "total": total }),
+            Value::Celsius(celsius) => (*celsius).into(),
+            Value::Missing => serde_json::Value::Null,
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Text(text) => f.write_str(text),
+            Value::Count(count) => write!(f, "{}", count),
+            Value::Percent(value, decimals) => write!(f, "{:.*}%", decimals, value),
+            Value::Bytes(bytes) => f.write_str(&format_size(*bytes)),
+            Value::Rate(rate) => f.write_str(&format_rate(*rate)),
+            Value::UsedOf(used, total) => write!(f, "{} / {}", format_size(*used), format_size(*total)),
+            Value::Celsius(celsius) => write!(f, "{}°C", celsius),
+            Value::Watts(watts) => write!(f, "{:.1} W", watts),
+            Value::Missing => f.write_str("-"),
+        }
+    }
+}
+
+enum Body {
+    Fields(Vec<(String, Value)>),
+    // Columns as (header, JSON key).
+    Table { columns: Vec<(&'static str, &'static str)>, rows: Vec<Vec<Value>> },
+}
+
+struct Section {
+    title: String,
+    body: Body,
+}
+
+impl Section {
+    fn fields(title: &str, fields: Vec<(String, Value)>) -> Self {
+        Self { title: title.to_string(), body: Body::Fields(fields) }
+    }
+
+    fn table(title: &str, columns: Vec<(&'static str, &'static str)>, rows: Vec<Vec<Value>>) -> Self {
+        Self { title: title.to_string(), body: Body::Table { columns, rows } }
+    }
+}
+
+// One collection cycle: system information and the collected data.
+pub async fn collect_once(config: &AppConfig) -> (Vec<(String, String)>, DynamicData) {
+    let collector = DataCollector::new(config.clone());
+    let system_info = collector.get_system_info();
+    let (mut system, mut gpu, mut containers) = collector.into_collectors();
+    let query = headless_control(config).query;
+
+    // CPU usage is a delta, so the first sample only primes the counters.
+    system.collect(&query);
+    tokio::time::sleep(Duration::from_millis(config.refresh_rate_ms.min(1000))).await;
+
+    let mut data = DynamicData::default();
+    data.apply(system.collect(&query));
+    data.apply(gpu.collect());
+    data.apply(containers.collect().await);
+    (system_info, data)
+}
+
+pub async fn run(config: &AppConfig, format: ReportFormat, output: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let (system_info, data) = collect_once(config).await;
+
+    let manager = SystemManager::new();
+    let (services, logs) = tokio::task::spawn_blocking(move || {
+        (manager.get_services(), manager.get_logs(LOG_LINES, None, None))
+    }).await?;
+
+    let generated = chrono::Local::now().format("%Y-%m-%d %H:%M:%S %Z").to_string();
+    let sections = build(system_info, &data, Some((&services, &logs)));
+    let report = match format {
+        ReportFormat::Text => to_text("PULS System Report", &generated, &sections),
+        ReportFormat::Markdown => to_markdown(&generated, &sections),
+        ReportFormat::Html => to_html(&generated, &sections),
+    };
+    match output {
+        Some(path) => std::fs::write(path, report)?,
+        None => std::io::Write::write_all(&mut std::io::stdout().lock(), report.as_bytes())?,
+    }
+    Ok(())
+}
+
+// `puls --once`: the report without services and logs, on stdout.
+pub async fn once(config: &AppConfig, format: OnceFormat) -> Result<(), Box<dyn Error>> {
+    let (system_info, data) = collect_once(config).await;
+    let generated = chrono::Local::now().format("%Y-%m-%d %H:%M:%S %Z").to_string();
+    let sections = build(system_info, &data, None);
+    let mut stdout = std::io::stdout().lock();
+    match format {
+        OnceFormat::Text => std::io::Write::write_all(&mut stdout, to_text("PULS Snapshot", &generated, &sections).as_bytes())?,
+        OnceFormat::Json => {
+            serde_json::to_writer_pretty(&mut stdout, &to_json(&generated, &sections))?;
+            std::io::Write::write_all(&mut stdout, b"\n")?;
+        }
+    }
+    Ok(())
+}
+
+// Services and logs are left out when None.
+fn build(system_info: Vec<(String, String)>, data: &DynamicData, services_and_logs: Option<(&[ServiceInfo], &[LogEntry])>) -> Vec<Section> {
+    let usage = &data.global_usage;
+    let text = |text: &str| Value::Text(text.to_string());
+    let mut sections = vec![Section::fields("System", system_info.into_iter().map(|(name, value)| (name, Value::Text(value))).collect())];
+
+    sections.push(Section::fields("Usage", vec![
+        ("CPU".to_string(), Value::Percent(usage.cpu as f64, 1)),
+        ("Memory".to_string(), Value::UsedOf(usage.mem_used, usage.mem_total)),
+        ("Swap".to_string(), Value::UsedOf(usage.swap_used, usage.swap_total)),
+    ]));
+
+    let process_rows = |by_memory: bool| {
+        let mut processes: Vec<_> = data.processes.iter().collect();
+        if by_memory {
+            processes.sort_by_key(|p| std::cmp::Reverse(p.mem));
+        } else {
+            processes.sort_by(|a, b| b.cpu.total_cmp(&a.cpu));
+        }
+        processes.into_iter().take(TOP_PROCESSES).map(|p| vec![
+            p.pid.parse().map_or_else(|_| text(&p.pid), Value::Count),
+            text(&p.name),
+            text(&p.user),
+            Value::Percent(p.cpu as f64, 1),
+            Value::Bytes(p.mem),
+            text(&p.status),
+        ]).collect()
+    };
+    let process_columns = vec![("PID", "pid"), ("Name", "name"), ("User", "user"), ("CPU", "cpu"), ("Memory", "memory"), ("Status", "status")];
+    sections.push(Section::table("Top Processes by CPU", process_columns.clone(), process_rows(false)));
+    sections.push(Section::table("Top Processes by Memory", process_columns, process_rows(true)));
+
+    sections.push(Section::table("Disks",
+        vec![("Mount", "mount"), ("Device", "device"), ("Filesystem", "filesystem"), ("Used", "used"), ("Total", "total"), ("Use%", "usage")],
+        data.disks.iter().map(|d| vec![
+            text(&d.name),
+            text(&d.device),
+            text(&d.fs),
+            Value::Bytes(d.used),
+            Value::Bytes(d.total),
+            Value::Percent(crate::utils::safe_percentage(d.used, d.total) as f64, 0),
+        ]).collect()));
+
+    sections.push(Section::table("Network",
+        vec![("Interface", "interface"), ("State", "state"), ("Down", "down"), ("Up", "up"), ("Received", "received"), ("Sent", "sent"), ("Errors", "errors")],
+        data.networks.iter().map(|n| vec![
+            text(&n.name),
+            text(if n.is_up { "up" } else { "down" }),
+            Value::Rate(n.down_rate),
+            Value::Rate(n.up_rate),
+            Value::Bytes(n.total_down),
+            Value::Bytes(n.total_up),
+            Value::Count(n.errors_rx + n.errors_tx),
+        ]).collect()));
+
+    if let Some((services, logs)) = services_and_logs {
+        sections.push(Section::table("Failed Services", vec![("Service", "service"), ("Description", "description")],
+            services.iter()
+                .filter(|s| s.status == "Failed")
+                .map(|s| vec![text(&s.name), text(&s.description)])
+                .collect()));
+
+        let errors: Vec<&LogEntry> = logs.iter().filter(|entry| entry.level == "ERROR").collect();
+        sections.push(Section::table("Recent Errors", vec![("Time", "time"), ("Source", "source"), ("Message", "message")],
+            errors[errors.len().saturating_sub(RECENT_ERRORS)..].iter()
+                .map(|entry| vec![text(&entry.timestamp), text(&entry.service), text(&entry.message)])
+                .collect()));
+    }
+
+    match &data.gpus {
+        Ok(gpus) => sections.push(Section::table("GPU",
+            vec![("Name", "name"), ("Utilization", "utilization"), ("Memory", "memory"), ("Temperature", "temperature"), ("Power", "power")],
+            gpus.iter().map(|g| vec![
+                text(&g.name),
+                g.utilization.map_or(Value::Missing, |u| Value::Percent(u as f64, 0)),
+                Value::UsedOf(g.memory_used, g.memory_total),
+                g.temperature.map_or(Value::Missing, Value::Celsius),
+                g.power_usage.map_or(Value::Missing, |p| Value::Watts(p as f64 / 1000.0)),
+            ]).collect())),
+        Err(e) => sections.push(Section::fields("GPU", vec![("Status".to_string(), text(e))])),
+    }
+
+    if !data.containers.is_empty() {
+        sections.push(Section::table("Containers",
+            vec![("Name", "name"), ("Image", "image"), ("Status", "status"), ("CPU", "cpu"), ("Memory", "memory")],
+            data.containers.iter().map(|c| vec![
+                text(&c.name), text(&c.image), text(&c.status), Value::Percent(c.cpu_percent, 2), Value::Bytes(c.mem_bytes),
+            ]).collect()));
+    }
+    sections
+}
+
+fn to_text(title: &str, generated: &str, sections: &[Section]) -> String {
+    let mut out = format!("{}\nGenerated {}\n", title, generated);
+    for section in sections {
+        let _ = write!(out, "\n{}\n{}\n", section.title, "=".repeat(section.title.width()));
+        match &section.body {
+            Body::Fields(fields) => {
+                let width = fields.iter().map(|(key, _)| key.width()).max().unwrap_or(0);
+                for (key, value) in fields {
+                    let _ = writeln!(out, "{}  {}", pad_to_width(key, width), value);
+                }
+            }
+            Body::Table { rows, .. } if rows.is_empty() => out.push_str("None.\n"),
+            Body::Table { columns, rows } => {
+                let headers: Vec<&str> = columns.iter().map(|(header, _)| *header).collect();
+                let rows: Vec<Vec<String>> = rows.iter().map(|row| row.iter().map(Value::to_string).collect()).collect();
+                let mut widths: Vec<usize> = headers.iter().map(|h| h.width()).collect();
+                for row in &rows {
+                    for (width, cell) in widths.iter_mut().zip(row) {
+                        *width = (*width).max(cell.width());
+                    }
+                }
+                let dashes: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+                let _ = writeln!(out, "{}", pad_row(&headers, &widths));
+                let _ = writeln!(out, "{}", pad_row(&dashes, &widths));
+                for row in &rows {
+                    let _ = writeln!(out, "{}", pad_row(row, &widths));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn pad_row<S: AsRef<str>>(cells: &[S], widths: &[usize]) -> String {
+    let padded: Vec<String> = cells.iter().zip(widths)
+        .map(|(cell, &width)| pad_to_width(cell.as_ref(), width))
+        .collect();
+    padded.join("  ").trim_end().to_string()
+}
+
+fn to_markdown(generated: &str, sections: &[Section]) -> String {
+    let cell = |text: &str| text.replace('|', "\\|").replace('\n', " ");
+    let mut out = format!("# PULS System Report\n\nGenerated {}\n", generated);
+    for section in sections {
+        let _ = write!(out, "\n## {}\n\n", section.title);
+        match &section.body {
+            Body::Fields(fields) => {
+                for (key, value) in fields {
+                    let _ = writeln!(out, "- **{}**: {}", key, value);
+                }
+            }
+            Body::Table { rows, .. } if rows.is_empty() => out.push_str("None.\n"),
+            Body::Table { columns, rows } => {
+                let headers: Vec<&str> = columns.iter().map(|(header, _)| *header).collect();
+                let _ = writeln!(out, "| {} |", headers.join(" | "));
+                let _ = writeln!(out, "|{}", " --- |".repeat(headers.len()));
+                for row in rows {
+                    let cells: Vec<String> = row.iter().map(|c| cell(&c.to_string())).collect();
+                    let _ = writeln!(out, "| {} |", cells.join(" | "));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn to_html(generated: &str, sections: &[Section]) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>PULS System Report</title>\n\
+         <style>body {{ font-family: sans-serif; margin: 2em; }} table {{ border-collapse: collapse; margin-bottom: 1em; }} \
+         th, td {{ border: 1px solid #ccc; padding: 0.2em 0.6em; text-align: left; }} th {{ background: #eee; }}</style>\n\
+         </head>\n<body>\n<h1>PULS System Report</h1>\n<p>Generated {}</p>\n",
+        escape(generated),
+    );
+    for section in sections {
+        let _ = writeln!(out, "<h2>{}</h2>", escape(&section.title));
+        match &section.body {
+            Body::Fields(fields) => {
+                out.push_str("<table>\n");
+                for (key, value) in fields {
+                    let _ = writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", escape(key), escape(&value.to_string()));
+                }
+                out.push_str("</table>\n");
+            }
+            Body::Table { rows, .. } if rows.is_empty() => out.push_str("<p>None.</p>\n"),
+            Body::Table { columns, rows } => {
+                out.push_str("<table>\n<tr>");
+                for (header, _) in columns {
+                    let _ = write!(out, "<th>{}</th>", escape(header));
+                }
+                out.push_str("</tr>\n");
+                for row in rows {
+                    out.push_str("<tr>");
+                    for cell in row {
+                        let _ = write!(out, "<td>{}</td>", escape(&cell.to_string()));
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</table>\n");
+            }
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+// Sections by snake_case title: fields as an object, tables as an array of
+// objects keyed by column. Figures are plain numbers, unformatted.
+fn to_json(generated: &str, sections: &[Section]) -> serde_json::Value {
+    let key = |text: &str| text.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()).map(str::to_lowercase).collect::<Vec<_>>().join("_");
+    let mut out = serde_json::Map::new();
+    out.insert("generated".to_string(), generated.into());
+    for section in sections {
+        let value = match &section.body {
+            Body::Fields(fields) => fields.iter()
+                .map(|(name, value)| (key(name), value.json()))
+                .collect::<serde_json::Map<_, _>>()
+                .into(),
+            Body::Table { columns, rows } => rows.iter()
+                .map(|row| columns.iter().zip(row)
+                    .map(|((_, key), cell)| (key.to_string(), cell.json()))
+                    .collect::<serde_json::Map<_, _>>()
+                    .into())
+                .collect::<Vec<serde_json::Value>>()
+                .into(),
+        };
+        out.insert(key(&section.title), value);
+    }
+    out.into()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> Value {
+        Value::Text(text.to_string())
+    }
+
+    fn sample() -> Vec<Section> {
+        vec![
+            Section::fields("System", vec![("Hostname".to_string(), text("db1")), ("OS".to_string(), text("Linux"))]),
+            Section::table("Failed Services", vec![("Service", "service"), ("Description", "description")], vec![
+                vec![text("backup.service"), text("Nightly <tar> | gzip")],
+            ]),
+            Section::table("Recent Errors", vec![("Time", "time"), ("Source", "source"), ("Message", "message")], Vec::new()),
+        ]
+    }
+
+    #[test]
+    fn test_failed_services() {
+        let service = |name: &str, status: &str| ServiceInfo {
+            name: name.to_string(),
+            description: format!("{} daemon", name),
+            status: status.to_string(),
+            enabled: true,
+            can_start: true,
+            can_stop: true,
+            memory: None,
+            cpu_time_usec: None,
+            tasks: None,
+        };
+        let services = [service("backup.service", "Failed"), service("sshd.service", "Running")];
+        let sections = build(Vec::new(), &DynamicData::default(), Some((&services, &[])));
+        let failed = sections.iter().find(|section| section.title == "Failed Services").unwrap();
+        let Body::Table { rows, .. } = &failed.body else { panic!("not a table") };
+        let rows: Vec<Vec<String>> = rows.iter().map(|row| row.iter().map(Value::to_string).collect()).collect();
+        assert_eq!(rows, vec![vec!["backup.service".to_string(), "backup.service daemon".to_string()]]);
+    }
+
+    #[test]
+    fn test_report_formats() {
+        let text = to_text("PULS System Report", "now", &sample());
+        assert!(text.contains("System\n======\nHostname  db1\nOS        Linux\n"));
+        assert!(text.contains("Service         Description\n--------------  --------------------\nbackup.service  Nightly <tar> | gzip\n"));
+        assert!(text.contains("Recent Errors\n=============\nNone.\n"));
+
+        let markdown = to_markdown("now", &sample());
+        assert!(markdown.contains("## System\n\n- **Hostname**: db1\n"));
+        assert!(markdown.contains("| Service | Description |\n| --- | --- |\n| backup.service | Nightly <tar> \\| gzip |\n"));
+
+        let html = to_html("now", &sample());
+        assert!(html.contains("<td>Nightly &lt;tar&gt; | gzip</td>"));
+        assert!(html.contains("<h2>Recent Errors</h2>\n<p>None.</p>"));
+        assert!(html.ends_with("</html>\n"));
+
+        let json = to_json("now", &sample());
+        assert_eq!(json["system"]["hostname"], "db1");
+        assert_eq!(json["failed_services"][0]["description"], "Nightly <tar> | gzip");
+        assert_eq!(json["recent_errors"], serde_json::json!([]));
+    }
+
+    // Scripts get numbers, not the text the table shows.
+    #[test]
+    fn test_json_values() {
+        let data = DynamicData {
+            disks: vec![crate::types::DetailedDiskInfo {
+                name: "/".to_string(),
+                used: 45 * 1024 * 1024 * 1024,
+                total: 100 * 1024 * 1024 * 1024,
+                ..Default::default()
+            }],
+            global_usage: crate::types::GlobalUsage { cpu: 12.5, mem_used: 2048, mem_total: 8192, ..Default::default() },
+            gpus: Ok(vec![crate::types::GpuInfo { name: "RTX".to_string(), temperature: Some(61), ..Default::default() }]),
+            ..Default::default()
+        };
+        let sections = build(vec![("Kernel Version".to_string(), "6.8".to_string())], &data, None);
+
+        let json = to_json("now", &sections);
+        assert_eq!(json["system"]["kernel_version"], "6.8");
+        assert_eq!(json["usage"]["cpu"], 12.5);
+        assert_eq!(json["usage"]["memory"], serde_json::json!({ "used": 2048, "total": 8192 }));
+        assert_eq!(json["disks"][0]["used"], 45u64 * 1024 * 1024 * 1024);
+        assert_eq!(json["disks"][0]["usage"], 45.0);
+        assert_eq!(json["gpu"][0]["temperature"], 61);
+        assert_eq!(json["gpu"][0]["power"], serde_json::Value::Null);
+
+        let text = to_text("PULS Snapshot", "now", &sections);
+        assert!(text.contains("Use%"));
+        assert!(text.contains("45.0 GiB"));
+        assert!(text.contains("61°C"));
+    }
+}
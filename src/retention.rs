@@ -0,0 +1,193 @@
+// Retention for the files puls keeps writing to on a long run: `puls record`
+// output and capture window recordings. A file that reaches the size limit is
+// rotated ("out.ndjson" becomes "out.ndjson.1", the older parts moving up one)
+// and older files are deleted by count and age, so a recording left running
+// doesn't fill the disk it is watching. Nothing is rotated or deleted unless a
+// limit is set.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Retention {
+    pub max_size: Option<FileSize>,
+    pub max_age: Option<FileAge>,
+    // Older files kept: rotated parts of a recording, finished captures.
+    pub keep: Option<usize>,
+}
+
+impl Retention {
+    pub fn needs_rotation(&self, written: u64) -> bool {
+        self.max_size.is_some_and(|limit| written >= limit.0)
+    }
+}
+
+// "500K", "100M", "2G" or plain bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FileSize(pub u64);
+
+impl FromStr for FileSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || format!("invalid size '{}', expected e.g. 500K, 100M or 2G", s);
+        let (number, unit) = s.find(|c: char| !c.is_ascii_digit()).map_or((s, ""), |split| s.split_at(split));
+        let number: u64 = number.parse().map_err(|_| invalid())?;
+        let scale: u64 = match unit.to_ascii_uppercase().trim_end_matches("IB").trim_end_matches('B') {
+            "" => 1,
+            "K" => 1 << 10,
+            "M" => 1 << 20,
+            "G" => 1 << 30,
+            "T" => 1 << 40,
+            _ => return Err(invalid()),
+        };
+        match number.checked_mul(scale) {
+            Some(bytes) if bytes > 0 => Ok(Self(bytes)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for FileSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&crate::utils::format_size(self.0))
+    }
+}
+
+// "30m", "12h", "7d", "4w" or plain seconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FileAge(pub Duration);
+
+impl FromStr for FileAge {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || format!("invalid age '{}', expected e.g. 12h or 7d", s);
+        let (number, unit) = s.find(|c: char| !c.is_ascii_digit()).map_or((s, "s"), |split| s.split_at(split));
+        let number: u64 = number.parse().map_err(|_| invalid())?;
+        let scale: u64 = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            "w" => 7 * 24 * 60 * 60,
+            _ => return Err(invalid()),
+        };
+        match number.checked_mul(scale) {
+            Some(secs) if secs > 0 => Ok(Self(Duration::from_secs(secs))),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+// The n-th older part of `path`, "out.ndjson.n".
+fn part(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+// Moves `path` to its first part, shifting the older parts up by one, then
+// applies the count and age limits to the parts.
+pub fn rotate(path: &Path, retention: &Retention) -> std::io::Result<()> {
+    let mut last = 0;
+    while part(path, last + 1).exists() {
+        last += 1;
+    }
+    for n in (1..=last).rev() {
+        std::fs::rename(part(path, n), part(path, n + 1))?;
+    }
+    std::fs::rename(path, part(path, 1))?;
+
+    // Parts go by number rather than time, which can tie between parts
+    // rotated in quick succession.
+    if let Some(keep) = retention.keep {
+        for n in keep + 1..=last + 1 {
+            std::fs::remove_file(part(path, n))?;
+        }
+    }
+    let by_age = Retention { keep: None, ..*retention };
+    let Some(dir) = path.parent().map(|dir| if dir.as_os_str().is_empty() { Path::new(".") } else { dir }) else { return Ok(()) };
+    let prefix = format!("{}.", path.file_name().unwrap_or_default().to_string_lossy());
+    prune(dir, |name| name.strip_prefix(&prefix).is_some_and(|n| n.parse::<usize>().is_ok()), &by_age, None);
+    Ok(())
+}
+
+// Deletes the files in `dir` whose names match, oldest first, past the count
+// limit or the age limit. `current` is still being written and is neither
+// counted nor deleted. Returns what was deleted.
+pub fn prune(dir: &Path, matches: impl Fn(&str) -> bool, retention: &Retention, current: Option<&Path>) -> Vec<PathBuf> {
+    if retention.keep.is_none() && retention.max_age.is_none() {
+        return Vec::new();
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let mut files: Vec<(SystemTime, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| matches(&entry.file_name().to_string_lossy()))
+        .filter(|entry| current.is_none_or(|current| entry.path() != current))
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok().filter(|meta| meta.is_file())?;
+            Some((meta.modified().ok()?, entry.path()))
+        })
+        .collect();
+    // Newest first.
+    files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+
+    let now = SystemTime::now();
+    let mut removed = Vec::new();
+    for (index, (modified, path)) in files.into_iter().enumerate() {
+        let too_many = retention.keep.is_some_and(|keep| index >= keep);
+        let too_old = retention.max_age.is_some_and(|age| now.duration_since(modified).is_ok_and(|elapsed| elapsed > age.0));
+        if !too_many && !too_old {
+            continue;
+        }
+        match std::fs::remove_file(&path) {
+            Ok(()) => {
+                log::info!("Removed {} (retention)", path.display());
+                removed.push(path);
+            }
+            Err(e) => log::warn!("Could not remove {}: {}", path.display(), e),
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_and_prune() {
+        assert_eq!("100M".parse::<FileSize>(), Ok(FileSize(100 << 20)));
+        assert_eq!("2GiB".parse::<FileSize>(), Ok(FileSize(2 << 30)));
+        assert_eq!("4096".parse::<FileSize>(), Ok(FileSize(4096)));
+        assert!("0".parse::<FileSize>().is_err());
+        assert!("10X".parse::<FileSize>().is_err());
+        assert_eq!("7d".parse::<FileAge>(), Ok(FileAge(Duration::from_secs(7 * 86400))));
+        assert!("soon".parse::<FileAge>().is_err());
+        assert!("0d".parse::<FileAge>().is_err());
+        assert!("99999999999999999w".parse::<FileAge>().is_err());
+
+        let dir = std::env::temp_dir().join(format!("puls-retention-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("out.ndjson");
+        let retention = Retention { keep: Some(2), ..Default::default() };
+        for generation in 0..4 {
+            std::fs::write(&path, generation.to_string()).unwrap();
+            rotate(&path, &retention).unwrap();
+        }
+        let read = |n| std::fs::read_to_string(part(&path, n)).ok();
+        assert_eq!((read(1), read(2), read(3)), (Some("3".to_string()), Some("2".to_string()), None));
+        assert!(!path.exists());
+
+        std::fs::write(dir.join("capture-1.ndjson"), "").unwrap();
+        let unrelated = Retention { keep: Some(0), ..Default::default() };
+        assert_eq!(prune(&dir, |name| name.starts_with("capture-"), &unrelated, None).len(), 1);
+        assert!(read(1).is_some());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
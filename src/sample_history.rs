@@ -0,0 +1,119 @@
+// The last few system samples, kept so that after pausing, ←/→ can step back
+// to a spike that has already scrolled by. Samples are shared, so copies of
+// the app state don't duplicate them.
+
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use chrono::{DateTime, Local};
+
+use crate::types::DynamicData;
+
+pub const RETAINED: usize = 60;
+
+#[derive(Clone, Default)]
+pub struct SampleHistory {
+    // Oldest first; the newest matches what was live when it was taken.
+    samples: VecDeque<(DateTime<Local>, Rc<DynamicData>)>,
+    // The sample on screen, and the live data it replaced.
+    viewing: Option<(usize, DynamicData)>,
+}
+
+impl SampleHistory {
+    pub fn record(&mut self, data: &DynamicData) {
+        self.samples.push_back((Local::now(), Rc::new(data.clone())));
+        if self.samples.len() > RETAINED {
+            self.samples.pop_front();
+            if let Some((index, _)) = self.viewing.as_mut() {
+                *index = index.saturating_sub(1);
+            }
+        }
+    }
+
+    // Shows the sample before the one on screen; false at the oldest.
+    pub fn step_back(&mut self, current: &mut DynamicData) -> bool {
+        let index = match &self.viewing {
+            Some((index, _)) => *index,
+            None => self.samples.len().saturating_sub(1),
+        };
+        if index == 0 {
+            return false;
+        }
+        let sample = (*self.samples[index - 1].1).clone();
+        let live = std::mem::replace(current, sample);
+        match self.viewing.as_mut() {
+            Some((viewed, _)) => *viewed = index - 1,
+            None => self.viewing = Some((index - 1, live)),
+        }
+        true
+    }
+
+    // Shows the next sample, or the live data after the newest.
+    pub fn step_forward(&mut self, current: &mut DynamicData) -> bool {
+        let Some((index, _)) = self.viewing else { return false };
+        if index + 2 >= self.samples.len() {
+            self.resume(current);
+        } else {
+            *current = (*self.samples[index + 1].1).clone();
+            self.viewing = self.viewing.take().map(|(_, live)| (index + 1, live));
+        }
+        true
+    }
+
+    // Puts the live data back on screen.
+    pub fn resume(&mut self, current: &mut DynamicData) {
+        if let Some((_, live)) = self.viewing.take() {
+            *current = live;
+        }
+    }
+
+    // When the sample on screen was taken, and its position counted back
+    // from live (1 for the sample before the newest).
+    pub fn viewing(&self) -> Option<(DateTime<Local>, usize)> {
+        let (index, _) = self.viewing.as_ref()?;
+        Some((self.samples[*index].0, self.samples.len() - 1 - index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(cpu: f32) -> DynamicData {
+        let mut data = DynamicData::default();
+        data.global_usage.cpu = cpu;
+        data
+    }
+
+    #[test]
+    fn test_step_through_samples() {
+        let mut history = SampleHistory::default();
+        for cpu in [10.0, 20.0, 30.0] {
+            history.record(&sample(cpu));
+        }
+        let mut current = sample(30.0);
+
+        assert!(history.step_back(&mut current));
+        assert_eq!(current.global_usage.cpu, 20.0);
+        assert_eq!(history.viewing().map(|(_, back)| back), Some(1));
+        assert!(history.step_back(&mut current));
+        assert!(!history.step_back(&mut current));
+        assert_eq!(current.global_usage.cpu, 10.0);
+
+        assert!(history.step_forward(&mut current));
+        assert_eq!(current.global_usage.cpu, 20.0);
+        assert!(history.step_forward(&mut current));
+        assert_eq!(current.global_usage.cpu, 30.0);
+        assert!(history.viewing().is_none());
+        assert!(!history.step_forward(&mut current));
+
+        for cpu in 0..RETAINED {
+            history.record(&sample(cpu as f32));
+        }
+        assert_eq!(history.len(), RETAINED);
+    }
+}
@@ -0,0 +1,186 @@
+// `puls diff a.json b.json`: what changed between two `puls snapshot` files,
+// for before/after checks around a deployment. Only the fields compared are
+// read, so snapshots from older versions still load.
+
+use std::error::Error;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::utils::format_size;
+
+// Smaller memory and disk changes are noise between two samples.
+const MIN_BYTES_CHANGE: i64 = 10 * 1024 * 1024;
+const MIN_CPU_CHANGE: f32 = 5.0;
+// Rows listed per section; the rest are counted.
+const MAX_ROWS: usize = 20;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Snapshot {
+    processes: Vec<Process>,
+    disks: Vec<Disk>,
+    services: Vec<Service>,
+    global_usage: Usage,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Process {
+    pid: String,
+    name: String,
+    cpu: f32,
+    mem: u64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Disk {
+    name: String,
+    used: u64,
+    total: u64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Service {
+    name: String,
+    status: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Usage {
+    cpu: f32,
+    mem_used: u64,
+    swap_used: u64,
+}
+
+fn load(path: &Path) -> Result<Snapshot, Box<dyn Error>> {
+    let content = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(serde_json::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))?)
+}
+
+pub fn run(before: &Path, after: &Path) -> Result<(), Box<dyn Error>> {
+    let report = diff(&load(before)?, &load(after)?);
+    std::io::Write::write_all(&mut std::io::stdout().lock(), report.as_bytes())?;
+    Ok(())
+}
+
+fn signed_size(bytes: i64) -> String {
+    format!("{}{}", if bytes < 0 { "-" } else { "+" }, format_size(bytes.unsigned_abs()))
+}
+
+// Writes a titled list, truncated to MAX_ROWS.
+fn section(out: &mut String, title: &str, rows: &[String]) {
+    let _ = writeln!(out, "{} ({})", title, rows.len());
+    if rows.is_empty() {
+        out.push_str("  none\n");
+    }
+    for row in rows.iter().take(MAX_ROWS) {
+        let _ = writeln!(out, "  {}", row);
+    }
+    if rows.len() > MAX_ROWS {
+        let _ = writeln!(out, "  ... and {} more", rows.len() - MAX_ROWS);
+    }
+    out.push('\n');
+}
+
+fn diff(before: &Snapshot, after: &Snapshot) -> String {
+    let mut out = String::new();
+    let (b, a) = (&before.global_usage, &after.global_usage);
+    let _ = writeln!(out, "System");
+    let _ = writeln!(out, "  CPU     {:.1}% -> {:.1}% ({:+.1})", b.cpu, a.cpu, a.cpu - b.cpu);
+    let _ = writeln!(out, "  Memory  {} -> {} ({})", format_size(b.mem_used), format_size(a.mem_used),
+        signed_size(a.mem_used as i64 - b.mem_used as i64));
+    let _ = writeln!(out, "  Swap    {} -> {} ({})\n", format_size(b.swap_used), format_size(a.swap_used),
+        signed_size(a.swap_used as i64 - b.swap_used as i64));
+
+    // A PID alone may have been reused by something else in between.
+    let same = |x: &Process, y: &Process| x.pid == y.pid && x.name == y.name;
+    let describe = |p: &Process| format!("{} {} ({}, {:.1}% CPU)", p.pid, p.name, format_size(p.mem), p.cpu);
+    let new: Vec<String> = after.processes.iter()
+        .filter(|p| !before.processes.iter().any(|q| same(p, q)))
+        .map(describe)
+        .collect();
+    let gone: Vec<String> = before.processes.iter()
+        .filter(|p| !after.processes.iter().any(|q| same(p, q)))
+        .map(describe)
+        .collect();
+    section(&mut out, "New processes", &new);
+    section(&mut out, "Gone processes", &gone);
+
+    let mut changed: Vec<(i64, String)> = after.processes.iter().filter_map(|p| {
+        let old = before.processes.iter().find(|q| same(p, q))?;
+        let mem = p.mem as i64 - old.mem as i64;
+        let cpu = p.cpu - old.cpu;
+        if mem.abs() < MIN_BYTES_CHANGE && cpu.abs() < MIN_CPU_CHANGE {
+            return None;
+        }
+        Some((mem.abs(), format!("{} {}: memory {} -> {} ({}), CPU {:.1}% -> {:.1}% ({:+.1})",
+            p.pid, p.name, format_size(old.mem), format_size(p.mem), signed_size(mem), old.cpu, p.cpu, cpu)))
+    }).collect();
+    changed.sort_by_key(|(bytes, _)| std::cmp::Reverse(*bytes));
+    section(&mut out, "Memory/CPU changes", &changed.into_iter().map(|(_, row)| row).collect::<Vec<_>>());
+
+    let disks: Vec<String> = after.disks.iter().filter_map(|d| {
+        let old = before.disks.iter().find(|o| o.name == d.name)?;
+        let growth = d.used as i64 - old.used as i64;
+        (growth.abs() >= MIN_BYTES_CHANGE).then(|| format!("{}: {} -> {} ({}, {} free)",
+            d.name, format_size(old.used), format_size(d.used), signed_size(growth), format_size(d.total.saturating_sub(d.used))))
+    }).collect();
+    section(&mut out, "Disk usage changes", &disks);
+
+    let mut services: Vec<String> = after.services.iter().filter_map(|s| {
+        match before.services.iter().find(|o| o.name == s.name) {
+            Some(old) if old.status != s.status => Some(format!("{}: {} -> {}", s.name, old.status, s.status)),
+            Some(_) => None,
+            None => Some(format!("{}: new ({})", s.name, s.status)),
+        }
+    }).collect();
+    services.extend(before.services.iter()
+        .filter(|o| !after.services.iter().any(|s| s.name == o.name))
+        .map(|o| format!("{}: removed (was {})", o.name, o.status)));
+    section(&mut out, "Service changes", &services);
+
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_diff() {
+        let before: Snapshot = serde_json::from_str(r#"{
+            "processes": [
+                {"pid": "10", "name": "postgres", "cpu": 2.0, "mem": 104857600, "user": "pg"},
+                {"pid": "20", "name": "old-worker", "cpu": 0.0, "mem": 1048576},
+                {"pid": "30", "name": "sshd", "cpu": 0.0, "mem": 5242880}
+            ],
+            "disks": [{"name": "/", "used": 1073741824, "total": 10737418240}],
+            "services": [{"name": "nginx.service", "status": "active"}, {"name": "cron.service", "status": "active"}],
+            "global_usage": {"cpu": 10.0, "mem_used": 1073741824, "swap_used": 0}
+        }"#).unwrap();
+        let after: Snapshot = serde_json::from_str(r#"{
+            "processes": [
+                {"pid": "10", "name": "postgres", "cpu": 20.0, "mem": 314572800},
+                {"pid": "20", "name": "new-worker", "cpu": 1.0, "mem": 2097152},
+                {"pid": "30", "name": "sshd", "cpu": 0.0, "mem": 5242880}
+            ],
+            "disks": [{"name": "/", "used": 2147483648, "total": 10737418240}],
+            "services": [{"name": "nginx.service", "status": "failed"}, {"name": "cron.service", "status": "active"}]
+        }"#).unwrap();
+
+        let report = diff(&before, &after);
+        assert!(report.contains("New processes (1)\n  20 new-worker (2.0 MiB, 1.0% CPU)\n"));
+        assert!(report.contains("Gone processes (1)\n  20 old-worker"));
+        assert!(report.contains("Memory/CPU changes (1)\n  10 postgres: memory 100.0 MiB -> 300.0 MiB (+200.0 MiB), CPU 2.0% -> 20.0% (+18.0)\n"));
+        assert!(report.contains("Disk usage changes (1)\n  /: 1.0 GiB -> 2.0 GiB (+1.0 GiB, 8.0 GiB free)\n"));
+        assert!(report.contains("Service changes (1)\n  nginx.service: active -> failed"));
+        assert!(report.contains("Memory  1.0 GiB -> 0 B (-1.0 GiB)"));
+    }
+}
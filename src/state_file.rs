@@ -0,0 +1,64 @@
+// UI state remembered between runs. It lives apart from configuration, under
+// the platform state directory, so deleting it only ever loses convenience.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Tab, ThemeName};
+use crate::types::{AppState, ProcessSortBy};
+
+// Fields missing from files written by older versions take their defaults.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedState {
+    pub last_tab: Option<Tab>,
+    pub theme: Option<ThemeName>,
+    pub sort: Option<ProcessSortBy>,
+    pub sort_ascending: bool,
+    pub filter: String,
+    pub show_container_column: bool,
+    pub show_state_columns: bool,
+    pub show_container_details: bool,
+    pub hide_summary: bool,
+}
+
+impl SavedState {
+    pub fn from_state(state: &AppState) -> Self {
+        Self {
+            last_tab: Tab::from_index(state.active_tab),
+            theme: ThemeName::from_index(state.current_theme),
+            sort: Some(state.sort_by.clone()),
+            sort_ascending: state.sort_ascending,
+            filter: state.filter_text.clone(),
+            show_container_column: state.show_container_column,
+            show_state_columns: state.show_state_columns,
+            show_container_details: state.show_container_details,
+            hide_summary: state.hide_summary,
+        }
+    }
+}
+
+pub fn path() -> Option<PathBuf> {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .map(|dir| dir.join("puls").join("state.json"))
+}
+
+// A missing or unreadable file just means a first run.
+pub fn load() -> SavedState {
+    path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(state: &SavedState) -> io::Result<()> {
+    let path = path().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no state directory"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(state)?)
+}
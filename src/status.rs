@@ -0,0 +1,151 @@
+// Status messages raised from anywhere in the app. While the TUI owns the
+// terminal, printing to stderr corrupts the alternate screen, so messages are
+// routed to the UI loop instead: the footer shows the latest warning and the
+// full history is kept in a bounded buffer, viewable with Ctrl+L.
+//
+// Code reports through the `log` macros; `BusLogger` forwards every record
+// onto the bus.
+
+use std::collections::VecDeque;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::types::AppMessage;
+
+const BUFFER_CAPACITY: usize = 200;
+const FOOTER_TIMEOUT: Duration = Duration::from_secs(5);
+
+static BUS: OnceLock<UnboundedSender<AppMessage>> = OnceLock::new();
+static LOGGER: BusLogger = BusLogger;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum StatusLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+impl StatusLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatusLevel::Info => "INFO",
+            StatusLevel::Warning => "WARN",
+            StatusLevel::Error => "ERROR",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StatusMessage {
+    pub level: StatusLevel,
+    pub text: String,
+    pub time: DateTime<Local>,
+    pub received: Instant,
+}
+
+pub fn init(tx: UnboundedSender<AppMessage>) {
+    let _ = BUS.set(tx);
+}
+
+pub fn init_logger(verbose: bool) -> Result<(), log::SetLoggerError> {
+    log::set_logger(&LOGGER)?;
+    log::set_max_level(if verbose { log::LevelFilter::Debug } else { log::LevelFilter::Info });
+    Ok(())
+}
+
+pub fn report(level: StatusLevel, text: impl Into<String>) {
+    let message = StatusMessage {
+        level,
+        text: text.into(),
+        time: Local::now(),
+        received: Instant::now(),
+    };
+
+    if level == StatusLevel::Error {
+        crate::error_logger::log_error(&message.text);
+    }
+
+    // Without a UI (headless subcommands) stderr is free to use.
+    match BUS.get() {
+        Some(tx) => {
+            let _ = tx.send(AppMessage::Status(message));
+        }
+        None => eprintln!("{}: {}", message.level.label(), message.text),
+    }
+}
+
+struct BusLogger;
+
+impl log::Log for BusLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let level = match record.level() {
+            log::Level::Error => StatusLevel::Error,
+            log::Level::Warn => StatusLevel::Warning,
+            _ => StatusLevel::Info,
+        };
+        report(level, record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StatusLog {
+    messages: VecDeque<StatusMessage>,
+}
+
+impl StatusLog {
+    pub fn push(&mut self, message: StatusMessage) {
+        if self.messages.len() == BUFFER_CAPACITY {
+            self.messages.pop_front();
+        }
+        self.messages.push_back(message);
+    }
+
+    pub fn messages(&self) -> impl DoubleEndedIterator<Item = &StatusMessage> + ExactSizeIterator {
+        self.messages.iter()
+    }
+
+    // The most recent warning or error, while it is still fresh enough to show.
+    pub fn footer_message(&self) -> Option<&StatusMessage> {
+        self.messages.iter()
+            .rev()
+            .find(|m| m.level >= StatusLevel::Warning)
+            .filter(|m| m.received.elapsed() < FOOTER_TIMEOUT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(level: StatusLevel, text: &str) -> StatusMessage {
+        StatusMessage { level, text: text.to_string(), time: Local::now(), received: Instant::now() }
+    }
+
+    #[test]
+    fn test_status_log() {
+        let mut log = StatusLog::default();
+        log.push(message(StatusLevel::Warning, "docker slow"));
+        log.push(message(StatusLevel::Info, "started"));
+        assert_eq!(log.footer_message().map(|m| m.text.as_str()), Some("docker slow"));
+
+        for i in 0..BUFFER_CAPACITY {
+            log.push(message(StatusLevel::Info, &i.to_string()));
+        }
+        assert_eq!(log.messages.len(), BUFFER_CAPACITY);
+        assert_eq!(log.messages.front().map(|m| m.text.as_str()), Some("0"));
+        assert!(log.footer_message().is_none());
+    }
+}
@@ -2,7 +2,9 @@ use std::process::Command;
 use std::path::Path;
 use std::io::Write;
 use std::collections::{HashMap, HashSet};
-use crate::types::{ServiceInfo, LogEntry, ConfigItem};
+use crate::types::{ServiceInfo, ServiceSortBy, LogEntry, ConfigItem, KillSignal};
+use crate::monitors::cgroup_monitor::{cgroup_root, parse_cpu_stat};
+use crate::{macos_service, windows_service};
 use chrono::Local;
 
 pub struct SystemManager {
@@ -19,11 +21,24 @@ impl SystemManager {
         self.has_sudo
     }
 
+    #[cfg(unix)]
     fn check_sudo() -> bool {
         users::get_current_uid() == 0
     }
 
+    #[cfg(not(unix))]
+    fn check_sudo() -> bool {
+        windows_service::is_elevated()
+    }
+
     pub fn get_services(&self) -> Vec<ServiceInfo> {
+        if cfg!(windows) {
+            return windows_service::get_services(self.has_sudo);
+        }
+        if cfg!(target_os = "macos") {
+            return macos_service::get_services(self.has_sudo);
+        }
+        
         let mut services = Vec::new();
         let mut loaded_states = HashMap::new();
         let mut visited_services = HashSet::new();
@@ -89,6 +104,7 @@ impl SystemManager {
                         enabled: is_enabled,
                         can_start: !is_running && self.has_sudo,
                         can_stop: is_running && self.has_sudo,
+                        ..Default::default()
                     });
                 }
             }
@@ -110,11 +126,13 @@ impl SystemManager {
                      enabled: false,
                      can_start: !is_running && self.has_sudo,
                      can_stop: is_running && self.has_sudo,
+                     ..Default::default()
                  });
             }
         }
         
         services.sort_by(|a, b| a.name.cmp(&b.name));
+        update_service_usage(&mut services);
 
         services
     }
@@ -124,6 +142,13 @@ impl SystemManager {
             return Err("Insufficient privileges (root required)".to_string());
         }
 
+        if cfg!(windows) {
+            return windows_service::service_action("start", service_name);
+        }
+        if cfg!(target_os = "macos") {
+            return macos_service::service_action("start", service_name);
+        }
+
         let output = Command::new("systemctl")
             .args(&["start", &format!("{}.service", service_name)])
             .output()
@@ -141,6 +166,13 @@ impl SystemManager {
             return Err("Insufficient privileges (root required)".to_string());
         }
 
+        if cfg!(windows) {
+            return windows_service::service_action("stop", service_name);
+        }
+        if cfg!(target_os = "macos") {
+            return macos_service::service_action("stop", service_name);
+        }
+
         let output = Command::new("systemctl")
             .args(&["stop", &format!("{}.service", service_name)])
             .output()
@@ -158,6 +190,13 @@ impl SystemManager {
             return Err("Insufficient privileges (root required)".to_string());
         }
 
+        if cfg!(windows) {
+            return windows_service::service_action("restart", service_name);
+        }
+        if cfg!(target_os = "macos") {
+            return macos_service::service_action("restart", service_name);
+        }
+
         let output = Command::new("systemctl")
             .args(&["restart", &format!("{}.service", service_name)])
             .output()
@@ -175,6 +214,13 @@ impl SystemManager {
             return Err("Insufficient privileges (root required)".to_string());
         }
 
+        if cfg!(windows) {
+            return windows_service::service_action("enable", service_name);
+        }
+        if cfg!(target_os = "macos") {
+            return macos_service::service_action("enable", service_name);
+        }
+
         let output = Command::new("systemctl")
             .args(&["enable", &format!("{}.service", service_name)])
             .output()
@@ -192,6 +238,13 @@ impl SystemManager {
             return Err("Insufficient privileges (root required)".to_string());
         }
 
+        if cfg!(windows) {
+            return windows_service::service_action("disable", service_name);
+        }
+        if cfg!(target_os = "macos") {
+            return macos_service::service_action("disable", service_name);
+        }
+
         let output = Command::new("systemctl")
             .args(&["disable", &format!("{}.service", service_name)])
             .output()
@@ -204,7 +257,35 @@ impl SystemManager {
         }
     }
 
+    // Processes of other users need root, which kill reports rather than
+    // this checking for it up front.
+    pub fn signal_process(&self, pid: u32, signal: KillSignal) -> Result<(), String> {
+        let output = if cfg!(windows) {
+            let mut args = vec!["/PID".to_string(), pid.to_string()];
+            if signal == KillSignal::Kill {
+                args.insert(0, "/F".to_string());
+            }
+            Command::new("taskkill").args(&args).output()
+        } else {
+            Command::new("kill").args([signal.kill_arg(), &pid.to_string()]).output()
+        }
+        .map_err(|e| e.to_string())?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        }
+    }
+
     pub fn get_service_status(&self, service_name: &str) -> String {
+        if cfg!(windows) {
+            return windows_service::get_service_status(service_name);
+        }
+        if cfg!(target_os = "macos") {
+            return macos_service::get_service_status(service_name);
+        }
+
         let output = Command::new("systemctl")
             .args(&["status", &format!("{}.service", service_name), "--no-pager"])
             .output();
@@ -222,9 +303,31 @@ impl SystemManager {
         }
     }
 
+    // None when the state can't be queried (no systemd, or other platforms).
+    pub fn is_service_active(&self, service_name: &str) -> Option<bool> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+
+        let output = Command::new("systemctl")
+            .args(["is-active", &format!("{}.service", service_name)])
+            .output()
+            .ok()?;
+        match String::from_utf8_lossy(&output.stdout).trim() {
+            "active" | "reloading" | "activating" | "deactivating" | "refreshing" => Some(true),
+            "inactive" | "failed" => Some(false),
+            _ => None,
+        }
+    }
+
     pub fn get_boots(&self) -> Vec<crate::types::BootInfo> {
         let mut boots = Vec::new();
         
+        // Neither the Windows event log nor the unified log has a notion of boots.
+        if !cfg!(target_os = "linux") {
+            return boots;
+        }
+        
         let output = match Command::new("journalctl")
             .arg("--list-boots")
             .output()
@@ -252,6 +355,13 @@ impl SystemManager {
     }
 
     pub fn get_logs(&self, limit: usize, filter: Option<&str>, boot_id: Option<&str>) -> Vec<LogEntry> {
+        if cfg!(windows) {
+            return windows_service::get_logs(limit, filter);
+        }
+        if cfg!(target_os = "macos") {
+            return macos_service::get_logs(limit, filter);
+        }
+
         let mut logs = Vec::new();
 
         let mut args = vec![
@@ -322,6 +432,17 @@ impl SystemManager {
         let mut configs = Vec::new();
         let grub_file = "/etc/default/grub";
 
+        // WSL boots through the Windows host, so there is no GRUB to edit.
+        if !cfg!(target_os = "linux") || crate::utils::detect_wsl().is_some() {
+            configs.push(ConfigItem {
+                key: "hostname".to_string(),
+                value: sysinfo::System::host_name().unwrap_or_default(),
+                description: "System hostname".to_string(),
+                category: "System".to_string(),
+            });
+            return configs;
+        }
+
         if !Path::new(grub_file).exists() {
             return configs;
         }
@@ -383,6 +504,10 @@ impl SystemManager {
             return Err("Insufficient privileges (root required)".to_string());
         }
 
+        if !cfg!(target_os = "linux") || crate::utils::detect_wsl().is_some() {
+            return Err("GRUB configuration is not available on this system".to_string());
+        }
+
         let grub_file = "/etc/default/grub";
         
         let timestamp = Local::now().format("%Y%m%d_%H%M%S").to_string();
@@ -434,6 +559,17 @@ impl SystemManager {
             return Err("Insufficient privileges (root required)".to_string());
         }
 
+        if cfg!(windows) {
+            return Err("Changing the hostname is not supported on Windows".to_string());
+        }
+        if cfg!(target_os = "macos") {
+            return Command::new("scutil")
+                .args(["--set", "HostName", new_hostname])
+                .output()
+                .map(|_| ())
+                .map_err(|e| e.to_string());
+        }
+
         Command::new("hostnamectl")
             .args(&["set-hostname", new_hostname])
             .output()
@@ -447,6 +583,21 @@ impl SystemManager {
             return Err("Insufficient privileges (root required)".to_string());
         }
 
+        if cfg!(windows) {
+            return Command::new("tzutil")
+                .args(["/s", timezone])
+                .output()
+                .map(|_| ())
+                .map_err(|e| e.to_string());
+        }
+        if cfg!(target_os = "macos") {
+            return Command::new("systemsetup")
+                .args(["-settimezone", timezone])
+                .output()
+                .map(|_| ())
+                .map_err(|e| e.to_string());
+        }
+
         Command::new("timedatectl")
             .args(&["set-timezone", timezone])
             .output()
@@ -460,4 +611,49 @@ impl Default for SystemManager {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+// Memory, CPU time and tasks of each running unit, read from its cgroup in
+// system.slice. Units without a cgroup there keep None.
+pub fn update_service_usage(services: &mut [ServiceInfo]) {
+    let Some(root) = cgroup_root() else { return };
+    let slice = root.join("system.slice");
+    for service in services {
+        let dir = slice.join(format!("{}.service", service.name));
+        let read = |file: &str| std::fs::read_to_string(dir.join(file)).ok();
+        service.memory = read("memory.current").and_then(|c| c.trim().parse().ok());
+        service.cpu_time_usec = read("cpu.stat").and_then(|c| parse_cpu_stat(&c));
+        service.tasks = read("pids.current")
+            .and_then(|c| c.trim().parse().ok())
+            .or_else(|| read("cgroup.threads").map(|c| c.lines().count() as u64));
+    }
+}
+
+pub fn sort_services(services: &mut [ServiceInfo], sort_by: ServiceSortBy, reversed: bool) {
+    services.sort_by(|a, b| {
+        let cmp = match sort_by {
+            ServiceSortBy::Name => a.name.cmp(&b.name),
+            ServiceSortBy::Memory => b.memory.cmp(&a.memory),
+            ServiceSortBy::CpuTime => b.cpu_time_usec.cmp(&a.cpu_time_usec),
+            ServiceSortBy::Tasks => b.tasks.cmp(&a.tasks),
+        };
+        if reversed { cmp.reverse() } else { cmp }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_services() {
+        let service = |name: &str, memory: Option<u64>| ServiceInfo { name: name.to_string(), memory, ..Default::default() };
+        let mut services = vec![service("cron", Some(4096)), service("bluetooth", None), service("sshd", Some(8192))];
+
+        sort_services(&mut services, ServiceSortBy::Memory, false);
+        let names: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["sshd", "cron", "bluetooth"]);
+
+        sort_services(&mut services, ServiceSortBy::Name, true);
+        assert_eq!(services[0].name, "sshd");
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::VecDeque;
+use std::time::Duration;
 use sysinfo::Pid;
 use ratatui::widgets::TableState;
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug)]
 pub struct NetworkStats {
@@ -16,7 +18,7 @@ pub struct ContainerIoStats {
     pub disk_w: u64,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ProcessInfo {
     pub pid: String,
     pub name: String,
@@ -26,11 +28,82 @@ pub struct ProcessInfo {
     pub mem_display: String, 
     pub disk_read: String,
     pub disk_write: String,
+    // Bytes per second, for sorting.
+    #[serde(default)]
+    pub disk_read_rate: u64,
+    #[serde(default)]
+    pub disk_write_rate: u64,
+    // Resident memory change in bytes per minute over the leak window.
+    #[serde(default)]
+    pub mem_growth: i64,
+    // RSS only went up, and by something, across the whole leak window.
+    #[serde(default)]
+    pub leak_suspect: bool,
     pub user: String,
     pub status: String,
+    pub container: Option<String>,
+    #[serde(default)]
+    pub parent: Option<String>,
+    // 0 where the platform doesn't report threads.
+    #[serde(default)]
+    pub threads: u32,
+    // Seconds since the epoch.
+    #[serde(default)]
+    pub start_time: u64,
+    // User plus system CPU seconds over the process's lifetime; None where
+    // the platform doesn't report it.
+    #[serde(default)]
+    pub cpu_time: Option<u64>,
+    // Tree guides drawn before the name in tree mode, e.g. "│  └─ ".
+    #[serde(default)]
+    pub tree_prefix: String,
+    // Descendants hidden under this process while it is collapsed.
+    #[serde(default)]
+    pub hidden_children: usize,
+    // Bytes swapped out (VmSwap); None off Linux and for kernel threads.
+    #[serde(default)]
+    pub swap: Option<u64>,
 }
 
-#[derive(Clone, Debug)]
+impl ProcessInfo {
+    // Suspended with SIGSTOP (or a job-control stop).
+    pub fn is_stopped(&self) -> bool {
+        self.status == STOPPED_STATUS
+    }
+
+    pub fn is_zombie(&self) -> bool {
+        matches!(self.status.to_lowercase().as_str(), "zombie" | "z")
+    }
+
+    // Blocked in the kernel, usually on I/O; many of these point at a slow
+    // or hung disk or network mount.
+    pub fn is_disk_sleep(&self) -> bool {
+        self.status == DISK_SLEEP_STATUS
+    }
+
+    // The one-letter state `ps` shows.
+    pub fn state_letter(&self) -> &'static str {
+        match self.status.as_str() {
+            "Running" | "Runnable" => "R",
+            "Sleeping" => "S",
+            "Idle" => "I",
+            DISK_SLEEP_STATUS => "D",
+            "Zombie" => "Z",
+            STOPPED_STATUS => "T",
+            "Tracing" => "t",
+            "Dead" => "X",
+            "Parked" => "P",
+            "Waking" | "Wakekill" => "W",
+            _ => "?",
+        }
+    }
+}
+
+// sysinfo's names for ProcessStatus::Stop and ProcessStatus::UninterruptibleDiskSleep.
+pub const STOPPED_STATUS: &str = "Stopped";
+pub const DISK_SLEEP_STATUS: &str = "UninterruptibleDiskSleep";
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ContainerInfo {
     pub id: String,
     pub name: String,
@@ -43,29 +116,125 @@ pub struct ContainerInfo {
     pub disk_w: String,
     pub image: String,
     pub ports: String,
+    // Bytes; None until the image list has been read.
+    #[serde(default)]
+    pub image_size: Option<u64>,
+    // Seconds since the epoch.
+    #[serde(default)]
+    pub created: Option<i64>,
+    #[serde(default)]
+    pub started: Option<i64>,
+    #[serde(default)]
+    pub restart_count: u32,
+    // CPU in percent of one core and memory in bytes, as `cpu` and `mem` show.
+    #[serde(default)]
+    pub cpu_percent: f64,
+    #[serde(default)]
+    pub mem_bytes: u64,
+    // From the container's HostConfig; None when it has no limit.
+    #[serde(default)]
+    pub cpu_limit: Option<f64>,
+    #[serde(default)]
+    pub mem_limit: Option<u64>,
 }
 
-#[derive(Clone, Debug, Default)]
+impl ContainerInfo {
+    // Usage as a share of the container's own limits rather than the host's.
+    pub fn cpu_of_limit(&self) -> Option<f64> {
+        self.cpu_limit.filter(|&cores| cores > 0.0).map(|cores| self.cpu_percent / cores)
+    }
+
+    pub fn mem_of_limit(&self) -> Option<f64> {
+        self.mem_limit.filter(|&limit| limit > 0).map(|limit| self.mem_bytes as f64 / limit as f64 * 100.0)
+    }
+}
+
+// The kernel killed a process in a container for going over its memory limit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContainerOom {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub name: String,
+}
+
+impl ContainerOom {
+    pub const ALERT_WINDOW: chrono::Duration = chrono::Duration::minutes(5);
+    pub const RETAINED: usize = 50;
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct GpuInfo {
     pub name: String,
     pub brand: String,
-    pub utilization: u32,
+    pub utilization: Option<u32>,
     pub memory_used: u64,
     pub memory_total: u64,
-    pub temperature: u32,
+    pub temperature: Option<u32>,
     pub memory_temperature: Option<u32>,
-    pub power_usage: u32,
-    pub graphics_clock: u32,
-    pub memory_clock: u32,
+    pub power_usage: Option<u32>,
+    pub graphics_clock: Option<u32>,
+    pub memory_clock: Option<u32>,
+    pub sm_clock: Option<u32>,
+    pub video_clock: Option<u32>,
+    pub max_graphics_clock: Option<u32>,
     pub fan_speed: Option<u32>,
     pub utilization_history: Vec<u32>,
     pub memory_history: Vec<u32>,
+    pub temperature_history: Vec<u32>,
     pub pci_link_gen: Option<u32>,
     pub pci_link_width: Option<u32>,
+    pub pci_link_gen_max: Option<u32>,
+    pub pci_link_width_max: Option<u32>,
+    pub pcie_rx_bytes: Option<u64>,
+    pub pcie_tx_bytes: Option<u64>,
+    // Seconds since the PCIe RX/TX rates were read; they come from a report
+    // that is only produced now and then.
+    pub pcie_sample_age: Option<u64>,
+    pub pci_bus_id: Option<String>,
+    pub throttle_reasons: Vec<String>,
+    pub ecc_errors_corrected: Option<u64>,
+    pub ecc_errors_uncorrected: Option<u64>,
+    // None where the GPU reports neither retired pages nor remapped rows.
+    #[serde(default)]
+    pub memory_repair: Option<GpuMemoryRepair>,
+    // Uncorrected ECC errors, retired pages and remapped rows added since puls started.
+    #[serde(default)]
+    pub ecc_growth: u64,
     pub driver_version: String,
 }
 
-#[derive(Clone, Debug, Default)]
+// How an NVIDIA datacenter GPU has worked around bad memory: pages retired
+// after ECC errors (before Ampere) or rows remapped (Ampere and later). A
+// pending retirement or remap takes effect at the next GPU reset.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GpuMemoryRepair {
+    pub retired_single_bit: Option<u64>,
+    pub retired_double_bit: Option<u64>,
+    pub retired_pending: bool,
+    pub remapped_correctable: Option<u64>,
+    pub remapped_uncorrectable: Option<u64>,
+    pub remapped_pending: bool,
+    pub remap_failed: bool,
+}
+
+impl GpuMemoryRepair {
+    pub fn total(&self) -> u64 {
+        [self.retired_single_bit, self.retired_double_bit, self.remapped_correctable, self.remapped_uncorrectable]
+            .iter()
+            .flatten()
+            .sum()
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GpuProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    pub gpu_index: Option<usize>,
+    pub kind: String,
+    pub memory_used: u64,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DetailedProcessInfo {
     pub pid: String,
     pub name: String,
@@ -74,23 +243,240 @@ pub struct DetailedProcessInfo {
     pub cpu_usage: f32,
     pub memory_rss: u64,
     pub memory_vms: u64,
+    #[serde(default)]
+    pub memory_swap: Option<u64>,
     pub command: String,
+    // The command line as separate arguments; `command` joins them with spaces.
+    #[serde(default)]
+    pub args: Vec<String>,
     pub start_time: String,
     pub parent: Option<String>,
     pub environ: Vec<String>,
     pub threads: u32,
     pub file_descriptors: Option<u32>,
     pub cwd: Option<String>,
+    // Recorded for as long as the process stays selected.
+    #[serde(default)]
+    pub cpu_history: VecDeque<f32>,
+    #[serde(default)]
+    pub memory_history: VecDeque<u64>,
+    // Linux only, and only readable for our own processes unless root.
+    #[serde(default)]
+    pub io: Option<ProcessIo>,
+    #[serde(default)]
+    pub security: Option<ProcessSecurity>,
+    // Linux only; CPU usage is filled in from the previous sample.
+    #[serde(default)]
+    pub thread_list: Vec<ThreadInfo>,
+    #[serde(default)]
+    pub io_priority: Option<IoPriority>,
 }
 
-#[derive(Clone, Debug, Default)]
+impl DetailedProcessInfo {
+    // The command line with arguments quoted for a shell, so it can be
+    // copied and run again. Kernel threads have no arguments.
+    pub fn command_line(&self) -> String {
+        if self.args.is_empty() {
+            return self.command.clone();
+        }
+        self.args.iter().map(|arg| crate::utils::shell_quote(arg)).collect::<Vec<_>>().join(" ")
+    }
+}
+
+// I/O scheduling classes of ioprio_set(2), in ionice's numbering.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IoClass {
+    // No class set: the kernel derives best-effort from the nice value.
+    #[default]
+    None,
+    Realtime,
+    BestEffort,
+    Idle,
+}
+
+impl IoClass {
+    pub const ALL: [IoClass; 4] = [IoClass::None, IoClass::Realtime, IoClass::BestEffort, IoClass::Idle];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            IoClass::None => "none",
+            IoClass::Realtime => "realtime",
+            IoClass::BestEffort => "best-effort",
+            IoClass::Idle => "idle",
+        }
+    }
+
+    // Only realtime and best-effort take a level.
+    pub fn has_level(self) -> bool {
+        matches!(self, IoClass::Realtime | IoClass::BestEffort)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IoPriority {
+    pub class: IoClass,
+    // 0 (highest) to 7.
+    pub level: u8,
+}
+
+impl std::fmt::Display for IoPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.class.has_level() {
+            write!(f, "{} {}", self.class.label(), self.level)
+        } else {
+            write!(f, "{}", self.class.label())
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThreadInfo {
+    pub tid: u32,
+    pub name: String,
+    pub state: String,
+    // Percent of one core, as in top's thread mode.
+    pub cpu_usage: f32,
+    pub cpu_time: Duration,
+}
+
+// Capability names indexed by bit, as in linux/capability.h.
+pub const CAPABILITY_NAMES: [&str; 41] = [
+    "chown", "dac_override", "dac_read_search", "fowner", "fsetid", "kill", "setgid", "setuid",
+    "setpcap", "linux_immutable", "net_bind_service", "net_broadcast", "net_admin", "net_raw",
+    "ipc_lock", "ipc_owner", "sys_module", "sys_rawio", "sys_chroot", "sys_ptrace", "sys_pacct",
+    "sys_admin", "sys_boot", "sys_nice", "sys_resource", "sys_time", "sys_tty_config", "mknod",
+    "lease", "audit_write", "audit_control", "setfcap", "mac_override", "mac_admin", "syslog",
+    "wake_alarm", "block_suspend", "audit_read", "perfmon", "bpf", "checkpoint_restore",
+];
+
+// LSM label and privilege restrictions from /proc/<pid>/attr and /proc/<pid>/status.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProcessSecurity {
+    // "SELinux" or "AppArmor", with the process's context or profile.
+    pub lsm: Option<String>,
+    pub label: Option<String>,
+    pub cap_eff: u64,
+    pub no_new_privs: bool,
+    // 0 disabled, 1 strict, 2 filter.
+    pub seccomp: u8,
+}
+
+impl ProcessSecurity {
+    pub fn capabilities(&self) -> Vec<String> {
+        (0..64)
+            .filter(|bit| self.cap_eff & (1 << bit) != 0)
+            .map(|bit| match CAPABILITY_NAMES.get(bit) {
+                Some(name) => format!("cap_{}", name),
+                None => format!("cap_{}", bit),
+            })
+            .collect()
+    }
+
+    // Short form for display: root processes hold (nearly) every capability,
+    // so those are described by what they lack.
+    pub fn capabilities_summary(&self) -> String {
+        let held = self.capabilities();
+        if held.is_empty() {
+            return "none".to_string();
+        }
+        let missing: Vec<String> = CAPABILITY_NAMES.iter().enumerate()
+            .filter(|(bit, _)| self.cap_eff & (1 << bit) == 0)
+            .map(|(_, name)| format!("cap_{}", name))
+            .collect();
+        match missing.len() {
+            0 => "all".to_string(),
+            n if n < CAPABILITY_NAMES.len() / 4 => format!("all except {}", missing.join(", ")),
+            _ => held.join(", "),
+        }
+    }
+
+    pub fn seccomp_mode(&self) -> &'static str {
+        match self.seccomp {
+            0 => "disabled",
+            1 => "strict",
+            _ => "filter",
+        }
+    }
+}
+
+// Cumulative I/O counters from /proc/<pid>/io. `rchar`/`wchar` count every
+// byte passed through read and write calls, page cache hits included;
+// `read_bytes`/`write_bytes` only what actually reached the block layer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProcessIo {
+    pub rchar: u64,
+    pub wchar: u64,
+    pub syscr: u64,
+    pub syscw: u64,
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+    pub cancelled_write_bytes: u64,
+}
+
+impl ProcessIo {
+    // Share of the bytes read that came from the page cache rather than disk.
+    pub fn cache_hit_percent(&self) -> Option<f64> {
+        (self.rchar > 0).then(|| self.rchar.saturating_sub(self.read_bytes) as f64 / self.rchar as f64 * 100.0)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct CoreInfo {
     pub usage: f32,
     pub freq: u64,
     pub temp: Option<f32>,
+    // From /proc/stat, so Linux only; None on the first sample.
+    #[serde(default)]
+    pub breakdown: Option<CpuBreakdown>,
 }
 
-#[derive(Clone, Debug, Default)]
+// Cumulative jiffies of one CPU line in /proc/stat.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CpuTimes {
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+}
+
+impl CpuTimes {
+    fn total(&self) -> u64 {
+        self.user + self.nice + self.system + self.idle + self.iowait + self.irq + self.softirq + self.steal
+    }
+
+    // Share of the time since `prev` spent in each state, in percent.
+    pub fn breakdown_since(&self, prev: &CpuTimes) -> CpuBreakdown {
+        let total = self.total().saturating_sub(prev.total());
+        if total == 0 {
+            return CpuBreakdown::default();
+        }
+        let percent = |now: u64, before: u64| now.saturating_sub(before) as f32 / total as f32 * 100.0;
+        CpuBreakdown {
+            user: percent(self.user + self.nice, prev.user + prev.nice),
+            system: percent(self.system, prev.system),
+            iowait: percent(self.iowait, prev.iowait),
+            irq: percent(self.irq + self.softirq, prev.irq + prev.softirq),
+            steal: percent(self.steal, prev.steal),
+        }
+    }
+}
+
+// Where a core's time went, in percent; idle is the remainder. Nice time
+// counts as user and softirq as irq.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CpuBreakdown {
+    pub user: f32,
+    pub system: f32,
+    pub iowait: f32,
+    pub irq: f32,
+    pub steal: f32,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DetailedDiskInfo {
     pub name: String,
     pub device: String,
@@ -100,12 +486,25 @@ pub struct DetailedDiskInfo {
     pub used: u64,
     pub read_rate: u64,
     pub write_rate: u64,
+    // Per second.
     pub read_ops: u64,
     pub write_ops: u64,
     pub is_ssd: Option<bool>,
+    // The /proc/diskstats entry the rates come from; None for tmpfs, overlays
+    // and on other platforms, where the rates stay 0.
+    #[serde(default)]
+    pub io_device: Option<String>,
+    #[serde(default)]
+    pub read_history: VecDeque<u64>,
+    #[serde(default)]
+    pub write_history: VecDeque<u64>,
+    // Seconds until full at the rate usage grew over the last hour; None
+    // until there are a few minutes of history, or while it isn't growing.
+    #[serde(default)]
+    pub time_to_full: Option<u64>,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DetailedNetInfo {
     pub name: String,
     pub down_rate: u64,
@@ -120,14 +519,39 @@ pub struct DetailedNetInfo {
     pub is_up: bool,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct SystemTemperatures {
     pub cpu_temp: Option<f32>,
     pub gpu_temps: Vec<f32>,
     pub motherboard_temp: Option<f32>,
+    // NVMe drives by model, in °C.
+    #[serde(default)]
+    pub drives: Vec<(String, f32)>,
+    // Recent readings per sensor, oldest first, in the order of `readings`.
+    // GPUs keep their own history.
+    #[serde(default)]
+    pub history: Vec<(String, VecDeque<f32>)>,
+    // Why host sensors can't be read at all, as opposed to there being none.
+    #[serde(default)]
+    pub unavailable: Option<String>,
 }
 
-#[derive(Clone, Debug)]
+impl SystemTemperatures {
+    // Current value of every sensor that has a history.
+    pub fn readings(&self) -> Vec<(String, f32)> {
+        let mut readings = Vec::new();
+        if let Some(temp) = self.cpu_temp {
+            readings.push(("CPU".to_string(), temp));
+        }
+        if let Some(temp) = self.motherboard_temp {
+            readings.push(("Board".to_string(), temp));
+        }
+        readings.extend(self.drives.iter().cloned());
+        readings
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GlobalUsage {
     pub cpu: f32,
     pub mem_used: u64,
@@ -152,6 +576,65 @@ pub struct GlobalUsage {
     pub load_average: (f64, f64, f64),
     pub uptime: u64,
     pub boot_time: u64,
+    // Linux only; read from /proc/meminfo.
+    #[serde(default)]
+    pub memory: Option<MemoryBreakdown>,
+    // Runnable tasks (procs_running in /proc/stat), Linux only.
+    #[serde(default)]
+    pub run_queue: Option<u32>,
+    // Tasks in uninterruptible sleep, almost always waiting on I/O
+    // (procs_blocked in /proc/stat), Linux only.
+    #[serde(default)]
+    pub blocked: Option<u32>,
+    // 1, 5 and 15 minute averages, oldest first.
+    #[serde(default)]
+    pub load_history: VecDeque<(f64, f64, f64)>,
+    #[serde(default)]
+    pub run_queue_history: VecDeque<u32>,
+    #[serde(default)]
+    pub blocked_history: VecDeque<u32>,
+}
+
+// Where the memory is, in bytes. "Used" alone counts page cache that the
+// kernel hands back as soon as applications need it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemoryBreakdown {
+    pub total: u64,
+    pub free: u64,
+    pub available: u64,
+    pub buffers: u64,
+    pub cached: u64,
+    pub shmem: u64,
+    pub slab_reclaimable: u64,
+    pub slab_unreclaimable: u64,
+    pub dirty: u64,
+    pub writeback: u64,
+    pub swap_total: u64,
+    pub swap_free: u64,
+    pub swap_cached: u64,
+}
+
+impl MemoryBreakdown {
+    // Memory held by processes and the kernel that can't simply be dropped.
+    pub fn apps(&self) -> u64 {
+        self.total
+            .saturating_sub(self.free)
+            .saturating_sub(self.buffers)
+            .saturating_sub(self.cached)
+            .saturating_sub(self.slab_reclaimable)
+            .saturating_sub(self.slab_unreclaimable)
+    }
+
+    // Stacked bar segments; they add up to `total`.
+    pub fn segments(&self) -> [(&'static str, u64); 5] {
+        [
+            ("Apps", self.apps()),
+            ("Kernel", self.slab_unreclaimable),
+            ("Buffers", self.buffers),
+            ("Cache", self.cached + self.slab_reclaimable),
+            ("Free", self.free),
+        ]
+    }
 }
 
 impl Default for GlobalUsage {
@@ -180,11 +663,17 @@ impl Default for GlobalUsage {
             load_average: (0.0, 0.0, 0.0),
             uptime: 0,
             boot_time: 0,
+            memory: None,
+            run_queue: None,
+            blocked: None,
+            load_history: VecDeque::new(),
+            run_queue_history: VecDeque::new(),
+            blocked_history: VecDeque::new(),
         }
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct DynamicData {
     pub processes: Vec<ProcessInfo>,
     pub detailed_process: Option<DetailedProcessInfo>,
@@ -192,11 +681,375 @@ pub struct DynamicData {
     pub disks: Vec<DetailedDiskInfo>,
     pub networks: Vec<DetailedNetInfo>,
     pub containers: Vec<ContainerInfo>,
+    // None until the cgroups tab has been opened.
+    pub cgroups: Option<Vec<CgroupNode>>,
+    // None until the security tab has been opened.
+    pub security: Option<SecurityOverview>,
+    pub auth: AuthSummary,
+    // None until the clock has been checked.
+    pub clock: Option<ClockStatus>,
+    // None until the network tab has been opened.
+    pub network_config: Option<NetworkConfig>,
+    // Newest last.
+    pub port_changes: VecDeque<PortChange>,
+    pub neighbors: Vec<Neighbor>,
+    // Newest last.
+    pub neighbor_changes: VecDeque<NeighborChange>,
+    pub tcp: Option<TcpStats>,
     pub gpus: Result<Vec<GpuInfo>, String>,
+    pub gpu_processes: Vec<GpuProcessInfo>,
     pub global_usage: GlobalUsage,
     pub temperatures: SystemTemperatures,
+    #[serde(skip)]
     pub last_update: std::time::Instant,
+    #[serde(skip)]
+    pub updated: CollectorUpdates,
     pub docker_error: Option<String>,
+    // Newest last.
+    pub container_ooms: VecDeque<ContainerOom>,
+    pub self_usage: SelfUsage,
+}
+
+// When each collector last delivered data; None until its first update.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CollectorUpdates {
+    pub system: Option<std::time::Instant>,
+    pub gpu: Option<std::time::Instant>,
+    pub containers: Option<std::time::Instant>,
+}
+
+impl CollectorUpdates {
+    pub fn get(&self, kind: CollectorKind) -> Option<std::time::Instant> {
+        match kind {
+            CollectorKind::System => self.system,
+            CollectorKind::Gpu => self.gpu,
+            CollectorKind::Containers => self.containers,
+        }
+    }
+}
+
+// Resource usage of the puls process itself.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SelfUsage {
+    pub cpu: f32,
+    pub memory: u64,
+    pub virtual_memory: u64,
+    pub threads: u32,
+    pub open_fds: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SystemSnapshot {
+    // None when the active tab doesn't show processes; the last list is kept.
+    pub processes: Option<Vec<ProcessInfo>>,
+    pub detailed_process: Option<DetailedProcessInfo>,
+    pub cores: Vec<CoreInfo>,
+    pub disks: Vec<DetailedDiskInfo>,
+    pub networks: Vec<DetailedNetInfo>,
+    pub global_usage: GlobalUsage,
+    pub temperatures: SystemTemperatures,
+    pub self_usage: SelfUsage,
+    // Only walked while the cgroups tab is open.
+    #[serde(default)]
+    pub cgroups: Option<Vec<CgroupNode>>,
+    // Only gathered while the security tab is open, and less often than the
+    // rest of the snapshot.
+    #[serde(default)]
+    pub security: Option<SecurityOverview>,
+    // Only set when the journal was read again.
+    #[serde(default)]
+    pub auth: Option<AuthSummary>,
+    // Only set when the clock was checked again.
+    #[serde(default)]
+    pub clock: Option<ClockStatus>,
+    // Only gathered while the network tab is open, every 30 seconds.
+    #[serde(default)]
+    pub network_config: Option<NetworkConfig>,
+    // Listening sockets that came or went since the last snapshot.
+    #[serde(default)]
+    pub port_changes: Vec<PortChange>,
+    // Only set when the neighbor table was read again.
+    #[serde(default)]
+    pub neighbors: Option<Vec<Neighbor>>,
+    #[serde(default)]
+    pub neighbor_changes: Vec<NeighborChange>,
+    // None off Linux and on the first sample.
+    #[serde(default)]
+    pub tcp: Option<TcpStats>,
+}
+
+// One cgroup v2 node, listed in pre-order so the tree can be drawn top down.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CgroupNode {
+    pub path: String,
+    pub name: String,
+    pub depth: usize,
+    pub has_children: bool,
+    pub processes: usize,
+    pub cpu_percent: f32,
+    // memory.current; the root cgroup has none.
+    pub memory: Option<u64>,
+    pub io_read_rate: u64,
+    pub io_write_rate: u64,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SecurityOverview {
+    pub ports: Vec<ListeningPort>,
+    pub firewalls: Vec<FirewallStatus>,
+    pub sessions: Vec<UserSession>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListeningPort {
+    pub protocol: String,
+    pub address: std::net::IpAddr,
+    pub port: u16,
+    pub inode: u64,
+    pub process: Option<(u32, String)>,
+}
+
+impl ListeningPort {
+    // Bound to every interface rather than loopback or one address.
+    pub fn is_exposed(&self) -> bool {
+        self.address.is_unspecified()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PortChangeKind {
+    Opened,
+    Closed,
+}
+
+// A socket that started or stopped listening between two checks.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PortChange {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub kind: PortChangeKind,
+    pub port: ListeningPort,
+}
+
+impl PortChange {
+    pub const ALERT_WINDOW: chrono::Duration = chrono::Duration::minutes(5);
+    pub const RETAINED: usize = 50;
+}
+
+impl std::fmt::Display for ListeningPort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let address = match self.address {
+            std::net::IpAddr::V6(address) => format!("[{}]", address),
+            address => address.to_string(),
+        };
+        write!(f, "{} {}:{}", self.protocol, address, self.port)?;
+        if let Some((pid, name)) = &self.process {
+            write!(f, " ({}, pid {})", name, pid)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FirewallStatus {
+    pub name: String,
+    // None when the state can't be read, usually for lack of root.
+    pub active: Option<bool>,
+    pub detail: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuthService {
+    Ssh,
+    Sudo,
+}
+
+impl AuthService {
+    pub fn label(self) -> &'static str {
+        match self {
+            AuthService::Ssh => "ssh",
+            AuthService::Sudo => "sudo",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FailedLogin {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub service: AuthService,
+    pub user: String,
+    // Remote address for SSH, "local" for sudo.
+    pub source: String,
+}
+
+// One source's failed attempts over the retained window.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuthOffender {
+    pub source: String,
+    pub count: usize,
+    pub last_user: String,
+    pub last_seen: chrono::DateTime<chrono::Local>,
+}
+
+// Failed SSH and sudo authentications from the last day of the journal,
+// oldest first.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AuthSummary {
+    pub failures: VecDeque<FailedLogin>,
+    pub error: Option<String>,
+}
+
+impl AuthSummary {
+    pub const ALERT_WINDOW: chrono::Duration = chrono::Duration::minutes(10);
+
+    pub fn recent(&self, now: chrono::DateTime<chrono::Local>, window: chrono::Duration) -> usize {
+        self.failures.iter().rev().take_while(|failure| now - failure.at <= window).count()
+    }
+
+    // Sources by attempt count, most active first.
+    pub fn offenders(&self) -> Vec<AuthOffender> {
+        let mut offenders: Vec<AuthOffender> = Vec::new();
+        for failure in &self.failures {
+            match offenders.iter_mut().find(|o| o.source == failure.source) {
+                Some(offender) => {
+                    offender.count += 1;
+                    offender.last_user = failure.user.clone();
+                    offender.last_seen = failure.at;
+                }
+                None => offenders.push(AuthOffender {
+                    source: failure.source.clone(),
+                    count: 1,
+                    last_user: failure.user.clone(),
+                    last_seen: failure.at,
+                }),
+            }
+        }
+        offenders.sort_by(|a, b| b.count.cmp(&a.count).then(b.last_seen.cmp(&a.last_seen)));
+        offenders
+    }
+}
+
+// The network tab's header: where traffic leaves the machine and whether it
+// gets anywhere.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    // Default routes as (interface, next hop).
+    pub gateways: Vec<(String, std::net::IpAddr)>,
+    pub dns_servers: Vec<String>,
+    // None when the check is turned off.
+    pub reachability: Option<Reachability>,
+    // Only looked up when asked for with --public-ip.
+    pub public_ip: Option<Result<std::net::IpAddr, String>>,
+}
+
+// TCP health from the kernel's protocol counters, as rates over the last
+// sample.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TcpStats {
+    // Share of segments sent that were retransmissions, in percent; None
+    // when too few were sent to tell.
+    pub retrans_percent: Option<f64>,
+    // Resets sent.
+    pub resets_per_sec: f64,
+    // Connections dropped by listening sockets, mostly on a full accept queue.
+    pub listen_drops_per_sec: f64,
+    pub listen_drops_last_minute: u64,
+    // Since boot.
+    pub listen_drops_total: u64,
+    // Receive queues pruned for lack of buffer memory.
+    pub prunes_per_sec: f64,
+    pub sockets_in_use: u64,
+    pub orphaned: u64,
+    pub time_wait: u64,
+    pub buffer_bytes: u64,
+    // Retransmitted share in hundredths of a percent.
+    pub retrans_history: VecDeque<u64>,
+    pub reset_history: VecDeque<u64>,
+    pub drop_history: VecDeque<u64>,
+}
+
+// An entry of the kernel's neighbor (ARP/NDP) table.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Neighbor {
+    pub address: std::net::IpAddr,
+    // None until the address resolves.
+    pub mac: Option<String>,
+    // REACHABLE, STALE, FAILED and so on, as `ip neigh` prints them.
+    pub state: String,
+    pub interface: String,
+}
+
+impl Neighbor {
+    // Asked for and not answering.
+    pub fn is_failed(&self) -> bool {
+        matches!(self.state.as_str(), "FAILED" | "INCOMPLETE")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum NeighborChangeKind {
+    // A MAC address not seen before at this address.
+    New,
+    // The address moved to another MAC, the previous one given.
+    MacChanged(String),
+    Failed,
+    Recovered,
+}
+
+// A neighbor that showed up or changed between two checks.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NeighborChange {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub kind: NeighborChangeKind,
+    pub neighbor: Neighbor,
+}
+
+impl NeighborChange {
+    pub const RETAINED: usize = 50;
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Reachability {
+    pub target: String,
+    // Time to complete a TCP handshake.
+    pub latency_ms: Result<f64, String>,
+}
+
+// Clock synchronization as the running time sync daemon reports it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClockStatus {
+    // "chrony", "ntpd" or "timesyncd"; None when no sync service is running.
+    pub service: Option<String>,
+    pub synchronized: bool,
+    // How far the local clock is ahead of the reference, when reported.
+    pub offset_ms: Option<f64>,
+    // Reference server and stratum.
+    pub detail: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserSession {
+    pub user: String,
+    pub terminal: String,
+    pub since: String,
+    // Remote host or X display; empty for local logins.
+    pub from: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum MonitorUpdate {
+    System(Box<SystemSnapshot>),
+    Gpus {
+        gpus: Result<Vec<GpuInfo>, String>,
+        processes: Vec<GpuProcessInfo>,
+        utilization: Option<u32>,
+        history: Option<VecDeque<u32>>,
+    },
+    Containers {
+        containers: Vec<ContainerInfo>,
+        error: Option<String>,
+        #[serde(default)]
+        ooms: Vec<ContainerOom>,
+    },
 }
 
 impl Default for DynamicData {
@@ -208,15 +1061,24 @@ impl Default for DynamicData {
             disks: Vec::new(),
             networks: Vec::new(),
             containers: Vec::new(),
+            cgroups: None,
+            security: None,
+            auth: AuthSummary::default(),
+            clock: None,
+            network_config: None,
+            port_changes: VecDeque::new(),
+            neighbors: Vec::new(),
+            neighbor_changes: VecDeque::new(),
+            tcp: None,
             gpus: Ok(Vec::new()),
+            gpu_processes: Vec::new(),
             global_usage: GlobalUsage::default(),
-            temperatures: SystemTemperatures {
-                cpu_temp: None,
-                gpu_temps: Vec::new(),
-                motherboard_temp: None,
-            },
+            temperatures: SystemTemperatures::default(),
             last_update: std::time::Instant::now(),
+            updated: CollectorUpdates::default(),
             docker_error: None,
+            container_ooms: VecDeque::new(),
+            self_usage: SelfUsage::default(),
         }
     }
 }
@@ -227,14 +1089,60 @@ pub struct BootInfo {
     pub timestamp: String,
 }
 
+// Text typed after ' on the dashboard, jumping to the first matching process
+// name. It ends after a pause or on any key other than text.
+#[derive(Clone, Debug)]
+pub struct TypeAhead {
+    pub text: String,
+    pub last_key: std::time::Instant,
+}
+
+impl TypeAhead {
+    pub const TIMEOUT: Duration = Duration::from_secs(2);
+
+    pub fn new() -> Self {
+        Self { text: String::new(), last_key: std::time::Instant::now() }
+    }
+
+    pub fn expired(&self) -> bool {
+        self.last_key.elapsed() >= Self::TIMEOUT
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct AppState {
     pub active_tab: usize,
     pub process_table_state: TableState,
     pub container_table_state: TableState,
+    pub gpu_process_table_state: TableState,
+    pub selected_gpu: usize,
     pub services_table_state: TableState,
+    pub service_sort: ServiceSortBy,
+    // Names sort A to Z and figures largest first unless reversed.
+    pub service_sort_reversed: bool,
     pub logs_table_state: TableState,
     pub config_table_state: TableState,
+    pub cgroup_table_state: TableState,
+    pub port_table_state: TableState,
+    pub disk_table_state: TableState,
+    // The du-style scan started with Enter on the Disks tab.
+    pub disk_scan: crate::disk_usage::DiskScanSlot,
+    // Events under the --watch-path paths, shown on the Disks tab.
+    pub fs_events: crate::fs_watch::FsEventLog,
+    // The latest output of each plugin, shown on the System tab.
+    pub plugins: crate::plugins::PluginReports,
+    pub thread_table_state: TableState,
+    pub zombie_table_state: TableState,
+    pub thread_sort: ThreadSortBy,
+    pub thread_sort_reversed: bool,
+    // First line shown of the detail tab's command and environment panel.
+    pub command_scroll: usize,
+    // Paths of cgroups whose children are hidden in the tree.
+    pub cgroup_collapsed: std::collections::HashSet<String>,
+    // The Dashboard lists processes under their parents.
+    pub process_tree: bool,
+    // Pids whose descendants are hidden in the process tree.
+    pub collapsed_processes: std::collections::HashSet<String>,
     pub selected_pid: Option<Pid>,
     pub system_info: Vec<(String, String)>,
     pub dynamic_data: DynamicData,
@@ -243,6 +1151,10 @@ pub struct AppState {
     pub filter_text: String,
     pub show_system_processes: bool,
     pub paused: bool,
+    // Recent samples to step through while paused.
+    pub samples: crate::sample_history::SampleHistory,
+    // Traffic per interface since puls started or the count was reset.
+    pub net_session: crate::net_session::NetSession,
     pub services: Vec<ServiceInfo>,
     pub logs: Vec<LogEntry>,
     pub boots: Vec<BootInfo>,
@@ -252,16 +1164,109 @@ pub struct AppState {
     pub editing_config: Option<usize>,
     pub edit_buffer: String,
     pub has_sudo: bool,
+    // --read-only: nothing is changed, whatever the privileges.
+    pub read_only: bool,
+    // sudo or pkexec, for running root actions without root; None as root.
+    pub elevate_tool: Option<crate::elevate::Tool>,
+    // A confirmed elevated command, run by the UI loop since it needs the
+    // terminal.
+    pub elevation: Option<Vec<String>>,
+    // A path to find the holders of, searched by the UI loop off the UI
+    // thread since it walks every process's descriptors.
+    pub open_files_lookup: Option<String>,
     pub log_filter: String,
     pub service_status_modal: Option<(String, String)>,
     pub editing_filter: bool,
     pub docker_error: Option<String>,
     pub current_theme: usize,
-    pub pending_kill_pid: Option<sysinfo::Pid>,
-    pub pending_service_action: Option<(String, String)>,
+    pub confirm: Option<crate::ui::widgets::ConfirmDialog<PendingAction>>,
+    pub io_priority_popup: Option<crate::ui::widgets::IoPriorityPopup>,
+    pub cpu_limit_popup: Option<crate::ui::widgets::CpuLimitPopup>,
+    pub open_files_popup: Option<crate::ui::widgets::OpenFilesPopup>,
+    pub port_lookup_popup: Option<crate::ui::widgets::PortLookupPopup>,
+    pub cpu_limits: crate::cpu_limit::CpuLimits,
+    pub toasts: crate::ui::widgets::ToastQueue,
+    pub show_container_column: bool,
+    // Toggled with x: the Threads, State, Elapsed and CPU Time columns.
+    pub show_state_columns: bool,
+    // Toggled with x on the Containers tab: image size, age, uptime and restarts.
+    pub show_container_details: bool,
+    pub container_filter: Option<String>,
+    pub type_ahead: Option<TypeAhead>,
+    pub alert_thresholds: AlertThresholds,
+    // Watched services down for good, from the watchdog.
+    pub watchdog_down: Vec<String>,
+    pub show_perf_overlay: bool,
+    // Set by F10; the UI loop saves the next frame it draws.
+    pub export_screen: bool,
+    // From --export; None writes a new timestamped file on each `E`.
+    pub export_path: Option<std::path::PathBuf>,
+    // The tab's main panel fills the summary bar and content area.
+    pub zoomed: bool,
+    // Toggled with b; the content area takes the summary bar's rows.
+    pub hide_summary: bool,
+    pub performance: AppPerformance,
+    pub status_log: crate::status::StatusLog,
+    pub show_log_viewer: bool,
+    // Lines scrolled back from the newest entry.
+    pub log_viewer_scroll: usize,
+}
+
+impl AppState {
+    // Whether actions that change the system (signals, service control,
+    // config writes, container removal) are offered.
+    pub fn can_modify(&self) -> bool {
+        self.has_sudo && !self.read_only
+    }
+
+    // Whether root actions are offered through sudo or pkexec instead.
+    pub fn can_elevate(&self) -> bool {
+        !self.has_sudo && !self.read_only && self.elevate_tool.is_some()
+    }
+}
+
+// How a process is asked to go: SIGTERM lets it clean up first, SIGKILL
+// can't be caught. On Windows the difference is taskkill with or without /F.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KillSignal {
+    Term,
+    Kill,
 }
 
+impl KillSignal {
+    pub fn name(self) -> &'static str {
+        match self {
+            KillSignal::Term => "SIGTERM",
+            KillSignal::Kill => "SIGKILL",
+        }
+    }
+
+    // The option kill(1) takes.
+    pub fn kill_arg(self) -> &'static str {
+        match self {
+            KillSignal::Term => "-TERM",
+            KillSignal::Kill => "-9",
+        }
+    }
+}
+
+// Destructive actions that wait for the user to confirm them.
 #[derive(Clone, Debug)]
+pub enum PendingAction {
+    KillProcess(sysinfo::Pid, KillSignal),
+    // The root and its start time; the descendants are looked up again once
+    // confirmed.
+    // Elevated ones run `puls kill-tree` through sudo or pkexec, which checks
+    // again after the password prompt.
+    KillTree { root: sysinfo::Pid, start_time: u64, elevated: bool },
+    StopService(String),
+    RemoveContainer(String),
+    WriteConfig { index: usize, key: String, value: String },
+    // A command to run as root through `AppState::elevate_tool`.
+    Elevated(Vec<String>),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ServiceInfo {
     pub name: String,
     pub description: String,
@@ -269,6 +1274,10 @@ pub struct ServiceInfo {
     pub enabled: bool,
     pub can_start: bool,
     pub can_stop: bool,
+    // From the unit's cgroup; None when it isn't running or on other platforms.
+    pub memory: Option<u64>,
+    pub cpu_time_usec: Option<u64>,
+    pub tasks: Option<u64>,
 }
 
 impl Default for ServiceInfo {
@@ -280,10 +1289,77 @@ impl Default for ServiceInfo {
             enabled: false,
             can_start: false,
             can_stop: false,
+            memory: None,
+            cpu_time_usec: None,
+            tasks: None,
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ServiceSortBy {
+    #[default]
+    Name,
+    Memory,
+    CpuTime,
+    Tasks,
+}
+
+impl ServiceSortBy {
+    pub fn next(self) -> Self {
+        match self {
+            ServiceSortBy::Name => ServiceSortBy::Memory,
+            ServiceSortBy::Memory => ServiceSortBy::CpuTime,
+            ServiceSortBy::CpuTime => ServiceSortBy::Tasks,
+            ServiceSortBy::Tasks => ServiceSortBy::Name,
+        }
+    }
+
+    // Index of the sorted column in the services table.
+    pub fn column(self) -> usize {
+        match self {
+            ServiceSortBy::Name => 0,
+            ServiceSortBy::Memory => 3,
+            ServiceSortBy::CpuTime => 4,
+            ServiceSortBy::Tasks => 5,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThreadSortBy {
+    #[default]
+    Cpu,
+    CpuTime,
+    Tid,
+    Name,
+}
+
+impl ThreadSortBy {
+    pub fn next(self) -> Self {
+        match self {
+            ThreadSortBy::Cpu => ThreadSortBy::CpuTime,
+            ThreadSortBy::CpuTime => ThreadSortBy::Tid,
+            ThreadSortBy::Tid => ThreadSortBy::Name,
+            ThreadSortBy::Name => ThreadSortBy::Cpu,
+        }
+    }
+
+    // Index of the sorted column in the threads table.
+    pub fn column(self) -> usize {
+        match self {
+            ThreadSortBy::Tid => 0,
+            ThreadSortBy::Name => 1,
+            ThreadSortBy::Cpu => 3,
+            ThreadSortBy::CpuTime => 4,
+        }
+    }
+
+    pub fn default_ascending(self) -> bool {
+        matches!(self, ThreadSortBy::Tid | ThreadSortBy::Name)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct LogEntry {
     pub timestamp: String,
@@ -322,10 +1398,27 @@ impl Default for ConfigItem {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProcessQuery {
+    pub selected_pid: Option<Pid>,
+    pub show_system_processes: bool,
+    pub filter_text: String,
+    pub container_filter: Option<String>,
+    pub sort_by: ProcessSortBy,
+    pub sort_ascending: bool,
+    pub active_tab: usize,
+    pub tree: bool,
+    pub collapsed: std::collections::HashSet<String>,
+    // The Threads/State/CPU Time columns are shown, so CPU time is read.
+    pub show_state_columns: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ProcessSortBy {
     Cpu,
     Memory,
+    Swap,
     Name,
     Pid,
     DiskRead,
@@ -339,21 +1432,116 @@ impl Default for ProcessSortBy {
     }
 }
 
+impl ProcessSortBy {
+    // Order the sort key cycles through, following the table's columns.
+    const CYCLE: [ProcessSortBy; 8] = [
+        ProcessSortBy::Pid,
+        ProcessSortBy::Name,
+        ProcessSortBy::Cpu,
+        ProcessSortBy::Memory,
+        ProcessSortBy::Swap,
+        ProcessSortBy::DiskRead,
+        ProcessSortBy::DiskWrite,
+        ProcessSortBy::General,
+    ];
+
+    pub fn next(&self) -> ProcessSortBy {
+        let index = Self::CYCLE.iter().position(|key| key == self).unwrap_or(0);
+        Self::CYCLE[(index + 1) % Self::CYCLE.len()].clone()
+    }
+
+    // PIDs and names read naturally A-Z; usage columns put the heaviest first.
+    pub fn default_ascending(&self) -> bool {
+        matches!(self, ProcessSortBy::Pid | ProcessSortBy::Name)
+    }
+
+    // Index of the process table column this key sorts by, if it has one.
+    pub fn column(&self) -> Option<usize> {
+        match self {
+            ProcessSortBy::Pid => Some(0),
+            ProcessSortBy::Name => Some(1),
+            ProcessSortBy::Cpu => Some(3),
+            ProcessSortBy::Memory => Some(4),
+            ProcessSortBy::Swap => Some(5),
+            ProcessSortBy::DiskRead => Some(6),
+            ProcessSortBy::DiskWrite => Some(7),
+            ProcessSortBy::General => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum AppMessage {
-    UpdateData(DynamicData),
-    Error(String),
-    TogglePause,
+    Update(MonitorUpdate),
+    Timing(CollectorKind, CollectorTiming),
+    Input(crossterm::event::KeyEvent),
+    Resize,
+    Shutdown(&'static str),
+    Status(crate::status::StatusMessage),
+    // Watched services the watchdog has given up on.
+    Watchdog(Vec<String>),
+    FsEvents(Vec<crate::fs_watch::FsEvent>),
+    Plugin(crate::plugins::PluginUpdate),
+    // The processes holding a path open, for the open files popup.
+    OpenFiles(String, Result<Vec<crate::open_files::FileHolder>, String>),
+}
+
+// What the collectors need to know about the UI, published over a watch channel.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CollectorControl {
+    pub query: ProcessQuery,
+    pub paused: bool,
+}
+
+impl CollectorControl {
+    pub fn from_state(state: &AppState) -> Self {
+        Self {
+            query: ProcessQuery {
+                selected_pid: state.selected_pid,
+                show_system_processes: state.show_system_processes,
+                filter_text: state.filter_text.clone(),
+                container_filter: state.container_filter.clone(),
+                sort_by: state.sort_by.clone(),
+                sort_ascending: state.sort_ascending,
+                active_tab: state.active_tab,
+                tree: state.process_tree,
+                collapsed: state.collapsed_processes.clone(),
+                show_state_columns: state.show_state_columns,
+            },
+            paused: state.paused,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct AppPerformance {
-    pub update_duration_ms: u64,
-    pub render_duration_ms: u64,
-    pub memory_usage: u64,
+    pub system: CollectorTiming,
+    pub gpu: CollectorTiming,
+    pub containers: CollectorTiming,
+    pub render_duration: Duration,
     pub fps: f32,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CollectorKind {
+    System,
+    Gpu,
+    Containers,
+}
+
+// Observed cadence of one collector. `jitter` is a smoothed average of how far
+// each period strayed from `target`; `overruns` counts collections that took
+// longer than a whole period.
+#[derive(Clone, Debug, Default)]
+pub struct CollectorTiming {
+    pub target: Duration,
+    pub last_period: Duration,
+    pub jitter: Duration,
+    pub last_duration: Duration,
+    pub overruns: u64,
+    pub samples: u64,
+}
+
 #[derive(Clone, Debug)]
 pub struct AppConfig {
     pub safe_mode: bool,
@@ -364,5 +1552,108 @@ pub struct AppConfig {
     pub enable_network_monitoring: bool,
     pub show_system_processes: bool,
     pub auto_scroll: bool,
+    pub start_tab: Option<crate::config::Tab>,
+    pub start_pid: Option<u32>,
+    pub theme: Option<usize>,
+    pub color_depth: crate::ui::colors::ColorDepth,
+    pub graphics: Option<crate::ui::graphics::GraphicsProtocol>,
+    // None when not given, so the saved ones apply.
+    pub process_filter: Option<String>,
+    pub process_sort: Option<ProcessSortBy>,
+    // Whether the UI state saved at exit is restored at startup and updated.
+    pub restore_state: bool,
+    // Every action that changes the system is disabled.
+    pub read_only: bool,
     pub language: crate::language::Language,
+    pub locale: Option<String>,
+    pub alert_thresholds: AlertThresholds,
+    pub enable_expensive_ops: bool,
+    pub performance_profile: Option<&'static str>,
+    pub leak_window: std::time::Duration,
+    pub watch_services: Vec<crate::watchdog::WatchRule>,
+    pub capture_windows: Vec<crate::capture::CaptureWindow>,
+    pub capture_dir: Option<std::path::PathBuf>,
+    // Size, age and count limits for recordings and captures.
+    pub retention: crate::retention::Retention,
+    pub watch_paths: Vec<std::path::PathBuf>,
+    pub plugin_dir: Option<std::path::PathBuf>,
+    // Where `E` exports the live metrics; None for a timestamped file.
+    pub export_path: Option<std::path::PathBuf>,
+    // host:port the network tab checks for connectivity; None disables.
+    pub reach_target: Option<String>,
+    pub public_ip: bool,
+}
+
+// Warning and critical levels of one usage metric, in percent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AlertLevels {
+    pub warn: f32,
+    pub critical: f32,
+}
+
+impl AlertLevels {
+    pub fn color(&self, percent: f32, theme: &crate::ui::colors::ColorScheme) -> ratatui::style::Color {
+        if percent >= self.critical {
+            theme.error
+        } else if percent >= self.warn {
+            theme.warning
+        } else if percent >= self.warn * 2.0 / 3.0 {
+            theme.info
+        } else {
+            theme.success
+        }
+    }
+}
+
+impl std::str::FromStr for AlertLevels {
+    type Err = String;
+    
+    // "WARN,CRITICAL", e.g. "70,85".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (warn, critical) = s.split_once(',').ok_or("expected WARN,CRITICAL, e.g. 70,85")?;
+        let parse = |value: &str| value.trim().parse::<f32>()
+            .ok()
+            .filter(|v| (0.0..=100.0).contains(v))
+            .ok_or(format!("'{}' is not a percentage", value.trim()));
+        let levels = AlertLevels { warn: parse(warn)?, critical: parse(critical)? };
+        if levels.warn > levels.critical {
+            return Err("the warning level must not exceed the critical level".to_string());
+        }
+        Ok(levels)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AlertThresholds {
+    pub cpu: AlertLevels,
+    pub memory: AlertLevels,
+    pub disk: AlertLevels,
+    pub gpu_temperature: u32,
+    pub gpu_memory_percent: u32,
+    // Failed SSH/sudo attempts within `AuthSummary::ALERT_WINDOW`; 0 disables.
+    pub auth_failures: u32,
+    // Clock offset from the time source, in milliseconds; 0 disables clock
+    // alerts, including lost sync.
+    pub clock_offset_ms: u32,
+    // Hours until a growing mount is projected to be full; 0 disables.
+    pub disk_full_hours: u32,
+    // Share of TCP segments retransmitted, in percent; 0 disables this and
+    // the listen drop alert.
+    pub tcp_retrans_percent: u32,
+}
+
+impl Default for AlertThresholds {
+    fn default() -> Self {
+        Self {
+            cpu: AlertLevels { warn: 70.0, critical: 85.0 },
+            memory: AlertLevels { warn: 80.0, critical: 90.0 },
+            disk: AlertLevels { warn: 85.0, critical: 95.0 },
+            gpu_temperature: 85,
+            gpu_memory_percent: 95,
+            auth_failures: 20,
+            clock_offset_ms: 100,
+            disk_full_hours: 24,
+            tcp_retrans_percent: 5,
+        }
+    }
 }
\ No newline at end of file
@@ -1,7 +1,156 @@
 #![allow(dead_code)]
 
+use ratatui::buffer::Buffer;
 use ratatui::style::Color;
 
+// How many colours the terminal can show. Themes are written with named and
+// RGB colours; on lesser terminals every frame is mapped down to the nearest
+// colour the terminal has, instead of letting it guess at escape codes it
+// doesn't understand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+// xterm's default values for the 16 basic colours.
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl ColorDepth {
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        let term = std::env::var("TERM").unwrap_or_default();
+        // Windows Terminal doesn't set COLORTERM or have terminfo.
+        if cfg!(windows) && std::env::var_os("WT_SESSION").is_some() {
+            return ColorDepth::TrueColor;
+        }
+        Self::from_env(&colorterm, &term, terminfo_colors())
+    }
+
+    pub fn from_env(colorterm: &str, term: &str, terminfo_colors: Option<u32>) -> Self {
+        let colorterm = colorterm.to_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorDepth::TrueColor;
+        }
+
+        match terminfo_colors {
+            Some(colors) if colors >= 1 << 24 => ColorDepth::TrueColor,
+            Some(colors) if colors >= 256 => ColorDepth::Ansi256,
+            Some(_) => ColorDepth::Ansi16,
+            None if term.contains("256color") => ColorDepth::Ansi256,
+            None => ColorDepth::Ansi16,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorDepth::TrueColor => "truecolor",
+            ColorDepth::Ansi256 => "256 colors",
+            ColorDepth::Ansi16 => "16 colors",
+        }
+    }
+
+    pub fn adapt(&self, color: Color) -> Color {
+        match (self, color) {
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(nearest_256(r, g, b)),
+            (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => nearest_16(r, g, b),
+            (ColorDepth::Ansi16, Color::Indexed(index)) => {
+                let (r, g, b) = indexed_to_rgb(index);
+                nearest_16(r, g, b)
+            }
+            _ => color,
+        }
+    }
+
+    pub fn adapt_buffer(&self, buffer: &mut Buffer) {
+        if *self == ColorDepth::TrueColor {
+            return;
+        }
+        for cell in buffer.content.iter_mut() {
+            cell.fg = self.adapt(cell.fg);
+            cell.bg = self.adapt(cell.bg);
+        }
+    }
+}
+
+fn terminfo_colors() -> Option<u32> {
+    let output = std::process::Command::new("tput").arg("colors").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn nearest_16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16.iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+// Best of the 6x6x6 colour cube (16-231) and the grey ramp (232-255).
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| CUBE_LEVELS.iter().enumerate().min_by_key(|(_, l)| (**l as i32 - v as i32).abs()).map_or(0, |(i, _)| i as u8);
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let grey = 232 + ((average.saturating_sub(8) + 5) / 10).min(23) as u8;
+
+    if distance(indexed_to_rgb(grey), (r, g, b)) < distance(indexed_to_rgb(cube), (r, g, b)) {
+        grey
+    } else {
+        cube
+    }
+}
+
+// Approximate RGB value of any colour, for code that draws pixels itself.
+pub fn rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index) => indexed_to_rgb(index),
+        Color::Reset => (229, 229, 229),
+        named => ANSI16.iter().find(|(c, _)| *c == named).map_or((229, 229, 229), |(_, rgb)| *rgb),
+    }
+}
+
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[index as usize].1,
+        16..=231 => {
+            let i = index - 16;
+            (CUBE_LEVELS[(i / 36) as usize], CUBE_LEVELS[(i / 6 % 6) as usize], CUBE_LEVELS[(i % 6) as usize])
+        }
+        _ => {
+            let v = 8 + 10 * (index - 232);
+            (v, v, v)
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ColorScheme {
     pub primary: Color,
@@ -69,6 +218,59 @@ impl ColorScheme {
             highlight: Color::Blue,
         }
     }
+
+    pub fn matrix() -> Self {
+        Self {
+            primary: Color::LightGreen,
+            secondary: Color::Green,
+            accent: Color::LightGreen,
+            background: Color::Black,
+            text: Color::Green,
+            text_secondary: Color::Rgb(0, 140, 0),
+            success: Color::LightGreen,
+            warning: Color::Yellow,
+            error: Color::Red,
+            info: Color::Green,
+            border: Color::Rgb(0, 100, 0),
+            highlight: Color::White,
+        }
+    }
+
+    // Solarized dark, from Ethan Schoonover's palette.
+    pub fn solarized() -> Self {
+        Self {
+            primary: Color::Rgb(38, 139, 210),
+            secondary: Color::Rgb(42, 161, 152),
+            accent: Color::Rgb(108, 113, 196),
+            background: Color::Rgb(0, 43, 54),
+            text: Color::Rgb(147, 161, 161),
+            text_secondary: Color::Rgb(101, 123, 131),
+            success: Color::Rgb(133, 153, 0),
+            warning: Color::Rgb(181, 137, 0),
+            error: Color::Rgb(220, 50, 47),
+            info: Color::Rgb(42, 161, 152),
+            border: Color::Rgb(88, 110, 117),
+            highlight: Color::Rgb(238, 232, 213),
+        }
+    }
+
+    // Bright colours on black only, for low vision and washed-out projectors.
+    pub fn high_contrast() -> Self {
+        Self {
+            primary: Color::LightYellow,
+            secondary: Color::White,
+            accent: Color::LightMagenta,
+            background: Color::Black,
+            text: Color::White,
+            text_secondary: Color::White,
+            success: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
+            info: Color::LightCyan,
+            border: Color::White,
+            highlight: Color::LightYellow,
+        }
+    }
 }
 
 pub fn cpu_usage_color(usage: f32) -> Color {
@@ -153,6 +355,8 @@ pub struct ThemeManager {
 }
 
 impl ThemeManager {
+    pub const COUNT: usize = 6;
+
     pub fn new() -> Self {
         Self {
             current_theme: ColorScheme::nord(),
@@ -160,10 +364,13 @@ impl ThemeManager {
     }
     
     pub fn from_index(index: usize) -> Self {
-        let theme = match index % 3 {
+        let theme = match index % Self::COUNT {
             0 => ColorScheme::nord(),
             1 => ColorScheme::dark(),
             2 => ColorScheme::light(),
+            3 => ColorScheme::matrix(),
+            4 => ColorScheme::solarized(),
+            5 => ColorScheme::high_contrast(),
             _ => ColorScheme::nord(),
         };
         Self { current_theme: theme }
@@ -174,10 +381,13 @@ impl ThemeManager {
     }
     
     pub fn theme_name(index: usize) -> &'static str {
-        match index % 3 {
+        match index % Self::COUNT {
             0 => "Nord",
             1 => "Dark",
             2 => "Light",
+            3 => "Matrix",
+            4 => "Solarized",
+            5 => "High Contrast",
             _ => "Nord",
         }
     }
@@ -209,6 +419,33 @@ mod tests {
         let light = ColorScheme::light();
         assert_eq!(light.primary, Color::Blue);
         assert_eq!(light.background, Color::White);
+        
+        // The cycle covers every --theme value, in the same order.
+        assert_eq!(ThemeManager::COUNT, crate::config::ThemeName::ALL.len());
+        assert_eq!(ThemeManager::theme_name(5), "High Contrast");
+        assert_eq!(ThemeManager::from_index(4).get_theme().background, ColorScheme::solarized().background);
+        assert_eq!(ThemeManager::theme_name(ThemeManager::COUNT), "Nord");
+    }
+    
+    #[test]
+    fn test_color_depth_detection() {
+        assert_eq!(ColorDepth::from_env("truecolor", "xterm", Some(8)), ColorDepth::TrueColor);
+        assert_eq!(ColorDepth::from_env("", "xterm-direct", Some(16777216)), ColorDepth::TrueColor);
+        assert_eq!(ColorDepth::from_env("", "xterm-256color", Some(256)), ColorDepth::Ansi256);
+        assert_eq!(ColorDepth::from_env("", "xterm-256color", None), ColorDepth::Ansi256);
+        assert_eq!(ColorDepth::from_env("", "linux", Some(8)), ColorDepth::Ansi16);
+        assert_eq!(ColorDepth::from_env("", "dumb", None), ColorDepth::Ansi16);
+    }
+    
+    #[test]
+    fn test_color_fallback() {
+        let orange = Color::Rgb(255, 165, 0);
+        assert_eq!(ColorDepth::TrueColor.adapt(orange), orange);
+        assert_eq!(ColorDepth::Ansi256.adapt(orange), Color::Indexed(214));
+        assert_eq!(ColorDepth::Ansi256.adapt(Color::Rgb(128, 128, 128)), Color::Indexed(244));
+        assert_eq!(ColorDepth::Ansi16.adapt(orange), Color::Yellow);
+        assert_eq!(ColorDepth::Ansi16.adapt(Color::Indexed(196)), Color::LightRed);
+        assert_eq!(ColorDepth::Ansi16.adapt(Color::Cyan), Color::Cyan);
     }
     
     #[test]
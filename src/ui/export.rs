@@ -0,0 +1,216 @@
+// Screen export: serializes a rendered frame to ANSI text and to a standalone
+// HTML page, keeping colours and text attributes, so the monitor state can be
+// attached to a ticket as it looked on screen.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+use unicode_width::UnicodeWidthStr;
+
+use crate::ui::colors;
+
+// Page colours for cells that use the terminal's default colours.
+const HTML_FOREGROUND: Color = Color::Gray;
+const HTML_BACKGROUND: Color = Color::Black;
+
+// Writes `puls-screen-<timestamp>.ans` and `.html` to the current directory.
+pub fn save(buffer: &Buffer) -> std::io::Result<(PathBuf, PathBuf)> {
+    let stem = format!("puls-screen-{}", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+    let ansi_path = PathBuf::from(format!("{}.ans", stem));
+    let html_path = PathBuf::from(format!("{}.html", stem));
+    std::fs::write(&ansi_path, to_ansi(buffer))?;
+    std::fs::write(&html_path, to_html(buffer))?;
+    Ok((ansi_path, html_path))
+}
+
+pub fn to_ansi(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    for row in rows(buffer) {
+        let mut current: Option<(Color, Color, Modifier)> = None;
+        for cell in row {
+            let style = (cell.fg, cell.bg, cell.modifier);
+            if current != Some(style) {
+                out.push_str(&sgr(cell));
+                current = Some(style);
+            }
+            out.push_str(cell.symbol());
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+pub fn to_html(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    let (fg_r, fg_g, fg_b) = colors::rgb(HTML_FOREGROUND);
+    let (bg_r, bg_g, bg_b) = colors::rgb(HTML_BACKGROUND);
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>PULS - {}</title>\n\
+         <style>body {{ margin: 0; background: #{:02x}{:02x}{:02x}; }} \
+         pre {{ margin: 1em; color: #{:02x}{:02x}{:02x}; font-family: monospace; line-height: 1.2; }}</style>\n\
+         </head>\n<body>\n<pre>",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), bg_r, bg_g, bg_b, fg_r, fg_g, fg_b,
+    );
+
+    for row in rows(buffer) {
+        // Runs of cells with the same style share one span.
+        let mut run = String::new();
+        let mut current: Option<&Cell> = None;
+        for cell in row {
+            if let Some(style) = current {
+                if (style.fg, style.bg, style.modifier) != (cell.fg, cell.bg, cell.modifier) {
+                    push_span(&mut out, style, &run);
+                    run.clear();
+                }
+            }
+            current = Some(cell);
+            run.push_str(cell.symbol());
+        }
+        if let Some(style) = current {
+            push_span(&mut out, style, &run);
+        }
+        out.push('\n');
+    }
+
+    out.push_str("</pre>\n</body>\n</html>\n");
+    out
+}
+
+// Cells of each row, without the blank cells hidden behind wide characters.
+fn rows(buffer: &Buffer) -> impl Iterator<Item = Vec<&Cell>> {
+    let width = buffer.area.width as usize;
+    buffer.content.chunks(width.max(1)).map(|row| {
+        let mut cells = Vec::with_capacity(row.len());
+        let mut hidden = 0;
+        for cell in row {
+            if hidden > 0 {
+                hidden -= 1;
+                continue;
+            }
+            hidden = cell.symbol().width().saturating_sub(1);
+            cells.push(cell);
+        }
+        cells
+    })
+}
+
+fn sgr(cell: &Cell) -> String {
+    let mut codes = vec!["0".to_string()];
+    for (modifier, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if cell.modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+    codes.extend(color_code(cell.fg, false));
+    codes.extend(color_code(cell.bg, true));
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let base = match color {
+        Color::Reset => return None,
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Gray => 37,
+        Color::DarkGray => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+        Color::Indexed(index) => return Some(format!("{};5;{}", 38 + offset, index)),
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{};{};{}", 38 + offset, r, g, b)),
+    };
+    Some((base + offset).to_string())
+}
+
+fn push_span(out: &mut String, cell: &Cell, text: &str) {
+    let (mut fg, mut bg) = (cell.fg, cell.bg);
+    if cell.modifier.contains(Modifier::REVERSED) {
+        (fg, bg) = (
+            if bg == Color::Reset { HTML_BACKGROUND } else { bg },
+            if fg == Color::Reset { HTML_FOREGROUND } else { fg },
+        );
+    }
+
+    let mut style = String::new();
+    if fg != Color::Reset {
+        let (r, g, b) = colors::rgb(fg);
+        let _ = write!(style, "color:#{:02x}{:02x}{:02x};", r, g, b);
+    }
+    if bg != Color::Reset {
+        let (r, g, b) = colors::rgb(bg);
+        let _ = write!(style, "background:#{:02x}{:02x}{:02x};", r, g, b);
+    }
+    if cell.modifier.contains(Modifier::BOLD) {
+        style.push_str("font-weight:bold;");
+    }
+    if cell.modifier.contains(Modifier::DIM) {
+        style.push_str("opacity:0.6;");
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        style.push_str("font-style:italic;");
+    }
+    if cell.modifier.contains(Modifier::UNDERLINED) {
+        style.push_str("text-decoration:underline;");
+    } else if cell.modifier.contains(Modifier::CROSSED_OUT) {
+        style.push_str("text-decoration:line-through;");
+    }
+
+    let text = text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+    if style.is_empty() {
+        out.push_str(&text);
+    } else {
+        let _ = write!(out, "<span style=\"{}\">{}</span>", style, text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    fn sample() -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        buffer.set_string(0, 0, "CPU", Style::default().fg(Color::Rgb(255, 165, 0)).add_modifier(Modifier::BOLD));
+        buffer.set_string(0, 1, "<日>", Style::default().bg(Color::Blue));
+        buffer
+    }
+
+    #[test]
+    fn test_ansi_export() {
+        let ansi = to_ansi(&sample());
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "\x1b[0;1;38;2;255;165;0mCPU\x1b[0m   \x1b[0m");
+        // The wide character's hidden cell isn't written twice.
+        assert_eq!(lines[1], "\x1b[0;44m<日>\x1b[0m  \x1b[0m");
+    }
+
+    #[test]
+    fn test_html_export() {
+        let html = to_html(&sample());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<span style=\"color:#ffa500;font-weight:bold;\">CPU</span>   \n"));
+        assert!(html.contains("<span style=\"background:#0000ee;\">&lt;日&gt;</span>  \n"));
+    }
+}
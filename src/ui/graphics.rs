@@ -0,0 +1,430 @@
+// Raster history charts for terminals that speak the kitty graphics protocol
+// or sixel. While a chart is rendered, its cells are left blank and marked as
+// skipped in the buffer; after ratatui has flushed the frame, the UI loop
+// draws an image over them. Without a graphics protocol the same call falls
+// back to a cell-based sparkline.
+
+use std::io::{self, Write};
+use std::sync::{Mutex, OnceLock};
+
+use crossterm::{cursor::MoveTo, queue};
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+use ratatui::widgets::Sparkline;
+
+use crate::ui::colors;
+use crate::utils::base64;
+
+// Kitty wants the payload split into chunks of at most 4096 bytes.
+const KITTY_CHUNK: usize = 4096;
+// Cell size used for kitty images when the terminal doesn't report one; the
+// image is scaled to the cell area anyway.
+const DEFAULT_CELL_PIXELS: (u16, u16) = (8, 16);
+
+static ACTIVE: OnceLock<GraphicsProtocol> = OnceLock::new();
+static PLOTS: Mutex<Vec<Plot>> = Mutex::new(Vec::new());
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Sixel,
+}
+
+impl GraphicsProtocol {
+    pub fn detect() -> Option<Self> {
+        let var = |name: &str| std::env::var(name).unwrap_or_default();
+        Self::from_env(&var("TERM"), &var("TERM_PROGRAM"), std::env::var_os("KITTY_WINDOW_ID").is_some(), std::env::var_os("TMUX").is_some())
+    }
+
+    pub fn from_env(term: &str, term_program: &str, kitty_window: bool, tmux: bool) -> Option<Self> {
+        // Images would have to be wrapped in passthrough sequences for tmux.
+        if tmux {
+            return None;
+        }
+        if kitty_window || term == "xterm-kitty" || term == "xterm-ghostty" || matches!(term_program, "WezTerm" | "ghostty") {
+            return Some(GraphicsProtocol::Kitty);
+        }
+        if term.contains("sixel") || term.starts_with("foot") || term.starts_with("mlterm") || matches!(term_program, "mlterm" | "contour") {
+            return Some(GraphicsProtocol::Sixel);
+        }
+        None
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plot {
+    pub area: Rect,
+    // Drawn in order, later series over earlier ones.
+    pub series: Vec<(Vec<u64>, Color)>,
+    pub max: Option<u64>,
+}
+
+// Turns raster charts on for the rest of the session. Sixel images are drawn
+// at the terminal's pixel size, so sixel is only used when the terminal
+// reports its size in pixels.
+pub fn init(protocol: Option<GraphicsProtocol>) -> Option<Plotter> {
+    let protocol = protocol?;
+    if protocol == GraphicsProtocol::Sixel && cell_pixels().is_none() {
+        log::info!("Terminal does not report its pixel size, using text charts instead of sixel");
+        return None;
+    }
+    let _ = ACTIVE.set(protocol);
+    Some(Plotter { protocol, last: Vec::new(), last_area: Rect::default() })
+}
+
+// Renders a history chart: an image when a graphics protocol is active,
+// otherwise a sparkline.
+pub fn render_history(f: &mut Frame, data: &[u64], max: Option<u64>, color: Color, area: Rect) {
+    if queue_plot(f, &[(data, color)], max, area) {
+        return;
+    }
+
+    let mut sparkline = Sparkline::default()
+        .data(data)
+        .style(Style::default().fg(color));
+    if let Some(max) = max {
+        sparkline = sparkline.max(max);
+    }
+    f.render_widget(sparkline, area);
+}
+
+// Two histories on one scale in the same area, such as download and upload.
+// As an image they are drawn over each other. As text each column takes the
+// colour of whichever series is higher there, so one-sided traffic like a
+// backup upload shows in its own colour.
+pub fn render_history_pair(f: &mut Frame, first: (&[u64], Color), second: (&[u64], Color), area: Rect) {
+    let max = first.0.iter().chain(second.0).copied().max().unwrap_or(0).max(1);
+    if queue_plot(f, &[first, second], Some(max), area) {
+        return;
+    }
+
+    let buffer = f.buffer_mut();
+    let columns = first.0.len().max(second.0.len()).min(area.width as usize);
+    for x in 0..columns {
+        let (value, color) = higher(first, second, x);
+        // Bar height in eighths of a cell, filled from the bottom row up.
+        let mut eighths = value * area.height as u64 * 8 / max;
+        for y in (area.top()..area.bottom()).rev() {
+            buffer.get_mut(area.x + x as u16, y).set_symbol(bar_symbol(eighths)).set_fg(color);
+            eighths = eighths.saturating_sub(8);
+        }
+    }
+}
+
+// The text form of render_history_pair in a single line, for a table cell:
+// the newest `width` samples, scaled to their own peak.
+pub fn history_pair_line(first: (&[u64], Color), second: (&[u64], Color), width: usize) -> Line<'static> {
+    let tail = |data: &[u64]| data.len().saturating_sub(width);
+    let first = (&first.0[tail(first.0)..], first.1);
+    let second = (&second.0[tail(second.0)..], second.1);
+    let max = first.0.iter().chain(second.0).copied().max().unwrap_or(0).max(1);
+    let columns = first.0.len().max(second.0.len());
+    Line::from((0..columns).map(|x| {
+        let (value, color) = higher(first, second, x);
+        Span::styled(bar_symbol(value * 8 / max), Style::default().fg(color))
+    }).collect::<Vec<_>>())
+}
+
+// The larger of the two samples at `x`, with its series' colour.
+fn higher(first: (&[u64], Color), second: (&[u64], Color), x: usize) -> (u64, Color) {
+    let sample = |data: &[u64]| data.get(x).copied().unwrap_or(0);
+    if sample(second.0) > sample(first.0) { (sample(second.0), second.1) } else { (sample(first.0), first.1) }
+}
+
+// A cell filled `eighths`/8 from the bottom.
+fn bar_symbol(eighths: u64) -> &'static str {
+    match eighths.min(8) {
+        0 => " ",
+        1 => symbols::bar::ONE_EIGHTH,
+        2 => symbols::bar::ONE_QUARTER,
+        3 => symbols::bar::THREE_EIGHTHS,
+        4 => symbols::bar::HALF,
+        5 => symbols::bar::FIVE_EIGHTHS,
+        6 => symbols::bar::THREE_QUARTERS,
+        7 => symbols::bar::SEVEN_EIGHTHS,
+        _ => symbols::bar::FULL,
+    }
+}
+
+// Blanks `area` and queues its image when a graphics protocol is active;
+// false means the caller draws text instead.
+fn queue_plot(f: &mut Frame, series: &[(&[u64], Color)], max: Option<u64>, area: Rect) -> bool {
+    if ACTIVE.get().is_none() || area.width == 0 || area.height == 0 {
+        return false;
+    }
+    let buffer = f.buffer_mut();
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            buffer.get_mut(x, y).reset();
+            buffer.get_mut(x, y).set_skip(true);
+        }
+    }
+    if let Ok(mut plots) = PLOTS.lock() {
+        let series = series.iter().map(|(data, color)| (data.to_vec(), *color)).collect();
+        plots.push(Plot { area, series, max });
+    }
+    true
+}
+
+pub struct Plotter {
+    protocol: GraphicsProtocol,
+    last: Vec<Plot>,
+    last_area: Rect,
+}
+
+impl Plotter {
+    // Draws the charts queued during the last frame. Charts that something
+    // else was drawn over (a popup, say) are left out.
+    pub fn draw<W: Write>(&mut self, out: &mut W, buffer: &Buffer) -> io::Result<()> {
+        let plots: Vec<Plot> = PLOTS.lock().map(|mut plots| std::mem::take(&mut *plots)).unwrap_or_default();
+        let visible: Vec<Plot> = plots.into_iter()
+            .filter(|plot| covered(buffer, plot.area))
+            .collect();
+
+        if visible == self.last && buffer.area == self.last_area {
+            return Ok(());
+        }
+
+        let (cell_width, cell_height) = cell_pixels().unwrap_or(DEFAULT_CELL_PIXELS);
+        if self.protocol == GraphicsProtocol::Kitty {
+            // Drop the previous frame's images; text doesn't overwrite them.
+            write!(out, "\x1b_Ga=d,d=a,q=2\x1b\\")?;
+        }
+        for plot in &visible {
+            let data: Vec<&[u64]> = plot.series.iter().map(|(data, _)| data.as_slice()).collect();
+            let colors: Vec<Color> = plot.series.iter().map(|(_, color)| *color).collect();
+            let canvas = Canvas::plot(&data, plot.max, plot.area.width as usize * cell_width as usize, plot.area.height as usize * cell_height as usize);
+            queue!(out, MoveTo(plot.area.x, plot.area.y))?;
+            match self.protocol {
+                GraphicsProtocol::Kitty => out.write_all(canvas.to_kitty(&colors, plot.area).as_bytes())?,
+                GraphicsProtocol::Sixel => out.write_all(canvas.to_sixel(&colors).as_bytes())?,
+            }
+        }
+        out.flush()?;
+
+        self.last = visible;
+        self.last_area = buffer.area;
+        Ok(())
+    }
+}
+
+fn covered(buffer: &Buffer, area: Rect) -> bool {
+    let area = area.intersection(buffer.area);
+    (area.top()..area.bottom()).all(|y| (area.left()..area.right()).all(|x| buffer.get(x, y).skip))
+}
+
+fn cell_pixels() -> Option<(u16, u16)> {
+    let size = crossterm::terminal::window_size().ok()?;
+    if size.width == 0 || size.height == 0 || size.columns == 0 || size.rows == 0 {
+        return None;
+    }
+    Some((size.width / size.columns, size.height / size.rows))
+}
+
+const EMPTY: u8 = 0;
+const FILL: u8 = 1;
+const LINE: u8 = 2;
+
+// The pixel value for a fill or line pixel of the n-th series: FILL and LINE
+// for the first, then two more values per series.
+fn pixel(series: usize, kind: u8) -> u8 {
+    series as u8 * 2 + kind
+}
+
+// The series and FILL/LINE a non-empty pixel value stands for.
+fn unpack(pixel: u8) -> (usize, u8) {
+    ((pixel as usize - 1) / 2, (pixel - 1) % 2 + 1)
+}
+
+// A chart as a grid of pixels, EMPTY or a series' FILL/LINE, coloured when
+// encoded.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn plot(series: &[&[u64]], max: Option<u64>, width: usize, height: usize) -> Self {
+        let mut canvas = Canvas { width, height, pixels: vec![EMPTY; width * height] };
+        let max = max.unwrap_or_else(|| series.iter().flat_map(|data| data.iter()).copied().max().unwrap_or(0)).max(1) as f64;
+        for (index, data) in series.iter().enumerate() {
+            canvas.draw(data, max, index);
+        }
+        canvas
+    }
+
+    fn draw(&mut self, data: &[u64], max: f64, series: usize) {
+        let (width, height) = (self.width, self.height);
+        if data.is_empty() || width == 0 || height == 0 {
+            return;
+        }
+
+        let thickness = (height / 48).max(1);
+        let mut previous = None;
+        for x in 0..width {
+            // Spread the samples over the full width, interpolating between them.
+            let position = if width > 1 { x as f64 * (data.len() - 1) as f64 / (width - 1) as f64 } else { 0.0 };
+            let index = position.floor() as usize;
+            let next = data[(index + 1).min(data.len() - 1)] as f64;
+            let value = data[index] as f64 + (next - data[index] as f64) * position.fract();
+
+            let scaled = (value / max).clamp(0.0, 1.0);
+            let y = (height - 1) - (scaled * (height - 1) as f64).round() as usize;
+            // A later series' fill doesn't hide the lines under it.
+            for row in y..height {
+                let cell = &mut self.pixels[row * width + x];
+                if *cell == EMPTY || unpack(*cell).1 == FILL {
+                    *cell = pixel(series, FILL);
+                }
+            }
+            let (top, bottom) = match previous {
+                Some(prev) if prev < y => (prev, y),
+                Some(prev) => (y, prev),
+                None => (y, y),
+            };
+            for row in top..(bottom + thickness).min(height) {
+                self.pixels[row * width + x] = pixel(series, LINE);
+            }
+            previous = Some(y);
+        }
+    }
+
+    fn to_kitty(&self, colors: &[Color], area: Rect) -> String {
+        let rgb: Vec<(u8, u8, u8)> = colors.iter().map(|&color| colors::rgb(color)).collect();
+        let mut rgba = Vec::with_capacity(self.pixels.len() * 4);
+        for &pixel in &self.pixels {
+            if pixel == EMPTY {
+                rgba.extend_from_slice(&[0, 0, 0, 0]);
+                continue;
+            }
+            let (series, kind) = unpack(pixel);
+            let (r, g, b) = rgb.get(series).copied().unwrap_or_default();
+            let alpha = if kind == LINE { 255 } else { 80 };
+            rgba.extend_from_slice(&[r, g, b, alpha]);
+        }
+
+        let payload = base64(&rgba);
+        let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK).collect();
+        let mut out = String::with_capacity(payload.len() + chunks.len() * 16 + 64);
+        for (i, chunk) in chunks.iter().enumerate() {
+            let more = (i + 1 < chunks.len()) as u8;
+            if i == 0 {
+                out.push_str(&format!("\x1b_Ga=T,f=32,s={},v={},c={},r={},C=1,q=2,m={};", self.width, self.height, area.width, area.height, more));
+            } else {
+                out.push_str(&format!("\x1b_Gm={};", more));
+            }
+            out.push_str(std::str::from_utf8(chunk).unwrap_or_default());
+            out.push_str("\x1b\\");
+        }
+        out
+    }
+
+    fn to_sixel(&self, colors: &[Color]) -> String {
+        let percent = |v: u8, scale: u32| v as u32 * 100 * scale / (255 * 100);
+
+        // P2=1 keeps unset pixels transparent. Each pixel value gets a colour
+        // register of its own.
+        let mut out = format!("\x1bP0;1;0q\"1;1;{};{}", self.width, self.height);
+        let registers: Vec<u8> = (0..colors.len()).flat_map(|series| [pixel(series, FILL), pixel(series, LINE)]).collect();
+        for &register in &registers {
+            let (series, kind) = unpack(register);
+            let (r, g, b) = colors::rgb(colors[series]);
+            let scale = if kind == LINE { 100 } else { 40 };
+            out.push_str(&format!("#{};2;{};{};{}", register, percent(r, scale), percent(g, scale), percent(b, scale)));
+        }
+
+        for band in (0..self.height).step_by(6) {
+            for &color in &registers {
+                let sixels: Vec<u8> = (0..self.width).map(|x| {
+                    (0..6).filter(|bit| {
+                        let y = band + bit;
+                        y < self.height && self.pixels[y * self.width + x] == color
+                    }).fold(0u8, |bits, bit| bits | (1 << bit))
+                }).collect();
+                if sixels.iter().all(|&bits| bits == 0) {
+                    continue;
+                }
+                out.push_str(&format!("#{}", color));
+                push_sixel_runs(&mut out, &sixels);
+                out.push('$');
+            }
+            out.push('-');
+        }
+        out.push_str("\x1b\\");
+        out
+    }
+}
+
+// Sixel data is run-length encoded as !<count><char>.
+fn push_sixel_runs(out: &mut String, sixels: &[u8]) {
+    let mut i = 0;
+    while i < sixels.len() {
+        let run = sixels[i..].iter().take_while(|&&bits| bits == sixels[i]).count();
+        let ch = (63 + sixels[i]) as char;
+        if run > 3 {
+            out.push_str(&format!("!{}{}", run, ch));
+        } else {
+            (0..run).for_each(|_| out.push(ch));
+        }
+        i += run;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protocol_detection() {
+        assert_eq!(GraphicsProtocol::from_env("xterm-kitty", "", false, false), Some(GraphicsProtocol::Kitty));
+        assert_eq!(GraphicsProtocol::from_env("xterm-256color", "WezTerm", false, false), Some(GraphicsProtocol::Kitty));
+        assert_eq!(GraphicsProtocol::from_env("foot", "", false, false), Some(GraphicsProtocol::Sixel));
+        assert_eq!(GraphicsProtocol::from_env("xterm-256color", "", false, false), None);
+        assert_eq!(GraphicsProtocol::from_env("xterm-kitty", "", true, true), None);
+    }
+
+    #[test]
+    fn test_canvas_plot() {
+        let canvas = Canvas::plot(&[&[0, 100]], Some(100), 4, 4);
+        // Rising line: bottom-left to top-right, filled underneath.
+        assert_eq!(canvas.pixels[3 * 4], LINE);
+        assert_eq!(canvas.pixels[3], LINE);
+        assert_eq!(canvas.pixels[0], EMPTY);
+        assert_eq!(canvas.pixels[3 * 4 + 3], FILL);
+
+        // A second series is drawn over the first one's fill but not its line.
+        let pair = Canvas::plot(&[&[0, 0], &[100, 100]], None, 4, 4);
+        assert_eq!(pair.pixels[0], pixel(1, LINE));
+        assert_eq!(pair.pixels[4], pixel(1, FILL));
+        assert_eq!(pair.pixels[3 * 4], LINE);
+        assert_eq!(unpack(pixel(1, LINE)), (1, LINE));
+    }
+
+    #[test]
+    fn test_history_pair_line() {
+        let line = history_pair_line((&[0, 0, 40, 80], Color::Red), (&[99, 10, 20, 0], Color::Blue), 3);
+        let cells: Vec<(&str, Option<Color>)> = line.spans.iter().map(|span| (span.content.as_ref(), span.style.fg)).collect();
+        assert_eq!(cells, vec![
+            (symbols::bar::ONE_EIGHTH, Some(Color::Blue)),
+            (symbols::bar::HALF, Some(Color::Red)),
+            (symbols::bar::FULL, Some(Color::Red)),
+        ]);
+    }
+
+    #[test]
+    fn test_encoders() {
+        let mut runs = String::new();
+        push_sixel_runs(&mut runs, &[0, 0, 0, 0, 0, 63, 1]);
+        assert_eq!(runs, "!5?~@");
+
+        let canvas = Canvas::plot(&[&[50; 10]], Some(100), 200, 100);
+        let kitty = canvas.to_kitty(&[Color::Red], Rect::new(0, 0, 25, 6));
+        assert!(kitty.starts_with("\x1b_Ga=T,f=32,s=200,v=100,c=25,r=6,C=1,q=2,m=1;"));
+        assert!(kitty.contains("\x1b_Gm=0;"));
+
+        let sixel = canvas.to_sixel(&[Color::Red]);
+        assert!(sixel.starts_with("\x1bP0;1;0q\"1;1;200;100"));
+        assert!(sixel.ends_with("-\x1b\\"));
+    }
+}
@@ -0,0 +1,190 @@
+// Key hints for the footer. Each list matches the keys main.rs handles for
+// what is on screen, with the tab's own keys first and the global ones last,
+// since the footer drops hints from the end when it runs out of room. Actions
+// that need root are only offered when puls has it and isn't read-only, or
+// when it can run them through sudo or pkexec.
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::language::Translator;
+use crate::types::AppState;
+
+// A key and the translation key of what it does.
+pub type Hint = (&'static str, &'static str);
+
+const GLOBAL: &[Hint] = &[
+    ("p", "hint.pause"),
+    ("Tab/1-9", "hint.navigate"),
+    ("F", "hint.open_files"),
+    ("W", "hint.port_owner"),
+    ("E", "hint.export_metrics"),
+    ("z", "hint.zoom"),
+    ("t", "hint.theme"),
+    ("Ctrl+l", "hint.status_log"),
+    ("q", "hint.quit"),
+];
+
+pub fn footer_hints(state: &AppState) -> Vec<Hint> {
+    let root = state.can_modify();
+    // Kills, service control and container removal can go through sudo.
+    let elevate = state.can_elevate();
+    let mut hints: Vec<Hint> = Vec::new();
+    let mut add = |hint: Hint, when: bool| {
+        if when {
+            hints.push(hint);
+        }
+    };
+
+    // Text entry takes every key until it ends.
+    if state.editing_filter {
+        return vec![("type", "hint.filter_logs"), ("Enter", "hint.apply"), ("Esc", "hint.cancel")];
+    }
+    if state.editing_config.is_some() {
+        return vec![("type", "hint.new_value"), ("Enter", "hint.save"), ("Esc", "hint.cancel")];
+    }
+    if state.editing_service.is_some() {
+        return vec![("Esc", "hint.cancel")];
+    }
+
+    match state.active_tab {
+        0 => {
+            add(("↑↓", "hint.select"), true);
+            add(("Enter", "hint.details"), true);
+            add(("'", "hint.jump_to_name"), true);
+            add(("Esc", "hint.clear_container_filter"), state.container_filter.is_some());
+            add(("k/F9", "hint.terminate"), root || elevate);
+            add(("K", "hint.force_kill"), root || elevate);
+            add(("f", "hint.freeze_resume"), root);
+            add(("s/S", "hint.sort_reverse"), true);
+            add(("c", "hint.container_column"), true);
+            add(("x", "hint.process_columns"), true);
+            add(("v/F5", "hint.tree_view"), true);
+            add(("←→", "hint.collapse_expand"), state.process_tree && !state.paused);
+            add(("Ctrl+c/m/n/g", "hint.sort_keys"), true);
+        }
+        1 => {
+            add(("↑↓", "hint.select_thread"), true);
+            add(("s/S", "hint.sort_reverse_threads"), true);
+            add(("k", "hint.kill_tree"), root || elevate);
+            add(("f", "hint.freeze_resume"), root);
+            add(("i", "hint.io_priority"), root && cfg!(target_os = "linux"));
+            add(("l", "hint.cpu_limit"), root);
+            add(("PgUp/PgDn", "hint.scroll_command"), true);
+            add(("c", "hint.copy_command"), true);
+        }
+        4 => {
+            add(("↑↓", "hint.select_mount"), true);
+            add(("Enter", "hint.scan_largest_dirs"), !state.disk_scan.is_running());
+            match state.disk_scan.get() {
+                Some(scan) if scan.result().is_none() => add(("Esc", "hint.cancel_scan"), true),
+                Some(_) => add(("Esc", "hint.close_scan"), true),
+                None => {}
+            }
+        }
+        5 => {
+            add(("r", "hint.reset_session_totals"), true);
+        }
+        6 => {
+            add(("←→", "hint.select_gpu"), true);
+            add(("↑↓", "hint.select_gpu_process"), true);
+            add(("Enter", "hint.process_details"), true);
+        }
+        7 => {
+            add(("↑↓", "hint.select_zombie"), true);
+            add(("Enter", "hint.parent_details"), true);
+            add(("r", "hint.send_sigchld"), root);
+            add(("k", "hint.kill_parent"), root || elevate);
+        }
+        8 => {
+            let selected = state.services_table_state.selected().and_then(|idx| state.services.get(idx));
+            add(("↑↓", "hint.select"), true);
+            let elevate = elevate && cfg!(target_os = "linux");
+            add(("s", "hint.start"), (root && selected.is_some_and(|s| s.can_start)) || elevate);
+            add(("x", "hint.stop"), (root && selected.is_some_and(|s| s.can_stop)) || elevate);
+            add(("r", "hint.restart"), root || elevate);
+            add(("+/_", "hint.enable_disable"), root || elevate);
+            add(("l", "hint.status"), true);
+            add(("o/O", "hint.sort_reverse"), true);
+            add(("", if state.read_only { "hint.read_only" } else { "hint.service_actions_need_root" }), !root && !elevate);
+        }
+        9 => {
+            add(("↑↓", "hint.scroll"), true);
+            add(("/", "hint.filter"), true);
+            add(("←→", "hint.older_newer_boot"), state.boots.len() > 1);
+        }
+        10 => {
+            add(("↑↓", "hint.select"), true);
+            add(("e", "hint.edit"), root);
+            add(("", if state.read_only { "hint.read_only" } else { "hint.editing_needs_root" }), !root);
+        }
+        11 => {
+            add(("↑↓", "hint.select"), true);
+            add(("Enter", "hint.show_container_processes"), true);
+            add(("x", "hint.container_columns"), true);
+            add(("d", "hint.remove"), root || elevate);
+        }
+        12 => {
+            add(("↑↓", "hint.select"), true);
+            add(("Enter/←→", "hint.collapse_expand"), true);
+        }
+        13 => {
+            add(("↑↓", "hint.select_port"), true);
+            add(("Enter", "hint.owning_process_details"), true);
+        }
+        _ => {}
+    }
+    let elevated_tab = matches!(state.active_tab, 0 | 1 | 7 | 11) || (state.active_tab == 8 && cfg!(target_os = "linux"));
+    add(("", "hint.root_actions_password"), elevate && elevated_tab);
+    hints.extend_from_slice(GLOBAL);
+    hints
+}
+
+// Joins as many hints as fit in `width` columns, in order.
+pub fn fit(hints: &[Hint], width: usize, translator: &Translator) -> String {
+    let mut line = String::new();
+    for (key, action) in hints {
+        let action = translator.t(action);
+        let hint = if key.is_empty() { action } else { format!("{}: {}", key, action) };
+        let separator = if line.is_empty() { "" } else { " | " };
+        if line.width() + separator.len() + hint.width() > width {
+            break;
+        }
+        line.push_str(separator);
+        line.push_str(&hint);
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::Language;
+
+    #[test]
+    fn test_footer_hints() {
+        let en = Translator::new(Language::English);
+        let fit = |hints: &[Hint], width| fit(hints, width, &en);
+        let mut state = AppState { active_tab: 8, ..Default::default() };
+        let text = fit(&footer_hints(&state), 500);
+        assert!(text.starts_with("↑↓: Select | l: Status | o/O: Sort/Reverse | Service actions need root | p: Pause"));
+        assert!(!text.contains("Restart"));
+
+        state.has_sudo = true;
+        assert!(fit(&footer_hints(&state), 500).contains("r: Restart | +/_: Enable/Disable"));
+        state.read_only = true;
+        assert!(fit(&footer_hints(&state), 500).starts_with("↑↓: Select | l: Status | o/O: Sort/Reverse | Read-only mode | p: Pause"));
+        state.read_only = false;
+
+        let elevated = AppState { active_tab: 11, elevate_tool: Some(crate::elevate::Tool::Sudo), ..Default::default() };
+        assert!(fit(&footer_hints(&elevated), 500).contains("d: Remove | Root actions ask for a password | p: Pause"));
+
+        state.active_tab = 9;
+        state.editing_filter = true;
+        assert_eq!(fit(&footer_hints(&state), 500), "type: Filter logs | Enter: Apply | Esc: Cancel");
+        // Whole hints are dropped from the end.
+        assert_eq!(fit(&footer_hints(&state), 35), "type: Filter logs | Enter: Apply");
+
+        let de = Translator::new(Language::German);
+        assert_eq!(super::fit(&footer_hints(&state), 500, &de), "type: Logs filtern | Enter: Anwenden | Esc: Abbrechen");
+    }
+}
@@ -12,12 +12,14 @@ pub struct MainLayout {
     pub footer_area: Rect,
 }
 
-pub fn create_main_layout(area: Rect) -> MainLayout {
+// With the summary bar hidden its rows go to the content area and
+// `summary_area` is left empty.
+pub fn create_main_layout(area: Rect, show_summary: bool) -> MainLayout {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),  // Tab bar
-            Constraint::Length(4),  // Summary bar
+            Constraint::Length(if show_summary { 4 } else { 0 }),  // Summary bar
             Constraint::Min(0),     // Main content
             Constraint::Length(1),  // Footer
         ])
@@ -376,12 +378,17 @@ mod tests {
     #[test]
     fn test_main_layout() {
         let area = Rect::new(0, 0, 80, 24);
-        let layout = create_main_layout(area);
+        let layout = create_main_layout(area, true);
         
         assert_eq!(layout.tab_area.height, 3);
         assert_eq!(layout.summary_area.height, 4);
         assert_eq!(layout.footer_area.height, 1);
         assert!(layout.content_area.height > 0);
+        
+        let hidden = create_main_layout(area, false);
+        assert_eq!(hidden.summary_area.height, 0);
+        assert_eq!(hidden.content_area.height, layout.content_area.height + 4);
+        assert_eq!(hidden.content_area.y, layout.summary_area.y);
     }
     
     #[test]
@@ -1,15 +1,22 @@
 pub mod widgets;
 pub mod colors;
+pub mod export;
+pub mod graphics;
 pub mod layouts;
+pub mod hints;
 
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Gauge, Paragraph, Row, Sparkline, Table, Tabs, BorderType, Chart, Dataset, GraphType, Axis},
+    widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, Tabs, BorderType, Chart, Dataset, GraphType, Axis},
     symbols::Marker,
 };
 
-use crate::types::AppState;
-use crate::utils::{format_size, format_rate, format_percentage, format_frequency, get_usage_color, truncate_string, get_system_health, get_cpu_efficiency, estimate_memory_availability};
+use std::time::{Duration, Instant};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::types::{AppState, CollectorKind};
+use crate::utils::{format_size, format_rate, format_percentage, format_frequency, format_growth, format_duration, get_usage_color, truncate_string, get_system_health, get_cpu_efficiency, estimate_memory_availability};
 use crate::language::Translator;
 
 pub use layouts::*;
@@ -18,44 +25,336 @@ pub fn render_ui(f: &mut Frame, state: &mut AppState, is_safe_mode: bool, transl
     let theme_manager = crate::ui::colors::ThemeManager::from_index(state.current_theme);
     let theme = theme_manager.get_theme();
     
-    let main_layout = create_main_layout(f.size());
+    let main_layout = create_main_layout(f.size(), !state.hide_summary);
     
     render_tab_bar(f, state, main_layout.tab_area, is_safe_mode, translator, theme);
     
-    render_summary_bar(f, state, main_layout.summary_area, translator, theme);
+    // Zoom gives the tab's main panel the summary bar's space as well.
+    let content_area = if state.zoomed {
+        main_layout.summary_area.union(main_layout.content_area)
+    } else {
+        if !state.hide_summary {
+            render_summary_bar(f, state, main_layout.summary_area, translator, theme);
+        }
+        main_layout.content_area
+    };
     
-    match state.active_tab {
-        0 => render_dashboard_tab(f, state, main_layout.content_area, translator, theme),
-        1 => render_process_detail_tab(f, state, main_layout.content_area, translator, theme),
-        2 => render_cpu_cores_tab(f, state, main_layout.content_area, translator, theme),
-        3 => render_memory_tab(f, state, main_layout.content_area, translator, theme),
-        4 => render_disks_tab(f, state, main_layout.content_area, translator, theme),
-        5 => render_network_tab(f, state, main_layout.content_area, is_safe_mode, translator, theme),
-        6 => render_gpu_tab(f, state, main_layout.content_area, is_safe_mode, translator, theme),
-        7 => render_system_info_tab(f, state, main_layout.content_area, translator, theme),
-        8 => render_services_tab(f, state, main_layout.content_area, translator, theme),
-        9 => render_logs_tab(f, state, main_layout.content_area, translator, theme),
-        10 => render_config_tab(f, state, main_layout.content_area, translator, theme),
-        11 => render_containers_tab(f, state, main_layout.content_area, theme),
-        _ => {}
+    if state.zoomed {
+        render_zoomed(f, state, content_area, is_safe_mode, translator, theme);
+    } else {
+        render_tab(f, state, content_area, is_safe_mode, translator, theme);
     }
     
-    render_footer(f, state, main_layout.footer_area, translator);
+    let tab_collector = match state.active_tab {
+        1..=5 | 7 | 12 | 13 => Some(CollectorKind::System),
+        6 => Some(CollectorKind::Gpu),
+        11 => Some(CollectorKind::Containers),
+        _ => None,
+    };
+    if let Some(kind) = tab_collector {
+        render_freshness(f, state, kind, content_area, theme);
+    }
+    
+    render_footer(f, state, main_layout.footer_area, translator, theme);
 
     if let Some((name, status)) = &state.service_status_modal {
         render_service_status_modal(f, name, status, theme);
     }
     
-    if let Some(pid) = state.pending_kill_pid {
-        render_kill_confirmation(f, pid, theme);
+    state.toasts.render(f, main_layout.content_area, theme);
+    
+    if let Some(dialog) = &state.confirm {
+        dialog.render(f, theme);
+    }
+    
+    if let Some(popup) = &state.io_priority_popup {
+        popup.render(f, theme);
+    }
+    
+    if let Some(popup) = &state.cpu_limit_popup {
+        popup.render(f, theme);
     }
     
-    if let Some((action, name)) = &state.pending_service_action {
-        render_service_action_confirmation(f, action, name, theme);
+    if let Some(popup) = &state.open_files_popup {
+        popup.render(f, theme);
+    }
+    
+    if let Some(popup) = &state.port_lookup_popup {
+        popup.render(f, theme);
+    }
+    
+    if state.show_perf_overlay {
+        render_perf_overlay(f, &state.performance, theme);
+    }
+    
+    if state.show_log_viewer {
+        render_log_viewer(f, state, theme);
     }
 }
 
-fn render_service_status_modal(f: &mut Frame, name: &str, status: &str, theme: &crate::ui::colors::ColorScheme) {
+fn render_tab(f: &mut Frame, state: &mut AppState, area: Rect, is_safe_mode: bool, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    match state.active_tab {
+        0 => render_dashboard_tab(f, state, area, translator, theme),
+        1 => render_process_detail_tab(f, state, area, translator, theme),
+        2 => render_cpu_cores_tab(f, state, area, translator, theme),
+        3 => render_memory_tab(f, state, area, translator, theme),
+        4 => render_disks_tab(f, state, area, translator, theme),
+        5 => render_network_tab(f, state, area, is_safe_mode, translator, theme),
+        6 => render_gpu_tab(f, state, area, is_safe_mode, translator, theme),
+        7 => render_system_info_tab(f, state, area, translator, theme),
+        8 => render_services_tab(f, state, area, translator, theme),
+        9 => render_logs_tab(f, state, area, translator, theme),
+        10 => render_config_tab(f, state, area, translator, theme),
+        11 => render_containers_tab(f, state, area, theme),
+        12 => render_cgroups_tab(f, state, area, translator, theme),
+        13 => render_security_tab(f, state, area, translator, theme),
+        _ => {}
+    }
+}
+
+// The panel worth a closer look on each tab; tabs without one zoom as a whole.
+fn render_zoomed(f: &mut Frame, state: &mut AppState, area: Rect, is_safe_mode: bool, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    match state.active_tab {
+        0 => render_process_table(f, state, area, translator, theme),
+        1 if state.dynamic_data.detailed_process.is_some() => {
+            if let Some(process) = &state.dynamic_data.detailed_process {
+                render_process_history(f, process, area, theme);
+            }
+        }
+        2 if !state.dynamic_data.cores.is_empty() => render_cpu_history_chart(f, state, area, theme),
+        6 => match &state.dynamic_data.gpus {
+            Ok(gpus) if !gpus.is_empty() => {
+                let selected = state.selected_gpu.min(gpus.len() - 1);
+                render_single_gpu(f, &gpus[selected], area, selected, theme);
+            }
+            _ => render_tab(f, state, area, is_safe_mode, translator, theme),
+        },
+        _ => render_tab(f, state, area, is_safe_mode, translator, theme),
+    }
+}
+
+// The cgroup v2 hierarchy as a collapsible tree; each node's figures include
+// its descendants, as the kernel accounts them.
+fn render_cgroups_tab(f: &mut Frame, state: &mut AppState, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    let block = Block::default()
+        .title(translator.t("title.cgroups"))
+        .title_style(Style::default().fg(theme.primary))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border));
+
+    let nodes = state.dynamic_data.cgroups.as_deref().unwrap_or_default();
+    if nodes.is_empty() {
+        let message = match state.dynamic_data.cgroups {
+            Some(_) => "No cgroup v2 hierarchy found (needs Linux with the unified hierarchy mounted)".to_string(),
+            None => translator.t("msg.loading"),
+        };
+        let paragraph = Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.text_secondary))
+            .block(block);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let visible = crate::monitors::cgroup_monitor::visible_cgroups(nodes, &state.cgroup_collapsed);
+    if state.cgroup_table_state.selected().is_none_or(|i| i >= visible.len()) {
+        state.cgroup_table_state.select(Some(0));
+    }
+
+    let rows: Vec<Row> = visible.iter().map(|node| {
+        let marker = match (node.has_children, state.cgroup_collapsed.contains(&node.path)) {
+            (false, _) => "  ",
+            (true, true) => "▸ ",
+            (true, false) => "▾ ",
+        };
+        Row::new(vec![
+            format!("{}{}{}", "  ".repeat(node.depth), marker, node.name),
+            node.processes.to_string(),
+            format!("{:.1}%", node.cpu_percent),
+            node.memory.map_or("-".to_string(), format_size),
+            format_rate(node.io_read_rate),
+            format_rate(node.io_write_rate),
+        ]).style(Style::default().fg(theme.text))
+    }).collect();
+
+    let headers = vec![
+        translator.t("header.name"),
+        "Procs".to_string(),
+        translator.t("header.cpu"),
+        translator.t("header.memory"),
+        translator.t("header.disk_read"),
+        translator.t("header.disk_write"),
+    ];
+    let widths = [
+        Constraint::Min(30),
+        Constraint::Length(7),
+        Constraint::Length(8),
+        Constraint::Length(10),
+        Constraint::Length(12),
+        Constraint::Length(12),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(headers).style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)).bottom_margin(1))
+        .block(block)
+        .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD))
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(table, area, &mut state.cgroup_table_state);
+}
+
+// A quick "is something off" glance: what listens, whether a firewall is
+// loaded, who is failing to log in over SSH and who is logged in.
+fn render_security_tab(f: &mut Frame, state: &mut AppState, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    let Some(security) = state.dynamic_data.security.as_ref() else {
+        let paragraph = Paragraph::new(translator.t("msg.loading"))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.text_secondary))
+            .block(Block::default()
+                .title(translator.t("title.security"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border)));
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let top_height = (security.firewalls.len().max(security.sessions.len() + 1).max(1) as u16 + 2).min(10);
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(top_height), Constraint::Min(8)])
+        .split(area);
+    let (firewall_area, sessions_area) = create_two_column_layout(layout[0], 45);
+    let (ports_column, logins_area) = create_two_column_layout(layout[1], 55);
+    // Port changes only take room once there have been some.
+    let changes = &state.dynamic_data.port_changes;
+    let changes_height = if changes.is_empty() { 0 } else { (changes.len() as u16 + 3).min(8) };
+    let ports_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(changes_height)])
+        .split(ports_column);
+    let ports_area = ports_layout[0];
+
+    let block = |title: String, color: Color| Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(color));
+    let header_style = Style::default().fg(theme.primary).add_modifier(Modifier::BOLD);
+
+    let any_active = security.firewalls.iter().any(|fw| fw.active == Some(true));
+    let firewall_lines: Vec<Line> = if security.firewalls.is_empty() {
+        vec![Line::from(Span::styled("No firewall tool found (nft, ufw, firewall-cmd)", Style::default().fg(theme.warning)))]
+    } else {
+        security.firewalls.iter().map(|fw| {
+            let (state_text, color) = match fw.active {
+                Some(true) => ("● active", theme.success),
+                Some(false) => ("○ inactive", theme.error),
+                None => ("? unknown", theme.text_secondary),
+            };
+            Line::from(vec![
+                Span::styled(format!("{:<10} ", fw.name), Style::default().fg(theme.text)),
+                Span::styled(format!("{:<11} ", state_text), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled(fw.detail.clone(), Style::default().fg(theme.text_secondary)),
+            ])
+        }).collect()
+    };
+    let firewall_color = if any_active { theme.border } else { theme.warning };
+    f.render_widget(Paragraph::new(firewall_lines).block(block("Firewall".to_string(), firewall_color)), firewall_area);
+
+    let session_rows = security.sessions.iter().map(|session| {
+        Row::new(vec![
+            session.user.clone(),
+            session.terminal.clone(),
+            if session.from.is_empty() { "local".to_string() } else { session.from.clone() },
+            session.since.clone(),
+        ]).style(Style::default().fg(theme.text))
+    });
+    let sessions = Table::new(session_rows, [Constraint::Length(12), Constraint::Length(8), Constraint::Min(16), Constraint::Length(17)])
+        .header(Row::new(vec!["User", "TTY", "From", "Since"]).style(header_style))
+        .block(block(format!("Sessions ({})", security.sessions.len()), theme.border));
+    f.render_widget(sessions, sessions_area);
+
+    let exposed = security.ports.iter().filter(|port| port.is_exposed()).count();
+    if state.port_table_state.selected().is_none_or(|i| i >= security.ports.len()) {
+        state.port_table_state.select(if security.ports.is_empty() { None } else { Some(0) });
+    }
+    let port_rows = security.ports.iter().map(|port| {
+        let color = if port.is_exposed() { theme.warning } else { theme.text };
+        Row::new(vec![
+            port.protocol.clone(),
+            port.address.to_string(),
+            port.port.to_string(),
+            port.process.as_ref().map_or("-".to_string(), |(pid, name)| format!("{} ({})", name, pid)),
+        ]).style(Style::default().fg(color))
+    });
+    let ports = Table::new(port_rows, [Constraint::Length(5), Constraint::Length(18), Constraint::Length(6), Constraint::Min(16)])
+        .header(Row::new(vec!["Proto", "Address", "Port", "Process"]).style(header_style))
+        .block(block(format!("Listening Ports ({}, {} on all interfaces)", security.ports.len(), exposed), theme.border))
+        .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD))
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(ports, ports_area, &mut state.port_table_state);
+
+    if !changes.is_empty() {
+        let change_rows = changes.iter().rev().map(|change| {
+            let (label, color) = match change.kind {
+                crate::types::PortChangeKind::Opened => ("opened", theme.warning),
+                crate::types::PortChangeKind::Closed => ("closed", theme.text_secondary),
+            };
+            Row::new(vec![change.at.format("%H:%M:%S").to_string(), label.to_string(), change.port.to_string()])
+                .style(Style::default().fg(color))
+        });
+        let table = Table::new(change_rows, [Constraint::Length(9), Constraint::Length(7), Constraint::Min(20)])
+            .block(block(format!("Port Changes ({})", changes.len()), theme.border));
+        f.render_widget(table, ports_layout[1]);
+    }
+
+    let auth = &state.dynamic_data.auth;
+    let recent = auth.recent(chrono::Local::now(), crate::types::AuthSummary::ALERT_WINDOW);
+    let alerting = state.alert_thresholds.auth_failures > 0 && recent >= state.alert_thresholds.auth_failures as usize;
+    let logins_color = if alerting { theme.error } else if auth.failures.is_empty() { theme.border } else { theme.warning };
+    let title = format!("Failed SSH/sudo Logins, 24h ({}, {} in last 10 min)", auth.failures.len(), recent);
+    if let Some(error) = &auth.error {
+        let paragraph = Paragraph::new(error.as_str())
+            .style(Style::default().fg(theme.text_secondary))
+            .wrap(ratatui::widgets::Wrap { trim: true })
+            .block(block(title, logins_color));
+        f.render_widget(paragraph, logins_area);
+        return;
+    }
+
+    let (offenders_area, recent_area) = create_two_row_layout(logins_area, 50);
+    let offenders = auth.offenders();
+    let offender_rows = offenders.iter().map(|offender| {
+        Row::new(vec![
+            offender.source.clone(),
+            offender.count.to_string(),
+            offender.last_user.clone(),
+            offender.last_seen.format("%H:%M:%S").to_string(),
+        ]).style(Style::default().fg(theme.text))
+    });
+    let table = Table::new(offender_rows, [Constraint::Min(15), Constraint::Length(6), Constraint::Length(14), Constraint::Length(9)])
+        .header(Row::new(vec!["Source", "Count", "Last User", "Last Seen"]).style(header_style))
+        .block(block(title, logins_color));
+    f.render_widget(table, offenders_area);
+
+    let failure_rows = auth.failures.iter().rev().map(|failure| {
+        Row::new(vec![
+            failure.at.format("%m-%d %H:%M:%S").to_string(),
+            failure.service.label().to_string(),
+            failure.user.clone(),
+            failure.source.clone(),
+        ]).style(Style::default().fg(theme.text))
+    });
+    let table = Table::new(failure_rows, [Constraint::Length(14), Constraint::Length(5), Constraint::Length(14), Constraint::Min(15)])
+        .header(Row::new(vec!["Time", "Via", "User", "Source"]).style(header_style))
+        .block(block("Recent Failures".to_string(), theme.border));
+    f.render_widget(table, recent_area);
+}
+
+fn render_log_viewer(f: &mut Frame, state: &AppState, theme: &crate::ui::colors::ColorScheme) {
+    use crate::status::StatusLevel;
+    
     let area = f.size();
     let popup_area = Rect {
         x: area.width / 10,
@@ -66,76 +365,119 @@ fn render_service_status_modal(f: &mut Frame, name: &str, status: &str, theme: &
     
     f.render_widget(ratatui::widgets::Clear, popup_area);
     
+    let messages = state.status_log.messages();
+    let total = messages.len();
+    let visible = popup_area.height.saturating_sub(2) as usize;
+    let scroll = state.log_viewer_scroll.min(total.saturating_sub(visible));
+    
+    let lines: Vec<Line> = messages
+        .rev()
+        .skip(scroll)
+        .take(visible)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .map(|m| {
+            let color = match m.level {
+                StatusLevel::Error => theme.error,
+                StatusLevel::Warning => theme.warning,
+                StatusLevel::Info => theme.text,
+            };
+            Line::from(vec![
+                Span::styled(format!("{} ", m.time.format("%H:%M:%S")), Style::default().fg(theme.text_secondary)),
+                Span::styled(format!("{:<5} ", m.level.label()), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled(m.text.clone(), Style::default().fg(theme.text)),
+            ])
+        })
+        .collect();
+    
     let block = Block::default()
-        .title(format!("Status: {} (Esc to close)", name))
+        .title(format!("Internal Log ({} entries) - ↑↓/PgUp/PgDn: Scroll | Esc: Close", total))
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .border_style(Style::default().fg(theme.highlight));
-            
-    let paragraph = Paragraph::new(status)
-        .block(block)
-        .style(Style::default().fg(theme.text))
-        .wrap(ratatui::widgets::Wrap { trim: false });
-        
-    f.render_widget(paragraph, popup_area);
+    
+    let paragraph = if lines.is_empty() {
+        Paragraph::new("No messages yet").alignment(Alignment::Center)
+    } else {
+        Paragraph::new(lines)
+    };
+    
+    f.render_widget(paragraph.block(block).style(Style::default().fg(theme.text)), popup_area);
 }
 
-fn render_kill_confirmation(f: &mut Frame, pid: sysinfo::Pid, theme: &crate::ui::colors::ColorScheme) {
+fn render_perf_overlay(f: &mut Frame, perf: &crate::types::AppPerformance, theme: &crate::ui::colors::ColorScheme) {
     let area = f.size();
+    let width = 58.min(area.width);
     let popup_area = Rect {
-        x: area.width / 4,
-        y: area.height / 2 - 2,
-        width: area.width / 2,
-        height: 5,
+        x: area.width.saturating_sub(width + 1),
+        y: 1,
+        width,
+        height: 7.min(area.height.saturating_sub(1)),
     };
     
     f.render_widget(ratatui::widgets::Clear, popup_area);
     
     let block = Block::default()
-        .title("⚠ Kill Process")
+        .title("Performance (F12 to close)")
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(theme.warning));
+        .border_style(Style::default().fg(theme.highlight));
+    
+    let ms = |d: std::time::Duration| d.as_secs_f64() * 1000.0;
+    let mut lines = vec![format!("{:<10} {:>8} {:>8} {:>7} {:>7} {:>4}", "", "target", "actual", "jitter", "work", "over")];
+    for (name, timing) in [("System", &perf.system), ("GPU", &perf.gpu), ("Containers", &perf.containers)] {
+        if timing.target.is_zero() {
+            lines.push(format!("{:<10} {:>8}", name, "idle"));
+            continue;
+        }
+        lines.push(format!(
+            "{:<10} {:>6.0}ms {:>6.0}ms {:>5.1}ms {:>5.0}ms {:>4}",
+            name,
+            ms(timing.target),
+            ms(timing.last_period),
+            ms(timing.jitter),
+            ms(timing.last_duration),
+            timing.overruns,
+        ));
+    }
+    lines.push(format!("UI         {:.1} fps, {:.1}ms per frame", perf.fps, ms(perf.render_duration)));
     
-    let text = format!("Kill process {}?\n\ny: Yes  |  n/Esc: Cancel", pid);
-    let paragraph = Paragraph::new(text)
+    let paragraph = Paragraph::new(lines.join("\n"))
         .block(block)
-        .style(Style::default().fg(theme.text))
-        .alignment(Alignment::Center);
-        
+        .style(Style::default().fg(theme.text));
+    
     f.render_widget(paragraph, popup_area);
 }
 
-fn render_service_action_confirmation(f: &mut Frame, action: &str, name: &str, theme: &crate::ui::colors::ColorScheme) {
+fn render_service_status_modal(f: &mut Frame, name: &str, status: &str, theme: &crate::ui::colors::ColorScheme) {
     let area = f.size();
     let popup_area = Rect {
-        x: area.width / 4,
-        y: area.height / 2 - 2,
-        width: area.width / 2,
-        height: 5,
+        x: area.width / 10,
+        y: area.height / 10,
+        width: area.width * 8 / 10,
+        height: area.height * 8 / 10,
     };
-
+    
     f.render_widget(ratatui::widgets::Clear, popup_area);
-
-    let title = format!("⚠ {} Service", action.to_uppercase());
+    
     let block = Block::default()
-        .title(title)
+        .title(format!("Status: {} (Esc to close)", name))
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
-        .border_style(Style::default().fg(theme.warning));
-
-    let text = format!("{} service '{}'?\n\ny: Yes  |  n/Esc: Cancel", action, name);
-    let paragraph = Paragraph::new(text)
+        .border_style(Style::default().fg(theme.highlight));
+            
+    let paragraph = Paragraph::new(status)
         .block(block)
         .style(Style::default().fg(theme.text))
-        .alignment(Alignment::Center);
-
+        .wrap(ratatui::widgets::Wrap { trim: false });
+        
     f.render_widget(paragraph, popup_area);
 }
 
 fn render_tab_bar(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode: bool, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
     let tab_keys = vec![
-        "tab.dashboard", "tab.process", "tab.cpu", "tab.memory", "tab.disks", "tab.network", "tab.gpu", "tab.system", "tab.services", "tab.logs", "tab.config", "tab.containers"
+        "tab.dashboard", "tab.process", "tab.cpu", "tab.memory", "tab.disks", "tab.network", "tab.gpu", "tab.system", "tab.services", "tab.logs", "tab.config", "tab.containers", "tab.cgroups", "tab.security"
     ];
     let tab_titles: Vec<Line> = tab_keys
     .iter()
@@ -158,6 +500,11 @@ fn render_tab_bar(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode: boo
             .title(translator.t("title.puls"))
             .title_style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
             .title(ratatui::widgets::block::Title::from(format!(" v{} ", env!("CARGO_PKG_VERSION"))).alignment(Alignment::Right))
+            .title(ratatui::widgets::block::Title::from(if state.read_only {
+                Span::styled(format!(" {} ", translator.t("label.read_only").to_uppercase()), Style::default().fg(theme.warning).add_modifier(Modifier::BOLD))
+            } else {
+                Span::raw("")
+            }).alignment(Alignment::Right))
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .border_style(Style::default().fg(theme.border)))
@@ -180,20 +527,68 @@ fn render_summary_bar(f: &mut Frame, state: &AppState, area: Rect, translator: &
         ])
         .split(area);
     
-    render_cpu_gauge(f, usage.cpu, usage.load_average, layout[0], translator, theme);
+    let thresholds = &state.alert_thresholds;
+    render_cpu_gauge(f, usage.cpu, usage.load_average, thresholds, layout[0], translator, theme);
     
-    render_memory_gauge(f, usage.mem_used, usage.mem_total, layout[1], translator, theme);
+    render_memory_gauge(f, usage.mem_used, usage.mem_total, thresholds, layout[1], translator, theme);
     
     render_gpu_gauge(f, usage.gpu_util, layout[2], translator, theme);
     
     render_network_summary(f, usage, layout[3], translator, theme);
     
     render_disk_summary(f, usage, layout[4], translator, theme);
+    
+    for (i, &panel) in layout.iter().enumerate() {
+        let kind = if i == 2 { CollectorKind::Gpu } else { CollectorKind::System };
+        render_freshness(f, state, kind, panel, theme);
+    }
+}
+
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const MIN_STALE_AFTER: Duration = Duration::from_secs(5);
+
+// "loading" until a collector's first update, "stale: Ns" once it has missed
+// a few of its intervals, None while its data is current or updates are paused.
+fn freshness(state: &AppState, kind: CollectorKind, now: Instant) -> Option<String> {
+    if state.paused {
+        return None;
+    }
+    let Some(updated) = state.dynamic_data.updated.get(kind) else {
+        let millis = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_millis();
+        return Some(format!("{} loading", SPINNER[(millis / 100) as usize % SPINNER.len()]));
+    };
+    let target = match kind {
+        CollectorKind::System => state.performance.system.target,
+        CollectorKind::Gpu => state.performance.gpu.target,
+        CollectorKind::Containers => state.performance.containers.target,
+    };
+    let age = now.duration_since(updated);
+    (age > (target * 3).max(MIN_STALE_AFTER)).then(|| format!("stale: {}s", age.as_secs()))
+}
+
+// Badges the top-right corner of a panel's border with its freshness, so a
+// collector that hasn't delivered doesn't pass for zeros.
+fn render_freshness(f: &mut Frame, state: &AppState, kind: CollectorKind, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let Some(text) = freshness(state, kind, Instant::now()) else {
+        return;
+    };
+    let text = format!(" {} ", text);
+    let width = text.width() as u16;
+    if area.height == 0 || area.width < width + 4 {
+        return;
+    }
+    f.buffer_mut().set_string(area.right() - width - 2, area.y, text, Style::default().fg(theme.warning));
+}
+
+// Gauge labels are centred and clipped at the border, so a translated label
+// wider than the gauge would lose both ends.
+fn fit_label(label: String, area: Rect) -> String {
+    truncate_string(&label, area.width.saturating_sub(2) as usize)
 }
 
-fn render_cpu_gauge(f: &mut Frame, cpu_percent: f32, load_avg: (f64, f64, f64), area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
-    let color = get_usage_color(cpu_percent);
-    let label = format!("{:.1}% | Load: {:.1}", cpu_percent, load_avg.0);
+fn render_cpu_gauge(f: &mut Frame, cpu_percent: f32, load_avg: (f64, f64, f64), thresholds: &crate::types::AlertThresholds, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    let color = thresholds.cpu.color(cpu_percent, theme);
+    let label = format!("{:.1}% | {}: {:.1}", cpu_percent, translator.t("label.load"), load_avg.0);
     let gauge = Gauge::default()
         .block(Block::default()
             .title(translator.t("title.cpu"))
@@ -202,22 +597,23 @@ fn render_cpu_gauge(f: &mut Frame, cpu_percent: f32, load_avg: (f64, f64, f64),
             .border_style(Style::default().fg(theme.border)))
         .gauge_style(Style::default().fg(color))
         .percent(cpu_percent.clamp(0.0, 100.0) as u16)
-        .label(label);
+        .label(fit_label(label, area));
     f.render_widget(gauge, area);
 }
 
-fn render_memory_gauge(f: &mut Frame, mem_used: u64, mem_total: u64, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+fn render_memory_gauge(f: &mut Frame, mem_used: u64, mem_total: u64, thresholds: &crate::types::AlertThresholds, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
     let mem_percent = if mem_total > 0 {
         (mem_used as f64 / mem_total as f64) * 100.0
     } else {
         0.0
     };
     
-    let color = get_usage_color(mem_percent as f32);
+    let levels = &thresholds.memory;
+    let color = levels.color(mem_percent as f32, theme);
     
-    let pressure = match mem_percent {
-        x if x >= 90.0 => "health.critical",
-        x if x >= 80.0 => "health.high",
+    let pressure = match mem_percent as f32 {
+        x if x >= levels.critical => "health.critical",
+        x if x >= levels.warn => "health.high",
         x if x >= 60.0 => "health.moderate",
         _ => "health.healthy",
     };
@@ -232,7 +628,7 @@ fn render_memory_gauge(f: &mut Frame, mem_used: u64, mem_total: u64, area: Rect,
             .border_style(Style::default().fg(theme.border)))
         .gauge_style(Style::default().fg(color))
         .percent(mem_percent.clamp(0.0, 100.0) as u16)
-        .label(label);
+        .label(fit_label(label, area));
     f.render_widget(gauge, area);
 }
 
@@ -244,7 +640,7 @@ fn render_gpu_gauge(f: &mut Frame, gpu_util: Option<u32>, area: Rect, translator
         .border_style(Style::default().fg(theme.border));
     
     if let Some(gpu_percent) = gpu_util {
-        let color = get_usage_color(gpu_percent as f32);
+        let color = get_usage_color(gpu_percent as f32, theme);
         let gauge = Gauge::default()
             .block(block)
             .gauge_style(Style::default().fg(color))
@@ -275,19 +671,14 @@ fn render_network_summary(f: &mut Frame, usage: &crate::types::GlobalUsage, area
         .constraints([Constraint::Length(1), Constraint::Min(0)])
         .split(inner_area);
     
-    let net_text = format!("▼{} ▲{}", format_rate(usage.net_down), format_rate(usage.net_up));
-    let net_paragraph = Paragraph::new(net_text)
-        .alignment(Alignment::Left)
-        .style(Style::default().fg(theme.accent));
-    f.render_widget(net_paragraph, layout[0]);
+    let net_text = Line::from(vec![
+        Span::styled(format!("▼{}", format_rate(usage.net_down)), Style::default().fg(theme.accent)),
+        Span::raw(" "),
+        Span::styled(format!("▲{}", format_rate(usage.net_up)), Style::default().fg(theme.secondary)),
+    ]);
+    f.render_widget(Paragraph::new(net_text), layout[0]);
     
-    if !usage.net_down_history.is_empty() {
-         let data: Vec<u64> = usage.net_down_history.iter().cloned().collect();
-         let sparkline = Sparkline::default()
-            .data(&data)
-            .style(Style::default().fg(theme.accent));
-        f.render_widget(sparkline, layout[1]);
-    }
+    render_scaled_history(f, (&usage.net_down_history, theme.accent), (&usage.net_up_history, theme.secondary), layout[1], theme);
 }
 
 fn render_disk_summary(f: &mut Frame, usage: &crate::types::GlobalUsage, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
@@ -305,18 +696,40 @@ fn render_disk_summary(f: &mut Frame, usage: &crate::types::GlobalUsage, area: R
         .constraints([Constraint::Length(1), Constraint::Min(0)])
         .split(inner_area);
 
-    let disk_text = format!("R:{} W:{}", format_rate(usage.disk_read), format_rate(usage.disk_write));
-    let disk_paragraph = Paragraph::new(disk_text)
-        .alignment(Alignment::Left)
-        .style(Style::default().fg(theme.warning));
-    f.render_widget(disk_paragraph, layout[0]);
-    
-    if !usage.disk_read_history.is_empty() {
-        let data: Vec<u64> = usage.disk_read_history.iter().cloned().collect();
-        let sparkline = Sparkline::default()
-             .data(&data)
-             .style(Style::default().fg(theme.warning));
-        f.render_widget(sparkline, layout[1]);
+    let disk_text = Line::from(vec![
+        Span::styled(format!("R:{}", format_rate(usage.disk_read)), Style::default().fg(theme.warning)),
+        Span::raw(" "),
+        Span::styled(format!("W:{}", format_rate(usage.disk_write)), Style::default().fg(theme.info)),
+    ]);
+    f.render_widget(Paragraph::new(disk_text), layout[0]);
+    
+    render_scaled_history(f, (&usage.disk_read_history, theme.warning), (&usage.disk_write_history, theme.info), layout[1], theme);
+}
+
+// Two rate histories on one scale, each in its own colour, with their range
+// written beside them. Only the newest samples that fit are drawn, and the
+// labels describe those.
+fn render_scaled_history(f: &mut Frame, first: (&std::collections::VecDeque<u64>, Color), second: (&std::collections::VecDeque<u64>, Color), area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let first_data: Vec<u64> = first.0.iter().copied().collect();
+    let second_data: Vec<u64> = second.0.iter().copied().collect();
+    let peaks: Vec<u64> = first_data.iter().zip(&second_data).map(|(a, b)| *a.max(b)).collect();
+    if peaks.is_empty() {
+        return;
+    }
+    let tail = |data: &[u64], width: u16| data.len().saturating_sub(width as usize);
+    // Which samples fit depends on the room the labels take, so the labels
+    // are sized on the whole history first.
+    let (chart, _) = widgets::AxisLabels::new(&peaks, format_rate).split(area, 8);
+    let labels = widgets::AxisLabels::new(&peaks[tail(&peaks, chart.width)..], format_rate);
+    let (chart, column) = labels.split(area, 8);
+    graphics::render_history_pair(
+        f,
+        (&first_data[tail(&first_data, chart.width)..], first.1),
+        (&second_data[tail(&second_data, chart.width)..], second.1),
+        chart,
+    );
+    if let Some(column) = column {
+        labels.render(f, column, theme);
     }
 }
 
@@ -331,6 +744,9 @@ fn render_dashboard_tab(f: &mut Frame, state: &mut AppState, area: Rect, transla
     render_process_table(f, state, layout[1], translator, theme);
     
     render_container_table(f, state, layout[2], translator, theme);
+    
+    render_freshness(f, state, CollectorKind::System, layout[1], theme);
+    render_freshness(f, state, CollectorKind::Containers, layout[2], theme);
 }
 
 fn render_system_status(f: &mut Frame, state: &AppState, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
@@ -359,16 +775,22 @@ fn render_system_status(f: &mut Frame, state: &AppState, area: Rect, translator:
     let (mem_available, availability_level) = estimate_memory_availability(usage.mem_used, usage.mem_total);
     
     let status_text = format!(
-        "Status {} | CPU: {:.0}% (Eff: {}) | Load: {:.2}/core | Mem: {:.0}% ({}) | Swap: {:.0}% | Up: {} | Procs: {}",
+        "Status {} | CPU: {:.0}% (Eff: {}) | Load: {:.2}/core{} | Mem: {:.0}% ({}) | Swap: {:.0}% | Up: {} | Procs: {}",
         status_str,
         usage.cpu,
         cpu_efficiency,
         load_per_core.parse::<f64>().unwrap_or(0.0),
+        match (usage.run_queue, usage.blocked) {
+            (Some(running), Some(blocked)) => format!(" (run {}, blocked {})", running, blocked),
+            (Some(running), None) => format!(" (run {})", running),
+            _ => String::new(),
+        },
         mem_percent,
         format_size(mem_available),
         if usage.swap_total > 0 { (usage.swap_used as f64 / usage.swap_total as f64) * 100.0 } else { 0.0 },
         crate::utils::format_uptime(usage.uptime),
-        state.dynamic_data.processes.len()
+        // Collapsed tree nodes still count.
+        state.dynamic_data.processes.iter().map(|p| 1 + p.hidden_children).sum::<usize>()
     );
     
     let status_paragraph = Paragraph::new(status_text)
@@ -387,46 +809,137 @@ fn render_system_status(f: &mut Frame, state: &AppState, area: Rect, translator:
 
 fn render_process_table(f: &mut Frame, state: &mut AppState, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
     let processes = &state.dynamic_data.processes;
-    let header_pid = translator.t("header.pid");
-    let header_name = translator.t("header.name");
-    let header_user = translator.t("header.user");
-    let header_cpu = translator.t("header.cpu");
-    let header_memory = translator.t("header.memory");
-    let header_disk_read = translator.t("header.disk_read");
-    let header_disk_write = translator.t("header.disk_write");
+    let containers = &state.dynamic_data.containers;
+    let show_container = state.show_container_column;
+    let show_state = state.show_state_columns;
+    let now = chrono::Utc::now().timestamp().max(0) as u64;
+    
+    let container_name = |id: &str| -> String {
+        containers.iter()
+            .find(|c| c.id == id)
+            .map(|c| c.name.clone())
+            .unwrap_or_else(|| id.to_string())
+    };
+    
+    let mut headers = vec![
+        translator.t("header.pid"),
+        translator.t("header.name"),
+        translator.t("header.user"),
+        translator.t("header.cpu"),
+        translator.t("header.memory"),
+        translator.t("header.swap"),
+        translator.t("header.disk_read"),
+        translator.t("header.disk_write"),
+        translator.t("header.mem_growth"),
+    ];
+    let mut widths = vec![
+        Constraint::Length(8),   // PID
+        Constraint::Min(15),     // Name
+        Constraint::Length(12),  // User
+        Constraint::Length(8),   // CPU
+        Constraint::Length(10),  // Memory
+        Constraint::Length(10),  // Swap
+        Constraint::Length(12),  // Read/s
+        Constraint::Length(12),  // Write/s
+        Constraint::Length(11),  // Growth/min
+    ];
+    if show_state {
+        headers.push(translator.t("header.threads"));
+        headers.push(translator.t("header.state"));
+        headers.push(translator.t("header.elapsed"));
+        headers.push(translator.t("header.cpu_time"));
+        widths.push(Constraint::Length(8));  // Threads
+        widths.push(Constraint::Length(6));  // State
+        widths.push(Constraint::Length(11)); // Elapsed
+        widths.push(Constraint::Length(11)); // CPU Time
+    }
+    if show_container {
+        headers.push(translator.t("header.container"));
+        widths.push(Constraint::Length(16)); // Container
+    }
     
     let rows = processes.iter().map(|p| {
-        Row::new(vec![
+        let limit = p.pid.parse::<usize>().ok().and_then(|pid| state.cpu_limits.get(sysinfo::Pid::from(pid)));
+        let name = if let Some(limit) = limit {
+            format!("{} ≤{}%", truncate_string(&p.name, 14), limit.percent)
+        } else if p.is_stopped() {
+            format!("⏸ {}", truncate_string(&p.name, 18))
+        } else {
+            truncate_string(&p.name, 20)
+        };
+        let name = match p.hidden_children {
+            0 => format!("{}{}", p.tree_prefix, name),
+            hidden => format!("{}{} [+{}]", p.tree_prefix, name, hidden),
+        };
+        let mut cells = vec![
             p.pid.clone(),
-            truncate_string(&p.name, 20),
+            name,
             truncate_string(&p.user, 12),
             p.cpu_display.clone(),
             p.mem_display.clone(),
+            p.swap.filter(|&swap| swap > 0).map_or("-".to_string(), format_size),
             p.disk_read.clone(),
             p.disk_write.clone(),
-        ]).style(Style::default().fg(theme.text))
+            format_growth(p.mem_growth),
+        ];
+        if show_state {
+            cells.push(if p.threads > 0 { p.threads.to_string() } else { "-".to_string() });
+            cells.push(p.state_letter().to_string());
+            cells.push(if p.start_time > 0 { format_duration(now.saturating_sub(p.start_time)) } else { "-".to_string() });
+            cells.push(p.cpu_time.map_or("-".to_string(), format_duration));
+        }
+        if show_container {
+            cells.push(p.container.as_deref().map_or("-".to_string(), |id| truncate_string(&container_name(id), 16)));
+        }
+        let style = if p.is_disk_sleep() {
+            Style::default().fg(theme.error).add_modifier(Modifier::BOLD)
+        } else if limit.is_some() {
+            Style::default().fg(theme.warning).add_modifier(Modifier::ITALIC)
+        } else if p.is_stopped() {
+            Style::default().fg(theme.text_secondary).add_modifier(Modifier::ITALIC)
+        } else if p.leak_suspect {
+            Style::default().fg(theme.warning)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        Row::new(cells).style(style)
     });
     
-    let table = Table::new(
-        rows,
-        [
-            Constraint::Length(8),   // PID
-            Constraint::Min(15),     // Name
-            Constraint::Length(12),  // User
-            Constraint::Length(8),   // CPU
-            Constraint::Length(10),  // Memory
-            Constraint::Length(12),  // Read/s
-            Constraint::Length(12),  // Write/s
-        ]
-    )
+    let mut title = match &state.container_filter {
+        Some(id) => format!("{} ({}: {})", translator.t("title.processes"), translator.t("header.container"), container_name(id)),
+        None => translator.t("title.processes"),
+    };
+    if !state.filter_text.is_empty() {
+        title = format!("{} [{}: {}]", title, translator.t("label.filter"), state.filter_text);
+    }
+    
+    // The sort column is highlighted and shows its direction; the combined
+    // CPU+memory score has no column of its own, so it goes in the title.
+    let arrow = if state.sort_ascending { "▲" } else { "▼" };
+    let sort_column = state.sort_by.column();
+    if sort_column.is_none() {
+        title = format!("{} [CPU+MEM {}]", title, arrow);
+    }
+    if state.process_tree {
+        title = format!("{} [Tree]", title);
+    }
+    let header_cells = headers.into_iter().enumerate().map(|(i, header)| {
+        if Some(i) == sort_column {
+            Cell::from(format!("{} {}", header, arrow)).style(Style::default().fg(theme.highlight).add_modifier(Modifier::UNDERLINED))
+        } else {
+            Cell::from(header)
+        }
+    });
+    
+    let table = Table::new(rows, widths)
     .header(
-        Row::new(vec![header_pid, header_name, header_user, header_cpu, header_memory, header_disk_read, header_disk_write])
+        Row::new(header_cells)
             .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
             .bottom_margin(1)
     )
     .block(
         Block::default()
-            .title(translator.t("title.processes"))
+            .title(title)
             .title_style(Style::default().fg(theme.primary))
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
@@ -536,10 +1049,15 @@ fn render_process_detail_tab(f: &mut Frame, state: &AppState, area: Rect, _trans
     f.render_widget(block, area);
     
     if let Some(ref process) = state.dynamic_data.detailed_process {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Percentage(35), Constraint::Length(6)])
+            .split(inner_area);
+        
         let layout = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(inner_area);
+            .split(rows[0]);
         
         let info_lines = vec![
             Line::from(vec![
@@ -578,10 +1096,28 @@ fn render_process_detail_tab(f: &mut Frame, state: &AppState, area: Rect, _trans
                 Span::styled("Memory (VMS): ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
                 Span::styled(format_size(process.memory_vms), Style::default().fg(theme.text))
             ]),
+            Line::from(vec![
+                Span::styled("Swap: ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+                Span::styled(process.memory_swap.map_or("N/A".to_string(), format_size), Style::default().fg(theme.text))
+            ]),
             Line::from(vec![
                 Span::styled("Threads: ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
                 Span::styled(process.threads.to_string(), Style::default().fg(theme.text))
             ]),
+            Line::from(vec![
+                Span::styled("I/O Priority: ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+                Span::styled(process.io_priority.map_or("N/A".to_string(), |p| p.to_string()), Style::default().fg(theme.text))
+            ]),
+            Line::from(vec![
+                Span::styled("CPU Limit: ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+                match process.pid.parse::<usize>().ok().and_then(|pid| state.cpu_limits.get(sysinfo::Pid::from(pid))) {
+                    Some(limit) => Span::styled(
+                        format!("{}% ({})", limit.percent, limit.method.label()),
+                        Style::default().fg(theme.warning).add_modifier(Modifier::BOLD),
+                    ),
+                    None => Span::styled("none", Style::default().fg(theme.text)),
+                },
+            ]),
         ];
         
         let final_info_lines: Vec<_> = if let Some(ref cwd) = process.cwd {
@@ -603,36 +1139,30 @@ fn render_process_detail_tab(f: &mut Frame, state: &AppState, area: Rect, _trans
                     .border_style(Style::default().fg(theme.border))
             )
             .wrap(ratatui::widgets::Wrap { trim: false });
-        f.render_widget(info_paragraph, layout[0]);
-        
-        let mut cmd_env_lines = vec![
-            Line::from(Span::styled("Command:", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))),
-            Line::from(""),
-            Line::from(Span::styled(&process.command, Style::default().fg(theme.text))),
-            Line::from(""),
-            Line::from(Span::styled("Environment Variables:", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))),
-            Line::from(""),
-        ];
-        
-        for (i, env) in process.environ.iter().enumerate() {
-            if i >= 20 {
-                cmd_env_lines.push(Line::from(Span::styled("... (truncated)", Style::default().fg(theme.text_secondary))));
-                break;
+        match &process.io {
+            Some(io) => {
+                let (info_area, io_area) = create_two_row_layout(layout[0], 60);
+                f.render_widget(info_paragraph, info_area);
+                render_process_io(f, io, io_area, theme);
             }
-            cmd_env_lines.push(Line::from(Span::styled(env, Style::default().fg(theme.text))));
+            None => f.render_widget(info_paragraph, layout[0]),
         }
         
-        let cmd_env_paragraph = Paragraph::new(cmd_env_lines)
-            .block(
-                Block::default()
-                    .title("Command & Environment")
-                    .borders(Borders::ALL)
-                    .border_type(ratatui::widgets::BorderType::Rounded)
-                    .border_style(Style::default().fg(theme.border))
-            )
-            .wrap(ratatui::widgets::Wrap { trim: false });
-        f.render_widget(cmd_env_paragraph, layout[1]);
+        let cmd_env_area = match &process.security {
+            Some(security) => {
+                let right = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(7), Constraint::Min(0)])
+                    .split(layout[1]);
+                render_process_security(f, security, right[0], theme);
+                right[1]
+            }
+            None => layout[1],
+        };
+        render_command_and_environment(f, state, process, cmd_env_area, theme);
         
+        render_thread_table(f, state, process, rows[1], theme);
+        render_process_history(f, process, rows[2], theme);
     } else {
         let message = Paragraph::new("Select a process from the Dashboard tab (↑↓ to navigate, Enter to select)")
             .alignment(Alignment::Center)
@@ -642,26 +1172,196 @@ fn render_process_detail_tab(f: &mut Frame, state: &AppState, area: Rect, _trans
     }
 }
 
-fn render_cpu_cores_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
-    use ratatui::widgets::{Chart, Dataset, Axis, Paragraph, Gauge};
-    use ratatui::layout::{Layout, Constraint, Direction};
-    use ratatui::text::{Line, Span};
-    use ratatui::style::{Style, Modifier};
-    use ratatui::widgets::{Block, Borders};
+// The command line wrapped between arguments, then the environment, scrolled
+// with PgUp/PgDn. Lines are wrapped here rather than by the paragraph so the
+// scroll limit is known.
+fn render_command_and_environment(f: &mut Frame, state: &AppState, process: &crate::types::DetailedProcessInfo, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let width = area.width.saturating_sub(2) as usize;
+    let heading = |text: &'static str| Line::from(Span::styled(text, Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)));
+    let text = |text: String| Line::from(Span::styled(text, Style::default().fg(theme.text)));
 
-    let cores = &state.dynamic_data.cores;
-    
-    if cores.is_empty() {
-        let message = Paragraph::new("No CPU core information available")
-            .alignment(Alignment::Center)
-            .block(
-                Block::default()
-                    .title("CPU Cores")
-                    .borders(Borders::ALL)
-                    .border_type(ratatui::widgets::BorderType::Rounded)
-                    .border_style(Style::default().fg(theme.border))
-            );
-        f.render_widget(message, area);
+    let args: Vec<String> = if process.args.is_empty() {
+        vec![process.command.clone()]
+    } else {
+        process.args.iter().map(|arg| crate::utils::shell_quote(arg)).collect()
+    };
+    let mut lines = vec![heading("Command:"), Line::from("")];
+    lines.extend(crate::utils::wrap_words(&args, width).into_iter().map(text));
+    lines.extend([Line::from(""), heading("Environment Variables:"), Line::from("")]);
+    for env in &process.environ {
+        lines.extend(crate::utils::wrap_words(std::slice::from_ref(env), width).into_iter().map(text));
+    }
+
+    let visible = area.height.saturating_sub(2) as usize;
+    let scroll = state.command_scroll.min(lines.len().saturating_sub(visible));
+    let title = if lines.len() > visible {
+        format!("Command & Environment ({}-{} of {})", scroll + 1, (scroll + visible).min(lines.len()), lines.len())
+    } else {
+        "Command & Environment".to_string()
+    };
+    let paragraph = Paragraph::new(lines.into_iter().skip(scroll).take(visible).collect::<Vec<_>>())
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border))
+        );
+    f.render_widget(paragraph, area);
+}
+
+fn render_thread_table(f: &mut Frame, state: &AppState, process: &crate::types::DetailedProcessInfo, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let headers = ["TID", "Name", "State", "CPU %", "CPU Time"];
+    let ascending = state.thread_sort.default_ascending() != state.thread_sort_reversed;
+    let arrow = if ascending { "▲" } else { "▼" };
+    let header_cells = headers.into_iter().enumerate().map(|(i, header)| {
+        if i == state.thread_sort.column() {
+            Cell::from(format!("{} {}", header, arrow)).style(Style::default().fg(theme.highlight).add_modifier(Modifier::UNDERLINED))
+        } else {
+            Cell::from(header)
+        }
+    });
+    
+    let rows = process.thread_list.iter().map(|thread| {
+        Row::new(vec![
+            thread.tid.to_string(),
+            thread.name.clone(),
+            thread.state.clone(),
+            format!("{:.1}", thread.cpu_usage),
+            format_duration(thread.cpu_time.as_secs()),
+        ]).style(Style::default().fg(crate::ui::colors::cpu_usage_color(thread.cpu_usage)))
+    });
+    
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Min(16),
+            Constraint::Length(12),
+            Constraint::Length(8),
+            Constraint::Length(12),
+        ]
+    )
+    .header(
+        Row::new(header_cells)
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+    )
+    .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD))
+    .block(
+        Block::default()
+            .title(format!("Threads ({})", process.thread_list.len()))
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border))
+    );
+    
+    f.render_stateful_widget(table, area, &mut state.thread_table_state.clone());
+}
+
+fn render_process_security(f: &mut Frame, security: &crate::types::ProcessSecurity, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    let value = |text: String| Span::styled(text, Style::default().fg(theme.text));
+    let lsm_label = match (&security.lsm, &security.label) {
+        (Some(lsm), Some(label)) => format!("{} ({})", label, lsm),
+        (Some(lsm), None) => format!("unreadable ({})", lsm),
+        (None, _) => "no SELinux/AppArmor".to_string(),
+    };
+    let no_new_privs = if security.no_new_privs { "yes" } else { "no" };
+
+    let lines = vec![
+        Line::from(vec![label("Label: "), value(lsm_label)]),
+        Line::from(vec![label("No New Privs: "), value(no_new_privs.to_string()), label("  Seccomp: "), value(security.seccomp_mode().to_string())]),
+        Line::from(vec![label("Capabilities: "), value(security.capabilities_summary())]),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title("Security Context")
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border))
+        )
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
+
+// Logical reads and writes next to what reached the disk, so cache hits and
+// writes that were truncated away before flushing stand out.
+fn render_process_io(f: &mut Frame, io: &crate::types::ProcessIo, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    let value = |text: String| Span::styled(text, Style::default().fg(theme.text));
+    let cache_hits = io.cache_hit_percent()
+        .map_or("N/A".to_string(), |percent| format!("{} ({:.1}%)", format_size(io.rchar.saturating_sub(io.read_bytes)), percent));
+
+    let lines = vec![
+        Line::from(vec![label("Read (calls): "), value(format!("{} in {} syscalls", format_size(io.rchar), io.syscr))]),
+        Line::from(vec![label("Read from Disk: "), value(format_size(io.read_bytes))]),
+        Line::from(vec![label("Page Cache Hits: "), value(cache_hits)]),
+        Line::from(vec![label("Written (calls): "), value(format!("{} in {} syscalls", format_size(io.wchar), io.syscw))]),
+        Line::from(vec![label("Written to Disk: "), value(format_size(io.write_bytes))]),
+        Line::from(vec![label("Cancelled Writes: "), value(format_size(io.cancelled_write_bytes))]),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title("I/O Accounting")
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border))
+        );
+    f.render_widget(paragraph, area);
+}
+
+fn render_process_history(f: &mut Frame, process: &crate::types::DetailedProcessInfo, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    
+    // Sparklines need integers; keep a tenth of a percent of resolution.
+    let cpu_data: Vec<u64> = process.cpu_history.iter().map(|&cpu| (cpu * 10.0) as u64).collect();
+    let cpu_peak = process.cpu_history.iter().cloned().fold(0.0f32, f32::max);
+    let cpu_block = Block::default()
+        .title(format!("CPU History (peak {:.1}%)", cpu_peak))
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border));
+    graphics::render_history(f, &cpu_data, None, theme.primary, cpu_block.inner(layout[0]));
+    f.render_widget(cpu_block, layout[0]);
+    
+    let memory_data: Vec<u64> = process.memory_history.iter().cloned().collect();
+    let memory_peak = memory_data.iter().cloned().max().unwrap_or(0);
+    let memory_block = Block::default()
+        .title(format!("Memory History (peak {})", format_size(memory_peak)))
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border));
+    graphics::render_history(f, &memory_data, None, theme.accent, memory_block.inner(layout[1]));
+    f.render_widget(memory_block, layout[1]);
+}
+
+fn render_cpu_cores_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    use ratatui::widgets::{Paragraph, Gauge};
+    use ratatui::layout::{Layout, Constraint, Direction};
+    use ratatui::text::{Line, Span};
+    use ratatui::style::{Style, Modifier};
+    use ratatui::widgets::{Block, Borders};
+
+    let cores = &state.dynamic_data.cores;
+    
+    if cores.is_empty() {
+        let message = Paragraph::new("No CPU core information available")
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title("CPU Cores")
+                    .borders(Borders::ALL)
+                    .border_type(ratatui::widgets::BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border))
+            );
+        f.render_widget(message, area);
         return;
     }
     
@@ -669,6 +1369,7 @@ fn render_cpu_cores_tab(f: &mut Frame, state: &AppState, area: Rect, _translator
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(8),
+            Constraint::Length(10),
             Constraint::Min(10),
         ])
         .split(area);
@@ -692,7 +1393,7 @@ fn render_cpu_cores_tab(f: &mut Frame, state: &AppState, area: Rect, _translator
             Span::styled(format!("{} Logical", core_count), Style::default().fg(theme.text)),
             Span::raw(" | "),
             Span::styled("Usage: ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
-            Span::styled(format!("{:.1}%", usage.cpu), Style::default().fg(get_usage_color(usage.cpu))),
+            Span::styled(format!("{:.1}%", usage.cpu), Style::default().fg(state.alert_thresholds.cpu.color(usage.cpu, theme))),
         ]),
         Line::from(vec![
              Span::styled("Load Average: ", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
@@ -709,34 +1410,44 @@ fn render_cpu_cores_tab(f: &mut Frame, state: &AppState, area: Rect, _translator
         );
     f.render_widget(info_paragraph, top_chunks[1]);
 
-    let history_data: Vec<(f64, f64)> = state.dynamic_data.global_usage.cpu_history
-        .iter()
-        .enumerate()
-        .map(|(i, &v)| (i as f64, v as f64))
-        .collect();
-
-    let datasets = vec![
-        Dataset::default()
-            .name("Total Usage")
-            .marker(ratatui::symbols::Marker::Braille)
-            .style(Style::default().fg(theme.primary))
-            .data(&history_data)
-    ];
-    
-    let chart = Chart::new(datasets)
-        .block(Block::default()
-            .title("Usage History")
-            .borders(Borders::ALL)
-            .border_type(ratatui::widgets::BorderType::Rounded)
-            .border_style(Style::default().fg(theme.border))
-        )
-        .x_axis(Axis::default().bounds([0.0, 60.0]))
-        .y_axis(Axis::default().bounds([0.0, 100.0]));
-    f.render_widget(chart, top_chunks[0]);
+    render_cpu_history_chart(f, state, top_chunks[0], theme);
+    let sensors = temperature_series(state);
+    let unavailable = state.dynamic_data.temperatures.unavailable.as_deref();
+    if sensors.is_empty() && unavailable.is_none() {
+        render_load_history_chart(f, state, chunks[1], theme);
+    } else {
+        let middle = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(chunks[1]);
+        render_load_history_chart(f, state, middle[0], theme);
+        match unavailable {
+            Some(reason) if sensors.is_empty() => {
+                let notice = Paragraph::new(reason)
+                    .style(Style::default().fg(theme.text_secondary))
+                    .block(Block::default()
+                        .title("Temperature History")
+                        .borders(Borders::ALL)
+                        .border_type(ratatui::widgets::BorderType::Rounded)
+                        .border_style(Style::default().fg(theme.border))
+                    );
+                f.render_widget(notice, middle[1]);
+            }
+            _ => render_temperature_history_chart(f, &sensors, middle[1], theme),
+        }
+    }
     
-    let inner_area = chunks[1];
+    let inner_area = chunks[2];
+    let mut title = vec![Span::raw(format!("Detailed Core Usage ({} cores)", cores.len()))];
+    if cores.iter().any(|core| core.breakdown.is_some()) {
+        title.push(Span::raw(" "));
+        for (name, color) in cpu_breakdown_legend(theme) {
+            title.push(Span::styled(format!(" █ {}", name), Style::default().fg(color)));
+        }
+        title.push(Span::raw(" "));
+    }
     let block = Block::default()
-        .title(format!("Detailed Core Usage ({} cores)", cores.len()))
+        .title(Line::from(title))
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .border_style(Style::default().fg(theme.border));
@@ -786,22 +1497,223 @@ fn render_cpu_cores_tab(f: &mut Frame, state: &AppState, area: Rect, _translator
             }
             
             let core = &cores[actual_core_idx];
-            let color = get_usage_color(core.usage);
+            let color = state.alert_thresholds.cpu.color(core.usage, theme);
             let freq_display = format_frequency(core.freq);
             
-            let gauge = Gauge::default()
-                .label(format!("C{} {} {:.1}%", actual_core_idx, freq_display, core.usage))
-                .gauge_style(Style::default().fg(color))
-                .ratio((core.usage / 100.0) as f64);
-            
-            f.render_widget(gauge, *core_area);
+            let label = format!("C{} {} {:.1}%", actual_core_idx, freq_display, core.usage);
+
+            let Some(breakdown) = core.breakdown else {
+                let gauge = Gauge::default()
+                    .label(label)
+                    .gauge_style(Style::default().fg(color))
+                    .ratio((core.usage / 100.0) as f64);
+                f.render_widget(gauge, *core_area);
+                continue;
+            };
+
+            let width = core_area.width.saturating_sub(1) as usize;
+            let segments: Vec<(f32, ratatui::style::Color)> = cpu_breakdown_legend(theme).iter()
+                .zip([breakdown.user, breakdown.system, breakdown.iowait, breakdown.irq, breakdown.steal])
+                .map(|(&(_, color), percent)| (percent, color))
+                .collect();
+            let lines = vec![
+                Line::from(Span::styled(label, Style::default().fg(color))),
+                widgets::stacked_bar(&segments, width, theme.text_secondary),
+            ];
+            f.render_widget(Paragraph::new(lines), *core_area);
         }
     }
 }
 
-fn render_disks_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+// Order and colors of the stacked per-core bars.
+fn cpu_breakdown_legend(theme: &crate::ui::colors::ColorScheme) -> [(&'static str, ratatui::style::Color); 5] {
+    [
+        ("user", theme.primary),
+        ("system", theme.error),
+        ("iowait", theme.warning),
+        ("irq", theme.accent),
+        ("steal", theme.info),
+    ]
+}
+
+fn render_cpu_history_chart(f: &mut Frame, state: &AppState, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let history_data: Vec<(f64, f64)> = state.dynamic_data.global_usage.cpu_history
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| (i as f64, v as f64))
+        .collect();
+
+    let datasets = vec![
+        Dataset::default()
+            .name("Total Usage")
+            .marker(ratatui::symbols::Marker::Braille)
+            .style(Style::default().fg(theme.primary))
+            .data(&history_data)
+    ];
+    
+    let chart = Chart::new(datasets)
+        .block(Block::default()
+            .title("Usage History")
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border))
+        )
+        .x_axis(Axis::default().bounds([0.0, 60.0]))
+        .y_axis(Axis::default().bounds([0.0, 100.0]));
+    f.render_widget(chart, area);
+}
+
+// Load averages with the run queue and blocked tasks on the same scale: a
+// queue well above the core count while the averages climb is CPU saturation,
+// while blocked tasks rising with the load point at I/O stalls.
+fn render_load_history_chart(f: &mut Frame, state: &AppState, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let usage = &state.dynamic_data.global_usage;
+    let series = |pick: fn(&(f64, f64, f64)) -> f64| -> Vec<(f64, f64)> {
+        usage.load_history.iter().enumerate().map(|(i, load)| (i as f64, pick(load))).collect()
+    };
+    let load1 = series(|load| load.0);
+    let load5 = series(|load| load.1);
+    let load15 = series(|load| load.2);
+    let run_queue: Vec<(f64, f64)> = usage.run_queue_history.iter().enumerate().map(|(i, &n)| (i as f64, n as f64)).collect();
+    let blocked: Vec<(f64, f64)> = usage.blocked_history.iter().enumerate().map(|(i, &n)| (i as f64, n as f64)).collect();
+    
+    let peak = load1.iter().chain(&load5).chain(&load15).chain(&run_queue).chain(&blocked)
+        .map(|&(_, v)| v)
+        .fold(state.dynamic_data.cores.len().max(1) as f64, f64::max);
+    let y_max = (peak * 1.2).ceil();
+    let x_max = usage.load_history.len().max(60) as f64;
+    
+    let mut datasets = vec![
+        Dataset::default().name("1m").marker(ratatui::symbols::Marker::Braille)
+            .graph_type(GraphType::Line).style(Style::default().fg(theme.primary)).data(&load1),
+        Dataset::default().name("5m").marker(ratatui::symbols::Marker::Braille)
+            .graph_type(GraphType::Line).style(Style::default().fg(theme.accent)).data(&load5),
+        Dataset::default().name("15m").marker(ratatui::symbols::Marker::Braille)
+            .graph_type(GraphType::Line).style(Style::default().fg(theme.secondary)).data(&load15),
+    ];
+    if !run_queue.is_empty() {
+        datasets.push(Dataset::default().name("Run queue").marker(ratatui::symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter).style(Style::default().fg(theme.warning)).data(&run_queue));
+    }
+    if !blocked.is_empty() {
+        datasets.push(Dataset::default().name("Blocked").marker(ratatui::symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter).style(Style::default().fg(theme.error)).data(&blocked));
+    }
+    
+    let chart = Chart::new(datasets)
+        .block(Block::default()
+            .title(format!("Load History ({} cores, up {})", state.dynamic_data.cores.len(), format_duration(usage.uptime)))
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border))
+        )
+        .x_axis(Axis::default().bounds([0.0, x_max]))
+        .y_axis(Axis::default()
+            .bounds([0.0, y_max])
+            .labels(vec![Span::raw("0"), Span::raw(format!("{:.0}", y_max / 2.0)), Span::raw(format!("{:.0}", y_max))]))
+        .legend_position(Some(ratatui::widgets::LegendPosition::TopLeft))
+        .hidden_legend_constraints((Constraint::Ratio(1, 3), Constraint::Ratio(3, 4)));
+    f.render_widget(chart, area);
+}
+
+// CPU, board and drive temperatures from the system collector, then each GPU's
+// from the GPU collector, as (label, readings oldest first). GPU readings are
+// re-sampled to the system collector's interval so both share the x axis.
+fn temperature_series(state: &AppState) -> Vec<(String, Vec<f64>)> {
+    let mut series: Vec<(String, Vec<f64>)> = state.dynamic_data.temperatures.history.iter()
+        .map(|(label, history)| (label.clone(), history.iter().map(|&t| t as f64).collect()))
+        .collect();
+    let system_len = series.iter().map(|(_, readings)| readings.len()).max().unwrap_or(0);
+    if let Ok(gpus) = &state.dynamic_data.gpus {
+        for (i, gpu) in gpus.iter().enumerate() {
+            if gpu.temperature.is_some() && !gpu.temperature_history.is_empty() {
+                let readings: Vec<f64> = gpu.temperature_history.iter().map(|&t| t as f64).collect();
+                let mut readings = crate::utils::resample_history(&readings, state.performance.gpu.target, state.performance.system.target);
+                // A slower GPU collector's history spans more time than the system's.
+                if system_len > 0 && readings.len() > system_len {
+                    readings.drain(..readings.len() - system_len);
+                }
+                series.push((format!("GPU {}", i), readings));
+            }
+        }
+    }
+    series
+}
+
+// Every sensor on one chart, lined up at the newest reading, so a component
+// heating up under load stands out against the rest.
+fn render_temperature_history_chart(f: &mut Frame, sensors: &[(String, Vec<f64>)], area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let colors = [theme.primary, theme.accent, theme.secondary, theme.warning, theme.info, theme.success, theme.error];
+    let x_max = sensors.iter().map(|(_, values)| values.len()).max().unwrap_or(0).max(60) as f64;
+    let points: Vec<Vec<(f64, f64)>> = sensors.iter()
+        .map(|(_, values)| {
+            let start = x_max - values.len() as f64;
+            values.iter().enumerate().map(|(i, &t)| (start + i as f64, t)).collect()
+        })
+        .collect();
+    let names: Vec<String> = sensors.iter()
+        .map(|(label, values)| format!("{} {:.0}°C", label, values.last().copied().unwrap_or_default()))
+        .collect();
+
+    let all = || sensors.iter().flat_map(|(_, values)| values.iter().copied());
+    let y_min = ((all().fold(f64::MAX, f64::min) - 5.0).max(0.0) / 10.0).floor() * 10.0;
+    let y_max = ((all().fold(0.0, f64::max) + 5.0) / 10.0).ceil() * 10.0;
+
+    let datasets = points.iter().zip(&names).enumerate()
+        .map(|(i, (data, name))| Dataset::default()
+            .name(name.as_str())
+            .marker(ratatui::symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(colors[i % colors.len()]))
+            .data(data))
+        .collect();
+
+    let chart = Chart::new(datasets)
+        .block(Block::default()
+            .title("Temperature History")
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border))
+        )
+        .x_axis(Axis::default().bounds([0.0, x_max]))
+        .y_axis(Axis::default()
+            .bounds([y_min, y_max])
+            .labels(vec![
+                Span::raw(format!("{:.0}°", y_min)),
+                Span::raw(format!("{:.0}°", (y_min + y_max) / 2.0)),
+                Span::raw(format!("{:.0}°", y_max)),
+            ]))
+        .legend_position(Some(ratatui::widgets::LegendPosition::TopLeft))
+        .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(3, 4)));
+    f.render_widget(chart, area);
+}
+
+// Samples in each disk row's sparkline.
+const DISK_HISTORY_WIDTH: usize = 12;
+
+fn render_disks_tab(f: &mut Frame, state: &mut AppState, area: Rect, _translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    let scanning = state.disk_scan.get().is_some();
+    let watching = !state.fs_events.watched.is_empty();
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(6),
+            Constraint::Percentage(if scanning { 50 } else { 0 }),
+            Constraint::Length(if watching { 12 } else { 0 }),
+        ])
+        .split(area);
+    if scanning {
+        render_disk_scan(f, state, layout[1], theme);
+    }
+    if watching {
+        render_fs_events(f, state, layout[2], theme);
+    }
+    let area = layout[0];
+    if state.disk_table_state.selected().is_none_or(|i| i >= state.dynamic_data.disks.len()) {
+        state.disk_table_state.select(if state.dynamic_data.disks.is_empty() { None } else { Some(0) });
+    }
     let disks = &state.dynamic_data.disks;
-    let headers = ["Mount", "Device", "FS", "Total", "Used", "Free", "Use%", "R/s", "W/s", "R-Ops", "W-Ops"];
+    let headers = ["Mount", "Device", "FS", "Total", "Used", "Free", "Use%", "Full In", "R/s", "W/s", "R-IOPS", "W-IOPS", "History R/W"];
     
     let rows = disks.iter().map(|disk| {
         let usage_percent = if disk.total > 0 {
@@ -818,13 +1730,18 @@ fn render_disks_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &T
             format_size(disk.used),
             format_size(disk.free),
             format_percentage(usage_percent),
+            disk.time_to_full.map_or("-".to_string(), |seconds| format!("~{}", crate::utils::format_rough_duration(seconds))),
             format_rate(disk.read_rate),
             format_rate(disk.write_rate),
             disk.read_ops.to_string(),
             disk.write_ops.to_string(),
-        ]).style(Style::default().fg(
-            if usage_percent > 90.0 { theme.error }
-            else if usage_percent > 75.0 { theme.warning }
+        ].into_iter().map(Cell::from).chain(std::iter::once(Cell::from(graphics::history_pair_line(
+            (&disk.read_history.iter().copied().collect::<Vec<_>>(), theme.warning),
+            (&disk.write_history.iter().copied().collect::<Vec<_>>(), theme.info),
+            DISK_HISTORY_WIDTH,
+        ))))).style(Style::default().fg(
+            if usage_percent > state.alert_thresholds.disk.critical { theme.error }
+            else if usage_percent > state.alert_thresholds.disk.warn { theme.warning }
             else { theme.text }
         ))
     });
@@ -839,10 +1756,12 @@ fn render_disks_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &T
             Constraint::Length(9),   // Used
             Constraint::Length(9),   // Free
             Constraint::Length(7),   // Use%
+            Constraint::Length(7),   // Full In
             Constraint::Length(9),   // R/s
             Constraint::Length(9),   // W/s
-            Constraint::Length(7),   // R-Ops
-            Constraint::Length(7),   // W-Ops
+            Constraint::Length(7),   // R-IOPS
+            Constraint::Length(7),   // W-IOPS
+            Constraint::Length(DISK_HISTORY_WIDTH as u16),
         ]
     )
     .header(
@@ -851,12 +1770,120 @@ fn render_disks_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &T
     )
     .block(
         Block::default()
-            .title("Disk Usage")
+            .title("Disk Usage (Enter: largest directories)")
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .border_style(Style::default().fg(theme.border))
-    );
+    )
+    .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD));
     
+    f.render_stateful_widget(table, area, &mut state.disk_table_state);
+}
+
+// Recent events under the watched paths beside the paths written to most.
+fn render_fs_events(f: &mut Frame, state: &AppState, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    use crate::fs_watch::{FsEventKind, BUSIEST_WINDOW};
+
+    let log = &state.fs_events;
+    let now = chrono::Local::now();
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
+    let block = |title: String| Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border));
+    let header_style = Style::default().fg(theme.primary).add_modifier(Modifier::BOLD);
+
+    let event_rows = log.events.iter().rev().map(|event| {
+        let color = match event.kind {
+            FsEventKind::Created => theme.success,
+            FsEventKind::Modified => theme.text,
+            FsEventKind::Deleted => theme.error,
+            FsEventKind::Moved => theme.warning,
+        };
+        Row::new(vec![
+            event.at.format("%H:%M:%S").to_string(),
+            event.kind.label().to_string(),
+            if event.count > 1 { format!("×{}", event.count) } else { String::new() },
+            event.path.display().to_string(),
+        ]).style(Style::default().fg(color))
+    });
+    let watched: Vec<String> = log.watched.iter().map(|path| path.display().to_string()).collect();
+    let events = Table::new(event_rows, [Constraint::Length(8), Constraint::Length(6), Constraint::Length(7), Constraint::Min(20)])
+        .header(Row::new(vec!["Time", "Event", "Count", "Path"]).style(header_style))
+        .block(block(format!("Filesystem Events ({})", watched.join(", "))));
+    f.render_widget(events, columns[0]);
+
+    let busiest = log.busiest(now, BUSIEST_WINDOW);
+    let busiest_rows = busiest.iter().map(|(path, count)| {
+        Row::new(vec![count.to_string(), path.display().to_string()]).style(Style::default().fg(theme.text))
+    });
+    let busiest_table = Table::new(busiest_rows, [Constraint::Length(7), Constraint::Min(20)])
+        .header(Row::new(vec!["Events", "Path"]).style(header_style))
+        .block(block("Busiest Paths, Last Minute".to_string()));
+    f.render_widget(busiest_table, columns[1]);
+}
+
+// Progress while the scan runs, then the largest directories with their share
+// of everything scanned.
+fn render_disk_scan(f: &mut Frame, state: &AppState, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let Some(scan) = state.disk_scan.get() else { return };
+    let block = |title: String, color| Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(color));
+
+    let Some((result, elapsed)) = scan.result() else {
+        let (files, bytes, current, elapsed) = scan.progress();
+        let lines = vec![
+            Line::from(Span::styled(
+                format!("{} files, {} in {}s", files, format_size(bytes), elapsed.as_secs()),
+                Style::default().fg(theme.text),
+            )),
+            Line::from(Span::styled(
+                truncate_string(&current.display().to_string(), area.width.saturating_sub(4) as usize),
+                Style::default().fg(theme.text_secondary),
+            )),
+        ];
+        let title = format!("Scanning {}... (Esc: cancel)", scan.root.display());
+        f.render_widget(Paragraph::new(lines).block(block(title, theme.warning)), area);
+        return;
+    };
+
+    let total = result.total_bytes.max(1);
+    let rows = result.largest.iter().map(|usage| {
+        let share = usage.bytes as f64 / total as f64 * 100.0;
+        let bar_width = 20;
+        let filled = ((share / 100.0 * bar_width as f64).round() as usize).min(bar_width);
+        Row::new(vec![
+            format_size(usage.bytes),
+            format!("{:>5.1}%", share),
+            format!("{}{}", "█".repeat(filled), "░".repeat(bar_width - filled)),
+            usage.path.display().to_string(),
+        ]).style(Style::default().fg(theme.text))
+    });
+
+    let mut title = format!(
+        "Largest Directories in {} ({} in {} files, {:.1}s",
+        scan.root.display(), format_size(result.total_bytes), result.files, elapsed.as_secs_f32(),
+    );
+    if result.unreadable > 0 {
+        title.push_str(&format!(", {} unreadable", result.unreadable));
+    }
+    if result.cancelled {
+        title.push_str(", cancelled");
+    }
+    title.push_str(") Esc: close");
+    let color = if result.cancelled || result.unreadable > 0 { theme.warning } else { theme.border };
+
+    let table = Table::new(rows, [Constraint::Length(10), Constraint::Length(7), Constraint::Length(20), Constraint::Min(20)])
+        .header(Row::new(vec!["Size", "Share", "", "Directory"])
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)))
+        .block(block(title, color));
     f.render_widget(table, area);
 }
 
@@ -876,10 +1903,39 @@ fn render_network_tab(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode:
         return;
     }
     
+    let tcp = state.dynamic_data.tcp.as_ref();
+    let config_height = match &state.dynamic_data.network_config {
+        Some(config) if config.public_ip.is_some() => 6,
+        _ if tcp.is_some() => 6,
+        _ => 5,
+    };
+    // The neighbor table only takes room once it has entries.
+    let neighbors = &state.dynamic_data.neighbors;
+    let neighbors_height = if neighbors.is_empty() { 0 } else { (neighbors.len() as u16 + 3).clamp(6, 12) };
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(config_height), Constraint::Min(5), Constraint::Length(neighbors_height)])
+        .split(area);
+    match tcp {
+        Some(tcp) => {
+            let (config_area, tcp_area) = create_two_column_layout(layout[0], 50);
+            render_network_config(f, state, config_area, theme);
+            render_tcp_stats(f, tcp, tcp_area, state.alert_thresholds.tcp_retrans_percent, theme);
+        }
+        None => render_network_config(f, state, layout[0], theme),
+    }
+    if !neighbors.is_empty() {
+        render_neighbors(f, state, layout[2], theme);
+    }
+    let area = layout[1];
+    
     let networks = &state.dynamic_data.networks;
-    let headers = ["Interface", "Status", "Download/s", "Upload/s", "Total Down", "Total Up", "Packets Rx/Tx"];
+    // Totals count from when the interface came up, session traffic from
+    // when puls started or `r` was pressed.
+    let headers = ["Interface", "Status", "Download/s", "Upload/s", "Total Down", "Total Up", "Session Down", "Session Up", "Packets Rx/Tx"];
     
     let rows = networks.iter().map(|net| {
+        let (session_down, session_up) = state.net_session.traffic(net);
         Row::new(vec![
             net.name.clone(),
             if net.is_up { "UP".to_string() } else { "DOWN".to_string() },
@@ -887,6 +1943,8 @@ fn render_network_tab(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode:
             format_rate(net.up_rate),
             format_size(net.total_down),
             format_size(net.total_up),
+            format_size(session_down),
+            format_size(session_up),
             format!("{}/{}", net.packets_rx, net.packets_tx),
         ]).style(Style::default().fg(
             if net.is_up { theme.success } else { theme.error }
@@ -902,6 +1960,8 @@ fn render_network_tab(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode:
             Constraint::Length(12),  // Upload/s
             Constraint::Length(12),  // Total Down
             Constraint::Length(12),  // Total Up
+            Constraint::Length(12),  // Session Down
+            Constraint::Length(12),  // Session Up
             Constraint::Length(15),  // Packets
         ]
     )
@@ -911,7 +1971,10 @@ fn render_network_tab(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode:
     )
     .block(
         Block::default()
-            .title("Network Interfaces")
+            .title(match state.net_session.since {
+                Some(since) => format!("Network Interfaces (session since {})", since.format("%H:%M:%S")),
+                None => "Network Interfaces".to_string(),
+            })
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .border_style(Style::default().fg(theme.border))
@@ -920,6 +1983,158 @@ fn render_network_tab(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode:
     f.render_widget(table, area);
 }
 
+// Retransmissions, resets and listen drops, each with its recent history.
+fn render_tcp_stats(f: &mut Frame, tcp: &crate::types::TcpStats, area: Rect, alert_percent: u32, theme: &crate::ui::colors::ColorScheme) {
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    let value = |text: String, alerting: bool| Span::styled(text, Style::default().fg(if alerting { theme.error } else { theme.text }));
+    let alerts = alert_percent > 0;
+    let retrans_alert = alerts && tcp.retrans_percent.is_some_and(|percent| percent >= alert_percent as f64);
+    let lines = vec![
+        Line::from(vec![
+            label("Retransmits: "),
+            value(tcp.retrans_percent.map_or("-".to_string(), |percent| format!("{:.2}%", percent)), retrans_alert),
+        ]),
+        Line::from(vec![label("Resets:      "), value(format!("{:.1}/s", tcp.resets_per_sec), false)]),
+        Line::from(vec![
+            label("Listen drops:"),
+            value(format!(" {:.1}/s, {} since boot", tcp.listen_drops_per_sec, tcp.listen_drops_total), alerts && tcp.listen_drops_last_minute > 0),
+        ]),
+        Line::from(vec![
+            label("Sockets:     "),
+            value(format!("{} open, {} time-wait, {} orphaned, {} buffers", tcp.sockets_in_use, tcp.time_wait, tcp.orphaned, format_size(tcp.buffer_bytes)), false),
+            Span::styled(
+                if tcp.prunes_per_sec > 0.0 { format!(", {:.1} prunes/s", tcp.prunes_per_sec) } else { String::new() },
+                Style::default().fg(theme.warning),
+            ),
+        ]),
+    ];
+    let block = Block::default()
+        .title("TCP")
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(if retrans_alert { theme.error } else { theme.border }));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // The three short lines get a chart of their history to the right.
+    f.render_widget(Paragraph::new(lines), inner);
+    let chart_width = inner.width.saturating_sub(34) / 2;
+    if chart_width < 8 {
+        return;
+    }
+    let histories = [(&tcp.retrans_history, theme.warning), (&tcp.reset_history, theme.info), (&tcp.drop_history, theme.error)];
+    for (row, (history, color)) in histories.into_iter().enumerate().take(inner.height.saturating_sub(1) as usize) {
+        let tail: Vec<u64> = history.iter().skip(history.len().saturating_sub(chart_width as usize)).copied().collect();
+        let chart = Rect { x: inner.right() - chart_width, y: inner.y + row as u16, width: chart_width, height: 1 };
+        crate::ui::graphics::render_history(f, &tail, None, color, chart);
+    }
+}
+
+fn render_neighbors(f: &mut Frame, state: &AppState, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    use crate::types::NeighborChangeKind;
+    let changes = &state.dynamic_data.neighbor_changes;
+    let (table_area, changes_area) = if changes.is_empty() { (area, None) } else {
+        let (left, right) = create_two_column_layout(area, 55);
+        (left, Some(right))
+    };
+    let block = |title: String| Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border));
+
+    // Neighbors that keep changing stand out.
+    let neighbors = &state.dynamic_data.neighbors;
+    let rows = neighbors.iter().map(|neighbor| {
+        let changed = changes.iter().filter(|change| change.neighbor.address == neighbor.address).count();
+        let color = if changed >= 3 {
+            theme.warning
+        } else if neighbor.is_failed() {
+            theme.text_secondary
+        } else {
+            theme.text
+        };
+        Row::new(vec![
+            neighbor.address.to_string(),
+            neighbor.mac.clone().unwrap_or_else(|| "-".to_string()),
+            neighbor.state.clone(),
+            neighbor.interface.clone(),
+            changed.to_string(),
+        ]).style(Style::default().fg(color))
+    });
+    let table = Table::new(rows, [Constraint::Min(16), Constraint::Length(18), Constraint::Length(11), Constraint::Length(10), Constraint::Length(7)])
+        .header(Row::new(vec!["Address", "MAC", "State", "Interface", "Changes"])
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)))
+        .block(block(format!("Neighbors ({})", neighbors.len())));
+    f.render_widget(table, table_area);
+
+    if let Some(changes_area) = changes_area {
+        let rows = changes.iter().rev().map(|change| {
+            let (label, color) = match &change.kind {
+                NeighborChangeKind::New => ("new".to_string(), theme.info),
+                NeighborChangeKind::MacChanged(from) => (format!("moved from {}", from), theme.warning),
+                NeighborChangeKind::Failed => ("failed".to_string(), theme.text_secondary),
+                NeighborChangeKind::Recovered => ("answers".to_string(), theme.text),
+            };
+            let neighbor = &change.neighbor;
+            Row::new(vec![
+                change.at.format("%H:%M:%S").to_string(),
+                format!("{} {}", neighbor.address, neighbor.mac.as_deref().unwrap_or("")).trim_end().to_string(),
+                label,
+            ]).style(Style::default().fg(color))
+        });
+        let table = Table::new(rows, [Constraint::Length(9), Constraint::Min(20), Constraint::Min(8)])
+            .block(block(format!("Neighbor Changes ({})", changes.len())));
+        f.render_widget(table, changes_area);
+    }
+}
+
+fn render_network_config(f: &mut Frame, state: &AppState, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(theme.accent).add_modifier(Modifier::BOLD));
+    let plain = |text: String| Span::styled(text, Style::default().fg(theme.text));
+    let none = || Span::styled("none", Style::default().fg(theme.warning));
+
+    let lines = match &state.dynamic_data.network_config {
+        None => vec![Line::from(Span::styled("Checking...", Style::default().fg(theme.text_secondary)))],
+        Some(config) => {
+            let gateways = config.gateways.iter()
+                .map(|(iface, address)| format!("{} ({})", address, iface))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let mut lines = vec![
+                Line::from(vec![label("Gateway:    "), if gateways.is_empty() { none() } else { plain(gateways) }]),
+                Line::from(vec![
+                    label("DNS:        "),
+                    if config.dns_servers.is_empty() { none() } else { plain(config.dns_servers.join(", ")) },
+                ]),
+            ];
+            lines.push(match &config.reachability {
+                Some(reach) => Line::from(vec![label("Reachable:  "), match &reach.latency_ms {
+                    Ok(ms) => Span::styled(format!("yes, {} in {:.0} ms", reach.target, ms), Style::default().fg(theme.success)),
+                    Err(e) => Span::styled(format!("NO, {}: {}", reach.target, e), Style::default().fg(theme.error)),
+                }]),
+                None => Line::from(vec![label("Reachable:  "), Span::styled("not checked", Style::default().fg(theme.text_secondary))]),
+            });
+            if let Some(public_ip) = &config.public_ip {
+                lines.push(Line::from(vec![label("Public IP:  "), match public_ip {
+                    Ok(ip) => plain(ip.to_string()),
+                    Err(e) => Span::styled(format!("unavailable ({})", e), Style::default().fg(theme.warning)),
+                }]));
+            }
+            lines
+        }
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title("Network Configuration")
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border))
+    );
+    f.render_widget(paragraph, area);
+}
+
 fn render_containers_tab(f: &mut Frame, state: &AppState, area: Rect, theme: &crate::ui::colors::ColorScheme) {
     use ratatui::widgets::BorderType; 
     if let Some(err) = &state.dynamic_data.docker_error {
@@ -953,11 +2168,52 @@ fn render_containers_tab(f: &mut Frame, state: &AppState, area: Rect, theme: &cr
     }
     
     let containers = &state.dynamic_data.containers;
+    let show_details = state.show_container_details;
+    // Images this large, or containers restarted this often, are flagged.
+    const LARGE_IMAGE: u64 = 1024 * 1024 * 1024;
+    const CRASH_LOOP_RESTARTS: u32 = 3;
+    let now = chrono::Utc::now().timestamp();
+    let age = |at: Option<i64>| at.map_or("-".to_string(), |at| format_duration(now.saturating_sub(at).max(0) as u64));
+    // Usage of a limited container is coloured against its limit.
+    let thresholds = &state.alert_thresholds;
+    let limit_cell = |text: &str, percent: Option<f64>, levels: &crate::types::AlertLevels| {
+        let cell = Cell::from(text.to_string());
+        match percent.map(|percent| percent as f32) {
+            Some(percent) if percent >= levels.critical => cell.style(Style::default().fg(theme.error).add_modifier(Modifier::BOLD)),
+            Some(percent) if percent >= levels.warn => cell.style(Style::default().fg(theme.warning)),
+            _ => cell,
+        }
+    };
+    let column_width = |width: fn(&crate::types::ContainerInfo) -> usize, least: usize| {
+        containers.iter().map(width).max().unwrap_or(0).max(least) as u16
+    };
     
-    let headers = vec![
+    let mut headers = vec![
         "ID", "Name", "Image", "Status", "CPU", "Memory", 
         "Net ↓/s", "Net ↑/s", "Disk R/s", "Disk W/s", "Ports"
     ];
+    let mut widths = vec![
+        Constraint::Length(12),  // ID
+        Constraint::Min(15),     // Name
+        Constraint::Length(25),  // Image
+        Constraint::Length(12),  // Status
+        Constraint::Length(column_width(|c| c.cpu.width(), 8)),   // CPU
+        Constraint::Length(column_width(|c| c.mem.width(), 10)),  // Memory
+        Constraint::Length(10),  // Net Down
+        Constraint::Length(10),  // Net Up
+        Constraint::Length(10),  // Disk Read
+        Constraint::Length(10),  // Disk Write
+        Constraint::Min(15),     // Ports
+    ];
+    if show_details {
+        headers.extend(["Image Size", "Created", "Uptime", "Restarts"]);
+        widths.extend([
+            Constraint::Length(10),  // Image Size
+            Constraint::Length(11),  // Created
+            Constraint::Length(11),  // Uptime
+            Constraint::Length(8),   // Restarts
+        ]);
+    }
     
     let rows = containers.iter().map(|c| {
         let status_color = if c.status.to_lowercase().contains("up") {
@@ -968,41 +2224,42 @@ fn render_containers_tab(f: &mut Frame, state: &AppState, area: Rect, theme: &cr
             theme.warning
         };
         
-        Row::new(vec![
+        let mut cells: Vec<Cell> = vec![
             c.id.clone(),
             truncate_string(&c.name, 20),
             truncate_string(&c.image, 25),
             c.status.clone(),
-            c.cpu.clone(),
-            c.mem.clone(),
-            c.net_down.clone(),
-            c.net_up.clone(),
-            c.disk_r.clone(),
-            c.disk_w.clone(),
-            truncate_string(&c.ports, 20),
-        ]).style(Style::default().fg(status_color))
-    });
-    
-    let table = Table::new(
-        rows,
-        [
-            Constraint::Length(12),  // ID
-            Constraint::Min(15),     // Name
-            Constraint::Length(25),  // Image
-            Constraint::Length(12),  // Status
-            Constraint::Length(8),   // CPU
-            Constraint::Length(10),  // Memory
-            Constraint::Length(10),  // Net Down
-            Constraint::Length(10),  // Net Up
-            Constraint::Length(10),  // Disk Read
-            Constraint::Length(10),  // Disk Write
-            Constraint::Min(15),     // Ports
-        ]
-    )
+        ].into_iter().map(Cell::from).collect();
+        cells.push(limit_cell(&c.cpu, c.cpu_of_limit(), &thresholds.cpu));
+        cells.push(limit_cell(&c.mem, c.mem_of_limit(), &thresholds.memory));
+        cells.extend([
+            c.net_down.clone(),
+            c.net_up.clone(),
+            c.disk_r.clone(),
+            c.disk_w.clone(),
+            truncate_string(&c.ports, 20),
+        ].into_iter().map(Cell::from));
+        if show_details {
+            let size = Cell::from(c.image_size.map_or("-".to_string(), format_size));
+            cells.push(if c.image_size.is_some_and(|size| size >= LARGE_IMAGE) { size.style(Style::default().fg(theme.warning)) } else { size });
+            cells.push(Cell::from(age(c.created)));
+            cells.push(Cell::from(age(c.started)));
+            let restarts = Cell::from(c.restart_count.to_string());
+            cells.push(match c.restart_count {
+                0 => restarts,
+                count if count >= CRASH_LOOP_RESTARTS => restarts.style(Style::default().fg(theme.error).add_modifier(Modifier::BOLD)),
+                _ => restarts.style(Style::default().fg(theme.warning)),
+            });
+        }
+        Row::new(cells).style(Style::default().fg(status_color))
+    });
+    
+    let table = Table::new(rows, widths)
     .header(
         Row::new(headers)
             .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
     )
+    .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD))
     .block(
         Block::default()
             .title(format!("Containers ({} running)", containers.len()))
@@ -1011,7 +2268,8 @@ fn render_containers_tab(f: &mut Frame, state: &AppState, area: Rect, theme: &cr
             .border_style(Style::default().fg(theme.border))
     );
     
-    f.render_widget(table, area);
+    let container_state = state.container_table_state.clone();
+    f.render_stateful_widget(table, area, &mut container_state.clone());
 }
 
 fn render_gpu_tab(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode: bool, _translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
@@ -1047,7 +2305,31 @@ fn render_gpu_tab(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode: boo
             f.render_widget(message, inner_area);
         }
         Ok(gpus) => {
-            render_gpu_details(f, gpus, inner_area, theme);
+            let overview_height = (gpus.len() as u16 + 3).min(inner_area.height / 3);
+            let processes_height = (state.dynamic_data.gpu_processes.len() as u16 + 3).clamp(4, 10);
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(overview_height),
+                    Constraint::Min(0),
+                    Constraint::Length(processes_height),
+                ])
+                .split(inner_area);
+            
+            render_gpu_history_overview(f, gpus, &state.dynamic_data.global_usage.gpu_history, layout[0], theme);
+            
+            let selected = state.selected_gpu.min(gpus.len() - 1);
+            if gpus.len() > 1 {
+                let columns = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Length(34), Constraint::Min(0)])
+                    .split(layout[1]);
+                render_gpu_list(f, gpus, selected, columns[0], theme);
+                render_single_gpu(f, &gpus[selected], columns[1], selected, theme);
+            } else {
+                render_single_gpu(f, &gpus[0], layout[1], 0, theme);
+            }
+            render_gpu_process_table(f, state, layout[2], theme);
         }
         Err(e) => {
             let message = Paragraph::new(format!("GPU Error: {}", e))
@@ -1058,37 +2340,154 @@ fn render_gpu_tab(f: &mut Frame, state: &AppState, area: Rect, is_safe_mode: boo
     }
 }
 
-fn render_gpu_details(f: &mut Frame, gpus: &[crate::types::GpuInfo], area: Rect, theme: &crate::ui::colors::ColorScheme) {
-    let num_gpus = gpus.len();
-    if num_gpus == 0 {
+fn render_gpu_process_table(f: &mut Frame, state: &AppState, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let processes = &state.dynamic_data.gpu_processes;
+    let block = Block::default()
+        .title(format!("GPU Processes ({})", processes.len()))
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border));
+    
+    if processes.is_empty() {
+        let paragraph = Paragraph::new("No processes are using the GPU (or access is restricted)")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.text_secondary))
+            .block(block);
+        f.render_widget(paragraph, area);
         return;
     }
     
-    let constraints: Vec<Constraint> = (0..num_gpus)
-        .map(|_| Constraint::Ratio(1, num_gpus as u32))
-        .collect();
+    let headers = ["PID", "Name", "GPU", "Type", "VRAM"];
+    let rows = processes.iter().map(|p| {
+        Row::new(vec![
+            p.pid.to_string(),
+            truncate_string(&p.name, 24),
+            p.gpu_index.map_or("?".to_string(), |i| i.to_string()),
+            p.kind.clone(),
+            format_size(p.memory_used),
+        ]).style(Style::default().fg(theme.text))
+    });
+    
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),   // PID
+            Constraint::Min(15),     // Name
+            Constraint::Length(5),   // GPU
+            Constraint::Length(10),  // Type
+            Constraint::Length(12),  // VRAM
+        ]
+    )
+    .header(
+        Row::new(headers)
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+    )
+    .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD))
+    .block(block);
+    
+    let gpu_process_state = state.gpu_process_table_state.clone();
+    f.render_stateful_widget(table, area, &mut gpu_process_state.clone());
+}
+
+fn render_gpu_history_overview(f: &mut Frame, gpus: &[crate::types::GpuInfo], peak_history: &std::collections::VecDeque<u32>, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let peak_now = peak_history.back().cloned().unwrap_or(0);
+    let block = Block::default()
+        .title(format!("Activity History (peak {}%)", peak_now))
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border));
+    
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
     
-    let gpu_layout = Layout::default()
+    if inner_area.height == 0 {
+        return;
+    }
+    
+    let row_constraints: Vec<Constraint> = std::iter::once(Constraint::Length(1))
+        .chain(gpus.iter().map(|_| Constraint::Length(1)))
+        .collect();
+    let rows = Layout::default()
         .direction(Direction::Vertical)
-        .constraints(constraints)
-        .split(area);
+        .constraints(row_constraints)
+        .split(inner_area);
+    
+    let peak_data: Vec<u64> = peak_history.iter().map(|&v| v as u64).collect();
+    render_history_row(f, "All (peak)".to_string(), &peak_data, None, rows[0], theme);
     
     for (i, gpu) in gpus.iter().enumerate() {
-        if i >= gpu_layout.len() {
-            continue;
-        }
-        
-        render_single_gpu(f, gpu, gpu_layout[i], i, theme);
+        let row = match rows.get(i + 1) {
+            Some(row) => *row,
+            None => break,
+        };
+        let util: Vec<u64> = gpu.utilization_history.iter().map(|&v| v as u64).collect();
+        let vram: Vec<u64> = gpu.memory_history.iter().map(|&v| v as u64).collect();
+        render_history_row(f, format!("GPU {}", i), &util, Some(&vram), row, theme);
     }
 }
 
+fn render_history_row(f: &mut Frame, label: String, util: &[u64], vram: Option<&[u64]>, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(12),
+            Constraint::Percentage(50),
+            Constraint::Percentage(50),
+        ])
+        .split(area);
+    
+    f.render_widget(Paragraph::new(label).style(Style::default().fg(theme.accent)), columns[0]);
+    
+    let util_color = get_usage_color(util.last().cloned().unwrap_or(0) as f32, theme);
+    graphics::render_history(f, &util[util.len().saturating_sub(columns[1].width as usize)..], Some(100), util_color, columns[1]);
+    
+    if let Some(vram) = vram {
+        graphics::render_history(f, &vram[vram.len().saturating_sub(columns[2].width as usize)..], Some(100), theme.accent, columns[2]);
+    }
+}
+
+fn render_gpu_list(f: &mut Frame, gpus: &[crate::types::GpuInfo], selected: usize, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let rows = gpus.iter().enumerate().map(|(i, gpu)| {
+        Row::new(vec![
+            i.to_string(),
+            truncate_string(&gpu.name, 18),
+            gpu.utilization.map_or("N/A".to_string(), |u| format!("{}%", u)),
+        ]).style(Style::default().fg(theme.text))
+    });
+    
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(3),  // Index
+            Constraint::Min(10),    // Name
+            Constraint::Length(5),  // Util
+        ]
+    )
+    .header(
+        Row::new(vec!["#", "Device", "Util"])
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+    )
+    .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD))
+    .block(
+        Block::default()
+            .title(format!("GPUs ({})", gpus.len()))
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border))
+    );
+    
+    let mut table_state = ratatui::widgets::TableState::default();
+    table_state.select(Some(selected));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
 fn render_single_gpu(f: &mut Frame, gpu: &crate::types::GpuInfo, area: Rect, index: usize, theme: &crate::ui::colors::ColorScheme) {
     let title = format!(
-        "GPU {} - {} ({}) - {}°C",
+        "GPU {} - {} ({}) - {}",
         index,
         truncate_string(&gpu.name, 25),
         gpu.brand,
-        gpu.temperature
+        gpu.temperature.map(|t| format!("{}°C", t)).unwrap_or_else(|| "N/A".to_string())
     );
     
     let block = Block::default()
@@ -1104,19 +2503,38 @@ fn render_single_gpu(f: &mut Frame, gpu: &crate::types::GpuInfo, area: Rect, ind
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(1),  // Gauge
-            Constraint::Percentage(40), // Utilization Chart
-            Constraint::Percentage(40), // Memory Chart
+            Constraint::Length(1),  // Temperature Sparkline
+            Constraint::Percentage(55), // History Charts
             Constraint::Min(3),     // Details
         ])
         .split(inner_area);
     
-    let util_color = get_usage_color(gpu.utilization as f32);
+    let charts = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(layout[2]);
+    
+    let utilization = gpu.utilization.unwrap_or(0).min(100);
+    let util_color = get_usage_color(utilization as f32, theme);
     let util_gauge = Gauge::default()
-        .label(format!("Utilization: {}%", gpu.utilization))
+        .label(match gpu.utilization {
+            Some(u) => format!("Utilization: {}%", u),
+            None => "Utilization: N/A".to_string(),
+        })
         .gauge_style(Style::default().fg(util_color))
-        .ratio(gpu.utilization as f64 / 100.0);
+        .ratio(utilization as f64 / 100.0);
     f.render_widget(util_gauge, layout[0]);
     
+    let temp_row = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(12), Constraint::Min(0)])
+        .split(layout[1]);
+    let temp_label = gpu.temperature.map_or("Temp N/A".to_string(), |t| format!("Temp {}°C", t));
+    f.render_widget(Paragraph::new(temp_label).style(Style::default().fg(theme.accent)), temp_row[0]);
+    
+    let temp_data: Vec<u64> = gpu.temperature_history.iter().map(|&t| t as u64).collect();
+    graphics::render_history(f, &temp_data[temp_data.len().saturating_sub(temp_row[1].width as usize)..], Some(100), get_usage_color(gpu.temperature.unwrap_or(0) as f32, theme), temp_row[1]);
+    
     let history_len = gpu.utilization_history.len();
     let data: Vec<(f64, f64)> = gpu.utilization_history
         .iter()
@@ -1140,7 +2558,7 @@ fn render_single_gpu(f: &mut Frame, gpu: &crate::types::GpuInfo, area: Rect, ind
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .border_style(Style::default().fg(theme.border))
         );
-    f.render_widget(chart, layout[1]);
+    f.render_widget(chart, charts[0]);
 
     let mem_history_len = gpu.memory_history.len();
     let mem_data: Vec<(f64, f64)> = gpu.memory_history
@@ -1165,7 +2583,7 @@ fn render_single_gpu(f: &mut Frame, gpu: &crate::types::GpuInfo, area: Rect, ind
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .border_style(Style::default().fg(theme.border))
         );
-    f.render_widget(mem_chart, layout[2]);
+    f.render_widget(mem_chart, charts[1]);
     
     let mem_percent = if gpu.memory_total > 0 {
         (gpu.memory_used as f64 / gpu.memory_total as f64 * 100.0) as f32
@@ -1173,63 +2591,218 @@ fn render_single_gpu(f: &mut Frame, gpu: &crate::types::GpuInfo, area: Rect, ind
         0.0
     };
     
-    let mut details = vec![
-        Line::from(vec![
-            Span::styled("Memory: ", Style::default().fg(theme.accent)),
-            Span::raw(format!("{} / {} ({:.1}%)",
-                format_size(gpu.memory_used),
-                format_size(gpu.memory_total),
-                mem_percent
-            ))
-        ]),
-        Line::from(vec![
-            Span::styled("Power: ", Style::default().fg(theme.accent)),
-            Span::raw(format!("{:.2} W", gpu.power_usage as f64 / 1000.0))
-        ]),
-        Line::from(vec![
-            Span::styled("Graphics Clock: ", Style::default().fg(theme.accent)),
-            Span::raw(format_frequency(gpu.graphics_clock as u64))
-        ]),
-        Line::from(vec![
-            Span::styled("Memory Clock: ", Style::default().fg(theme.accent)),
-            Span::raw(format_frequency(gpu.memory_clock as u64))
-        ]),
+    let na = || "N/A".to_string();
+    let clock = |c: Option<u32>| c.map(|c| format_frequency(c as u64)).unwrap_or_else(na);
+    let detail = |label: &str, value: String| Line::from(vec![
+        Span::styled(format!("{}: ", label), Style::default().fg(theme.accent)),
+        Span::raw(value),
+    ]);
+    
+    let mut left = vec![
+        detail("Memory", format!("{} / {} ({:.1}%)",
+            format_size(gpu.memory_used),
+            format_size(gpu.memory_total),
+            mem_percent
+        )),
+        detail("Power", gpu.power_usage
+            .map(|p| format!("{:.2} W", p as f64 / 1000.0))
+            .unwrap_or_else(na)),
+        detail("Graphics Clock", clock(gpu.graphics_clock)),
+        detail("Memory Clock", clock(gpu.memory_clock)),
     ];
+    
+    if gpu.sm_clock.is_some() {
+        left.push(detail("SM Clock", clock(gpu.sm_clock)));
+    }
+    if gpu.video_clock.is_some() {
+        left.push(detail("Video Clock", clock(gpu.video_clock)));
+    }
+    if gpu.max_graphics_clock.is_some() {
+        left.push(detail("Max Graphics Clock", clock(gpu.max_graphics_clock)));
+    }
 
     if let Some(temp) = gpu.memory_temperature {
-        details.push(Line::from(vec![
-            Span::styled("Memory Temp: ", Style::default().fg(theme.accent)),
-            Span::raw(format!("{}°C", temp))
-        ]));
+        left.push(detail("Memory Temp", format!("{}°C", temp)));
     }
 
     if let Some(fan) = gpu.fan_speed {
-        details.push(Line::from(vec![
-            Span::styled("Fan Speed: ", Style::default().fg(theme.accent)),
-            Span::raw(format!("{}%", fan))
-        ]));
+        left.push(detail("Fan Speed", format!("{}%", fan)));
+    }
+    
+    let mut right = vec![
+        detail("Driver", gpu.driver_version.clone()),
+        detail("Bus ID", gpu.pci_bus_id.clone().unwrap_or_else(na)),
+    ];
+    
+    let link = |gen: Option<u32>, width: Option<u32>| match (gen, width) {
+        (Some(gen), Some(width)) => Some(format!("Gen {} x{}", gen, width)),
+        (Some(gen), None) => Some(format!("Gen {}", gen)),
+        (None, Some(width)) => Some(format!("x{}", width)),
+        (None, None) => None,
+    };
+    let link_degraded = matches!((gpu.pci_link_gen, gpu.pci_link_gen_max), (Some(cur), Some(max)) if cur < max)
+        || matches!((gpu.pci_link_width, gpu.pci_link_width_max), (Some(cur), Some(max)) if cur < max);
+    let mut link_text = link(gpu.pci_link_gen, gpu.pci_link_width).unwrap_or_else(na);
+    if let Some(max) = link(gpu.pci_link_gen_max, gpu.pci_link_width_max) {
+        link_text = format!("{} (max {})", link_text, max);
+    }
+    right.push(Line::from(vec![
+        Span::styled("PCIe: ", Style::default().fg(theme.accent)),
+        Span::styled(link_text, if link_degraded {
+            Style::default().fg(theme.warning)
+        } else {
+            Style::default().fg(theme.text)
+        }),
+    ]));
+    
+    if gpu.pcie_rx_bytes.is_some() || gpu.pcie_tx_bytes.is_some() {
+        right.push(detail("PCIe RX/TX", format!("{} / {}{}",
+            gpu.pcie_rx_bytes.map_or_else(na, format_rate),
+            gpu.pcie_tx_bytes.map_or_else(na, format_rate),
+            gpu.pcie_sample_age.map_or_else(String::new, |age| format!(" ({} ago)", crate::utils::format_duration(age)))
+        )));
+    }
+    
+    if gpu.ecc_errors_corrected.is_some() || gpu.ecc_errors_uncorrected.is_some() {
+        let mut line = detail("ECC Errors", format!("{} corrected, {} uncorrected",
+            gpu.ecc_errors_corrected.map_or_else(na, |e| e.to_string()),
+            gpu.ecc_errors_uncorrected.map_or_else(na, |e| e.to_string())
+        ));
+        if gpu.ecc_growth > 0 {
+            line.spans.push(Span::styled(format!(" (+{} since start)", gpu.ecc_growth), Style::default().fg(theme.warning)));
+        }
+        right.push(line);
     }
 
-    if let (Some(gen), Some(width)) = (gpu.pci_link_gen, gpu.pci_link_width) {
-        details.push(Line::from(vec![
-            Span::styled("PCIe: ", Style::default().fg(theme.accent)),
-            Span::raw(format!("Gen {} x{}", gen, width))
-        ]));
+    if let Some(repair) = &gpu.memory_repair {
+        let status = |text: &str, style: Style, on: bool| on.then(|| Span::styled(format!(", {}", text), style));
+        let warning = Style::default().fg(theme.warning);
+        let error = Style::default().fg(theme.error).add_modifier(Modifier::BOLD);
+        if repair.retired_single_bit.is_some() || repair.retired_double_bit.is_some() {
+            let mut line = detail("Retired Pages", format!("{} single-bit, {} double-bit",
+                repair.retired_single_bit.map_or_else(na, |n| n.to_string()),
+                repair.retired_double_bit.map_or_else(na, |n| n.to_string())
+            ));
+            line.spans.extend(status("reset pending", warning, repair.retired_pending));
+            right.push(line);
+        }
+        if repair.remapped_correctable.is_some() || repair.remapped_uncorrectable.is_some() {
+            let mut line = detail("Remapped Rows", format!("{} correctable, {} uncorrectable",
+                repair.remapped_correctable.map_or_else(na, |n| n.to_string()),
+                repair.remapped_uncorrectable.map_or_else(na, |n| n.to_string())
+            ));
+            line.spans.extend(status("reset pending", warning, repair.remapped_pending));
+            line.spans.extend(status("remap FAILED", error, repair.remap_failed));
+            right.push(line);
+        }
     }
     
-    let details_paragraph = Paragraph::new(details).style(Style::default().fg(theme.text));
-    f.render_widget(details_paragraph, layout[3]);
+    let throttle_style = if gpu.throttle_reasons.iter().any(|r| r != "GPU idle") {
+        Style::default().fg(theme.warning)
+    } else {
+        Style::default().fg(theme.text)
+    };
+    right.push(Line::from(vec![
+        Span::styled("Throttle: ", Style::default().fg(theme.accent)),
+        Span::styled(
+            if gpu.throttle_reasons.is_empty() { "None".to_string() } else { gpu.throttle_reasons.join(", ") },
+            throttle_style
+        ),
+    ]));
+    
+    let detail_columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(layout[3]);
+    
+    f.render_widget(Paragraph::new(left).style(Style::default().fg(theme.text)), detail_columns[0]);
+    f.render_widget(
+        Paragraph::new(right).style(Style::default().fg(theme.text)).wrap(ratatui::widgets::Wrap { trim: true }),
+        detail_columns[1]
+    );
+}
+
+fn render_zombies(f: &mut Frame, state: &AppState, zombies: &[(&crate::types::ProcessInfo, Option<&crate::types::ProcessInfo>)], area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let rows = zombies.iter().map(|(zombie, parent)| {
+        Row::new(vec![
+            zombie.pid.clone(),
+            zombie.name.clone(),
+            zombie.parent.clone().unwrap_or_else(|| "-".to_string()),
+            parent.map_or("?".to_string(), |p| p.name.clone()),
+            parent.map_or("?".to_string(), |p| p.user.clone()),
+        ]).style(Style::default().fg(theme.error))
+    });
+    
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Min(16),
+            Constraint::Length(11),
+            Constraint::Min(16),
+            Constraint::Length(12),
+        ]
+    )
+    .header(
+        Row::new(vec!["PID", "Zombie", "Parent PID", "Parent (must reap)", "Parent User"])
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+    )
+    .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD))
+    .block(
+        Block::default()
+            .title(format!("Zombies ({})", zombies.len()))
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.error))
+    );
+    
+    f.render_stateful_widget(table, area, &mut state.zombie_table_state.clone());
+}
+
+// Sync state and offset, in the alert colors when they would raise one.
+fn clock_rows(clock: &crate::types::ClockStatus, thresholds: &crate::types::AlertThresholds, theme: &crate::ui::colors::ColorScheme) -> Vec<Row<'static>> {
+    let alerting = thresholds.clock_offset_ms > 0;
+    let (sync, sync_color) = match &clock.service {
+        None => ("no sync service running".to_string(), theme.warning),
+        Some(service) if clock.synchronized => (format!("synchronized ({})", service), theme.success),
+        Some(service) => (format!("NOT synchronized ({})", service), if alerting { theme.error } else { theme.warning }),
+    };
+    let mut rows = vec![Row::new(vec!["Clock Sync".to_string(), sync]).style(Style::default().fg(sync_color))];
+    if let Some(offset_ms) = clock.offset_ms {
+        let drifting = alerting && offset_ms.abs() >= thresholds.clock_offset_ms as f64;
+        let mut text = format!("{:+.3} ms", offset_ms);
+        if !clock.detail.is_empty() {
+            text.push_str(&format!(" from {}", clock.detail));
+        }
+        rows.push(Row::new(vec!["Clock Offset".to_string(), text])
+            .style(Style::default().fg(if drifting { theme.error } else { theme.text })));
+    }
+    rows
 }
 
 fn render_system_info_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    let zombies = crate::utils::zombies_with_parents(&state.dynamic_data.processes);
+    // The zombie list only takes room while there are zombies.
+    let zombie_height = if zombies.is_empty() { 0 } else { (zombies.len() as u16 + 3).min(10) };
+    let plugin_rows = plugin_rows(&state.plugins, theme);
+    let plugin_height = if plugin_rows.is_empty() { 0 } else { (plugin_rows.len() as u16 + 3).min(12) };
     let layout = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .constraints([
+            Constraint::Min(10),
+            Constraint::Length(3),
+            Constraint::Length(zombie_height),
+            Constraint::Length(plugin_height),
+            Constraint::Length(3),
+        ])
         .split(area);
     
-    let rows = state.system_info.iter().map(|(key, value)| {
+    let mut rows: Vec<Row> = state.system_info.iter().map(|(key, value)| {
         Row::new(vec![key.clone(), value.clone()]).style(Style::default().fg(theme.text))
-    });
+    }).collect();
+    if let Some(clock) = &state.dynamic_data.clock {
+        rows.extend(clock_rows(clock, &state.alert_thresholds, theme));
+    }
     
     let table = Table::new(
         rows,
@@ -1267,59 +2840,138 @@ fn render_system_info_tab(f: &mut Frame, state: &AppState, area: Rect, _translat
         );
     
     f.render_widget(stats, layout[1]);
-}
-
-fn render_footer(f: &mut Frame, state: &AppState, area: Rect, translator: &Translator) {
-    let usage = &state.dynamic_data.global_usage;
     
-    let mut alerts = Vec::new();
-    
-    if usage.cpu > 85.0 {
-        alerts.push(translator.t("alert.high_cpu"));
+    if !zombies.is_empty() {
+        render_zombies(f, state, &zombies, layout[2], theme);
     }
     
-    let mem_percent = if usage.mem_total > 0 {
-        (usage.mem_used as f64 / usage.mem_total as f64) * 100.0
-    } else {
-        0.0
-    };
-    
-    if mem_percent > 90.0 {
-        alerts.push(translator.t("alert.critical_memory"));
-    } else if mem_percent > 80.0 {
-        alerts.push(translator.t("alert.high_memory"));
+    if !plugin_rows.is_empty() {
+        let table = Table::new(
+            plugin_rows,
+            [Constraint::Length(18), Constraint::Min(20), Constraint::Length(16), Constraint::Length(16), Constraint::Length(8)]
+        )
+        .header(
+            Row::new(vec!["Plugin", "Metric", "Value", "Warn / Critical", "Updated"])
+                .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+        )
+        .block(
+            Block::default()
+                .title("Plugins")
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border))
+        );
+        f.render_widget(table, layout[3]);
     }
     
-    let full_disks = state.dynamic_data.disks.iter()
-        .filter(|d| d.total > 0 && (d.used as f64 / d.total as f64) > 0.95)
-        .count();
+    let usage = &state.dynamic_data.self_usage;
+    let self_text = format!(
+        "PID {} | CPU {:.2}% | Memory {} (virtual {}) | Threads {} | Open FDs {}",
+        std::process::id(),
+        usage.cpu,
+        format_size(usage.memory),
+        format_size(usage.virtual_memory),
+        usage.threads,
+        usage.open_fds.map_or("N/A".to_string(), |n| n.to_string()),
+    );
+    
+    let self_stats = Paragraph::new(self_text)
+        .alignment(Alignment::Left)
+        .style(Style::default().fg(theme.text))
+        .block(
+            Block::default()
+                .title("puls itself")
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border))
+        );
     
-    if full_disks > 0 {
-        alerts.push(translator.t("alert.disk_critical"));
+    f.render_widget(self_stats, layout[4]);
+}
+
+// A row per plugin metric and text line, then the plugin's last error.
+fn plugin_rows<'a>(reports: &'a crate::plugins::PluginReports, theme: &crate::ui::colors::ColorScheme) -> Vec<Row<'a>> {
+    use crate::plugins::MetricLevel;
+
+    let mut rows = Vec::new();
+    for report in &reports.plugins {
+        let updated = report.at.map(|at| at.format("%H:%M:%S").to_string()).unwrap_or_default();
+        let row = |metric: String, value: String, limits: String| {
+            Row::new(vec![report.title().to_string(), metric, value, limits, updated.clone()])
+        };
+        match &report.output {
+            Some(output) => {
+                for metric in &output.metrics {
+                    let color = match metric.level() {
+                        MetricLevel::Normal => theme.text,
+                        MetricLevel::Warn => theme.warning,
+                        MetricLevel::Critical => theme.error,
+                    };
+                    rows.push(row(metric.name.clone(), metric.value_text(), metric.limits_text()).style(Style::default().fg(color)));
+                }
+                for line in &output.text {
+                    rows.push(row(line.clone(), String::new(), String::new()).style(Style::default().fg(theme.text_secondary)));
+                }
+            }
+            None if report.error.is_none() => {
+                rows.push(row("Waiting for first report".to_string(), String::new(), String::new())
+                    .style(Style::default().fg(theme.text_secondary)));
+            }
+            None => {}
+        }
+        if let Some(error) = &report.error {
+            rows.push(row(error.clone(), String::new(), String::new()).style(Style::default().fg(theme.error)));
+        }
     }
+    rows
+}
+
+fn render_footer(f: &mut Frame, state: &AppState, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    let watchdog = state.watchdog_down.iter().map(|unit| crate::alerts::Alert::ServiceDown { unit: unit.clone() });
+    let alerts: Vec<String> = crate::alerts::evaluate(&state.dynamic_data, &state.alert_thresholds)
+        .into_iter()
+        .chain(watchdog)
+        .chain(state.plugins.alerts())
+        .map(|alert| alert.label(translator))
+        .collect();
+    
+    let status = state.status_log.footer_message();
     
-    let help_text = if state.paused {
+    let prefix = if !alerts.is_empty() {
+        Some(format!("{}: {}", translator.t("alert.title"), alerts.join(" | ")))
+    } else {
+        status.map(|status| format!("[{}] {}", status.time.format("%H:%M:%S"), status.text))
+    };
+    let room = (area.width as usize).saturating_sub(prefix.as_ref().map_or(0, |p| p.width() + 3));
+    let jump = state.type_ahead.as_ref().filter(|jump| !jump.expired());
+    let help_text = if let Some(jump) = jump {
+        let found = crate::utils::type_ahead_match(&state.dynamic_data.processes, &jump.text).is_some();
+        format!("Jump to: {}_{} | Enter: Details | Esc: Cancel", jump.text, if found || jump.text.is_empty() { "" } else { " (no match)" })
+    } else if let Some((at, back)) = state.samples.viewing() {
+        format!("[PAUSED] Sample from {} ({} back of {}) | ←→: Step | p: Resume live", at.format("%H:%M:%S"), back, state.samples.len() - 1)
+    } else if state.paused && state.samples.len() > 1 {
+        format!("{} | ←: Step back", translator.t("help.paused"))
+    } else if state.paused {
         translator.t("help.paused")
     } else {
-        match state.active_tab {
-            0 => "q: Quit | ↑↓: Select | k: Kill | p: Pause | t: Theme | /: Search | Tab/1-9: Navigate | Ctrl+g: Sort General".to_string(),
-            8 => "↑↓: Navigate | s: Start | x: Stop | r: Restart | +: Enable | _: Disable | l: Status".to_string(),
-            _ => translator.t("help.main"),
-        }
+        hints::fit(&hints::footer_hints(state), room, translator)
     };
     
-    let alert_text = if !alerts.is_empty() {
-        format!("{}: {} | {}", translator.t("alert.title"), alerts.join(" | "), help_text)
-    } else {
-        help_text
+    let alert_text = match prefix {
+        Some(prefix) if !help_text.is_empty() => format!("{} | {}", prefix, help_text),
+        Some(prefix) => prefix,
+        None => help_text,
     };
     
     let footer_style = if !alerts.is_empty() {
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        Style::default().fg(theme.error).add_modifier(Modifier::BOLD)
+    } else if let Some(status) = status {
+        let color = if status.level == crate::status::StatusLevel::Error { theme.error } else { theme.warning };
+        Style::default().fg(color)
     } else if state.paused {
-        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme.text_secondary)
     };
     
     let footer = Paragraph::new(alert_text)
@@ -1345,27 +2997,36 @@ fn render_services_tab(f: &mut Frame, state: &AppState, area: Rect, translator:
         return;
     }
     
-    let header_name = translator.t("header.name");
-    let header_status = translator.t("header.status");
-    let header_enabled = translator.t("header.enabled");
-    
     let headers = vec![
-        header_name.as_str(),
-        header_status.as_str(),
-        header_enabled.as_str(),
+        translator.t("header.name"),
+        translator.t("header.status"),
+        translator.t("header.enabled"),
+        translator.t("header.memory"),
+        translator.t("header.cpu_time"),
+        translator.t("header.tasks"),
     ];
+    // Same marker as the process table: the sort column shows its direction.
+    let ascending = (state.service_sort == crate::types::ServiceSortBy::Name) != state.service_sort_reversed;
+    let arrow = if ascending { "▲" } else { "▼" };
+    let header_cells = headers.into_iter().enumerate().map(|(i, header)| {
+        if i == state.service_sort.column() {
+            Cell::from(format!("{} {}", header, arrow)).style(Style::default().fg(theme.highlight).add_modifier(Modifier::UNDERLINED))
+        } else {
+            Cell::from(header)
+        }
+    });
     
     let rows = services.iter().enumerate().map(|(i, s)| {
         let enabled = if s.enabled { "✓" } else { "✗" };
-        let name_display = if state.has_sudo {
+        let name_display = if state.can_modify() {
             s.name.clone()
         } else {
             format!("{} [RO]", s.name)
         };
         
-        let style = if state.editing_service == Some(i) && state.has_sudo {
+        let style = if state.editing_service == Some(i) && state.can_modify() {
             Style::default().bg(theme.secondary).fg(theme.text)
-        } else if !state.has_sudo {
+        } else if !state.can_modify() {
             Style::default().fg(theme.text_secondary)
         } else {
             Style::default().fg(theme.text)
@@ -1375,6 +3036,9 @@ fn render_services_tab(f: &mut Frame, state: &AppState, area: Rect, translator:
             name_display,
             s.status.clone(),
             enabled.to_string(),
+            s.memory.map_or("-".to_string(), format_size),
+            s.cpu_time_usec.map_or("-".to_string(), |usec| format_duration(usec / 1_000_000)),
+            s.tasks.map_or("-".to_string(), |tasks| tasks.to_string()),
         ]).style(style)
     });
     
@@ -1384,23 +3048,26 @@ fn render_services_tab(f: &mut Frame, state: &AppState, area: Rect, translator:
             Constraint::Length(25),
             Constraint::Length(15),
             Constraint::Length(10),
+            Constraint::Length(12),
+            Constraint::Length(14),
+            Constraint::Length(8),
         ]
     )
     .header(
-        Row::new(headers)
+        Row::new(header_cells)
             .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
     )
     .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD))
     .block(
         Block::default()
-            .title(if state.has_sudo {
+            .title(if state.can_modify() {
                 translator.t("title.services")
             } else {
-                format!("{} (Read-Only)", translator.t("title.services"))
+                format!("{} ({})", translator.t("title.services"), translator.t("label.read_only"))
             })
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
-            .border_style(if state.has_sudo {
+            .border_style(if state.can_modify() {
                 Style::default().fg(theme.border)
             } else {
                 Style::default().fg(theme.text_secondary)
@@ -1550,7 +3217,7 @@ fn render_config_tab(f: &mut Frame, state: &AppState, area: Rect, translator: &T
     let configs = &state.config_items;
     
     if configs.is_empty() {
-        let paragraph = Paragraph::new("No configuration items available")
+        let paragraph = Paragraph::new(translator.t("config.empty"))
             .alignment(Alignment::Center)
             .style(Style::default().fg(theme.text_secondary))
             .block(Block::default()
@@ -1562,9 +3229,9 @@ fn render_config_tab(f: &mut Frame, state: &AppState, area: Rect, translator: &T
         return;
     }
     
-    let header_key = translator.t("config.grub_timeout");
-    let header_value = translator.t("info.load");
-    let header_desc = translator.t("header.message");
+    let header_key = translator.t("config.column.setting");
+    let header_value = translator.t("config.column.value");
+    let header_desc = translator.t("config.column.description");
     
     let headers = vec![
         header_key.as_str(),
@@ -1573,9 +3240,9 @@ fn render_config_tab(f: &mut Frame, state: &AppState, area: Rect, translator: &T
     ];
     
     let rows = configs.iter().enumerate().map(|(i, c)| {
-        let style = if state.editing_config == Some(i) && state.has_sudo {
+        let style = if state.editing_config == Some(i) && state.can_modify() {
             Style::default().bg(theme.secondary).fg(theme.text)
-        } else if !state.has_sudo {
+        } else if !state.can_modify() {
             Style::default().fg(theme.text_secondary)
         } else {
             Style::default().fg(theme.text)
@@ -1603,14 +3270,14 @@ fn render_config_tab(f: &mut Frame, state: &AppState, area: Rect, translator: &T
     .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD))
     .block(
         Block::default()
-            .title(if state.has_sudo {
+            .title(if state.can_modify() {
                 translator.t("title.config")
             } else {
-                format!("{} (Read-Only)", translator.t("title.config"))
+                format!("{} ({})", translator.t("title.config"), translator.t("label.read_only"))
             })
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
-            .border_style(if state.has_sudo {
+            .border_style(if state.can_modify() {
                 Style::default().fg(theme.border)
             } else {
                 Style::default().fg(theme.text_secondary)
@@ -1621,12 +3288,15 @@ fn render_config_tab(f: &mut Frame, state: &AppState, area: Rect, translator: &T
     f.render_stateful_widget(table, area, &mut config_state.clone());
 }
 
-fn render_memory_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+fn render_memory_tab(f: &mut Frame, state: &AppState, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    let usage = &state.dynamic_data.global_usage;
+    let breakdown_height = if usage.memory.is_some() { 4 } else { 0 };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(50), // RAM & Swap Gauges
-            Constraint::Percentage(50), // Details Table
+            Constraint::Percentage(40), // RAM & Swap Gauges
+            Constraint::Length(breakdown_height), // Stacked breakdown bar
+            Constraint::Min(0), // Details Table
         ])
         .split(area);
 
@@ -1638,15 +3308,13 @@ fn render_memory_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &
         ])
         .split(chunks[0]);
 
-    let usage = &state.dynamic_data.global_usage;
-    
     let mem_percent = if usage.mem_total > 0 {
         (usage.mem_used as f64 / usage.mem_total as f64) * 100.0
     } else { 0.0 };
     
     let mem_gauge = Gauge::default()
         .block(Block::default().title("RAM Usage").borders(Borders::ALL).border_type(BorderType::Rounded).border_style(Style::default().fg(theme.border)))
-        .gauge_style(Style::default().fg(get_usage_color(mem_percent as f32)))
+        .gauge_style(Style::default().fg(state.alert_thresholds.memory.color(mem_percent as f32, theme)))
         .percent(mem_percent as u16)
         .label(format!("{:.1}% ({} / {})", mem_percent, format_size(usage.mem_used), format_size(usage.mem_total)));
     f.render_widget(mem_gauge, gauge_chunks[0]);
@@ -1662,24 +3330,116 @@ fn render_memory_tab(f: &mut Frame, state: &AppState, area: Rect, _translator: &
         .label(format!("{:.1}% ({} / {})", swap_percent, format_size(usage.swap_used), format_size(usage.swap_total)));
     f.render_widget(swap_gauge, gauge_chunks[1]);
 
-    let total_mem_str = format_size(usage.mem_used + (usage.mem_total - usage.mem_used));
-    let used_mem_str = format_size(usage.mem_used);
-    let cached_mem_str = format_size(usage.mem_cached);
-    let free_mem_str = format_size(usage.mem_total.saturating_sub(usage.mem_used));
+    let rows = match &usage.memory {
+        Some(memory) => {
+            render_memory_breakdown(f, memory, chunks[1], theme);
+            vec![
+                Row::new(vec!["Total Memory".to_string(), format_size(memory.total)]),
+                Row::new(vec!["Available".to_string(), format_size(memory.available)]),
+                Row::new(vec!["Used by Applications".to_string(), format_size(memory.apps())]),
+                Row::new(vec!["Free".to_string(), format_size(memory.free)]),
+                Row::new(vec!["Buffers".to_string(), format_size(memory.buffers)]),
+                Row::new(vec!["Page Cache".to_string(), format_size(memory.cached)]),
+                Row::new(vec!["Shared (shmem)".to_string(), format_size(memory.shmem)]),
+                Row::new(vec!["Slab (reclaimable / unreclaimable)".to_string(),
+                    format!("{} / {}", format_size(memory.slab_reclaimable), format_size(memory.slab_unreclaimable))]),
+                Row::new(vec!["Dirty / Writeback".to_string(),
+                    format!("{} / {}", format_size(memory.dirty), format_size(memory.writeback))]),
+                Row::new(vec!["Swap Used / Cached".to_string(),
+                    format!("{} / {}", format_size(memory.swap_total.saturating_sub(memory.swap_free)), format_size(memory.swap_cached))]),
+            ]
+        }
+        None => vec![
+            Row::new(vec!["Total Memory".to_string(), format_size(usage.mem_total)]),
+            Row::new(vec!["Used Memory".to_string(), format_size(usage.mem_used)]),
+            Row::new(vec!["Cached / Buffers".to_string(), format_size(usage.mem_cached)]),
+            Row::new(vec!["Free / Available".to_string(), format_size(usage.mem_total.saturating_sub(usage.mem_used))]),
+        ],
+    };
 
     let headers = vec!["Metric", "Value"];
-    let rows = vec![
-        Row::new(vec!["Total Memory".to_string(), total_mem_str]), 
-        Row::new(vec!["Used Memory".to_string(), used_mem_str]),
-        Row::new(vec!["Cached / Buffers".to_string(), cached_mem_str]),
-        Row::new(vec!["Free / Available".to_string(), free_mem_str]),
-    ];
-    
+
     let table = Table::new(
         rows,
         [Constraint::Percentage(50), Constraint::Percentage(50)]
     ).header(Row::new(headers).style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)))
      .block(Block::default().title("Details").borders(Borders::ALL).border_type(BorderType::Rounded).border_style(Style::default().fg(theme.border)));
-     
-    f.render_widget(table, chunks[1]);
+
+    let (details_area, suspects_area) = create_two_column_layout(chunks[2], 50);
+    f.render_widget(table, details_area);
+    render_leak_suspects(f, state, suspects_area, translator, theme);
+}
+
+// Processes whose RSS has only grown over the whole leak window, fastest first.
+fn render_leak_suspects(f: &mut Frame, state: &AppState, area: Rect, translator: &Translator, theme: &crate::ui::colors::ColorScheme) {
+    let mut suspects: Vec<_> = state.dynamic_data.processes.iter().filter(|p| p.leak_suspect).collect();
+    suspects.sort_by_key(|p| std::cmp::Reverse(p.mem_growth));
+
+    let block = Block::default()
+        .title(format!("{} ({})", translator.t("title.leak_suspects"), suspects.len()))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border));
+
+    if suspects.is_empty() {
+        let paragraph = Paragraph::new("No process has grown steadily over the leak window")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(theme.text_secondary))
+            .block(block);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let rows = suspects.iter().map(|p| Row::new(vec![
+        p.pid.clone(),
+        truncate_string(&p.name, 20),
+        p.mem_display.clone(),
+        format_growth(p.mem_growth),
+    ]).style(Style::default().fg(theme.warning)));
+    let headers = vec![
+        translator.t("header.pid"),
+        translator.t("header.name"),
+        translator.t("header.memory"),
+        translator.t("header.mem_growth"),
+    ];
+    let table = Table::new(rows, [Constraint::Length(8), Constraint::Min(10), Constraint::Length(10), Constraint::Length(11)])
+        .header(Row::new(headers).style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)))
+        .block(block);
+    f.render_widget(table, area);
+}
+
+// One row of colored blocks, each segment sized by its share of total memory,
+// with a legend underneath.
+fn render_memory_breakdown(f: &mut Frame, memory: &crate::types::MemoryBreakdown, area: Rect, theme: &crate::ui::colors::ColorScheme) {
+    let block = Block::default()
+        .title("Breakdown")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    if inner.height == 0 || memory.total == 0 {
+        return;
+    }
+
+    let colors = [theme.error, theme.warning, theme.info, theme.primary, theme.text_secondary];
+    let segments = memory.segments();
+    let width = inner.width as u64;
+
+    let mut bar = Vec::new();
+    let mut legend = Vec::new();
+    let mut filled = 0;
+    let mut cumulative = 0;
+    for ((label, size), color) in segments.iter().zip(colors) {
+        // Rounding the running total keeps the segments filling the row exactly.
+        cumulative += size;
+        let end = (cumulative * width + memory.total / 2) / memory.total;
+        let cells = end.saturating_sub(filled) as usize;
+        filled = filled.max(end);
+        bar.push(Span::styled("█".repeat(cells), Style::default().fg(color)));
+        legend.push(Span::styled("■ ", Style::default().fg(color)));
+        legend.push(Span::styled(format!("{} {}  ", label, format_size(*size)), Style::default().fg(theme.text)));
+    }
+
+    f.render_widget(Paragraph::new(vec![Line::from(bar), Line::from(legend)]), inner);
 }
\ No newline at end of file
@@ -1,7 +1,759 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, BorderType, Clear, Paragraph, Row, Table},
+};
+
+use crate::open_files::FileHolder;
+use crate::port_lookup::PortOwner;
+use crate::types::{IoClass, IoPriority};
+use crate::ui::colors::ColorScheme;
+use crate::utils::truncate_string;
+use unicode_width::UnicodeWidthStr;
+
+const TOAST_TIMEOUT: Duration = Duration::from_secs(4);
+const MAX_TOASTS: usize = 4;
+
+// A modal yes/no question guarding a destructive action. While it is open it
+// takes every key press. For the riskiest actions the dialog can also ask the
+// user to type a name before Enter is accepted.
+#[derive(Clone, Debug)]
+pub struct ConfirmDialog<T> {
+    pub title: String,
+    pub message: String,
+    pub confirm_text: Option<String>,
+    pub input: String,
+    pub action: T,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DialogResponse {
+    Pending,
+    Confirm,
+    Cancel,
+}
+
+impl<T> ConfirmDialog<T> {
+    pub fn new(title: impl Into<String>, message: impl Into<String>, action: T) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            confirm_text: None,
+            input: String::new(),
+            action,
+        }
+    }
+
+    pub fn require_typing(mut self, text: impl Into<String>) -> Self {
+        self.confirm_text = Some(text.into());
+        self
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> DialogResponse {
+        match &self.confirm_text {
+            None => match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => DialogResponse::Confirm,
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Char('q') | KeyCode::Esc => DialogResponse::Cancel,
+                _ => DialogResponse::Pending,
+            },
+            Some(expected) => match key.code {
+                KeyCode::Enter if self.input == *expected => DialogResponse::Confirm,
+                KeyCode::Esc => DialogResponse::Cancel,
+                KeyCode::Char(c) => {
+                    self.input.push(c);
+                    DialogResponse::Pending
+                }
+                KeyCode::Backspace => {
+                    self.input.pop();
+                    DialogResponse::Pending
+                }
+                _ => DialogResponse::Pending,
+            },
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, theme: &ColorScheme) {
+        let mut lines: Vec<Line> = self.message.lines().map(Line::from).collect();
+        lines.push(Line::from(""));
+        let hint = match &self.confirm_text {
+            None => "y: Yes  |  n/Esc: Cancel".to_string(),
+            Some(expected) => {
+                lines.push(Line::from(format!("Type '{}' to confirm:", expected)));
+                let input_style = if self.input == *expected { theme.success } else { theme.warning };
+                lines.push(Line::from(Span::styled(format!("> {}_", self.input), Style::default().fg(input_style))));
+                lines.push(Line::from(""));
+                "Enter: Confirm  |  Esc: Cancel".to_string()
+            }
+        };
+        lines.push(Line::from(hint));
+
+        let area = f.size();
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let popup_area = Rect {
+            x: area.width / 4,
+            y: area.height.saturating_sub(height) / 2,
+            width: area.width / 2,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(format!("⚠ {}", self.title))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.warning));
+
+        let paragraph = Paragraph::new(lines)
+            .block(block)
+            .style(Style::default().fg(theme.text))
+            .alignment(Alignment::Center);
+
+        f.render_widget(paragraph, popup_area);
+    }
+}
+
+// Picks an I/O scheduling class and level for a process: ←→ change the
+// class, ↑↓ the level, Enter applies.
+#[derive(Clone, Debug)]
+pub struct IoPriorityPopup {
+    pub pid: sysinfo::Pid,
+    pub name: String,
+    pub priority: IoPriority,
+}
+
+impl IoPriorityPopup {
+    pub fn new(pid: sysinfo::Pid, name: impl Into<String>, current: Option<IoPriority>) -> Self {
+        // Processes without a class run as best-effort 4 unless reniced.
+        let priority = current
+            .filter(|p| p.class != IoClass::None)
+            .unwrap_or(IoPriority { class: IoClass::BestEffort, level: 4 });
+        Self { pid, name: name.into(), priority }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> DialogResponse {
+        let class_index = IoClass::ALL.iter().position(|c| *c == self.priority.class).unwrap_or(0);
+        let classes = IoClass::ALL.len();
+        match key.code {
+            KeyCode::Left => self.priority.class = IoClass::ALL[(class_index + classes - 1) % classes],
+            KeyCode::Right => self.priority.class = IoClass::ALL[(class_index + 1) % classes],
+            // Up raises the priority, which is a lower level number.
+            KeyCode::Up => self.priority.level = self.priority.level.saturating_sub(1),
+            KeyCode::Down => self.priority.level = (self.priority.level + 1).min(7),
+            KeyCode::Enter => return DialogResponse::Confirm,
+            KeyCode::Esc | KeyCode::Char('q') => return DialogResponse::Cancel,
+            _ => {}
+        }
+        DialogResponse::Pending
+    }
+
+    pub fn render(&self, f: &mut Frame, theme: &ColorScheme) {
+        let level = if self.priority.class.has_level() {
+            format!("◀ {} ▶  (0 = highest)", self.priority.level)
+        } else {
+            "-".to_string()
+        };
+        let mut lines = vec![
+            Line::from(format!("Process {} ({})", self.pid, self.name)),
+            Line::from(""),
+            Line::from(Span::styled(format!("Class: ◀ {} ▶", self.priority.class.label()), Style::default().fg(theme.highlight))),
+            Line::from(format!("Level: {}", level)),
+            Line::from(""),
+        ];
+        if self.priority.class == IoClass::Realtime {
+            lines.push(Line::from(Span::styled("Realtime I/O can starve the rest of the system.", Style::default().fg(theme.warning))));
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from("←→: Class  |  ↑↓: Level  |  Enter: Apply  |  Esc: Cancel"));
+
+        let area = f.size();
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let popup_area = Rect {
+            x: area.width / 4,
+            y: area.height.saturating_sub(height) / 2,
+            width: area.width / 2,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("I/O Priority")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border));
+
+        let paragraph = Paragraph::new(lines)
+            .block(block)
+            .style(Style::default().fg(theme.text))
+            .alignment(Alignment::Center);
+
+        f.render_widget(paragraph, popup_area);
+    }
+}
+
+// Picks a CPU cap for a process in steps of 5%: ↑↓ change it, Enter applies.
+#[derive(Clone, Debug)]
+pub struct CpuLimitPopup {
+    pub pid: sysinfo::Pid,
+    pub name: String,
+    pub percent: u32,
+}
+
+impl CpuLimitPopup {
+    pub fn new(pid: sysinfo::Pid, name: impl Into<String>, current: Option<u32>) -> Self {
+        Self { pid, name: name.into(), percent: current.unwrap_or(50) }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> DialogResponse {
+        match key.code {
+            KeyCode::Up | KeyCode::Right => self.percent = (self.percent + 5).min(95),
+            KeyCode::Down | KeyCode::Left => self.percent = self.percent.saturating_sub(5).max(5),
+            KeyCode::Enter => return DialogResponse::Confirm,
+            KeyCode::Esc | KeyCode::Char('q') => return DialogResponse::Cancel,
+            _ => {}
+        }
+        DialogResponse::Pending
+    }
+
+    pub fn render(&self, f: &mut Frame, theme: &ColorScheme) {
+        let lines = vec![
+            Line::from(format!("Process {} ({})", self.pid, self.name)),
+            Line::from(""),
+            Line::from(Span::styled(format!("Limit: ◀ {}% ▶  of one core", self.percent), Style::default().fg(theme.highlight))),
+            Line::from(""),
+            Line::from("↑↓: Change  |  Enter: Apply  |  Esc: Cancel"),
+        ];
+
+        let area = f.size();
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let popup_area = Rect {
+            x: area.width / 4,
+            y: area.height.saturating_sub(height) / 2,
+            width: area.width / 2,
+            height,
+        };
+
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("CPU Limit")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border));
+
+        let paragraph = Paragraph::new(lines)
+            .block(block)
+            .style(Style::default().fg(theme.text))
+            .alignment(Alignment::Center);
+
+        f.render_widget(paragraph, popup_area);
+    }
+}
+
+// What a key did in one of the lookup popups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LookupAction {
+    Pending,
+    Search,
+    // Show this process in the process table.
+    Jump(u32),
+    Cancel,
+}
+
+// Asks which processes hold a path open. Typing edits the path and Enter
+// searches; once results are listed, ↑↓ pick one and Enter jumps to it.
+#[derive(Clone, Debug, Default)]
+pub struct OpenFilesPopup {
+    pub input: String,
+    // The path the results are for, and what the search found.
+    pub results: Option<(String, Result<Vec<FileHolder>, String>)>,
+    pub selected: usize,
+    // Set from Enter until the results come back.
+    pub searching: bool,
+}
+
+impl OpenFilesPopup {
+    pub fn handle_key(&mut self, key: KeyEvent) -> LookupAction {
+        if self.searching && key.code == KeyCode::Enter {
+            return LookupAction::Pending;
+        }
+        let holders = match &self.results {
+            Some((path, Ok(holders))) if *path == self.input => holders.as_slice(),
+            _ => &[],
+        };
+        match key.code {
+            KeyCode::Esc => return LookupAction::Cancel,
+            KeyCode::Enter if !holders.is_empty() => {
+                return LookupAction::Jump(holders[self.selected.min(holders.len() - 1)].pid);
+            }
+            KeyCode::Enter if !self.input.trim().is_empty() => return LookupAction::Search,
+            KeyCode::Down if !holders.is_empty() => self.selected = (self.selected + 1) % holders.len(),
+            KeyCode::Up if !holders.is_empty() => {
+                self.selected = if self.selected == 0 { holders.len() - 1 } else { self.selected - 1 };
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            _ => {}
+        }
+        LookupAction::Pending
+    }
+
+    pub fn set_results(&mut self, path: String, results: Result<Vec<FileHolder>, String>) {
+        self.results = Some((path, results));
+        self.selected = 0;
+        self.searching = false;
+    }
+
+    pub fn render(&self, f: &mut Frame, theme: &ColorScheme) {
+        let area = f.size();
+        let width = (area.width * 3 / 4).max(40).min(area.width);
+        let height = (area.height * 3 / 4).max(10).min(area.height);
+        let popup_area = Rect {
+            x: (area.width - width) / 2,
+            y: (area.height - height) / 2,
+            width,
+            height,
+        };
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Open Files: Who Holds This Path?")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border));
+        let inner = block.inner(popup_area);
+        f.render_widget(block, popup_area);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let input = Paragraph::new(Span::styled(format!("Path: {}_", self.input), Style::default().fg(theme.highlight)));
+        f.render_widget(input, layout[0]);
+
+        let current = self.results.as_ref().filter(|(path, _)| *path == self.input);
+        let hint = match current {
+            None if self.searching => {
+                f.render_widget(Paragraph::new("Searching...")
+                    .style(Style::default().fg(theme.text_secondary)), layout[1]);
+                "Esc: Close"
+            }
+            None => {
+                f.render_widget(Paragraph::new("Enter a file, directory or mount point.")
+                    .style(Style::default().fg(theme.text_secondary)), layout[1]);
+                "Enter: Search  |  Esc: Close"
+            }
+            Some((_, Err(e))) => {
+                f.render_widget(Paragraph::new(e.as_str()).style(Style::default().fg(theme.error)), layout[1]);
+                "Enter: Search  |  Esc: Close"
+            }
+            Some((_, Ok(holders))) if holders.is_empty() => {
+                f.render_widget(Paragraph::new("No process has it open.")
+                    .style(Style::default().fg(theme.success)), layout[1]);
+                "Enter: Search  |  Esc: Close"
+            }
+            Some((_, Ok(holders))) => {
+                let rows = holders.iter().map(|holder| Row::new(vec![
+                    holder.pid.to_string(),
+                    holder.name.clone(),
+                    holder.how.clone(),
+                    holder.path.display().to_string(),
+                ]));
+                let table = Table::new(rows, [Constraint::Length(8), Constraint::Length(16), Constraint::Length(8), Constraint::Min(20)])
+                    .header(Row::new(vec!["PID", "Process", "As", "Path"])
+                        .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)))
+                    .style(Style::default().fg(theme.text))
+                    .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD));
+                let mut table_state = ratatui::widgets::TableState::default().with_selected(Some(self.selected));
+                f.render_stateful_widget(table, layout[1], &mut table_state);
+                "↑↓: Select  |  Enter: Show in process table  |  Esc: Close"
+            }
+        };
+        f.render_widget(Paragraph::new(hint).alignment(Alignment::Center), layout[2]);
+    }
+}
+
+// "Who owns :8080". Only digits are typed; Enter looks the port up, and on
+// a result with a process, shows that process.
+#[derive(Clone, Debug, Default)]
+pub struct PortLookupPopup {
+    pub input: String,
+    pub results: Option<(String, Vec<PortOwner>)>,
+    pub selected: usize,
+}
+
+impl PortLookupPopup {
+    pub fn handle_key(&mut self, key: KeyEvent) -> LookupAction {
+        let owners = match &self.results {
+            Some((port, owners)) if *port == self.input => owners.as_slice(),
+            _ => &[],
+        };
+        match key.code {
+            KeyCode::Esc => return LookupAction::Cancel,
+            KeyCode::Enter if !owners.is_empty() => {
+                let owner = &owners[self.selected.min(owners.len() - 1)];
+                if let Some((pid, _)) = owner.socket.as_ref().and_then(|socket| socket.process.as_ref()) {
+                    return LookupAction::Jump(*pid);
+                }
+            }
+            KeyCode::Enter if self.port().is_some() => return LookupAction::Search,
+            KeyCode::Down if !owners.is_empty() => self.selected = (self.selected + 1) % owners.len(),
+            KeyCode::Up if !owners.is_empty() => {
+                self.selected = if self.selected == 0 { owners.len() - 1 } else { self.selected - 1 };
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() && self.input.len() < 5 => self.input.push(c),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            _ => {}
+        }
+        LookupAction::Pending
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.input.parse().ok().filter(|&port| port > 0)
+    }
+
+    pub fn set_results(&mut self, owners: Vec<PortOwner>) {
+        self.results = Some((self.input.clone(), owners));
+        self.selected = 0;
+    }
+
+    pub fn render(&self, f: &mut Frame, theme: &ColorScheme) {
+        let area = f.size();
+        let width = (area.width * 3 / 4).max(40).min(area.width);
+        let height = (area.height / 2).max(10).min(area.height);
+        let popup_area = Rect {
+            x: (area.width - width) / 2,
+            y: (area.height - height) / 2,
+            width,
+            height,
+        };
+        f.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title("Port Lookup: Who Owns This Port?")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.border));
+        let inner = block.inner(popup_area);
+        f.render_widget(block, popup_area);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let input = Paragraph::new(Span::styled(format!("Port: :{}_", self.input), Style::default().fg(theme.highlight)));
+        f.render_widget(input, layout[0]);
+
+        let hint = match self.results.as_ref().filter(|(port, _)| *port == self.input) {
+            None => {
+                f.render_widget(Paragraph::new("Enter a TCP or UDP port number.")
+                    .style(Style::default().fg(theme.text_secondary)), layout[1]);
+                "Enter: Look up  |  Esc: Close"
+            }
+            Some((port, owners)) if owners.is_empty() => {
+                f.render_widget(Paragraph::new(format!("Nothing is listening on port {}.", port))
+                    .style(Style::default().fg(theme.text_secondary)), layout[1]);
+                "Enter: Look up  |  Esc: Close"
+            }
+            Some((_, owners)) => {
+                let rows = owners.iter().map(|owner| {
+                    let (socket, process) = match &owner.socket {
+                        Some(socket) => (
+                            format!("{} {}", socket.protocol, socket.address),
+                            socket.process.as_ref().map_or("?".to_string(), |(pid, name)| format!("{} ({})", name, pid)),
+                        ),
+                        None => ("forwarded".to_string(), "-".to_string()),
+                    };
+                    Row::new(vec![socket, process, owner.container.clone().unwrap_or_else(|| "-".to_string())])
+                });
+                let table = Table::new(rows, [Constraint::Length(26), Constraint::Min(20), Constraint::Length(24)])
+                    .header(Row::new(vec!["Socket", "Process", "Container"])
+                        .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)))
+                    .style(Style::default().fg(theme.text))
+                    .highlight_style(Style::default().bg(theme.border).fg(theme.highlight).add_modifier(Modifier::BOLD));
+                let mut table_state = ratatui::widgets::TableState::default().with_selected(Some(self.selected));
+                f.render_stateful_widget(table, layout[1], &mut table_state);
+                "↑↓: Select  |  Enter: Process details  |  Esc: Close"
+            }
+        };
+        f.render_widget(Paragraph::new(hint).alignment(Alignment::Center), layout[2]);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastKind {
+    Success,
+    Error,
+}
+
+#[derive(Clone, Debug)]
+pub struct Toast {
+    pub kind: ToastKind,
+    pub text: String,
+    pub created: Instant,
+}
+
+// Short-lived notifications for the results of user actions, stacked in the
+// bottom-right corner so several results in a row stay readable.
+#[derive(Clone, Debug, Default)]
+pub struct ToastQueue {
+    toasts: VecDeque<Toast>,
+}
+
+impl ToastQueue {
+    pub fn push(&mut self, kind: ToastKind, text: impl Into<String>) {
+        // Command output can span lines; the first one carries the message.
+        let text: String = text.into();
+        let text = text.lines().find(|l| !l.trim().is_empty()).unwrap_or("").trim().to_string();
+        self.toasts.push_back(Toast { kind, text, created: Instant::now() });
+        while self.toasts.len() > MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+    }
+
+    pub fn success(&mut self, text: impl Into<String>) {
+        self.push(ToastKind::Success, text);
+    }
+
+    pub fn error(&mut self, text: impl Into<String>) {
+        self.push(ToastKind::Error, text);
+    }
+
+    // Drops expired toasts; returns whether any were removed.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.toasts.len();
+        self.toasts.retain(|toast| now.duration_since(toast.created) < TOAST_TIMEOUT);
+        self.toasts.len() != before
+    }
+
+    // Newest toast at the bottom, older ones stacked above it.
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &ColorScheme) {
+        let mut bottom = area.bottom();
+        for toast in self.toasts.iter().rev() {
+            let max_width = (area.width / 3).max(20).min(area.width);
+            let text = truncate_string(&toast.text, max_width.saturating_sub(4) as usize);
+            let width = (text.width() as u16 + 4).min(max_width);
+            if bottom < area.top() + 3 {
+                break;
+            }
+            let toast_area = Rect {
+                x: area.right().saturating_sub(width + 1),
+                y: bottom - 3,
+                width,
+                height: 3,
+            };
+            bottom -= 3;
+
+            let color = match toast.kind {
+                ToastKind::Success => theme.success,
+                ToastKind::Error => theme.error,
+            };
+            f.render_widget(Clear, toast_area);
+            let paragraph = Paragraph::new(text)
+                .block(Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(color)))
+                .style(Style::default().fg(theme.text));
+            f.render_widget(paragraph, toast_area);
+        }
+    }
+}
+
+// Scale annotations for a history chart drawn without axes, so a sparkline
+// shows how large its peaks are: the highest, latest and lowest sample,
+// right-aligned in a column beside the chart. A single row only has room for
+// the range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisLabels {
+    max: String,
+    current: String,
+    min: String,
+}
+
+impl AxisLabels {
+    pub fn new(data: &[u64], format: impl Fn(u64) -> String) -> Self {
+        Self {
+            max: format(data.iter().copied().max().unwrap_or(0)),
+            current: format(data.last().copied().unwrap_or(0)),
+            min: format(data.iter().copied().min().unwrap_or(0)),
+        }
+    }
+
+    // One entry per row, top to bottom; rows in between are left blank.
+    pub fn lines(&self, height: u16) -> Vec<String> {
+        let height = height as usize;
+        match height {
+            0 => Vec::new(),
+            1 => vec![format!("{}-{}", self.min, self.max)],
+            _ => {
+                let mut lines = vec![String::new(); height];
+                lines[0] = self.max.clone();
+                if height > 2 {
+                    lines[height / 2] = self.current.clone();
+                }
+                lines[height - 1] = self.min.clone();
+                lines
+            }
+        }
+    }
+
+    // Splits `area` into the chart and the label column. The labels are left
+    // out when they would leave the chart narrower than `min_chart` columns.
+    pub fn split(&self, area: Rect, min_chart: u16) -> (Rect, Option<Rect>) {
+        let width = self.lines(area.height).iter().map(|line| line.width()).max().unwrap_or(0) as u16;
+        if width == 0 || area.width < min_chart + width + 1 {
+            return (area, None);
+        }
+        let chart = Rect { width: area.width - width - 1, ..area };
+        let labels = Rect { x: area.right() - width, width, ..area };
+        (chart, Some(labels))
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &ColorScheme) {
+        let lines: Vec<Line> = self.lines(area.height).into_iter().map(Line::from).collect();
+        let paragraph = Paragraph::new(lines)
+            .alignment(Alignment::Right)
+            .style(Style::default().fg(theme.text_secondary));
+        f.render_widget(paragraph, area);
+    }
+}
+
+// A one-line bar of percentages laid end to end, e.g. where a core's time
+// went. Boundaries are rounded on the running total so the segments never
+// add up to more than the width; what is left is drawn dim.
+pub fn stacked_bar(segments: &[(f32, Color)], width: usize, empty: Color) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut total = 0.0;
+    let mut drawn = 0;
+    for &(percent, color) in segments {
+        total += percent.max(0.0);
+        let end = ((total / 100.0 * width as f32).round() as usize).min(width);
+        if end > drawn {
+            spans.push(Span::styled("█".repeat(end - drawn), Style::default().fg(color)));
+            drawn = end;
+        }
+    }
+    if drawn < width {
+        spans.push(Span::styled("░".repeat(width - drawn), Style::default().fg(empty)));
+    }
+    Line::from(spans)
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_axis_labels() {
+        let labels = AxisLabels::new(&[300, 1200, 0, 500], |value| format!("{}B", value));
+        assert_eq!(labels.lines(1), vec!["0B-1200B"]);
+        assert_eq!(labels.lines(2), vec!["1200B", "0B"]);
+        assert_eq!(labels.lines(4), vec!["1200B", "", "500B", "0B"]);
+
+        let (chart, column) = labels.split(Rect::new(0, 0, 30, 1), 8);
+        assert_eq!((chart.width, column), (21, Some(Rect::new(22, 0, 8, 1))));
+        // Too narrow for both: the chart keeps the whole area.
+        assert_eq!(labels.split(Rect::new(0, 0, 12, 1), 8), (Rect::new(0, 0, 12, 1), None));
+    }
+
+    #[test]
+    fn test_stacked_bar() {
+        let widths = |line: Line| line.spans.iter().map(|span| span.content.chars().count()).collect::<Vec<_>>();
+        assert_eq!(widths(stacked_bar(&[(25.0, Color::Green), (0.4, Color::Red), (25.0, Color::Yellow)], 10, Color::Gray)), vec![3, 2, 5]);
+        assert_eq!(widths(stacked_bar(&[(80.0, Color::Green), (40.0, Color::Red)], 10, Color::Gray)), vec![8, 2]);
+        assert_eq!(widths(stacked_bar(&[], 4, Color::Gray)), vec![4]);
+    }
+
+    #[test]
+    fn test_yes_no_dialog() {
+        let mut dialog = ConfirmDialog::new("Kill Process", "Kill process 42?", 42);
+        assert_eq!(dialog.handle_key(key(KeyCode::Char('x'))), DialogResponse::Pending);
+        assert_eq!(dialog.handle_key(key(KeyCode::Char('y'))), DialogResponse::Confirm);
+        assert_eq!(dialog.handle_key(key(KeyCode::Esc)), DialogResponse::Cancel);
+    }
+
+    #[test]
+    fn test_typed_confirmation() {
+        let mut dialog = ConfirmDialog::new("Remove Container", "Remove web?", ()).require_typing("web");
+        // 'y' is just input here, and Enter needs the exact name.
+        assert_eq!(dialog.handle_key(key(KeyCode::Char('y'))), DialogResponse::Pending);
+        assert_eq!(dialog.handle_key(key(KeyCode::Enter)), DialogResponse::Pending);
+        dialog.handle_key(key(KeyCode::Backspace));
+        for c in "web".chars() {
+            dialog.handle_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(dialog.input, "web");
+        assert_eq!(dialog.handle_key(key(KeyCode::Enter)), DialogResponse::Confirm);
+        assert_eq!(dialog.handle_key(key(KeyCode::Esc)), DialogResponse::Cancel);
+    }
+
+    #[test]
+    fn test_io_priority_popup() {
+        let pid = sysinfo::Pid::from_u32(42);
+        let mut popup = IoPriorityPopup::new(pid, "rsync", Some(IoPriority { class: IoClass::None, level: 0 }));
+        assert_eq!(popup.priority, IoPriority { class: IoClass::BestEffort, level: 4 });
+        
+        popup.handle_key(key(KeyCode::Right));
+        assert_eq!(popup.priority.class, IoClass::Idle);
+        popup.handle_key(key(KeyCode::Right));
+        assert_eq!(popup.priority.class, IoClass::None);
+        popup.handle_key(key(KeyCode::Left));
+        popup.handle_key(key(KeyCode::Left));
+        assert_eq!(popup.priority.class, IoClass::BestEffort);
+        
+        for _ in 0..10 {
+            popup.handle_key(key(KeyCode::Down));
+        }
+        assert_eq!(popup.priority.level, 7);
+        assert_eq!(popup.handle_key(key(KeyCode::Enter)), DialogResponse::Confirm);
+        assert_eq!(popup.handle_key(key(KeyCode::Esc)), DialogResponse::Cancel);
+    }
+
+    #[test]
+    fn test_cpu_limit_popup() {
+        let mut popup = CpuLimitPopup::new(sysinfo::Pid::from_u32(42), "ffmpeg", None);
+        assert_eq!(popup.percent, 50);
+        popup.handle_key(key(KeyCode::Up));
+        assert_eq!(popup.percent, 55);
+        for _ in 0..20 {
+            popup.handle_key(key(KeyCode::Down));
+        }
+        assert_eq!(popup.percent, 5);
+        assert_eq!(popup.handle_key(key(KeyCode::Enter)), DialogResponse::Confirm);
+    }
+
     #[test]
-    fn test_placeholder() {
-        assert!(true);
+    fn test_toast_queue() {
+        let mut toasts = ToastQueue::default();
+        toasts.error("\nFailed to stop nginx.service: Access denied\nSee system logs.");
+        for i in 0..MAX_TOASTS {
+            toasts.success(format!("Service {} restarted", i));
+        }
+        // Oldest toasts make room for new ones.
+        assert_eq!(toasts.toasts.len(), MAX_TOASTS);
+        assert!(toasts.toasts.iter().all(|t| t.kind == ToastKind::Success));
+
+        let mut toasts = ToastQueue::default();
+        toasts.error("\nFailed to stop nginx.service: Access denied\nSee system logs.");
+        assert_eq!(toasts.toasts[0].text, "Failed to stop nginx.service: Access denied");
+        assert!(!toasts.expire(Instant::now()));
+        assert!(toasts.expire(Instant::now() + TOAST_TIMEOUT));
+        assert!(toasts.toasts.is_empty());
     }
-}
\ No newline at end of file
+}
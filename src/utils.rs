@@ -2,6 +2,8 @@
 
 use std::collections::VecDeque;
 use std::time::{SystemTime, UNIX_EPOCH};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 pub fn format_size(bytes: u64) -> String {
     const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
@@ -49,6 +51,15 @@ pub fn format_rate(bytes_per_sec: u64) -> String {
     }
 }
 
+// Signed memory change per minute, e.g. "+1.5 MiB" or "-512 B".
+pub fn format_growth(bytes_per_min: i64) -> String {
+    match bytes_per_min {
+        0 => "0 B".to_string(),
+        b if b > 0 => format!("+{}", format_size(b as u64)),
+        b => format!("-{}", format_size(b.unsigned_abs())),
+    }
+}
+
 pub fn format_frequency(hz: u64) -> String {
     let hz_value = hz * 1_000_000;
     
@@ -92,6 +103,17 @@ pub fn format_duration(seconds: u64) -> String {
     }
 }
 
+// One unit, for estimates that are only ever rough: "9d", "5h", "40m".
+pub fn format_rough_duration(seconds: u64) -> String {
+    if seconds >= 2 * 86400 {
+        format!("{}d", seconds / 86400)
+    } else if seconds >= 2 * 3600 {
+        format!("{}h", seconds / 3600)
+    } else {
+        format!("{}m", (seconds / 60).max(1))
+    }
+}
+
 pub fn format_percentage(value: f32) -> String {
     format!("{:.1}%", value)
 }
@@ -143,26 +165,140 @@ pub fn bytes_to_gb(bytes: u64) -> f64 {
     bytes as f64 / (1024.0 * 1024.0 * 1024.0)
 }
 
-pub fn get_usage_color(percentage: f32) -> ratatui::style::Color {
-    use ratatui::style::Color;
-    
+pub fn get_usage_color(percentage: f32, theme: &crate::ui::colors::ColorScheme) -> ratatui::style::Color {
     if percentage >= 90.0 {
-        Color::Red
+        theme.error
     } else if percentage >= 75.0 {
-        Color::Yellow
+        theme.warning
     } else if percentage >= 50.0 {
-        Color::Cyan
+        theme.info
+    } else {
+        theme.success
+    }
+}
+
+// Limits are in terminal columns, not bytes: CJK characters take two columns
+// and slicing by byte could split a multi-byte character. Whole grapheme
+// clusters are kept, so an accent or an emoji sequence is never cut in half,
+// and the result never runs past `max_width`, even below the ellipsis.
+pub fn truncate_string(s: &str, max_width: usize) -> String {
+    if s.width() <= max_width {
+        return s.to_string();
+    }
+
+    let ellipsis = if max_width >= 3 { "..." } else { "" };
+    let budget = max_width - ellipsis.len();
+    let mut width = 0;
+    let mut truncated = String::new();
+    for grapheme in s.graphemes(true) {
+        let grapheme_width = grapheme.width();
+        if width + grapheme_width > budget {
+            break;
+        }
+        width += grapheme_width;
+        truncated.push_str(grapheme);
+    }
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+// Truncates or pads `s` to exactly `width` columns, for text laid out in
+// columns without a table widget. format!("{:<10}") pads by characters.
+pub fn pad_to_width(s: &str, width: usize) -> String {
+    let mut padded = truncate_string(s, width);
+    let padding = width.saturating_sub(padded.width());
+    padded.extend(std::iter::repeat_n(' ', padding));
+    padded
+}
+
+// Wraps `words` into lines of at most `width` columns, breaking between
+// words. Continuation lines are indented; a word too long for a line of its
+// own is split wherever it has to be.
+pub fn wrap_words(words: &[String], width: usize) -> Vec<String> {
+    const INDENT: &str = "  ";
+    let width = width.max(INDENT.len() + 1);
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in words {
+        if !line.is_empty() && line.width() + 1 + word.width() <= width {
+            line.push(' ');
+            line.push_str(word);
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        let mut rest = word.as_str();
+        loop {
+            let indent = if lines.is_empty() { "" } else { INDENT };
+            if indent.width() + rest.width() <= width {
+                line = format!("{}{}", indent, rest);
+                break;
+            }
+            let (head, tail) = split_at_width(rest, width - indent.width());
+            lines.push(format!("{}{}", indent, head));
+            rest = tail;
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+// Splits before the first grapheme that would take `s` past `width` columns,
+// keeping at least one so the caller always makes progress.
+fn split_at_width(s: &str, width: usize) -> (&str, &str) {
+    let mut used = 0;
+    for (index, grapheme) in s.grapheme_indices(true) {
+        used += grapheme.width();
+        if used > width && index > 0 {
+            return s.split_at(index);
+        }
+    }
+    (s, "")
+}
+
+// Quotes an argument for a POSIX shell when it needs it.
+pub fn shell_quote(arg: &str) -> String {
+    let plain = !arg.is_empty() && arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c));
+    if plain {
+        arg.to_string()
     } else {
-        Color::Green
+        format!("'{}'", arg.replace('\'', "'\\''"))
     }
 }
 
-pub fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
+pub fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+// Puts `text` on the clipboard through the terminal (OSC 52), which works over
+// SSH too. Terminals without support ignore it; tmux passes it on when
+// allow-passthrough is on.
+pub fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    use std::io::Write;
+    let sequence = format!("\x1b]52;c;{}\x07", base64(text.as_bytes()));
+    let mut out = std::io::stdout();
+    if std::env::var_os("TMUX").is_some() {
+        write!(out, "\x1bPtmux;\x1b{}\x1b\\", sequence)?;
     } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
+        out.write_all(sequence.as_bytes())?;
     }
+    out.flush()
 }
 
 pub fn is_system_process(name: &str) -> bool {
@@ -182,6 +318,23 @@ pub fn update_history<T: Clone>(history: &mut VecDeque<T>, new_value: T, max_siz
     }
 }
 
+// Re-samples a history (oldest first) taken every `from` onto one taken every
+// `to`, lined up at the newest value, so histories from collectors running at
+// different rates can share an axis. Each point takes the nearest sample.
+pub fn resample_history(values: &[f64], from: std::time::Duration, to: std::time::Duration) -> Vec<f64> {
+    if values.is_empty() || from.is_zero() || to.is_zero() || from == to {
+        return values.to_vec();
+    }
+    let step = to.as_secs_f64() / from.as_secs_f64();
+    let newest = values.len() - 1;
+    let len = (newest as f64 / step).floor() as usize + 1;
+    let mut resampled: Vec<f64> = (0..len)
+        .map(|k| values[newest - ((k as f64 * step).round() as usize).min(newest)])
+        .collect();
+    resampled.reverse();
+    resampled
+}
+
 pub fn calculate_rate(current: u64, previous: u64, elapsed_secs: f64) -> u64 {
     if elapsed_secs <= 0.0 {
         return 0;
@@ -240,6 +393,30 @@ pub fn count_process_states(processes: &[crate::types::ProcessInfo]) -> (usize,
     (running, sleeping, zombie, other)
 }
 
+// Each zombie with its parent, the process that has to reap it, grouped by
+// parent. The parent is None when the process list doesn't include it.
+pub fn zombies_with_parents(processes: &[crate::types::ProcessInfo]) -> Vec<(&crate::types::ProcessInfo, Option<&crate::types::ProcessInfo>)> {
+    let pid_key = |pid: &str| pid.parse::<u32>().unwrap_or(0);
+    let mut zombies: Vec<_> = processes.iter()
+        .filter(|p| p.is_zombie())
+        .map(|zombie| {
+            let parent = zombie.parent.as_deref().and_then(|ppid| processes.iter().find(|p| p.pid == ppid));
+            (zombie, parent)
+        })
+        .collect();
+    zombies.sort_by_key(|(zombie, _)| (zombie.parent.as_deref().map_or(0, pid_key), pid_key(&zombie.pid)));
+    zombies
+}
+
+// The row a type-ahead jump lands on: the first name starting with `text`,
+// else the first containing it, ignoring case.
+pub fn type_ahead_match(processes: &[crate::types::ProcessInfo], text: &str) -> Option<usize> {
+    let text = text.to_lowercase();
+    let names: Vec<String> = processes.iter().map(|p| p.name.to_lowercase()).collect();
+    names.iter().position(|name| name.starts_with(&text))
+        .or_else(|| names.iter().position(|name| name.contains(&text)))
+}
+
 pub fn estimate_memory_per_core(mem_used: u64, cpu_cores: usize) -> u64 {
     if cpu_cores > 0 {
         mem_used / cpu_cores as u64
@@ -339,10 +516,63 @@ pub fn get_memory_breakdown(mem_available: u64, mem_total: u64) -> (u64, u64) {
     (mem_used, mem_available)
 }
 
+pub fn wsl_version_from_osrelease(osrelease: &str) -> Option<u8> {
+    let osrelease = osrelease.to_lowercase();
+    if !osrelease.contains("microsoft") {
+        return None;
+    }
+    
+    if osrelease.contains("wsl2") || osrelease.contains("microsoft-standard") {
+        Some(2)
+    } else {
+        Some(1)
+    }
+}
+
+pub fn detect_wsl() -> Option<u8> {
+    std::fs::read_to_string("/proc/sys/kernel/osrelease")
+        .ok()
+        .and_then(|osrelease| wsl_version_from_osrelease(&osrelease))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_zombies_with_parents() {
+        let process = |pid: &str, parent: &str, status: &str| crate::types::ProcessInfo {
+            pid: pid.to_string(),
+            parent: Some(parent.to_string()),
+            status: status.to_string(),
+            ..Default::default()
+        };
+        let processes = vec![
+            process("1", "0", "Sleeping"),
+            process("30", "20", "Zombie"),
+            process("20", "1", "Sleeping"),
+            process("12", "99", "Zombie"),
+            process("21", "20", "Zombie"),
+        ];
+        let zombies = zombies_with_parents(&processes);
+        let pids: Vec<(&str, Option<&str>)> = zombies.iter()
+            .map(|(zombie, parent)| (zombie.pid.as_str(), parent.map(|p| p.pid.as_str())))
+            .collect();
+        assert_eq!(pids, vec![("21", Some("20")), ("30", Some("20")), ("12", None)]);
+    }
+
+    #[test]
+    fn test_type_ahead_match() {
+        let processes: Vec<crate::types::ProcessInfo> = ["systemd", "nginx-worker", "Nginx", "sshd"].iter()
+            .map(|name| crate::types::ProcessInfo { name: name.to_string(), ..Default::default() })
+            .collect();
+        assert_eq!(type_ahead_match(&processes, "ngi"), Some(1));
+        assert_eq!(type_ahead_match(&processes, "NGINX"), Some(1));
+        assert_eq!(type_ahead_match(&processes, "worker"), Some(1));
+        assert_eq!(type_ahead_match(&processes, "shd"), Some(3));
+        assert_eq!(type_ahead_match(&processes, "bash"), None);
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(format_size(0), "0 B");
@@ -360,6 +590,21 @@ mod tests {
         assert_eq!(format_rate(1500), "1.5 KB/s");
     }
 
+    #[test]
+    fn test_format_growth() {
+        assert_eq!(format_growth(0), "0 B");
+        assert_eq!(format_growth(1536), "+1.5 KiB");
+        assert_eq!(format_growth(-512), "-512 B");
+    }
+
+    #[test]
+    fn test_format_rough_duration() {
+        assert_eq!(format_rough_duration(9 * 86400 + 5000), "9d");
+        assert_eq!(format_rough_duration(30 * 3600), "30h");
+        assert_eq!(format_rough_duration(40 * 60 + 59), "40m");
+        assert_eq!(format_rough_duration(5), "1m");
+    }
+
     #[test]
     fn test_safe_percentage() {
         assert_eq!(safe_percentage(50, 100), 50.0);
@@ -367,6 +612,39 @@ mod tests {
         assert_eq!(safe_percentage(100, 0), 0.0);
     }
 
+    #[test]
+    fn test_truncate_string_by_width() {
+        assert_eq!(truncate_string("nginx", 10), "nginx");
+        assert_eq!(truncate_string("postgres-worker", 10), "postgre...");
+        // Two columns per character: 3 fit before the ellipsis, not 7.
+        assert_eq!(truncate_string("プロセス監視ツール", 10), "プロセ...");
+        assert_eq!(truncate_string("çalışıyor-süreç", 8), "çalış...");
+        // A combining accent stays with its letter.
+        assert_eq!(truncate_string("cafe\u{301}-daemon", 7), "cafe\u{301}...");
+        // A wide character that doesn't fit whole leaves the column empty.
+        assert_eq!(truncate_string("監視ツール", 6), "監...");
+        assert_eq!(truncate_string("監視ツール", 2), "監");
+        assert_eq!(truncate_string("nginx", 0), "");
+
+        assert_eq!(pad_to_width("監視", 6), "監視  ");
+        assert_eq!(pad_to_width("postgres-worker", 10), "postgre...");
+        assert_eq!(pad_to_width("nginx", 10).width(), 10);
+    }
+
+    #[test]
+    fn test_wrap_command() {
+        let args: Vec<String> = ["java", "-Xmx4g", "-jar", "/opt/app/server.jar", "--name", "my app"].iter().map(|arg| shell_quote(arg)).collect();
+        assert_eq!(args[5], "'my app'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+        assert_eq!(wrap_words(&args, 21), vec!["java -Xmx4g -jar", "  /opt/app/server.jar", "  --name 'my app'"]);
+        // Too long for any line: split mid-word, continuation indented.
+        assert_eq!(wrap_words(&["--path=/a/very/long/directory".to_string()], 12), vec!["--path=/a/ve", "  ry/long/di", "  rectory"]);
+        assert_eq!(wrap_words(&[], 10), vec![""]);
+
+        assert_eq!(base64(b"puls"), "cHVscw==");
+        assert_eq!(base64(b"abc"), "YWJj");
+    }
+
     #[test]
     fn test_is_system_process() {
         assert!(is_system_process("kworker/0:1"));
@@ -374,4 +652,23 @@ mod tests {
         assert!(!is_system_process("firefox"));
         assert!(!is_system_process("puls"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_wsl_version_from_osrelease() {
+        assert_eq!(wsl_version_from_osrelease("5.15.153.1-microsoft-standard-WSL2"), Some(2));
+        assert_eq!(wsl_version_from_osrelease("4.4.0-19041-Microsoft"), Some(1));
+        assert_eq!(wsl_version_from_osrelease("6.8.0-45-generic"), None);
+    }
+
+    #[test]
+    fn test_resample_history() {
+        use std::time::Duration;
+        let second = Duration::from_secs(1);
+        // GPU sampled every 2s, system every 1s: each GPU reading covers two points.
+        assert_eq!(resample_history(&[40.0, 50.0, 60.0], 2 * second, second), vec![40.0, 40.0, 50.0, 50.0, 60.0]);
+        assert_eq!(resample_history(&[40.0, 45.0, 50.0, 55.0, 60.0], second, 2 * second), vec![40.0, 50.0, 60.0]);
+        assert_eq!(resample_history(&[40.0, 50.0], second, second), vec![40.0, 50.0]);
+        assert_eq!(resample_history(&[40.0, 50.0], Duration::ZERO, second), vec![40.0, 50.0]);
+        assert!(resample_history(&[], 2 * second, second).is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+// Restarts watched services when they go inactive, at most a set number of
+// times per hour each. Every action goes through the `log` macros, so it
+// shows in the footer and the Ctrl+L status log; units the watchdog could
+// not bring back are reported to the UI and raised as alerts.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc;
+
+use crate::system_service::SystemManager;
+use crate::types::AppMessage;
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+const RESTART_WINDOW: Duration = Duration::from_secs(3600);
+const DEFAULT_MAX_RESTARTS: u32 = 3;
+
+// "UNIT" or "UNIT:MAX_RESTARTS_PER_HOUR".
+#[derive(Clone, Debug, PartialEq)]
+pub struct WatchRule {
+    pub unit: String,
+    pub max_restarts: u32,
+}
+
+impl FromStr for WatchRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (unit, max_restarts) = match s.rsplit_once(':') {
+            Some((unit, max)) => {
+                let max = max.trim().parse::<u32>()
+                    .map_err(|_| format!("invalid restart limit '{}', expected UNIT[:MAX_PER_HOUR]", max))?;
+                (unit, max)
+            }
+            None => (s, DEFAULT_MAX_RESTARTS),
+        };
+        let unit = unit.trim().trim_end_matches(".service");
+        if unit.is_empty() {
+            return Err("expected UNIT[:MAX_PER_HOUR]".to_string());
+        }
+        Ok(Self { unit: unit.to_string(), max_restarts })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum WatchAction {
+    Restart { attempt: u32 },
+    GiveUp,
+}
+
+pub struct Watchdog {
+    rules: Vec<WatchRule>,
+    restarts: HashMap<String, VecDeque<Instant>>,
+    given_up: HashSet<String>,
+}
+
+impl Watchdog {
+    pub fn new(rules: Vec<WatchRule>) -> Self {
+        Self { rules, restarts: HashMap::new(), given_up: HashSet::new() }
+    }
+
+    // What to do about a unit seen in the given state. Giving up is only
+    // reported once per outage.
+    pub fn check(&mut self, unit: &str, active: bool, now: Instant) -> Option<WatchAction> {
+        let rule = self.rules.iter().find(|rule| rule.unit == unit)?;
+        if active {
+            self.given_up.remove(unit);
+            return None;
+        }
+
+        let history = self.restarts.entry(unit.to_string()).or_default();
+        while history.front().is_some_and(|&at| now.duration_since(at) >= RESTART_WINDOW) {
+            history.pop_front();
+        }
+        if (history.len() as u32) < rule.max_restarts {
+            history.push_back(now);
+            self.given_up.remove(unit);
+            return Some(WatchAction::Restart { attempt: history.len() as u32 });
+        }
+        self.given_up.insert(unit.to_string()).then_some(WatchAction::GiveUp)
+    }
+
+    // Watched units that are down and no longer being restarted.
+    pub fn down_units(&self) -> Vec<String> {
+        let mut units: Vec<String> = self.given_up.iter().cloned().collect();
+        units.sort();
+        units
+    }
+
+    fn poll(&mut self, sys_mgr: &SystemManager) {
+        let now = Instant::now();
+        for rule in self.rules.clone() {
+            let Some(active) = sys_mgr.is_service_active(&rule.unit) else { continue };
+            match self.check(&rule.unit, active, now) {
+                Some(WatchAction::Restart { attempt }) => {
+                    log::warn!("Watchdog: {} is inactive, restarting ({}/{} this hour)", rule.unit, attempt, rule.max_restarts);
+                    match sys_mgr.restart_service(&rule.unit) {
+                        Ok(_) => log::info!("Watchdog: restarted {}", rule.unit),
+                        Err(e) => log::error!("Watchdog: failed to restart {}: {}", rule.unit, e.trim()),
+                    }
+                }
+                Some(WatchAction::GiveUp) => {
+                    log::error!("Watchdog: {} is still inactive and reached its limit of {} restarts per hour, giving up", rule.unit, rule.max_restarts);
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+// Polls the watched units on the blocking pool until the UI goes away.
+pub async fn watchdog_loop(tx: mpsc::UnboundedSender<AppMessage>, rules: Vec<WatchRule>) {
+    let mut watchdog = Watchdog::new(rules);
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        watchdog = match tokio::task::spawn_blocking(move || {
+            watchdog.poll(&SystemManager::new());
+            watchdog
+        }).await {
+            Ok(watchdog) => watchdog,
+            Err(e) => {
+                log::error!("Service watchdog stopped: {}", e);
+                return;
+            }
+        };
+        if tx.send(AppMessage::Watchdog(watchdog.down_units())).is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watch_rule_parse() {
+        assert_eq!("nginx".parse(), Ok(WatchRule { unit: "nginx".to_string(), max_restarts: 3 }));
+        assert_eq!("sshd.service:5".parse(), Ok(WatchRule { unit: "sshd".to_string(), max_restarts: 5 }));
+        assert!("nginx:many".parse::<WatchRule>().is_err());
+        assert!(":2".parse::<WatchRule>().is_err());
+    }
+
+    #[test]
+    fn test_restart_limit() {
+        let mut watchdog = Watchdog::new(vec![WatchRule { unit: "web".to_string(), max_restarts: 2 }]);
+        let start = Instant::now();
+
+        assert_eq!(watchdog.check("other", false, start), None);
+        assert_eq!(watchdog.check("web", true, start), None);
+        assert_eq!(watchdog.check("web", false, start), Some(WatchAction::Restart { attempt: 1 }));
+        assert_eq!(watchdog.check("web", false, start + Duration::from_secs(10)), Some(WatchAction::Restart { attempt: 2 }));
+        assert_eq!(watchdog.check("web", false, start + Duration::from_secs(20)), Some(WatchAction::GiveUp));
+        assert_eq!(watchdog.check("web", false, start + Duration::from_secs(30)), None);
+        assert_eq!(watchdog.down_units(), vec!["web".to_string()]);
+
+        // Brought back by hand: no longer reported.
+        assert_eq!(watchdog.check("web", true, start + Duration::from_secs(40)), None);
+        assert!(watchdog.down_units().is_empty());
+
+        // The oldest restart falls out of the window an hour later.
+        let later = start + RESTART_WINDOW + Duration::from_secs(5);
+        assert_eq!(watchdog.check("web", false, later), Some(WatchAction::Restart { attempt: 2 }));
+    }
+}
@@ -0,0 +1,224 @@
+#![cfg_attr(not(windows), allow(dead_code))]
+
+// Windows counterparts of the systemctl/journalctl calls in system_service.
+// The parsers are platform independent so they can be tested anywhere; the
+// commands themselves are only invoked when running on Windows.
+
+use std::process::Command;
+use crate::types::{LogEntry, ServiceInfo};
+
+pub fn is_elevated() -> bool {
+    // `net session` only succeeds from an elevated prompt.
+    Command::new("net")
+        .arg("session")
+        .output()
+        .map(|out| out.status.success())
+        .unwrap_or(false)
+}
+
+pub fn get_services(has_admin: bool) -> Vec<ServiceInfo> {
+    let output = match Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "Get-CimInstance Win32_Service | Select-Object Name,DisplayName,State,StartMode | ConvertTo-Csv -NoTypeInformation",
+        ])
+        .output()
+    {
+        Ok(output) => output,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut services = parse_service_csv(&String::from_utf8_lossy(&output.stdout), has_admin);
+    services.sort_by_key(|s| s.name.to_lowercase());
+    services
+}
+
+pub fn service_action(action: &str, service_name: &str) -> Result<(), String> {
+    match action {
+        "start" => run_sc(&["start", service_name]),
+        "stop" => run_sc(&["stop", service_name]),
+        "restart" => {
+            run_sc(&["stop", service_name])?;
+            run_sc(&["start", service_name])
+        }
+        "enable" => run_sc(&["config", service_name, "start=", "auto"]),
+        "disable" => run_sc(&["config", service_name, "start=", "disabled"]),
+        _ => Err(format!("Unsupported service action: {}", action)),
+    }
+}
+
+pub fn get_service_status(service_name: &str) -> String {
+    match Command::new("sc").args(["queryex", service_name]).output() {
+        Ok(out) => String::from_utf8_lossy(&out.stdout).to_string(),
+        Err(e) => format!("Error getting status: {}", e),
+    }
+}
+
+pub fn get_logs(limit: usize, filter: Option<&str>) -> Vec<LogEntry> {
+    let output = match Command::new("wevtutil")
+        .args(["qe", "System", &format!("/c:{}", limit), "/rd:true", "/f:text"])
+        .output()
+    {
+        Ok(output) => output,
+        Err(_) => return Vec::new(),
+    };
+
+    let logs = parse_event_log(&String::from_utf8_lossy(&output.stdout));
+    match filter {
+        Some(f) if !f.is_empty() => {
+            let needle = f.to_lowercase();
+            logs.into_iter()
+                .filter(|log| log.message.to_lowercase().contains(&needle) || log.service.to_lowercase().contains(&needle))
+                .collect()
+        }
+        _ => logs,
+    }
+}
+
+fn run_sc(args: &[&str]) -> Result<(), String> {
+    let output = Command::new("sc")
+        .args(args)
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        // sc reports failures on stdout rather than stderr.
+        Err(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
+pub fn parse_service_csv(csv: &str, has_admin: bool) -> Vec<ServiceInfo> {
+    csv.lines()
+        .skip(1)
+        .map(split_csv_line)
+        .filter(|fields| fields.len() >= 4)
+        .map(|fields| {
+            let status = match fields[2].as_str() {
+                "Running" => "Running",
+                "Start Pending" => "Starting",
+                "Stop Pending" => "Stopping",
+                "Paused" => "Paused",
+                _ => "Stopped",
+            };
+            let is_running = status == "Running" || status == "Starting";
+
+            ServiceInfo {
+                name: fields[0].clone(),
+                description: fields[1].clone(),
+                status: status.to_string(),
+                enabled: fields[3] == "Auto",
+                can_start: !is_running && has_admin,
+                can_stop: is_running && has_admin,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+pub fn parse_event_log(text: &str) -> Vec<LogEntry> {
+    let mut logs = Vec::new();
+    let mut current: Option<LogEntry> = None;
+    let mut in_description = false;
+
+    for line in text.lines() {
+        if line.starts_with("Event[") {
+            logs.extend(current.take());
+            current = Some(LogEntry { level: "INFO".to_string(), ..Default::default() });
+            in_description = false;
+            continue;
+        }
+
+        let entry = match current.as_mut() {
+            Some(entry) => entry,
+            None => continue,
+        };
+
+        if in_description {
+            if !line.trim().is_empty() {
+                if !entry.message.is_empty() {
+                    entry.message.push(' ');
+                }
+                entry.message.push_str(line.trim());
+            }
+            continue;
+        }
+
+        let (key, value) = match line.trim().split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => continue,
+        };
+
+        match key {
+            "Source" => entry.service = value.to_string(),
+            "Date" => entry.timestamp = value.replace('T', " ").chars().take(19).collect(),
+            "Level" => {
+                entry.level = match value {
+                    "Error" | "Critical" => "ERROR",
+                    "Warning" => "WARNING",
+                    _ => "INFO",
+                }.to_string();
+            }
+            "Description" => {
+                entry.message = value.to_string();
+                in_description = true;
+            }
+            _ => {}
+        }
+    }
+
+    logs.extend(current);
+    logs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_service_csv() {
+        let csv = "\"Name\",\"DisplayName\",\"State\",\"StartMode\"\n\"Spooler\",\"Print Spooler\",\"Running\",\"Auto\"\n\"Fax\",\"Fax, \"\"legacy\"\"\",\"Stopped\",\"Manual\"\n";
+        let services = parse_service_csv(csv, true);
+        assert_eq!(services.len(), 2);
+        assert_eq!(services[0].name, "Spooler");
+        assert!(services[0].enabled && services[0].can_stop);
+        assert_eq!(services[1].description, "Fax, \"legacy\"");
+        assert_eq!(services[1].status, "Stopped");
+        assert!(services[1].can_start);
+    }
+
+    #[test]
+    fn test_parse_event_log() {
+        let text = "Event[0]:\n  Log Name: System\n  Source: Service Control Manager\n  Date: 2024-05-01T10:20:30.123\n  Level: Warning\n  Description: \nThe Print Spooler service\nentered the stopped state.\n\nEvent[1]:\n  Source: Kernel-Power\n  Date: 2024-05-01T09:00:00.000\n  Level: Information\n  Description: Resumed\n";
+        let logs = parse_event_log(text);
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].timestamp, "2024-05-01 10:20:30");
+        assert_eq!(logs[0].level, "WARNING");
+        assert_eq!(logs[0].message, "The Print Spooler service entered the stopped state.");
+        assert_eq!(logs[1].service, "Kernel-Power");
+        assert_eq!(logs[1].message, "Resumed");
+    }
+}